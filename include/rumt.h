@@ -0,0 +1,110 @@
+/* rumt C ABI. cbindgen --config cbindgen.toml --output include/rumt.h ile üretilir. */
+
+#ifndef RUMT_H
+#define RUMT_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+#define RUMT_OK 0
+
+#define RUMT_ERR_INVALID_ARGUMENT -1
+
+#define RUMT_ERR_NOT_INITIALIZED -2
+
+#define RUMT_ERR_UNKNOWN_CODEC -3
+
+#define RUMT_ERR_CODEC -4
+
+/**
+ * `rumt_init` başarısız oldu (ör. env yükleyicisi veya env kilidi) ya da FFI runtime'ı
+ * başlatılamadı.
+ */
+#define RUMT_ERR_INIT_FAILED -5
+
+/**
+ * Çağrı bir listener callback'inden veya bir tokio runtime thread'inden yapıldı.
+ */
+#define RUMT_ERR_REENTRANT -6
+
+/**
+ * Event adı runtime'ın `NamePolicy` kurallarına uymuyor.
+ */
+#define RUMT_ERR_INVALID_EVENT_NAME -7
+
+/**
+ * Runtime'ın `AuthorizationPolicy`'si kaydı reddetti.
+ */
+#define RUMT_ERR_UNAUTHORIZED -8
+
+/**
+ * Kayıt bus'ın başka bir kuralıyla reddedildi (ör. teslim garantisi).
+ */
+#define RUMT_ERR_REJECTED -9
+
+/**
+ * `rumt_init` runtime `rumt_shutdown` ile kapatılmadan tekrar çağrıldı.
+ */
+#define RUMT_ERR_ALREADY_INITIALIZED -10
+
+/**
+ * Listener callback'i: `user_data`, event adı ve codec ile kodlanmış payload.
+ * Callback içinden `rumt_*` fonksiyonları çağrılamaz (`RUMT_ERR_REENTRANT`).
+ */
+typedef void (*RumtListenerCallback)(void *user_data,
+                                     const char *event_name,
+                                     const uint8_t *data,
+                                     size_t len);
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+/**
+ * Runtime'ı başlatır. Başlatılamazsa `RUMT_ERR_INIT_FAILED`, runtime zaten çalışıyorsa
+ * `RUMT_ERR_ALREADY_INITIALIZED` döner.
+ *
+ * # Safety
+ * Parametreler geçerli, null ile biten UTF-8 C string'leri olmalıdır.
+ */
+int rumt_init(const char *app_name, const char *company, const char *qualifier);
+
+/**
+ * `data` byte'larını `codec` ile çözüp `event_name` event'i olarak yayınlar.
+ * Handler'lar tamamlanana kadar döner.
+ *
+ * # Safety
+ * String parametreler geçerli C string'leri, `data` en az `len` byte okunabilir olmalıdır
+ * (`len == 0` ise null olabilir).
+ */
+int rumt_emit(const char *event_name, const uint8_t *data, size_t len, const char *codec);
+
+/**
+ * `event_name` event'ine callback bağlar. Payload `codec` ile kodlanır; tipi uyuşmayan
+ * payload'lar callback'e iletilmez. Kayıt Rust tarafındaki kayıtlar gibi ad ve yetkilendirme
+ * politikasıyla denetlenir. Başarıda pozitif listener kimliği, hatada negatif kod döner.
+ *
+ * # Safety
+ * String parametreler geçerli C string'leri olmalıdır. `user_data` listener kaldırılana
+ * kadar geçerli kalmalı ve callback'in çağrıldığı thread'lerden erişilebilir olmalıdır.
+ */
+int64_t rumt_register_listener(const char *event_name,
+                               const char *codec,
+                               RumtListenerCallback callback,
+                               void *user_data);
+
+/**
+ * `rumt_register_listener` ile eklenen listener'ı kaldırır.
+ */
+int rumt_unregister_listener(int64_t listener_id);
+
+/**
+ * Runtime'ı kapatır; tüm listener'lar kaldırılır.
+ */
+int rumt_shutdown(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* RUMT_H */
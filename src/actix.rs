@@ -0,0 +1,103 @@
+use futures::future::BoxFuture;
+use std::sync::Arc;
+
+use crate::actor::{ActorSubscription, AskMailbox, Mailbox, subscribe_actor, subscribe_actor_replies};
+use crate::error::Result;
+use crate::event_bus::RuntimeEvent;
+use crate::log::Level;
+
+/// actix `Recipient<M>`'in rumt'un kullandığı kısmı. rumt `actix` crate'ine bağlı değildir;
+/// uygulama bu trait'i kendi aktörünün adresi için birkaç satırla uygular:
+///
+/// ```rust,ignore
+/// struct Pricing(actix::Recipient<Quote>);
+///
+/// impl ActixRecipient<Quote> for Pricing {
+///     type Reply = PriceQuoted;
+///
+///     fn do_send(&self, message: Quote) {
+///         self.0.do_send(message);
+///     }
+///
+///     fn send(&self, message: Quote) -> BoxFuture<'static, Result<PriceQuoted, String>> {
+///         let reply = self.0.send(message);
+///         Box::pin(async move { reply.await.map_err(|e| e.to_string()) })
+///     }
+/// }
+/// ```
+pub trait ActixRecipient<M>: Send + Sync + 'static {
+    /// Mesajın cevabı (`actix::Message::Result`).
+    type Reply: Send + Sync + 'static;
+
+    /// `Recipient::do_send`: cevap beklenmez, posta kutusu doluysa da iletilir.
+    fn do_send(&self, message: M);
+
+    /// `Recipient::send`: cevap beklenir; hata `MailboxError`'un metnidir.
+    fn send(&self, message: M) -> BoxFuture<'static, std::result::Result<Self::Reply, String>>;
+}
+
+struct Recipient<A>(A);
+
+impl<M, A: ActixRecipient<M>> Mailbox<M> for Recipient<A> {
+    fn deliver(&self, message: M) -> BoxFuture<'static, ()> {
+        self.0.do_send(message);
+        Box::pin(async {})
+    }
+}
+
+impl<M, A: ActixRecipient<M>> AskMailbox<M, A::Reply> for Recipient<A> {
+    fn ask(&self, message: M) -> BoxFuture<'static, Option<A::Reply>> {
+        let reply = self.0.send(message);
+        Box::pin(async move {
+            match reply.await {
+                Ok(reply) => Some(reply),
+                Err(e) => {
+                    crate::log(Level::Warn, "rumt::actix", format!("actor did not reply: {e}")).await;
+                    None
+                }
+            }
+        })
+    }
+}
+
+/// `event` payload'larını `map` ile mesaja çevirip aktöre `do_send` ile iletir. Runtime
+/// başlatılmamışsa `Error::NotInitialized` döner.
+///
+/// ```rust,ignore
+/// let pricing = Pricing(PricingActor.start().recipient());
+/// let subscription = rumt::actix::subscribe("pricing", QUOTE_REQUESTED, pricing, |req: Arc<QuoteRequested>| {
+///     Quote { sku: req.sku.clone() }
+/// })
+/// .await?;
+/// ```
+pub async fn subscribe<T, M, F>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    recipient: impl ActixRecipient<M>,
+    map: F,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    F: Fn(Arc<T>) -> M + Send + Sync + 'static,
+{
+    subscribe_actor(tag, event, Recipient(recipient), map).await
+}
+
+/// `subscribe` gibi, ama mesajlar `send` ile iletilir ve aktörün cevabı `replies` event'i
+/// olarak yayınlanır. Posta kutusu hataları `rumt::actix` hedefiyle loglanır.
+pub async fn subscribe_replies<T, M, A, F>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    replies: RuntimeEvent,
+    recipient: A,
+    map: F,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    A: ActixRecipient<M>,
+    F: Fn(Arc<T>) -> M + Send + Sync + 'static,
+{
+    subscribe_actor_replies(tag, event, replies, Recipient(recipient), map).await
+}
@@ -0,0 +1,181 @@
+use std::{marker::PhantomData, sync::Arc};
+use tokio::sync::{mpsc, oneshot};
+
+use crate::error::Result;
+use crate::event_bus::{
+    RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg,
+};
+use crate::rt::{MaybeSend, MaybeSendFuture, MaybeSync};
+
+/// Bir aktörün posta kutusu.
+///
+/// tokio kanalları için hazır implementasyon vardır; actix ve ractor aktörleri için `actix` ve
+/// `ractor` feature'larındaki `rumt::actix` ve `rumt::ractor` adaptörleri kullanılabilir. Diğer
+/// çerçeveler için implementasyon birkaç satırdır:
+///
+/// ```rust,ignore
+/// struct FlumeMailbox<M>(flume::Sender<M>);
+///
+/// impl<M: Send + 'static> Mailbox<M> for FlumeMailbox<M> {
+///     fn deliver(&self, message: M) -> BoxFuture<'static, ()> {
+///         let sender = self.0.clone();
+///         Box::pin(async move {
+///             let _ = sender.send_async(message).await;
+///         })
+///     }
+/// }
+/// ```
+pub trait Mailbox<M>: MaybeSend + MaybeSync + 'static {
+    fn deliver(&self, message: M) -> MaybeSendFuture<'static, ()>;
+}
+
+impl<M: Send + 'static> Mailbox<M> for mpsc::Sender<M> {
+    fn deliver(&self, message: M) -> MaybeSendFuture<'static, ()> {
+        let sender = self.clone();
+        // Posta kutusu doluysa handler beklemeye girer (backpressure)
+        Box::pin(async move {
+            let _ = sender.send(message).await;
+        })
+    }
+}
+
+impl<M: Send + 'static> Mailbox<M> for mpsc::UnboundedSender<M> {
+    fn deliver(&self, message: M) -> MaybeSendFuture<'static, ()> {
+        let _ = self.send(message);
+        Box::pin(async {})
+    }
+}
+
+/// Cevap bekleyen posta kutusu (actix `Recipient::send`, ractor `call` gibi).
+///
+/// Aktörün cevabı yanıt kanalıyla dönen tokio aktörleri için hazır implementasyon vardır.
+pub trait AskMailbox<M, R>: MaybeSend + MaybeSync + 'static {
+    /// Posta kutusu kapalıysa veya aktör cevap vermeden durursa `None` döner.
+    fn ask(&self, message: M) -> MaybeSendFuture<'static, Option<R>>;
+}
+
+impl<M: Send + 'static, R: Send + 'static> AskMailbox<M, R> for mpsc::Sender<(M, oneshot::Sender<R>)> {
+    fn ask(&self, message: M) -> MaybeSendFuture<'static, Option<R>> {
+        let sender = self.clone();
+        Box::pin(async move {
+            let (reply, replied) = oneshot::channel();
+            sender.send((message, reply)).await.ok()?;
+            replied.await.ok()
+        })
+    }
+}
+
+/// Aktörün bus'taki aboneliği. `unsubscribe` çağrılana kadar aktif kalır.
+pub struct ActorSubscription {
+    tag: String,
+}
+
+impl ActorSubscription {
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    pub async fn unsubscribe(self) {
+        let tag = self.tag;
+        // Runtime zaten kapatıldıysa kaldırılacak dinleyici yoktur
+        let _ = RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_all_listeners_by_tag(&tag)).await;
+    }
+}
+
+/// `event` payload'larını `map` ile aktör mesajına çevirip posta kutusuna iletir. Runtime
+/// başlatılmamışsa `Error::NotInitialized` döner.
+pub async fn subscribe_actor<T, M, F>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    mailbox: impl Mailbox<M>,
+    map: F,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    F: Fn(Arc<T>) -> M + MaybeSend + MaybeSync + 'static,
+{
+    let tag = tag.into();
+    let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+        match args.downcast::<Arc<T>>() {
+            Some(payload) => mailbox.deliver(map(Arc::clone(payload))),
+            None => Box::pin(async {}) as MaybeSendFuture<'static, ()>,
+        }
+    });
+
+    let listener = RuntimeEventListener::new(tag.clone(), handler);
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await?;
+    Ok(ActorSubscription { tag })
+}
+
+/// `subscribe_actor` gibi, ama aktörün her cevabı `replies` event'i olarak yayınlanır; cevap,
+/// tetikleyen emit'in zincirine bağlanır. Cevapsız kalan mesajlar yayın üretmez.
+pub async fn subscribe_actor_replies<T, M, R, F>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    replies: RuntimeEvent,
+    mailbox: impl AskMailbox<M, R>,
+    map: F,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    R: Send + Sync + 'static,
+    F: Fn(Arc<T>) -> M + MaybeSend + MaybeSync + 'static,
+{
+    let tag = tag.into();
+    let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+        let Some(payload) = args.downcast::<Arc<T>>() else {
+            return Box::pin(async {}) as MaybeSendFuture<'static, ()>;
+        };
+        let (reply, replies) = (mailbox.ask(map(Arc::clone(payload))), replies.clone());
+        Box::pin(async move {
+            if let Some(reply) = reply.await {
+                crate::global::emit_event(replies, reply).await;
+            }
+        }) as MaybeSendFuture<'static, ()>
+    });
+
+    let listener = RuntimeEventListener::new(tag.clone(), handler);
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await?;
+    Ok(ActorSubscription { tag })
+}
+
+/// Aktör cevaplarını bus'a event olarak yayınlayan posta kutusu.
+/// Aktöre "cevabı buraya gönder" adresi olarak verilebilir.
+pub struct EventPublisher<T> {
+    event: RuntimeEvent,
+    _payload: PhantomData<fn(T)>,
+}
+
+impl<T: Send + Sync + 'static> EventPublisher<T> {
+    pub fn new(event: RuntimeEvent) -> Self {
+        Self {
+            event,
+            _payload: PhantomData,
+        }
+    }
+
+    pub async fn publish(&self, reply: T) {
+        crate::global::emit_event(self.event.clone(), reply).await;
+    }
+
+    /// Kanaldan gelen her cevabı event olarak yayınlayan bir task başlatır.
+    /// Kanalın tüm gönderenleri kapandığında task sona erer ve dönen alıcı tamamlanır.
+    pub fn pump(self, mut replies: mpsc::Receiver<T>) -> oneshot::Receiver<()> {
+        let (done, finished) = oneshot::channel();
+        crate::rt::spawn(async move {
+            while let Some(reply) = replies.recv().await {
+                self.publish(reply).await;
+            }
+            let _ = done.send(());
+        });
+        finished
+    }
+}
+
+impl<T: Send + Sync + 'static> Mailbox<T> for EventPublisher<T> {
+    fn deliver(&self, message: T) -> MaybeSendFuture<'static, ()> {
+        Box::pin(crate::global::emit_event(self.event.clone(), message))
+    }
+}
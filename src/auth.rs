@@ -0,0 +1,191 @@
+use std::sync::Arc;
+
+use crate::crypto::{constant_time_eq, sha256};
+use crate::error::{Error, Result};
+use crate::transport::FrameFilter;
+
+/// Bir istemcinin dinleyebileceği ve yayınlayabileceği eventler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ClientPermissions {
+    pub subscribe: FrameFilter,
+    pub emit: FrameFilter,
+}
+
+impl ClientPermissions {
+    /// Hiçbir event'e izin vermez; izinler `subscribe`/`emit` alanlarıyla açılır.
+    pub fn none() -> Self {
+        Self { subscribe: FrameFilter::Events(Vec::new()), emit: FrameFilter::Events(Vec::new()) }
+    }
+
+    pub fn subscribe(mut self, filter: FrameFilter) -> Self {
+        self.subscribe = filter;
+        self
+    }
+
+    pub fn emit(mut self, filter: FrameFilter) -> Self {
+        self.emit = filter;
+        self
+    }
+}
+
+/// Doğrulanmış bir gateway istemcisi.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Client {
+    pub id: String,
+    pub permissions: ClientPermissions,
+}
+
+impl Client {
+    pub fn can_subscribe(&self, event: &str) -> bool {
+        self.permissions.subscribe.matches(event)
+    }
+
+    pub fn can_emit(&self, event: &str) -> bool {
+        self.permissions.emit.matches(event)
+    }
+
+    /// İzin yoksa `Error::Unauthorized` döner.
+    pub fn check_emit(&self, event: &str) -> Result<()> {
+        if self.can_emit(event) {
+            Ok(())
+        } else {
+            Err(Error::Unauthorized(format!("client `{}` may not emit `{event}`", self.id)))
+        }
+    }
+
+    pub fn check_subscribe(&self, event: &str) -> Result<()> {
+        if self.can_subscribe(event) {
+            Ok(())
+        } else {
+            Err(Error::Unauthorized(format!("client `{}` may not subscribe to `{event}`", self.id)))
+        }
+    }
+}
+
+/// Bus'ı süreç dışına açan gateway'ler (`rumt::ingress` HTTP sunucusu, `rumt::ipc` Unix soketi)
+/// için token tabanlı kimlik doğrulama.
+///
+/// Token'lar bellekte yalnızca SHA-256 özetleriyle tutulur ve sabit sürede karşılaştırılır.
+/// Her token bir istemciye, her istemci de kendi izin listesine bağlıdır; bilinmeyen token
+/// `Error::Unauthorized` döner. Gateway'ler token'ı ve izinleri aynı katmanda denetler: izinsiz
+/// `emit` yayınlanmaz, izinsiz `subscribe` reddedilir.
+///
+/// rumt kendi TLS veya noise uygulamasını içermez. IPC bağlantıları `IpcGateway::encryption`
+/// ile uygulamanın sağladığı bir şifreleme katmanına sarılabilir, sokete erişim ayrıca dosya
+/// izinleriyle sınırlanır; dışa açık HTTP gateway'lerinin önüne TLS sonlandıran bir proxy
+/// konmalıdır.
+///
+/// ```rust
+/// # use rumt::auth::{ClientPermissions, GatewayAuth};
+/// # use rumt::transport::FrameFilter;
+/// let auth = GatewayAuth::new().client(
+///     "dashboard",
+///     "tok_3f9a",
+///     ClientPermissions::none()
+///         .subscribe(FrameFilter::Prefix("metrics.".into()))
+///         .emit(FrameFilter::events(["dashboard.refresh"])),
+/// );
+/// let client = auth.authenticate("tok_3f9a").unwrap();
+/// assert!(client.can_subscribe("metrics.cpu"));
+/// assert!(!client.can_emit("order.created"));
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GatewayAuth {
+    clients: Vec<([u8; 32], Arc<Client>)>,
+}
+
+impl GatewayAuth {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `token` ile doğrulanacak istemciyi ekler. Aynı token tekrar eklenirse yenisi geçerli olur.
+    pub fn client(mut self, id: impl Into<String>, token: &str, permissions: ClientPermissions) -> Self {
+        let digest = sha256(token.as_bytes());
+        self.clients.retain(|(existing, _)| *existing != digest);
+        self.clients.push((digest, Arc::new(Client { id: id.into(), permissions })));
+        self
+    }
+
+    pub fn authenticate(&self, token: &str) -> Result<Arc<Client>> {
+        let digest = sha256(token.as_bytes());
+        // Eşleşme bulunsa da tüm liste taranır ki süre token'ın sırasını ele vermesin
+        let mut found = None;
+        for (expected, client) in &self.clients {
+            if constant_time_eq(expected, &digest) {
+                found = Some(Arc::clone(client));
+            }
+        }
+        found.ok_or_else(|| Error::Unauthorized("unknown token".into()))
+    }
+
+    /// `Authorization: Bearer <token>` başlık değerini doğrular.
+    pub fn authenticate_bearer(&self, authorization: Option<&str>) -> Result<Arc<Client>> {
+        let token = bearer_token(authorization).ok_or_else(|| Error::Unauthorized("missing bearer token".into()))?;
+        self.authenticate(token)
+    }
+}
+
+/// Gateway'de istenen işlem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub(crate) enum Action {
+    Subscribe,
+    Emit,
+}
+
+/// Gateway erişiminin reddedilme sebebi; HTTP'de `401` ve `403`'e karşılık gelir.
+#[derive(Debug)]
+pub(crate) enum Denied {
+    Unauthenticated(Error),
+    Forbidden(Error),
+}
+
+/// Gateway'lerin ortak erişim katmanı. `auth` verilmemişse gateway herkese açıktır ve
+/// istemci anonimdir (`None`).
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Gate {
+    auth: Option<GatewayAuth>,
+}
+
+impl Gate {
+    pub(crate) fn new(auth: Option<GatewayAuth>) -> Self {
+        Self { auth }
+    }
+
+    /// Gateway'e bağlanmak için token gerekip gerekmediği.
+    pub(crate) fn requires_token(&self) -> bool {
+        self.auth.is_some()
+    }
+
+    /// Token'ı doğrular; gateway açıksa token'a bakılmaz.
+    pub(crate) fn authenticate(&self, token: Option<&str>) -> Result<Option<Arc<Client>>> {
+        match &self.auth {
+            Some(auth) => {
+                let token = token.ok_or_else(|| Error::Unauthorized("missing token".into()))?;
+                auth.authenticate(token).map(Some)
+            }
+            None => Ok(None),
+        }
+    }
+
+    /// Doğrulanmış istemcinin `event` için `action` izni; anonim istemcilere her şey açıktır.
+    pub(crate) fn check(client: Option<&Client>, action: Action, event: &str) -> Result<()> {
+        match (client, action) {
+            (None, _) => Ok(()),
+            (Some(client), Action::Subscribe) => client.check_subscribe(event),
+            (Some(client), Action::Emit) => client.check_emit(event),
+        }
+    }
+
+    /// Her isteği ayrı doğrulanan gateway'ler için ikisi birden.
+    pub(crate) fn admit(&self, token: Option<&str>, action: Action, event: &str) -> Result<Option<Arc<Client>>, Denied> {
+        let client = self.authenticate(token).map_err(Denied::Unauthenticated)?;
+        Self::check(client.as_deref(), action, event).map_err(Denied::Forbidden)?;
+        Ok(client)
+    }
+}
+
+/// `Authorization: Bearer <token>` başlık değerindeki token.
+pub(crate) fn bearer_token(authorization: Option<&str>) -> Option<&str> {
+    authorization.and_then(|value| value.trim().strip_prefix("Bearer ")).map(str::trim)
+}
@@ -0,0 +1,131 @@
+use std::{
+    sync::{Arc, Mutex as StdMutex, MutexGuard as StdMutexGuard},
+    time::Duration,
+};
+
+use crate::clock;
+use crate::event_bus::{RuntimeEvent, RuntimeEventListenerHandlerArg};
+use crate::rt::MaybeSendFuture;
+use crate::telemetry::event_name;
+
+/// `rumt::coalesce` ile bir event için açılan gruplama ayarları. Pencerenin ilk emit'inden
+/// `window` kadar sonra veya grupta `max_items` payload biriktiğinde (hangisi önce olursa)
+/// grup tek seferde teslim edilir.
+///
+/// ```rust,ignore
+/// rumt::coalesce::<Quote>(QUOTE, Coalesce::new(Duration::from_millis(1)).max_items(512)).await?;
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Coalesce {
+    pub window: Duration,
+    pub max_items: usize,
+}
+
+impl Coalesce {
+    pub fn new(window: Duration) -> Self {
+        Self { window, max_items: usize::MAX }
+    }
+
+    /// Grup bu kadar payload'a ulaştığında pencere beklenmeden teslim edilir (en az 1).
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = max_items.max(1);
+        self
+    }
+}
+
+/// Gruplanan event'in batch handler'larının dinlediği event: `market.quote` için
+/// `market.quote.batch`. Payload'ı `Vec<Arc<T>>`'dir.
+pub fn batch_event(event: &RuntimeEvent) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: format!("{}.batch", event_name(event)) }
+}
+
+type Flush = Box<dyn Fn(Vec<Arc<dyn RuntimeEventListenerHandlerArg>>) -> MaybeSendFuture<'static, ()> + Send + Sync>;
+
+struct Pending {
+    // Teslim edilen her grupta artar; eski grubun zamanlayıcısı yeni grubu erken teslim etmez
+    generation: u64,
+    items: Vec<Arc<dyn RuntimeEventListenerHandlerArg>>,
+}
+
+impl Pending {
+    fn take(&mut self) -> Vec<Arc<dyn RuntimeEventListenerHandlerArg>> {
+        self.generation += 1;
+        std::mem::take(&mut self.items)
+    }
+}
+
+/// Bir event'in henüz teslim edilmemiş payload grubu. Payload'lar dispatch'teki gibi `Arc<T>`
+/// sarmalayan `Arc<dyn Arg>` olarak saklanır; teslimde `Vec<Arc<T>>`'ye toplanır.
+pub(crate) struct Coalescer {
+    settings: Coalesce,
+    pending: StdMutex<Pending>,
+    flush: Flush,
+}
+
+impl Coalescer {
+    pub(crate) fn new<T: Send + Sync + 'static>(event: &RuntimeEvent, settings: Coalesce) -> Self {
+        let target = batch_event(event);
+        let flush: Flush = Box::new(move |items| {
+            // Başka tipte (ör. `map_payload` ile dönüştürülmüş) payload'lar gruba girmez
+            let batch: Vec<Arc<T>> = items.iter().filter_map(|item| (**item).downcast::<Arc<T>>().cloned()).collect();
+            let target = target.clone();
+            Box::pin(async move {
+                if !batch.is_empty() {
+                    crate::global::emit_internal(target, batch).await;
+                }
+            })
+        });
+        Self {
+            settings,
+            pending: StdMutex::new(Pending { generation: 0, items: Vec::new() }),
+            flush,
+        }
+    }
+
+    /// Payload'ı gruba ekler. Grup dolduysa hemen teslim edilir; grubun ilk payload'ı pencere
+    /// sonunda teslim edecek zamanlayıcıyı başlatır.
+    pub(crate) async fn push(self: &Arc<Self>, payload: Arc<dyn RuntimeEventListenerHandlerArg>) {
+        let (full, opened) = {
+            let mut pending = self.lock();
+            pending.items.push(payload);
+            if pending.items.len() >= self.settings.max_items {
+                (Some(pending.take()), None)
+            } else {
+                (None, (pending.items.len() == 1).then_some(pending.generation))
+            }
+        };
+        if let Some(generation) = opened {
+            let coalescer = Arc::clone(self);
+            crate::rt::spawn(async move {
+                clock::sleep(coalescer.settings.window).await;
+                coalescer.expire(generation).await;
+            });
+        }
+        if let Some(items) = full {
+            (self.flush)(items).await;
+        }
+    }
+
+    /// Bekleyen grubu pencereyi beklemeden teslim eder; `shutdown_runtime` kullanır.
+    pub(crate) async fn flush_now(&self) {
+        let items = self.lock().take();
+        if !items.is_empty() {
+            (self.flush)(items).await;
+        }
+    }
+
+    async fn expire(&self, generation: u64) {
+        let items = {
+            let mut pending = self.lock();
+            if pending.generation != generation {
+                return;
+            }
+            pending.take()
+        };
+        (self.flush)(items).await;
+    }
+
+    fn lock(&self) -> StdMutexGuard<'_, Pending> {
+        self.pending.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
@@ -0,0 +1,133 @@
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use crate::codec::{CodecError, PayloadCodec};
+use crate::error::Error;
+use crate::event_bus::{
+    RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg,
+};
+use crate::rt::{MaybeSend, MaybeSendFuture, MaybeSync};
+
+/// Bridge'in event'leri ilettiği dış event sistemi (Tauri webview, Electron IPC vb.).
+///
+/// Tauri için örnek:
+///
+/// ```rust,ignore
+/// struct WebviewSink(tauri::AppHandle);
+///
+/// impl BridgeSink for WebviewSink {
+///     fn send(&self, name: &str, payload: Vec<u8>) -> BoxFuture<'static, ()> {
+///         let _ = self.0.emit(name, String::from_utf8_lossy(&payload).into_owned());
+///         Box::pin(async {})
+///     }
+/// }
+///
+/// // Webview'den gelen eventler:
+/// let bridge_clone = Arc::clone(&bridge);
+/// app.listen_any("order:created", move |e| {
+///     let bridge = Arc::clone(&bridge_clone);
+///     let payload = e.payload().as_bytes().to_vec();
+///     tauri::async_runtime::spawn(async move {
+///         let _ = bridge.handle_incoming("order:created", &payload).await;
+///     });
+/// });
+/// ```
+pub trait BridgeSink: MaybeSend + MaybeSync + 'static {
+    fn send(&self, name: &str, payload: Vec<u8>) -> MaybeSendFuture<'static, ()>;
+}
+
+type InboundRoute = Box<dyn Fn(&[u8]) -> Result<MaybeSendFuture<'static, ()>, CodecError> + Send + Sync>;
+
+tokio::task_local! {
+    // Dışarıdan gelen bir event işlenirken geri yansıtılmasını engellemek için
+    static INBOUND: String;
+}
+
+/// rumt eventlerini dış bir event sistemiyle iki yönlü eşleştirir.
+///
+/// Dışarıya giden eventler codec ile byte dizisine çevrilip [`BridgeSink`]'e verilir,
+/// dışarıdan gelenler çözülüp bus'a emit edilir. Event isimleri iki taraf arasında
+/// ayrı ayrı eşlenebilir (ör. `order.created` <-> `order:created`).
+pub struct EventBridge {
+    tag: String,
+    sink: Arc<dyn BridgeSink>,
+    inbound: StdMutex<HashMap<String, Arc<InboundRoute>>>,
+}
+
+/// Tauri event isimlerinde `.` kullanılamadığı için `order.created` -> `order:created`.
+pub fn colon_separated(event_name: &str) -> String {
+    event_name.replace('.', ":")
+}
+
+impl EventBridge {
+    pub fn new(tag: impl Into<String>, sink: impl BridgeSink) -> Arc<Self> {
+        Arc::new(Self {
+            tag: tag.into(),
+            sink: Arc::new(sink),
+            inbound: StdMutex::new(HashMap::new()),
+        })
+    }
+
+    /// Bus'taki `event`i `external_name` adıyla dış sisteme iletir. Runtime başlatılmamışsa
+    /// `Error::NotInitialized` döner.
+    pub async fn forward<T, C>(&self, event: RuntimeEvent, external_name: impl Into<String>, codec: C) -> Result<(), Error>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let external_name: String = external_name.into();
+        let sink = Arc::clone(&self.sink);
+        let codec = Arc::new(codec);
+
+        let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+            let echoed = INBOUND.try_with(|origin| *origin == external_name).unwrap_or(false);
+            let encoded = args
+                .downcast::<Arc<T>>()
+                .filter(|_| !echoed)
+                .and_then(|payload| codec.encode(payload).ok());
+
+            match encoded {
+                Some(bytes) => sink.send(&external_name, bytes),
+                None => Box::pin(async {}) as MaybeSendFuture<'static, ()>,
+            }
+        });
+
+        let listener = RuntimeEventListener::new(self.tag.clone(), handler);
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await
+    }
+
+    /// Dış sistemden `external_name` adıyla gelen mesajları `event` olarak bus'a emit eder.
+    pub fn receive<T, C>(&self, external_name: impl Into<String>, event: RuntimeEvent, codec: C)
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let route: InboundRoute = Box::new(move |bytes| {
+            let payload = codec.decode(bytes)?;
+            let event = event.clone();
+            Ok(Box::pin(crate::global::emit_event(event, payload)) as MaybeSendFuture<'static, ()>)
+        });
+        self.inbound
+            .lock()
+            .unwrap()
+            .insert(external_name.into(), Arc::new(route));
+    }
+
+    /// Dış sistemden gelen bir mesajı işler. Eşlenmemiş isimler hata döner.
+    pub async fn handle_incoming(&self, external_name: &str, payload: &[u8]) -> Result<(), CodecError> {
+        let route = self.inbound.lock().unwrap().get(external_name).cloned();
+        let route = route.ok_or_else(|| CodecError::new(format!("no inbound route for `{external_name}`")))?;
+        let emit = route(payload)?;
+        INBOUND.scope(external_name.to_string(), emit).await;
+        Ok(())
+    }
+
+    /// Bridge'in bus'a eklediği tüm dinleyicileri kaldırır.
+    pub async fn dispose(&self) {
+        let tag = self.tag.clone();
+        // Runtime zaten kapatıldıysa kaldırılacak dinleyici yoktur
+        let _ = RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_all_listeners_by_tag(&tag)).await;
+    }
+}
@@ -0,0 +1,146 @@
+use std::{
+    any::Any,
+    collections::HashMap,
+    sync::{Arc, RwLock, RwLockReadGuard, RwLockWriteGuard},
+};
+
+use crate::codec::{CodecError, PayloadCodec};
+use crate::event_bus::RuntimeEvent;
+
+/// `Cache` yazıldıkça yayınlanan event adı; payload'ı `CacheInvalidated`.
+pub const CACHE_INVALIDATED: &str = "rumt.cache.invalidated";
+
+/// Anahtarın neden geçersizleştiği.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Invalidation {
+    /// Anahtara yeni bir değer yazıldı.
+    Updated,
+    /// Anahtar silindi.
+    Removed,
+}
+
+/// `rumt.cache.invalidated` payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CacheInvalidated {
+    pub key: String,
+    pub reason: Invalidation,
+}
+
+/// Runtime'a ait, anahtar/değer biçiminde paylaşılan önbellek; `rumt::cache()` ile alınır.
+/// Değerler açıkça değiştirilene veya silinene kadar durur, `shutdown_runtime` ile temizlenir.
+///
+/// Her yazma ve silme `rumt.cache.invalidated` event'ini yayınlar; başka modüllerdeki
+/// servisler bu event'i dinleyerek kendi yerel kopyalarını atar. Event bir transport ile
+/// diğer süreçlere de taşınabilir (bkz. `InvalidationCodec`).
+///
+/// ```rust,ignore
+/// rumt::cache().put("pricing.rates", rates).await;
+/// let rates = rumt::cache().get::<Rates>("pricing.rates");
+///
+/// event_handlers! {
+///     PriceView;
+///     RuntimeEvent::Static { event_name: "rumt.cache.invalidated".into() } => forget : CacheInvalidated
+/// }
+/// ```
+#[derive(Default)]
+pub struct Cache {
+    entries: RwLock<HashMap<String, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Cache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Değeri yazar ve invalidation event'ini yayınlar; anahtarda aynı tipte önceki bir değer
+    /// varsa onu döner.
+    pub async fn put<T: Send + Sync + 'static>(&self, key: impl Into<String>, value: T) -> Option<Arc<T>> {
+        self.put_arc(key, Arc::new(value)).await
+    }
+
+    /// Zaten `Arc` içinde tutulan bir değeri kopyalamadan yazar.
+    pub async fn put_arc<T: Send + Sync + 'static>(&self, key: impl Into<String>, value: Arc<T>) -> Option<Arc<T>> {
+        let key = key.into();
+        let previous = self.write().insert(key.clone(), value);
+        invalidated(key, Invalidation::Updated).await;
+        previous.and_then(|previous| previous.downcast::<T>().ok())
+    }
+
+    /// Anahtarda `T` tipinde bir değer varsa onu döner.
+    pub fn get<T: Send + Sync + 'static>(&self, key: &str) -> Option<Arc<T>> {
+        let entry = Arc::clone(self.read().get(key)?);
+        entry.downcast::<T>().ok()
+    }
+
+    /// Anahtarı siler; anahtar varsa invalidation event'ini yayınlar ve `true` döner.
+    pub async fn remove(&self, key: &str) -> bool {
+        let removed = self.write().remove(key).is_some();
+        if removed {
+            invalidated(key.to_string(), Invalidation::Removed).await;
+        }
+        removed
+    }
+
+    pub fn contains(&self, key: &str) -> bool {
+        self.read().contains_key(key)
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.write().clear();
+    }
+
+    // Harita yalnızca tek adımlık işlemlerle değiştiği için zehirlenmiş kilit hâlâ tutarlıdır
+    fn read(&self) -> RwLockReadGuard<'_, HashMap<String, Arc<dyn Any + Send + Sync>>> {
+        self.entries.read().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn write(&self) -> RwLockWriteGuard<'_, HashMap<String, Arc<dyn Any + Send + Sync>>> {
+        self.entries.write().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
+
+// Kilit bırakıldıktan sonra çağrılır; dinleyiciler önbelleği tekrar okuyabilir
+async fn invalidated(key: String, reason: Invalidation) {
+    let event = RuntimeEvent::Static { event_name: CACHE_INVALIDATED.into() };
+    crate::global::emit_internal(event, CacheInvalidated { key, reason }).await;
+}
+
+/// `CacheInvalidated` payload'larını transport üzerinden taşır: ilk bayt nedeni
+/// (`u`: güncellendi, `r`: silindi), kalanı UTF-8 anahtardır.
+///
+/// ```rust,ignore
+/// manager.route_out(RuntimeEvent::Static { event_name: CACHE_INVALIDATED.into() }, "cache", InvalidationCodec).await?;
+/// manager.route_in("cache", RuntimeEvent::Static { event_name: CACHE_INVALIDATED.into() }, InvalidationCodec);
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct InvalidationCodec;
+
+impl PayloadCodec<CacheInvalidated> for InvalidationCodec {
+    fn encode(&self, payload: &CacheInvalidated) -> Result<Vec<u8>, CodecError> {
+        let mut bytes = Vec::with_capacity(payload.key.len() + 1);
+        bytes.push(match payload.reason {
+            Invalidation::Updated => b'u',
+            Invalidation::Removed => b'r',
+        });
+        bytes.extend_from_slice(payload.key.as_bytes());
+        Ok(bytes)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<CacheInvalidated, CodecError> {
+        let reason = match bytes.first() {
+            Some(b'u') => Invalidation::Updated,
+            Some(b'r') => Invalidation::Removed,
+            _ => return Err(CodecError::new("unknown cache invalidation reason")),
+        };
+        let key = String::from_utf8(bytes[1..].to_vec()).map_err(|e| CodecError::new(e.to_string()))?;
+        Ok(CacheInvalidated { key, reason })
+    }
+}
@@ -0,0 +1,150 @@
+use std::{collections::BTreeMap, fmt::Write, sync::Mutex as StdMutex};
+
+use crate::event_bus::RuntimeEventBus;
+use crate::schema::EventName;
+use crate::stats::push_json_string;
+
+// Runtime'dan bağımsızdır; `init_runtime` öncesinde ve yeniden başlatmalar arasında korunur
+static REGISTERED: StdMutex<BTreeMap<&'static str, EventName>> = StdMutex::new(BTreeMap::new());
+
+/// Event kataloğundaki bir kayıt.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventDescriptor {
+    pub name: String,
+    pub once: bool,
+    /// `///` yorumlarından alınan açıklama; satır başlarındaki boşluk kırpılmıştır.
+    pub doc: String,
+    /// Payload tipinin adı; tanımda payload yoksa veya event yalnızca dinleyicilerden
+    /// biliniyorsa `None`.
+    pub payload: Option<String>,
+    /// Payload alanları, tanım sırasıyla `(ad, Rust tipi)`.
+    pub fields: Vec<(String, String)>,
+    /// Event'i o an dinleyen tag'ler.
+    pub listeners: Vec<String>,
+}
+
+impl EventDescriptor {
+    fn from_name(name: &EventName) -> Self {
+        let doc: Vec<&str> = name.doc().lines().map(|line| line.strip_prefix(' ').unwrap_or(line)).collect();
+        Self {
+            name: name.name().to_string(),
+            once: name.is_once(),
+            doc: doc.join("\n").trim().to_string(),
+            payload: name.payload().map(str::to_string),
+            fields: name.fields().iter().map(|(field, ty)| (field.to_string(), ty.to_string())).collect(),
+            listeners: Vec::new(),
+        }
+    }
+
+    fn undocumented(name: &str) -> Self {
+        Self {
+            name: name.to_string(),
+            once: false,
+            doc: String::new(),
+            payload: None,
+            fields: Vec::new(),
+            listeners: Vec::new(),
+        }
+    }
+}
+
+/// Çalışan uygulamanın bildiği tüm eventler; `event_catalog()` ile alınır.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct EventCatalog {
+    /// Ada göre sıralı.
+    pub events: Vec<EventDescriptor>,
+}
+
+impl EventCatalog {
+    /// `schema::generate_module` ile aynı biçimde JSON (ek olarak `listeners` alanıyla);
+    /// çıktı doğrudan şema dosyası olarak da kullanılabilir.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"events\":[");
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            push_json_string(&mut out, &event.name);
+            let _ = write!(out, ",\"once\":{}", event.once);
+            if !event.doc.is_empty() {
+                out.push_str(",\"doc\":");
+                push_json_string(&mut out, &event.doc);
+            }
+            if let Some(payload) = &event.payload {
+                out.push_str(",\"payload\":{\"type\":");
+                push_json_string(&mut out, payload);
+                out.push_str(",\"fields\":{");
+                for (j, (field, ty)) in event.fields.iter().enumerate() {
+                    if j > 0 {
+                        out.push(',');
+                    }
+                    push_json_string(&mut out, field);
+                    out.push(':');
+                    push_json_string(&mut out, ty);
+                }
+                out.push_str("}}");
+            }
+            out.push_str(",\"listeners\":[");
+            for (j, tag) in event.listeners.iter().enumerate() {
+                if j > 0 {
+                    out.push(',');
+                }
+                push_json_string(&mut out, tag);
+            }
+            out.push_str("]}");
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+/// `define_events!` ile tanımlanan eventleri kataloğa ekler. Aynı adla tekrar eklenen
+/// tanım öncekinin yerini alır.
+///
+/// ```rust,ignore
+/// mod events {
+///     rumt::define_events! {
+///         /// Sipariş oluşturulduğunda yayınlanır.
+///         ORDER_CREATED = "order.created" => OrderCreated { order_id: u64 },
+///     }
+/// }
+///
+/// rumt::register_events(events::EVENTS);
+/// let order = rumt::describe_event("order.created").await.unwrap();
+/// assert_eq!(order.payload.as_deref(), Some("OrderCreated"));
+/// ```
+pub fn register_events(events: &[EventName]) {
+    let mut registered = REGISTERED.lock().unwrap();
+    for event in events {
+        registered.insert(event.name(), *event);
+    }
+}
+
+/// Kataloğa kaydedilmiş event'in payload tipinin adı.
+pub(crate) fn payload_of(name: &str) -> Option<&'static str> {
+    REGISTERED.lock().unwrap_or_else(|e| e.into_inner()).get(name).and_then(EventName::payload)
+}
+
+/// Kayıtlı tanımlar ile o an dinleyicisi olan eventlerin birleşimi. Tanımı kaydedilmemiş
+/// ama dinlenen eventler açıklamasız olarak yer alır. Runtime başlatılmamışsa yalnızca
+/// kayıtlı tanımlar dinleyicisiz döner.
+pub async fn event_catalog() -> EventCatalog {
+    let mut events: BTreeMap<String, EventDescriptor> = REGISTERED
+        .lock()
+        .unwrap()
+        .values()
+        .map(|name| (name.name().to_string(), EventDescriptor::from_name(name)))
+        .collect();
+    let attached = RuntimeEventBus::try_with_instance_mut(|bus| bus.topology().events).await.unwrap_or_default();
+    for node in attached {
+        let entry = events.entry(node.name.clone()).or_insert_with(|| EventDescriptor::undocumented(&node.name));
+        entry.listeners = node.listeners;
+    }
+    EventCatalog { events: events.into_values().collect() }
+}
+
+/// Tek bir event'in katalog kaydı; ne tanımı kayıtlı ne de dinleyicisi varsa `None`.
+pub async fn describe_event(name: &str) -> Option<EventDescriptor> {
+    event_catalog().await.events.into_iter().find(|event| event.name == name)
+}
@@ -0,0 +1,391 @@
+use std::{
+    future::Future,
+    pin::Pin,
+    sync::{Arc, Mutex as StdMutex, RwLock},
+    task::{Context, Poll, Waker},
+    time::{Duration, SystemTime},
+};
+
+use futures::future::BoxFuture;
+use once_cell::sync::Lazy;
+
+/// rumt'un zaman noktası. Native hedeflerde `std::time::Instant`'ın kendisidir; wasm32'de
+/// `std` saatleri panic ettiğinden host'un verdiği monoton saatle (`set_host_time`) ilerleyen
+/// aynı arayüzlü bir tiptir.
+#[cfg(not(target_arch = "wasm32"))]
+pub use std::time::Instant;
+#[cfg(target_arch = "wasm32")]
+pub use host::{HostTime, Instant, set_host_time};
+
+/// rumt'un zamana bağlı özelliklerinin (ticker'lar, emit süre sınırları, idempotency TTL'i,
+/// yeniden deneme beklemeleri, oturum oynatma, spool ömürleri) kullandığı zaman kaynağı.
+///
+/// Varsayılan `SystemClock` gerçek zamanı kullanır. Testlerde `RuntimeModuleEnv::clock` ile
+/// `ManualClock` verilerek zaman elle ilerletilebilir. Handler gecikme istatistikleri her zaman
+/// gerçek süreyi ölçer.
+pub trait Clock: Send + Sync + 'static {
+    fn now(&self) -> Instant;
+
+    /// `deadline` anına kadar bekler; an geçmişse hemen döner.
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()>;
+
+    /// Duvar saati; diske yazılan zaman damgaları (ör. spool ömürleri) bununla tutulur.
+    fn system_time(&self) -> SystemTime {
+        real_system_time()
+    }
+}
+
+/// Gerçek zaman; beklemeler native hedeflerde tokio zamanlayıcısıyla, wasm32'de host'un
+/// zamanlayıcısıyla (`HostTime::set_timeout`) yapılır.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now(&self) -> Instant {
+        real_now()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        Box::pin(tokio::time::sleep_until(deadline.into()))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        Box::pin(host::HostSleep::new(deadline))
+    }
+}
+
+/// Yalnızca `advance` çağrıldığında ilerleyen saat. Bekleyen task'lar, zaman beklediği ana
+/// ulaştığında uyandırılır.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use rumt::clock::{Clock, ManualClock};
+/// let clock = ManualClock::new();
+/// let start = clock.now();
+/// clock.advance(Duration::from_secs(90));
+/// assert_eq!(clock.now() - start, Duration::from_secs(90));
+/// ```
+#[derive(Clone, Default)]
+pub struct ManualClock {
+    inner: Arc<ManualInner>,
+}
+
+struct ManualInner {
+    now: StdMutex<Instant>,
+    // Oluşturulduğu andaki gerçek duvar saatinden başlar, `now` ile birlikte ilerler
+    wall: StdMutex<SystemTime>,
+    sleepers: StdMutex<Vec<Waker>>,
+}
+
+impl Default for ManualInner {
+    fn default() -> Self {
+        Self {
+            now: StdMutex::new(Instant::now()),
+            wall: StdMutex::new(real_system_time()),
+            sleepers: StdMutex::new(Vec::new()),
+        }
+    }
+}
+
+impl ManualClock {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Saati ilerletir ve süresi dolan beklemeleri uyandırır.
+    pub fn advance(&self, by: Duration) {
+        *self.inner.now.lock().unwrap_or_else(|e| e.into_inner()) += by;
+        *self.inner.wall.lock().unwrap_or_else(|e| e.into_inner()) += by;
+        let sleepers = std::mem::take(&mut *self.inner.sleepers.lock().unwrap_or_else(|e| e.into_inner()));
+        // Süresi dolmayanlar tekrar poll edildiğinde kendilerini yeniden kaydeder
+        for waker in sleepers {
+            waker.wake();
+        }
+    }
+
+    /// Şu anda bu saatte bekleyen task sayısı; testte bir task'ın beklemeye girdiğini görmek için.
+    pub fn sleepers(&self) -> usize {
+        self.inner.sleepers.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+}
+
+impl Clock for ManualClock {
+    fn now(&self) -> Instant {
+        *self.inner.now.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn sleep_until(&self, deadline: Instant) -> BoxFuture<'static, ()> {
+        Box::pin(ManualSleep { inner: Arc::clone(&self.inner), deadline })
+    }
+
+    fn system_time(&self) -> SystemTime {
+        *self.inner.wall.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+struct ManualSleep {
+    inner: Arc<ManualInner>,
+    deadline: Instant,
+}
+
+impl Future for ManualSleep {
+    type Output = ();
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+        // Uyandırıcı, saat okunmadan önce kaydedilir ki araya giren bir `advance` kaçmasın
+        let mut sleepers = self.inner.sleepers.lock().unwrap_or_else(|e| e.into_inner());
+        if *self.inner.now.lock().unwrap_or_else(|e| e.into_inner()) >= self.deadline {
+            return Poll::Ready(());
+        }
+        if !sleepers.iter().any(|waker| waker.will_wake(cx.waker())) {
+            sleepers.push(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+}
+
+static CLOCK: Lazy<RwLock<Arc<dyn Clock>>> = Lazy::new(|| RwLock::new(Arc::new(SystemClock)));
+
+/// Runtime'ın kullandığı saat; `init_runtime` env'deki saati kurar, `shutdown_runtime` gerçek
+/// zamana döner.
+pub(crate) fn install(clock: Arc<dyn Clock>) {
+    *CLOCK.write().unwrap_or_else(|e| e.into_inner()) = clock;
+}
+
+fn current() -> Arc<dyn Clock> {
+    Arc::clone(&CLOCK.read().unwrap_or_else(|e| e.into_inner()))
+}
+
+/// Kurulu saatten bağımsız gerçek an; handler süreleri ve çalışma süresi gibi ölçümler için.
+pub(crate) fn real_now() -> Instant {
+    Instant::now()
+}
+
+/// Gerçek duvar saati; wasm32'de host'un Unix saatinden okunur.
+pub(crate) fn real_system_time() -> SystemTime {
+    #[cfg(not(target_arch = "wasm32"))]
+    return SystemTime::now();
+
+    #[cfg(target_arch = "wasm32")]
+    return host::system_time();
+}
+
+/// Runtime saatine göre şu an.
+pub fn now() -> Instant {
+    current().now()
+}
+
+/// Runtime saatine göre duvar saati.
+pub fn system_time() -> SystemTime {
+    current().system_time()
+}
+
+/// Runtime saatine göre `duration` kadar bekler.
+pub async fn sleep(duration: Duration) {
+    let clock = current();
+    let deadline = clock.now() + duration;
+    clock.sleep_until(deadline).await
+}
+
+pub async fn sleep_until(deadline: Instant) {
+    current().sleep_until(deadline).await
+}
+
+/// Runtime saatine göre süre sınırı: `future` `duration` içinde biterse sonucunu, bitmezse
+/// `None` döner.
+pub async fn timeout<F: Future>(duration: Duration, future: F) -> Option<F::Output> {
+    let future = std::pin::pin!(future);
+    let expired = std::pin::pin!(sleep(duration));
+    match futures::future::select(future, expired).await {
+        futures::future::Either::Left((output, _)) => Some(output),
+        futures::future::Either::Right(_) => None,
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+mod host {
+    use std::{
+        future::Future,
+        ops::{Add, AddAssign, Sub, SubAssign},
+        pin::Pin,
+        sync::{Arc, Mutex as StdMutex},
+        task::{Context, Poll, Waker},
+        time::{Duration, SystemTime, UNIX_EPOCH},
+    };
+
+    use once_cell::sync::OnceCell;
+
+    /// Tarayıcının saat kaynakları; wasm-bindgen ile içe aktarılan fonksiyonlar verilir.
+    #[derive(Clone, Copy, Debug)]
+    pub struct HostTime {
+        /// Monoton milisaniye (ör. `performance.now()`).
+        pub monotonic_millis: fn() -> f64,
+        /// Unix epoch'undan bu yana milisaniye (ör. `Date.now()`).
+        pub unix_millis: fn() -> f64,
+        /// Callback'i verilen milisaniye sonra bir kez çağırır (ör. `setTimeout`). Ticker'lar,
+        /// `clock::timeout`, emit süre sınırları ve yeniden deneme beklemeleri bununla uyanır.
+        pub set_timeout: fn(f64, Box<dyn FnOnce()>),
+    }
+
+    static HOST_TIME: OnceCell<HostTime> = OnceCell::new();
+
+    /// Host saatlerini kurar; `rt::set_spawner` gibi `init_runtime`'dan önce ve bir kez
+    /// çağrılır, sonraki çağrılar `false` döner. Kurulmazsa zaman ilerlemez ve ilk bekleme
+    /// panic eder.
+    ///
+    /// ```rust,ignore
+    /// #[wasm_bindgen]
+    /// extern "C" {
+    ///     #[wasm_bindgen(js_namespace = performance, js_name = now)]
+    ///     fn performance_now() -> f64;
+    ///     #[wasm_bindgen(js_namespace = Date, js_name = now)]
+    ///     fn date_now() -> f64;
+    ///     #[wasm_bindgen(js_name = setTimeout)]
+    ///     fn set_timeout_js(callback: &JsValue, millis: f64) -> JsValue;
+    /// }
+    ///
+    /// fn set_timeout(millis: f64, callback: Box<dyn FnOnce()>) {
+    ///     set_timeout_js(&Closure::once_into_js(callback), millis);
+    /// }
+    ///
+    /// rumt::clock::set_host_time(HostTime { monotonic_millis: performance_now, unix_millis: date_now, set_timeout });
+    /// ```
+    pub fn set_host_time(time: HostTime) -> bool {
+        HOST_TIME.set(time).is_ok()
+    }
+
+    /// `deadline` anına kadar host zamanlayıcısıyla bekleyen future.
+    pub(super) struct HostSleep {
+        deadline: Instant,
+        timer: Option<Arc<StdMutex<Timer>>>,
+    }
+
+    struct Timer {
+        fired: bool,
+        waker: Option<Waker>,
+    }
+
+    impl HostSleep {
+        pub(super) fn new(deadline: Instant) -> Self {
+            Self { deadline, timer: None }
+        }
+    }
+
+    impl Future for HostSleep {
+        type Output = ();
+
+        fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<()> {
+            let now = Instant::now();
+            if now >= self.deadline {
+                return Poll::Ready(());
+            }
+            if let Some(timer) = &self.timer {
+                let mut timer = timer.lock().unwrap_or_else(|e| e.into_inner());
+                if !timer.fired {
+                    timer.waker = Some(cx.waker().clone());
+                    return Poll::Pending;
+                }
+            }
+            // Zamanlayıcı erken tetiklendiyse kalan süre için yeniden kurulur
+            let Some(host) = HOST_TIME.get() else {
+                panic!("wasm32 requires host timers: call rumt::clock::set_host_time before init_runtime");
+            };
+            let timer = Arc::new(StdMutex::new(Timer { fired: false, waker: Some(cx.waker().clone()) }));
+            let fired = Arc::clone(&timer);
+            let millis = (self.deadline.duration_since(now).as_secs_f64() * 1000.0).ceil();
+            (host.set_timeout)(
+                millis,
+                Box::new(move || {
+                    let mut timer = fired.lock().unwrap_or_else(|e| e.into_inner());
+                    timer.fired = true;
+                    if let Some(waker) = timer.waker.take() {
+                        waker.wake();
+                    }
+                }),
+            );
+            self.timer = Some(timer);
+            Poll::Pending
+        }
+    }
+
+    fn millis(read: impl Fn(&HostTime) -> f64) -> Duration {
+        let millis = HOST_TIME.get().map(read).unwrap_or(0.0);
+        Duration::from_secs_f64(millis.max(0.0) / 1000.0)
+    }
+
+    pub(super) fn system_time() -> SystemTime {
+        UNIX_EPOCH + millis(|time| (time.unix_millis)())
+    }
+
+    /// Host'un monoton saatinde bir an; `std::time::Instant` ile aynı işlemleri destekler.
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    pub struct Instant(Duration);
+
+    impl Instant {
+        pub fn now() -> Self {
+            Instant(millis(|time| (time.monotonic_millis)()))
+        }
+
+        pub fn duration_since(&self, earlier: Instant) -> Duration {
+            self.0.saturating_sub(earlier.0)
+        }
+
+        pub fn checked_duration_since(&self, earlier: Instant) -> Option<Duration> {
+            self.0.checked_sub(earlier.0)
+        }
+
+        pub fn saturating_duration_since(&self, earlier: Instant) -> Duration {
+            self.duration_since(earlier)
+        }
+
+        pub fn elapsed(&self) -> Duration {
+            Instant::now().duration_since(*self)
+        }
+
+        pub fn checked_add(&self, duration: Duration) -> Option<Instant> {
+            self.0.checked_add(duration).map(Instant)
+        }
+
+        pub fn checked_sub(&self, duration: Duration) -> Option<Instant> {
+            self.0.checked_sub(duration).map(Instant)
+        }
+    }
+
+    impl Add<Duration> for Instant {
+        type Output = Instant;
+
+        fn add(self, duration: Duration) -> Instant {
+            Instant(self.0 + duration)
+        }
+    }
+
+    impl AddAssign<Duration> for Instant {
+        fn add_assign(&mut self, duration: Duration) {
+            self.0 += duration;
+        }
+    }
+
+    impl Sub<Duration> for Instant {
+        type Output = Instant;
+
+        fn sub(self, duration: Duration) -> Instant {
+            Instant(self.0.saturating_sub(duration))
+        }
+    }
+
+    impl SubAssign<Duration> for Instant {
+        fn sub_assign(&mut self, duration: Duration) {
+            self.0 = self.0.saturating_sub(duration);
+        }
+    }
+
+    impl Sub<Instant> for Instant {
+        type Output = Duration;
+
+        fn sub(self, earlier: Instant) -> Duration {
+            self.duration_since(earlier)
+        }
+    }
+}
@@ -0,0 +1,164 @@
+use std::{collections::HashMap, fmt, marker::PhantomData, sync::Arc};
+
+use crate::event_bus::{RuntimeEvent, RuntimeEventListenerHandlerArg};
+use crate::rt::MaybeSendFuture;
+
+/// Payload'ın byte dizisine çevrilmesi veya geri çözülmesi sırasında oluşan hata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct CodecError {
+    pub message: String,
+}
+
+impl CodecError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for CodecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "codec error: {}", self.message)
+    }
+}
+
+impl std::error::Error for CodecError {}
+
+/// Bir payload tipini process dışına (webview, transport, FFI) taşımak için kullanılan codec.
+///
+/// rumt belirli bir serileştirme kütüphanesine bağlı değildir; serde_json gibi bir kütüphane
+/// ile birkaç satırda implemente edilebilir.
+pub trait PayloadCodec<T>: Send + Sync + 'static {
+    fn encode(&self, payload: &T) -> Result<Vec<u8>, CodecError>;
+    fn decode(&self, bytes: &[u8]) -> Result<T, CodecError>;
+}
+
+/// `String` payload'ları UTF-8 olarak taşır.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Utf8Codec;
+
+impl PayloadCodec<String> for Utf8Codec {
+    fn encode(&self, payload: &String) -> Result<Vec<u8>, CodecError> {
+        Ok(payload.as_bytes().to_vec())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<String, CodecError> {
+        String::from_utf8(bytes.to_vec()).map_err(|e| CodecError::new(e.to_string()))
+    }
+}
+
+/// `Vec<u8>` payload'ları olduğu gibi taşır.
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BytesCodec;
+
+impl PayloadCodec<Vec<u8>> for BytesCodec {
+    fn encode(&self, payload: &Vec<u8>) -> Result<Vec<u8>, CodecError> {
+        Ok(payload.clone())
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<Vec<u8>, CodecError> {
+        Ok(bytes.to_vec())
+    }
+}
+
+/// İki fonksiyondan codec oluşturur.
+pub struct FnCodec<E, D> {
+    encode: E,
+    decode: D,
+}
+
+pub fn codec_fn<T, E, D>(encode: E, decode: D) -> FnCodec<E, D>
+where
+    E: Fn(&T) -> Result<Vec<u8>, CodecError> + Send + Sync + 'static,
+    D: Fn(&[u8]) -> Result<T, CodecError> + Send + Sync + 'static,
+{
+    FnCodec { encode, decode }
+}
+
+impl<T, E, D> PayloadCodec<T> for FnCodec<E, D>
+where
+    E: Fn(&T) -> Result<Vec<u8>, CodecError> + Send + Sync + 'static,
+    D: Fn(&[u8]) -> Result<T, CodecError> + Send + Sync + 'static,
+{
+    fn encode(&self, payload: &T) -> Result<Vec<u8>, CodecError> {
+        (self.encode)(payload)
+    }
+
+    fn decode(&self, bytes: &[u8]) -> Result<T, CodecError> {
+        (self.decode)(bytes)
+    }
+}
+
+/// Tip bilgisi silinmiş codec; payload'ı bus üzerindeki `Arc<T>` hâlinden byte'a çevirir
+/// veya byte'ları çözüp doğru tiple emit eder.
+pub(crate) trait ErasedCodec: Send + Sync {
+    fn type_name(&self) -> &'static str;
+    /// Payload bu codec'in tipinde değilse `None` döner.
+    fn encode(&self, payload: &dyn RuntimeEventListenerHandlerArg) -> Option<Result<Vec<u8>, CodecError>>;
+    fn emit(&self, event: RuntimeEvent, bytes: &[u8]) -> Result<MaybeSendFuture<'static, ()>, CodecError>;
+}
+
+struct TypedCodec<T, C> {
+    codec: C,
+    _payload: PhantomData<fn() -> T>,
+}
+
+impl<T, C> ErasedCodec for TypedCodec<T, C>
+where
+    T: Send + Sync + 'static,
+    C: PayloadCodec<T>,
+{
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
+
+    fn encode(&self, payload: &dyn RuntimeEventListenerHandlerArg) -> Option<Result<Vec<u8>, CodecError>> {
+        payload.downcast::<Arc<T>>().map(|payload| self.codec.encode(payload))
+    }
+
+    fn emit(&self, event: RuntimeEvent, bytes: &[u8]) -> Result<MaybeSendFuture<'static, ()>, CodecError> {
+        let payload = self.codec.decode(bytes)?;
+        Ok(Box::pin(crate::global::emit_event(event, payload)))
+    }
+}
+
+/// İsimle aranan codec'ler. Byte tabanlı uçlar (FFI, transport'lar) payload'ları bununla çözer.
+#[derive(Clone, Default)]
+pub struct CodecRegistry {
+    codecs: HashMap<String, Arc<dyn ErasedCodec>>,
+}
+
+impl CodecRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `bytes` (`Vec<u8>`) ve `utf8` (`String`) codec'leri kayıtlı olarak başlar.
+    pub fn with_builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register::<Vec<u8>, _>("bytes", BytesCodec);
+        registry.register::<String, _>("utf8", Utf8Codec);
+        registry
+    }
+
+    pub fn register<T, C>(&mut self, name: impl Into<String>, codec: C)
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let codec = TypedCodec {
+            codec,
+            _payload: PhantomData,
+        };
+        self.codecs.insert(name.into(), Arc::new(codec));
+    }
+
+    pub fn contains(&self, name: &str) -> bool {
+        self.codecs.contains_key(name)
+    }
+
+    pub(crate) fn get(&self, name: &str) -> Option<Arc<dyn ErasedCodec>> {
+        self.codecs.get(name).cloned()
+    }
+}
@@ -0,0 +1,184 @@
+use std::fmt;
+
+use crate::codec::CodecError;
+
+/// Transport frame'lerinde kullanılabilen sıkıştırma algoritmaları.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Compression {
+    /// LZ4 blok biçimi; hızlıdır, tekrar eden JSON/metin payload'larında iyi sonuç verir.
+    Lz4,
+}
+
+impl Compression {
+    /// Bu sürümün açabildiği tüm algoritmalar; `rumt.transport.hello` ile duyurulur.
+    pub const SUPPORTED: &'static [Compression] = &[Compression::Lz4];
+
+    /// Frame başlığında taşınan ad.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Compression::Lz4 => "lz4",
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.trim() {
+            name if name.eq_ignore_ascii_case("lz4") => Some(Compression::Lz4),
+            _ => None,
+        }
+    }
+
+    pub fn compress(&self, input: &[u8]) -> Vec<u8> {
+        match self {
+            Compression::Lz4 => lz4_compress(input),
+        }
+    }
+
+    /// `decoded_len` sıkıştırılmamış verinin tam uzunluğudur; tutmazsa hata döner.
+    pub fn decompress(&self, input: &[u8], decoded_len: usize) -> Result<Vec<u8>, CodecError> {
+        match self {
+            Compression::Lz4 => lz4_decompress(input, decoded_len),
+        }
+    }
+}
+
+impl fmt::Display for Compression {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Sıkıştırmanın hangi payload'lara uygulanacağı.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CompressionConfig {
+    pub algorithm: Compression,
+    /// Bu boyuttan (bayt) küçük payload'lar olduğu gibi gönderilir.
+    pub threshold: usize,
+}
+
+impl CompressionConfig {
+    pub fn lz4(threshold: usize) -> Self {
+        Self { algorithm: Compression::Lz4, threshold }
+    }
+}
+
+// LZ4 blok biçimi sabitleri: en kısa eşleşme, son eşleşmenin bitebileceği en geç nokta ve
+// bloğun sonunda her zaman literal kalması gereken bayt sayısı
+const MIN_MATCH: usize = 4;
+const MF_LIMIT: usize = 12;
+const LAST_LITERALS: usize = 5;
+const HASH_BITS: u32 = 12;
+
+fn lz4_compress(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len() / 2 + 16);
+    let mut table = vec![0usize; 1 << HASH_BITS];
+    let (mut anchor, mut pos) = (0, 0);
+
+    if input.len() > MF_LIMIT {
+        let limit = input.len() - MF_LIMIT;
+        while pos < limit {
+            let sequence = u32::from_le_bytes([input[pos], input[pos + 1], input[pos + 2], input[pos + 3]]);
+            let hash = (sequence.wrapping_mul(2654435761) >> (32 - HASH_BITS)) as usize;
+            // Tablo konum + 1 tutar; 0 boş demektir
+            let candidate = std::mem::replace(&mut table[hash], pos + 1);
+            if let Some(candidate) = candidate.checked_sub(1)
+                && pos - candidate <= u16::MAX as usize
+                && input[candidate..candidate + MIN_MATCH] == input[pos..pos + MIN_MATCH]
+            {
+                let max = input.len() - LAST_LITERALS - pos;
+                let mut length = MIN_MATCH;
+                while length < max && input[candidate + length] == input[pos + length] {
+                    length += 1;
+                }
+                write_sequence(&mut out, &input[anchor..pos], Some(((pos - candidate) as u16, length)));
+                pos += length;
+                anchor = pos;
+                continue;
+            }
+            pos += 1;
+        }
+    }
+    write_sequence(&mut out, &input[anchor..], None);
+    out
+}
+
+fn write_sequence(out: &mut Vec<u8>, literals: &[u8], matched: Option<(u16, usize)>) {
+    let match_len = matched.map_or(0, |(_, length)| length - MIN_MATCH);
+    out.push(((literals.len().min(15) as u8) << 4) | match_len.min(15) as u8);
+    write_length(out, literals.len());
+    out.extend_from_slice(literals);
+    if let Some((offset, _)) = matched {
+        out.extend_from_slice(&offset.to_le_bytes());
+        write_length(out, match_len);
+    }
+}
+
+fn write_length(out: &mut Vec<u8>, length: usize) {
+    if length < 15 {
+        return;
+    }
+    let mut rest = length - 15;
+    while rest >= 255 {
+        out.push(255);
+        rest -= 255;
+    }
+    out.push(rest as u8);
+}
+
+fn lz4_decompress(input: &[u8], decoded_len: usize) -> Result<Vec<u8>, CodecError> {
+    let corrupt = || CodecError::new("corrupt lz4 block");
+    let mut out = Vec::with_capacity(decoded_len);
+    let mut pos = 0;
+    loop {
+        let token = *input.get(pos).ok_or_else(corrupt)?;
+        pos += 1;
+
+        let literals = read_length(input, &mut pos, (token >> 4) as usize).ok_or_else(corrupt)?;
+        let end = pos.checked_add(literals).filter(|end| *end <= input.len()).ok_or_else(corrupt)?;
+        if out.len() + literals > decoded_len {
+            return Err(corrupt());
+        }
+        out.extend_from_slice(&input[pos..end]);
+        pos = end;
+        if pos == input.len() {
+            break;
+        }
+
+        let offset = match input.get(pos..pos + 2) {
+            Some(bytes) => u16::from_le_bytes([bytes[0], bytes[1]]) as usize,
+            None => return Err(corrupt()),
+        };
+        pos += 2;
+        if offset == 0 || offset > out.len() {
+            return Err(corrupt());
+        }
+        let length = read_length(input, &mut pos, (token & 0x0f) as usize).ok_or_else(corrupt)? + MIN_MATCH;
+        if out.len() + length > decoded_len {
+            return Err(corrupt());
+        }
+        // Eşleşme kendi üstüne binebilir (offset < length), bu yüzden bayt bayt kopyalanır
+        let start = out.len() - offset;
+        for index in 0..length {
+            out.push(out[start + index]);
+        }
+    }
+
+    if out.len() != decoded_len {
+        return Err(corrupt());
+    }
+    Ok(out)
+}
+
+fn read_length(input: &[u8], pos: &mut usize, initial: usize) -> Option<usize> {
+    let mut length = initial;
+    if initial == 15 {
+        loop {
+            let byte = *input.get(*pos)?;
+            *pos += 1;
+            length = length.checked_add(byte as usize)?;
+            if byte != 255 {
+                break;
+            }
+        }
+    }
+    Some(length)
+}
@@ -0,0 +1,213 @@
+use std::time::Duration;
+
+use crate::log::Level;
+use crate::queue::{Fairness, Priority};
+
+/// Event'lerin dinleyicilere nasıl iletileceğini belirler.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum DispatchMode {
+    /// Handler'lar `emit_event` çağrısı içinde sırayla çalışır (varsayılan).
+    #[default]
+    Sequential,
+    /// Emit kuyruğa bırakılır, handler'lar dispatcher worker'ları tarafından çalıştırılır.
+    /// Yüksek öncelikli eventler bekleyen düşük öncelikli eventlerin önüne geçer.
+    Queued,
+    /// Emit kuyruğa bırakılır ama handler'lar yalnızca `drain_pending` çağrıldığında çalışır.
+    /// Sabit tick ile çalışan oyun döngüleri gibi, işin belirli bir noktada yapılması gereken
+    /// senaryolar içindir.
+    Deferred,
+    /// Handler'lar `emit_event` çağrısı içinde aynı anda çalışır; emit hepsi bitince döner.
+    /// Birbirinden bağımsız, I/O bekleyen handler'lar için uygundur, sıra garantisi yoktur.
+    Concurrent,
+}
+
+impl DispatchMode {
+    pub(crate) fn is_queued(self) -> bool {
+        matches!(self, DispatchMode::Queued | DispatchMode::Deferred)
+    }
+
+    pub(crate) fn is_concurrent(self) -> bool {
+        self == DispatchMode::Concurrent
+    }
+}
+
+/// Event bus ayarları. `RuntimeModuleEnv::bus_config` ile runtime'a verilir.
+///
+/// Kapasite alanları yalnızca ön ayırma ipucudur; sınır koymazlar. Binlerce event kaydeden
+/// uygulamalar başlangıçtaki tekrar tekrar büyüme ve rehash maliyetini bunlarla önleyebilir:
+///
+/// ```rust
+/// # use rumt::BusConfig;
+/// let config = BusConfig {
+///     event_capacity: 4096,
+///     listener_capacity: 8,
+///     queue_capacity: 1024,
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct BusConfig {
+    pub mode: DispatchMode,
+    /// `Queued` modda kuyruğu tüketen worker sayısı.
+    pub workers: usize,
+    /// Idempotency anahtarı taşıyan emit'ler için event başına hatırlanan anahtar sayısı.
+    pub dedup_capacity: usize,
+    /// Bir idempotency anahtarının tekrar sayılacağı süre.
+    pub dedup_ttl: Duration,
+    /// Dinleyici tablosunda baştan yer ayrılan event sayısı.
+    pub event_capacity: usize,
+    /// Yeni bir event'in dinleyici listesi için ayrılan yer (ilk 4 dinleyici zaten heap'e çıkmaz).
+    pub listener_capacity: usize,
+    /// Kuyruklu modlarda emit kuyruğu için baştan ayrılan yer.
+    pub queue_capacity: usize,
+    /// `bus_stats` gecikme yüzdeliklerinin hesaplandığı son handler çalışması sayısı.
+    pub latency_window: usize,
+    /// Handler içinden yapılan emit'ler en az tetikleyen emit'in önceliğiyle kuyruğa girer;
+    /// böylece yüksek öncelikli bir alarmın devamındaki bildirimler yoğun trafiğin arkasında
+    /// beklemez. Daha yüksek öncelik verilen emit'ler kendi önceliğini korur.
+    pub inherit_priority: bool,
+    /// `export_topology` için event başına ve emit'i yapan handler tag'i başına emit sayılarını
+    /// tutar. Kapalıyken topoloji yalnızca kayıtlı dinleyicileri ve kaynakları içerir.
+    pub record_topology: bool,
+    /// `rumt::log` ile yazılan kayıtlardan bu seviyenin altındakiler yayınlanmaz.
+    pub log_level: Level,
+    /// `shutdown_runtime` sırasında bir fazın servisleri kapatıldıktan sonra kuyruğun boşalması
+    /// için beklenen en uzun süre.
+    pub shutdown_drain_timeout: Duration,
+    /// Kuyruklu modlarda worker'ların emit'i yapan tag'ler arasında paylaştırılması.
+    pub fairness: Fairness,
+    /// Sıralı çalışan handler zincirlerinde executor'ın bırakılma sıklığı.
+    pub yield_policy: YieldPolicy,
+    /// `init` ile kaydedilen servislerde tag + event bazında tekrar eden dinleyicilerin ele alınışı.
+    pub duplicate_listeners: DuplicatePolicy,
+}
+
+impl Default for BusConfig {
+    fn default() -> Self {
+        Self {
+            mode: DispatchMode::Sequential,
+            workers: 4,
+            dedup_capacity: 1024,
+            dedup_ttl: Duration::from_secs(10 * 60),
+            event_capacity: 0,
+            listener_capacity: 0,
+            queue_capacity: 0,
+            latency_window: 1024,
+            inherit_priority: false,
+            record_topology: false,
+            log_level: Level::Info,
+            shutdown_drain_timeout: Duration::from_secs(5),
+            fairness: Fairness::Off,
+            yield_policy: YieldPolicy::default(),
+            duplicate_listeners: DuplicatePolicy::Allow,
+        }
+    }
+}
+
+/// Aynı tag'in aynı event'i (aynı tenant için) başka bir instance ile ikinci kez dinlemesi,
+/// ör. `try_init()`'in yanlışlıkla iki kez çağrılması durumunda ne yapılacağı. Her durumda
+/// `rumt::log` ile bir uyarı yazılır.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum DuplicatePolicy {
+    /// İki kayıt da tutulur ve her emit'te ikisi de çağrılır (varsayılan).
+    #[default]
+    Allow,
+    /// Yeni kayıt `Error::DuplicateListener` ile reddedilir.
+    Reject,
+    /// Eski kayıt kaldırılır; tüm handler'ları böylece kaldırılan instance'ın `on_dispose`
+    /// kancası çağrılır.
+    Replace,
+}
+
+/// Sıralı dispatch'te handler'lar arasında executor'ın diğer task'lara bırakılacağı noktalar.
+/// Çok dinleyicili bir event'in sıralı çalıştırılması, handler'lar `await` etmeden dönüyorsa
+/// aynı thread'deki diğer task'ları bekletebilir. İki sınırdan biri aşıldığında bir sonraki
+/// handler'dan önce `yield_now` yapılır; ikisi de `None` ise (varsayılan) hiç bırakılmaz.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use rumt::{BusConfig, YieldPolicy};
+/// let config = BusConfig {
+///     yield_policy: YieldPolicy { handlers: Some(32), elapsed: Some(Duration::from_millis(2)) },
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct YieldPolicy {
+    /// Son bırakmadan bu yana çalışan handler sayısı.
+    pub handlers: Option<usize>,
+    /// Son bırakmadan (veya emit'in başından) bu yana geçen süre.
+    pub elapsed: Option<Duration>,
+}
+
+/// Tek bir emit için bus ayarlarını geçersiz kılar. `emit_with` ile kullanılır.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use rumt::{DispatchMode, EmitOptions, Priority};
+/// let options = EmitOptions {
+///     mode: Some(DispatchMode::Sequential),
+///     timeout: Some(Duration::from_millis(200)),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug)]
+pub struct EmitOptions {
+    /// `None` ise bus'ın modu kullanılır.
+    pub mode: Option<DispatchMode>,
+    /// Emit'ten itibaren handler'lara tanınan toplam süre. Süre dolduğunda bitmemiş veya
+    /// kuyrukta bekleyen handler'lar bırakılır ve emit `expired` olarak sayılır.
+    pub timeout: Option<Duration>,
+    /// Kuyruklu modlarda işlenme önceliği.
+    pub priority: Priority,
+    /// `false` ise emit telemetri gözlemcilerine bildirilmez ve trace bilgisi taşımaz.
+    pub trace: bool,
+    /// Aynı event için bu anahtarla yakın zamanda emit yapıldıysa (ör. tekrar gönderilen bir
+    /// webhook) emit dinleyicilere ulaşmadan bırakılır. Bkz. `BusConfig::dedup_ttl`.
+    pub idempotency_key: Option<String>,
+    /// Emit'in tenant'ı; `None` ise handler içinden yapılan emit'ler o anki context'in
+    /// tenant'ını devralır. Tenant'a bağlı dinleyiciler yalnızca kendi tenant'larının emit'lerini
+    /// alır, bağlı olmayanlar hepsini.
+    pub tenant: Option<String>,
+}
+
+impl Default for EmitOptions {
+    fn default() -> Self {
+        Self {
+            mode: None,
+            timeout: None,
+            priority: Priority::Normal,
+            trace: true,
+            idempotency_key: None,
+            tenant: None,
+        }
+    }
+}
+
+/// Başarısız bir işlemin (ör. webhook gönderimi) kaç kez ve hangi aralıklarla tekrar deneneceği.
+/// Bekleme her denemede iki katına çıkar, `max_backoff` ile sınırlanır.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// İlk deneme dahil toplam deneme sayısı.
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl RetryPolicy {
+    /// `attempt`. denemeden (1'den başlar) sonra beklenecek süre.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 1u32.checked_shl(attempt.saturating_sub(1)).unwrap_or(u32::MAX);
+        self.initial_backoff.saturating_mul(factor).min(self.max_backoff)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+        }
+    }
+}
@@ -0,0 +1,145 @@
+use std::{
+    collections::hash_map::RandomState,
+    fmt,
+    future::Future,
+    hash::{BuildHasher, Hasher},
+    sync::{
+        Arc,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crate::queue::Priority;
+use crate::trace::TraceContext;
+
+/// Bir emit'i tekil olarak tanımlayan kimlik.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct EventId(pub u128);
+
+// Rastgele sayı üretiminde her çağrının farklı girdi alması için
+static NEXT_SEED: AtomicU64 = AtomicU64::new(1);
+
+impl EventId {
+    /// Runtime'ın `IdGenerator`'ından yeni bir kimlik.
+    pub(crate) fn generate() -> Self {
+        crate::ids::generate()
+    }
+}
+
+/// 32 haneli hex; transport başlıklarındaki biçimle aynıdır.
+impl fmt::Display for EventId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:032x}", self.0)
+    }
+}
+
+/// Kriptografik olmayan rastgele sayı (span kimlikleri vb. için).
+pub(crate) fn random_u64() -> u64 {
+    let mut hasher = RandomState::new().build_hasher();
+    hasher.write_u64(NEXT_SEED.fetch_add(1, Ordering::Relaxed));
+    hasher.finish()
+}
+
+/// Emit'ler boyunca taşınan korelasyon/nedensellik bilgisi.
+///
+/// Zincirin ilk emit'inde oluşturulur (veya [`Context::with_correlation_id`] ile verilir);
+/// handler içinden yapılan her emit bu bilgiyi otomatik olarak devralır.
+#[derive(Clone, Debug)]
+pub struct Context {
+    /// Zincirin tamamı boyunca aynı kalan kimlik.
+    pub correlation_id: EventId,
+    /// Bu context'e ait emit'in kimliği.
+    pub event_id: EventId,
+    /// Bu emit'i tetikleyen emit, zincirin köküyse `None`.
+    pub causation_id: Option<EventId>,
+    /// Dağıtık trace bilgisi; zincir boyunca her emit'te yeni bir span ile devam eder.
+    pub trace: Option<TraceContext>,
+    /// Emit'in ait olduğu tenant; `emit_scoped` ile verilir, zincir boyunca devralınır.
+    pub tenant: Option<String>,
+    /// Bu emit'in önceliği; `BusConfig::inherit_priority` açıksa devamındaki emit'lere aktarılır.
+    pub priority: Priority,
+    chain: Arc<[EventId]>,
+}
+
+tokio::task_local! {
+    static CURRENT: Context;
+}
+
+impl Context {
+    /// Dışarıdan gelen bir korelasyon kimliğiyle (ör. HTTP request id) kök context oluşturur.
+    pub fn with_correlation_id(correlation_id: EventId) -> Self {
+        Self {
+            correlation_id,
+            event_id: correlation_id,
+            causation_id: None,
+            trace: None,
+            tenant: None,
+            priority: Priority::Normal,
+            chain: Arc::from([correlation_id]),
+        }
+    }
+
+    /// Context'e dışarıdan gelen (ör. transport header'larından okunan) trace bilgisini ekler.
+    pub fn with_trace(mut self, trace: TraceContext) -> Self {
+        self.trace = Some(trace);
+        self
+    }
+
+    /// Bu context altında yapılan emit'leri verilen tenant'a bağlar.
+    pub fn with_tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Üst context'in altında yeni bir emit için context üretir; üst yoksa yeni zincir başlatır.
+    pub(crate) fn child_of(parent: Option<&Context>) -> Self {
+        let event_id = EventId::generate();
+        match parent {
+            Some(parent) => Self {
+                correlation_id: parent.correlation_id,
+                event_id,
+                causation_id: Some(parent.event_id),
+                trace: parent.trace.as_ref().map(TraceContext::child),
+                tenant: parent.tenant.clone(),
+                priority: parent.priority,
+                chain: parent.chain.iter().copied().chain([event_id]).collect(),
+            },
+            None => Self {
+                correlation_id: event_id,
+                event_id,
+                causation_id: None,
+                trace: None,
+                tenant: None,
+                priority: Priority::Normal,
+                chain: Arc::from([event_id]),
+            },
+        }
+    }
+
+    /// Mevcut task içinde yapılacak bir emit için context üretir.
+    pub(crate) fn next() -> Self {
+        CURRENT
+            .try_with(|parent| Self::child_of(Some(parent)))
+            .unwrap_or_else(|_| Self::child_of(None))
+    }
+
+    /// Kökten bu emit'e kadar olan emit kimlikleri.
+    pub fn causation_chain(&self) -> &[EventId] {
+        &self.chain
+    }
+}
+
+/// Handler içinden çağrıldığında o anki emit'in context'ini döner.
+pub fn current() -> Option<Context> {
+    CURRENT.try_with(Context::clone).ok()
+}
+
+/// Handler içinden çağrıldığında o anki emit'in tenant'ı.
+pub(crate) fn current_tenant() -> Option<String> {
+    CURRENT.try_with(|context| context.tenant.clone()).ok().flatten()
+}
+
+/// Future'ı verilen context altında çalıştırır; içeride yapılan emit'ler bu zincire bağlanır.
+pub async fn scope<F: Future>(context: Context, f: F) -> F::Output {
+    CURRENT.scope(context, f).await
+}
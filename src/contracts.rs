@@ -0,0 +1,221 @@
+use std::{fmt, fmt::Write};
+
+use crate::json::{self, JsonValue};
+use crate::schema::{EventName, SchemaError};
+use crate::stats::push_json_string;
+
+/// Bir crate'in yayınladığı (veya beklediği) eventlerin sözleşmesi. Üretici crate sözleşmesini
+/// `to_json()` ile dışa verir; tüketici crate testlerinde bunu okuyup kendi beklentileriyle
+/// karşılaştırır. Böylece event adı veya payload alanı değişiklikleri production'a çıkmadan
+/// CI'da yakalanır.
+///
+/// ```rust,ignore
+/// // üretici: tests/contract.rs
+/// let contract = Contract::from_events("orders", orders::events::EVENTS).version("order.created", 2);
+/// std::fs::write("contracts/orders.json", contract.to_json()).unwrap();
+///
+/// // tüketici: tests/contract.rs
+/// let producer = Contract::from_json(include_str!("../../orders/contracts/orders.json")).unwrap();
+/// let expected = Contract::from_events("billing", billing::events::EVENTS).version("order.created", 2);
+/// producer.assert_compatible(&expected);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Contract {
+    pub producer: String,
+    pub events: Vec<EventContract>,
+}
+
+/// Sözleşmedeki tek bir event.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventContract {
+    pub name: String,
+    pub once: bool,
+    /// Payload'ın sürümü; uyumsuz değişikliklerde üretici tarafından artırılır. Varsayılan 1.
+    pub version: u32,
+    pub payload: Option<String>,
+    /// Payload alanları, `(ad, Rust tipi)`.
+    pub fields: Vec<(String, String)>,
+}
+
+/// `Contract::check` ile bulunan bir uyumsuzluk.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ContractViolation {
+    pub event: String,
+    pub reason: String,
+}
+
+impl fmt::Display for ContractViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "`{}`: {}", self.event, self.reason)
+    }
+}
+
+impl Contract {
+    pub fn new(producer: impl Into<String>) -> Self {
+        Self { producer: producer.into(), events: Vec::new() }
+    }
+
+    /// `define_events!` tanımlarından sözleşme; tüm eventler 1. sürümdedir.
+    pub fn from_events(producer: impl Into<String>, events: &[EventName]) -> Self {
+        let events = events
+            .iter()
+            .map(|event| EventContract {
+                name: event.name().to_string(),
+                once: event.is_once(),
+                version: 1,
+                payload: event.payload().map(str::to_string),
+                fields: event.fields().iter().map(|(name, ty)| (name.to_string(), ty.to_string())).collect(),
+            })
+            .collect();
+        Self { producer: producer.into(), events }
+    }
+
+    pub fn event(mut self, event: EventContract) -> Self {
+        self.events.push(event);
+        self
+    }
+
+    /// Event'in payload sürümünü değiştirir; sözleşmede olmayan adlar yok sayılır.
+    pub fn version(mut self, name: &str, version: u32) -> Self {
+        if let Some(event) = self.events.iter_mut().find(|event| event.name == name) {
+            event.version = version;
+        }
+        self
+    }
+
+    pub fn get(&self, name: &str) -> Option<&EventContract> {
+        self.events.iter().find(|event| event.name == name)
+    }
+
+    /// Tüketicinin beklentileri (`consumer`) bu sözleşmeyle karşılanıyor mu? Tüketicinin her
+    /// eventi üreticide aynı adla, aynı türde (`once`) ve aynı sürümde bulunmalı; beklediği her
+    /// payload alanı aynı tiple yer almalıdır. Üreticinin fazladan eventleri ve alanları uyumu
+    /// bozmaz.
+    pub fn check(&self, consumer: &Contract) -> Result<(), Vec<ContractViolation>> {
+        let mut violations = Vec::new();
+        for expected in &consumer.events {
+            let mut violation = |reason: String| violations.push(ContractViolation { event: expected.name.clone(), reason });
+            let Some(actual) = self.get(&expected.name) else {
+                violation(format!("not emitted by `{}`", self.producer));
+                continue;
+            };
+            if actual.once != expected.once {
+                let kind = |once| if once { "once-triggered" } else { "static" };
+                violation(format!("expected a {} event, `{}` emits a {} event", kind(expected.once), self.producer, kind(actual.once)));
+            }
+            if actual.version != expected.version {
+                violation(format!("expected version {}, `{}` emits version {}", expected.version, self.producer, actual.version));
+            }
+            for (field, ty) in &expected.fields {
+                match actual.fields.iter().find(|(name, _)| name == field) {
+                    None => violation(format!("payload field `{field}` is missing")),
+                    Some((_, actual_ty)) if normalize(actual_ty) != normalize(ty) => {
+                        violation(format!("payload field `{field}` is `{actual_ty}`, expected `{ty}`"))
+                    }
+                    Some(_) => {}
+                }
+            }
+        }
+        if violations.is_empty() { Ok(()) } else { Err(violations) }
+    }
+
+    /// `check` başarısızsa tüm uyumsuzlukları listeleyerek panic eder; testlerde kullanılır.
+    #[track_caller]
+    pub fn assert_compatible(&self, consumer: &Contract) {
+        if let Err(violations) = self.check(consumer) {
+            let report: Vec<String> = violations.iter().map(ToString::to_string).collect();
+            panic!(
+                "`{}` is not compatible with `{}`:\n  {}",
+                consumer.producer,
+                self.producer,
+                report.join("\n  ")
+            );
+        }
+    }
+
+    /// `{"producer":...,"events":[...]}` biçiminde JSON; event kayıtları `schema::generate_module`
+    /// ile aynı biçimdedir (ek olarak `version` alanıyla).
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"producer\":");
+        push_json_string(&mut out, &self.producer);
+        out.push_str(",\"events\":[");
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            push_json_string(&mut out, &event.name);
+            let _ = write!(out, ",\"once\":{},\"version\":{}", event.once, event.version);
+            if let Some(payload) = &event.payload {
+                out.push_str(",\"payload\":{\"type\":");
+                push_json_string(&mut out, payload);
+                out.push_str(",\"fields\":{");
+                for (j, (field, ty)) in event.fields.iter().enumerate() {
+                    if j > 0 {
+                        out.push(',');
+                    }
+                    push_json_string(&mut out, field);
+                    out.push(':');
+                    push_json_string(&mut out, ty);
+                }
+                out.push_str("}}");
+            }
+            out.push('}');
+        }
+        out.push_str("]}");
+        out
+    }
+
+    /// `to_json` çıktısını (veya aynı biçimdeki bir şema dosyasını) okur.
+    pub fn from_json(input: &str) -> Result<Self, SchemaError> {
+        let root = json::parse(input).map_err(|e| SchemaError::new(e.to_string()))?;
+        let producer = root.get("producer").and_then(JsonValue::as_str).unwrap_or_default().to_string();
+        let events = root
+            .get("events")
+            .and_then(JsonValue::as_array)
+            .ok_or_else(|| SchemaError::new("`events` array is missing"))?;
+        let mut contract = Self::new(producer);
+        for (index, event) in events.iter().enumerate() {
+            let name = event
+                .get("name")
+                .and_then(JsonValue::as_str)
+                .ok_or_else(|| SchemaError::new(format!("events[{index}]: `name` is missing")))?;
+            let version = match event.get("version") {
+                Some(version) => version
+                    .as_f64()
+                    .filter(|v| v.fract() == 0.0 && (0.0..=u32::MAX as f64).contains(v))
+                    .ok_or_else(|| SchemaError::new(format!("`{name}`: `version` must be a non-negative integer")))?
+                    as u32,
+                None => 1,
+            };
+            let payload = event.get("payload");
+            let fields = match payload.and_then(|payload| payload.get("fields")) {
+                Some(fields) => fields
+                    .as_object()
+                    .ok_or_else(|| SchemaError::new(format!("`{name}`: payload `fields` must be an object")))?,
+                None => &[],
+            };
+            let fields = fields
+                .iter()
+                .map(|(field, ty)| {
+                    ty.as_str()
+                        .map(|ty| (field.clone(), ty.to_string()))
+                        .ok_or_else(|| SchemaError::new(format!("`{name}`: type of `{field}` must be a string")))
+                })
+                .collect::<Result<_, _>>()?;
+            contract.events.push(EventContract {
+                name: name.to_string(),
+                once: event.get("once").and_then(JsonValue::as_bool).unwrap_or(false),
+                version,
+                payload: payload.and_then(|payload| payload.get("type")).and_then(JsonValue::as_str).map(str::to_string),
+                fields,
+            });
+        }
+        Ok(contract)
+    }
+}
+
+// `stringify!` çıktısı ile elle yazılmış tipler boşluk farkıyla eşleşsin diye
+fn normalize(ty: &str) -> String {
+    ty.chars().filter(|c| !c.is_whitespace()).collect()
+}
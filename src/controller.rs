@@ -0,0 +1,140 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, AtomicU64, Ordering},
+};
+
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListenerTrait};
+use crate::guarantee::Backlog;
+
+/// Bir servis instance'ını bus üzerinde tekil olarak tanımlar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct InstanceId(pub u64);
+
+static NEXT_INSTANCE: AtomicU64 = AtomicU64::new(1);
+
+impl InstanceId {
+    pub(crate) fn generate() -> Self {
+        Self(NEXT_INSTANCE.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Controller'a ait tek bir handler kaydı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ListenerHandle {
+    pub event: RuntimeEvent,
+    pub tag: &'static str,
+    pub instance: InstanceId,
+}
+
+/// `try_init()` ile kaydedilen bir servis instance'ının handler'larını yönetir.
+///
+/// İşlemler instance bazındadır: aynı tipten iki servis kaydedildiğinde birinin
+/// `dispose` edilmesi diğerinin handler'larını etkilemez.
+pub struct ListenerController {
+    instance: InstanceId,
+    tag: &'static str,
+    paused: Arc<AtomicBool>,
+    // `Queued`/`Durable` eventlerde henüz teslim edilmemiş emit'ler
+    backlog: Arc<Backlog>,
+    handles: Vec<ListenerHandle>,
+    service: Arc<dyn RuntimeEventListenerTrait>,
+}
+
+// Bkz. `RuntimeEventListener`: atomics'siz wasm32'de tek thread vardır
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Send for ListenerController {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Sync for ListenerController {}
+
+impl ListenerController {
+    pub(crate) fn new(
+        instance: InstanceId,
+        tag: &'static str,
+        paused: Arc<AtomicBool>,
+        backlog: Arc<Backlog>,
+        handles: Vec<ListenerHandle>,
+        service: Arc<dyn RuntimeEventListenerTrait>,
+    ) -> Self {
+        Self {
+            instance,
+            tag,
+            paused,
+            backlog,
+            handles,
+            service,
+        }
+    }
+
+    pub fn instance_id(&self) -> InstanceId {
+        self.instance
+    }
+
+    pub fn tag(&self) -> &'static str {
+        self.tag
+    }
+
+    pub fn service(&self) -> &Arc<dyn RuntimeEventListenerTrait> {
+        &self.service
+    }
+
+    pub fn handles(&self) -> &[ListenerHandle] {
+        &self.handles
+    }
+
+    /// Handler'lar bus'ta kalır ama yeni emit'lerde çalıştırılmaz.
+    pub fn pause(&self) {
+        self.paused.store(true, Ordering::Release);
+    }
+
+    /// Pause sırasında `Queued` veya `Durable` eventlerden gelen payload'lar sırayla (arka planda)
+    /// teslim edilir; beklemek için `redeliver` kullanılabilir.
+    pub fn resume(&self) {
+        self.paused.store(false, Ordering::Release);
+        if self.backlog.len() > 0 {
+            let pending = self.backlog.take();
+            crate::rt::spawn(async move {
+                for pending in pending {
+                    pending.run().await;
+                }
+            });
+        }
+    }
+
+    /// Teslim edilmeyi bekleyen payload sayısı: pause sırasında gelenler ve handler'ı panic
+    /// ettiği için onaylanmamış `Durable` teslimler.
+    pub fn pending(&self) -> usize {
+        self.backlog.len()
+    }
+
+    /// Bekleyen payload'ları şimdi sırayla teslim eder ve sayısını döner. Yine başarısız olan
+    /// `Durable` teslimler (veya instance hâlâ pause'daysa tümü) tekrar bekletilir.
+    pub async fn redeliver(&self) -> usize {
+        let pending = self.backlog.take();
+        let count = pending.len();
+        for pending in pending {
+            pending.run().await;
+        }
+        count
+    }
+
+    pub fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Bu instance'a ait tüm handler'ları bus'tan kaldırır. Birden fazla çağrılabilir.
+    /// Handler'lar kaldırılmadan önce servisin `on_dispose` kancası (bir kez) çağrılır.
+    pub async fn dispose(&self) {
+        let instance = self.instance;
+        // Runtime zaten kapatıldıysa kaldırılacak dinleyici yoktur
+        let registered = RuntimeEventBus::try_with_instance_mut(|bus| bus.take_service(instance))
+            .await
+            .ok()
+            .flatten();
+        if let Some(service) = registered {
+            service.on_dispose().await;
+        }
+        // Bekleyen planlar dinleyicileri tuttuğu için bırakılır
+        drop(self.backlog.take());
+        let _ = RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_listeners_by_instance(instance)).await;
+    }
+}
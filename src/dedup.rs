@@ -0,0 +1,65 @@
+use std::{
+    collections::{HashMap, HashSet, VecDeque},
+    time::Duration,
+};
+
+use crate::clock::Instant;
+use crate::event_bus::RuntimeEvent;
+
+/// Her event için son görülen idempotency anahtarlarını tutar. Event başına en fazla
+/// `capacity` anahtar saklanır (en eskisi düşer), `ttl`'i dolan anahtarlar unutulur.
+/// Bus kilidi altında kullanıldığından kendi kilidi yoktur.
+pub(crate) struct DedupCache {
+    capacity: usize,
+    ttl: Duration,
+    events: HashMap<RuntimeEvent, SeenKeys>,
+}
+
+#[derive(Default)]
+struct SeenKeys {
+    order: VecDeque<(String, Instant)>,
+    keys: HashSet<String>,
+}
+
+impl DedupCache {
+    pub(crate) fn new(capacity: usize, ttl: Duration) -> Self {
+        Self {
+            capacity,
+            ttl,
+            events: HashMap::new(),
+        }
+    }
+
+    /// Anahtar bu event için yakın zamanda görüldüyse `true` döner; görülmediyse kaydeder.
+    pub(crate) fn is_duplicate(&mut self, event: &RuntimeEvent, key: &str, now: Instant) -> bool {
+        if self.capacity == 0 {
+            return false;
+        }
+        let seen = match self.events.get_mut(event) {
+            Some(seen) => seen,
+            None => self.events.entry(event.clone()).or_default(),
+        };
+
+        // Anahtarlar eklenme sırasıyla tutulduğu için süresi dolanlar hep baştadır
+        while let Some((_, at)) = seen.order.front() {
+            if now.duration_since(*at) < self.ttl {
+                break;
+            }
+            if let Some((expired, _)) = seen.order.pop_front() {
+                seen.keys.remove(&expired);
+            }
+        }
+
+        if seen.keys.contains(key) {
+            return true;
+        }
+        if seen.order.len() == self.capacity
+            && let Some((oldest, _)) = seen.order.pop_front()
+        {
+            seen.keys.remove(&oldest);
+        }
+        seen.order.push_back((key.to_string(), now));
+        seen.keys.insert(key.to_string());
+        false
+    }
+}
@@ -0,0 +1,413 @@
+use std::{
+    any::Any,
+    panic::{AssertUnwindSafe, catch_unwind},
+    sync::Arc,
+};
+
+use futures::FutureExt;
+use tokio::sync::SemaphorePermit;
+
+use crate::batch::Coalescer;
+use crate::clock::{self, Instant};
+use crate::config::{EmitOptions, YieldPolicy};
+use crate::context::{self, Context};
+use crate::failure::{HANDLER_FAILED, HandlerFailure};
+use crate::guarantee::{Guarantee, Pending};
+use crate::event_bus::{
+    ListenerSnapshot, RuntimeEvent, RuntimeEventListener, RuntimeEventListenerHandlerArg,
+};
+use crate::queue::{EventQueue, Priority};
+use crate::replay::ReplayBuffer;
+use crate::rt::MaybeSendFuture;
+use crate::stats::StatsRecorder;
+use crate::telemetry::{EmitSpan, HandlerSpan, TelemetryObserver};
+
+pub(crate) type Observers = Arc<[Arc<dyn TelemetryObserver>]>;
+
+tokio::task_local! {
+    // O an çalışan handler'ın tag'i; yetkilendirme politikası emit'in kaynağı olarak kullanır
+    static SOURCE_TAG: String;
+}
+
+/// Handler içinden çağrıldığında handler'ın tag'i. Yalnızca bus'ta yetkilendirme politikası,
+/// topoloji kaydı veya `Fairness` açıkken izlenir.
+pub(crate) fn source_tag() -> Option<String> {
+    SOURCE_TAG.try_with(String::clone).ok()
+}
+
+/// `map_payload` ile kaydedilen dönüşüm. Argüman `Option<Raw>` olarak verilir; tip eşleşirse
+/// değer alınır ve dönüştürülmüş payload (`Arc<Enriched>`) döner, eşleşmezse `None`.
+pub(crate) type PayloadMap =
+    Arc<dyn Fn(&mut dyn Any) -> Option<Arc<dyn RuntimeEventListenerHandlerArg>> + Send + Sync>;
+
+/// Kilit altında hazırlanan, kilit bırakıldıktan sonra çalıştırılan dispatch bilgisi.
+pub(crate) struct DispatchPlan {
+    pub(crate) event: RuntimeEvent,
+    pub(crate) listeners: ListenerSnapshot,
+    /// Event için `retain_last` ayarlıysa payload'ın yazılacağı geçmiş tamponu.
+    pub(crate) replay: Option<Arc<ReplayBuffer>>,
+    /// Event `coalesce` ile gruplanıyorsa payload'ın ekleneceği grup.
+    pub(crate) batch: Option<Arc<Coalescer>>,
+    /// Teslimin kaçıncı denemesi; `Durable` tekrar teslimlerde artar.
+    pub(crate) attempt: u32,
+    pub(crate) queue: Option<Arc<EventQueue>>,
+    /// Handler'lar sırayla değil, aynı anda çalıştırılır (`DispatchMode::Concurrent`).
+    pub(crate) concurrent: bool,
+    /// Bu süreden sonra başlamamış veya bitmemiş handler'lar bırakılır.
+    pub(crate) deadline: Option<Instant>,
+    /// `false` ise telemetri gözlemcileri çağrılmaz ve trace bilgisi taşınmaz.
+    pub(crate) trace: bool,
+    pub(crate) telemetry: Observers,
+    /// `rumt::tracing` span izleyicileri; `trace` kapalıysa boştur.
+    #[cfg(feature = "tracing")]
+    pub(crate) spans: crate::tracing::Subscribers,
+    pub(crate) stats: Arc<StatsRecorder>,
+    /// Dinleyicilerden önce payload'a uygulanacak dönüşüm.
+    pub(crate) map: Option<PayloadMap>,
+    pub(crate) guarantee: Guarantee,
+    /// Emit'in tenant'ı; handler'ların context'ine aktarılır.
+    pub(crate) tenant: Option<String>,
+    /// Handler'lar tag'leriyle çalıştırılır ki içlerinden yapılan emit'lerin kaynağı bilinsin.
+    pub(crate) track_source: bool,
+    /// Emit'in önceliği en az tetikleyen emit'inki kadar yüksek tutulur.
+    pub(crate) inherit_priority: bool,
+    /// Sıralı çalıştırmada handler'lar arasında executor'ın bırakılma sıklığı.
+    pub(crate) yield_policy: YieldPolicy,
+}
+
+impl DispatchPlan {
+    /// Planı bus moduna göre hemen çalıştırır veya kuyruğa bırakır.
+    /// Handler'lar emit'in context'i altında çalışır.
+    pub(crate) async fn deliver<T: Send + Sync + 'static>(mut self, priority: Priority, arg: T) {
+        let mut slot = Some(arg);
+        let mapped = self.map.take().and_then(|map| map(&mut slot));
+        // Sıfır kopya: Veri bir kez Arc içine alınır
+        let payload: Arc<dyn RuntimeEventListenerHandlerArg> = match (mapped, slot) {
+            (Some(mapped), _) => mapped,
+            (None, Some(arg)) => Arc::new(Arc::new(arg)),
+            // Dönüşüm değeri aldıysa her zaman sonuç döner
+            (None, None) => return,
+        };
+        if let Some(replay) = &self.replay {
+            replay.push(self.tenant.clone(), Arc::clone(&payload));
+        }
+        if let Some(batch) = &self.batch {
+            batch.push(Arc::clone(&payload)).await;
+        }
+        if self.listeners.is_empty() {
+            return;
+        }
+
+        let context = self.begin(priority);
+        #[cfg(feature = "tracing")]
+        let _span = crate::tracing::Entered::emit(&self.spans, &self.event, &context, self.listeners.len());
+        let priority = context.priority;
+        match self.queue.take() {
+            Some(queue) => queue.push(source_tag(), priority, context, self, payload),
+            // Her handler'a verinin pointer'ı (Arc) gönderilir
+            None => context::scope(context, self.execute(&payload)).await,
+        }
+    }
+
+    /// Veriyi ödünç alarak, bus moduna bakmadan hemen dağıtır. Senkron handler'lar `&T` ile
+    /// çağrılır; async handler varsa veri yalnızca bir kez kopyalanıp `Arc`'a alınır.
+    pub(crate) async fn deliver_ref<T: Clone + Send + Sync + 'static>(mut self, arg: &T) {
+        if self.map.is_some() {
+            // Dönüşüm sahiplik ister; veri bir kez kopyalanıp yine hemen dağıtılır
+            self.queue = None;
+            return self.deliver(Priority::Normal, arg.clone()).await;
+        }
+        if let Some(replay) = &self.replay {
+            // Tampon veriyi emit'ten sonra da tuttuğu için burada bir kopya gerekir
+            replay.push(self.tenant.clone(), Arc::new(Arc::new(arg.clone())));
+        }
+        if let Some(batch) = &self.batch {
+            batch.push(Arc::new(Arc::new(arg.clone()))).await;
+        }
+        if self.listeners.is_empty() {
+            return;
+        }
+        let context = self.begin(Priority::Normal);
+        #[cfg(feature = "tracing")]
+        let _span = crate::tracing::Entered::emit(&self.spans, &self.event, &context, self.listeners.len());
+        context::scope(context, self.run_ref(arg)).await;
+    }
+
+    /// Emit başına tek seferlik ayarları (mod, süre sınırı, trace) plana uygular.
+    pub(crate) fn with_options(mut self, options: &EmitOptions, queue: &Arc<EventQueue>) -> Self {
+        if let Some(mode) = options.mode {
+            self.concurrent = mode.is_concurrent();
+            self.queue = mode.is_queued().then(|| Arc::clone(queue));
+        }
+        self.deadline = options.timeout.map(|timeout| clock::now() + timeout);
+        if !options.trace {
+            self.trace = false;
+            self.telemetry = Arc::new([]);
+            #[cfg(feature = "tracing")]
+            {
+                self.spans = Arc::new([]);
+            }
+        }
+        self
+    }
+
+    fn begin(&self, priority: Priority) -> Context {
+        let mut context = Context::next();
+        context.tenant = self.tenant.clone();
+        // Context'in önceliği bu noktada tetikleyen emit'inkidir
+        context.priority = match self.inherit_priority {
+            true => priority.max(context.priority),
+            false => priority,
+        };
+        if !self.trace {
+            context.trace = None;
+        }
+        for observer in self.telemetry.iter() {
+            observer.on_emit(&EmitSpan {
+                event: &self.event,
+                context: &context,
+                listener_count: self.listeners.len(),
+            });
+        }
+        context
+    }
+
+    /// Handler'ları varsa süre sınırı içinde çalıştırır. Süresi dolan emit "expired" sayılır;
+    /// kuyrukta beklerken süresi dolmuşsa hiç çalıştırılmaz.
+    pub(crate) async fn execute(&self, arg: &Arc<dyn RuntimeEventListenerHandlerArg>) {
+        let Some(deadline) = self.deadline else {
+            return self.run(arg).await;
+        };
+        if clock::now() >= deadline {
+            self.stats.record_expired();
+            return;
+        }
+        if !run_until(deadline, self.run(arg)).await {
+            self.stats.record_expired();
+        }
+    }
+
+    /// Snapshot'taki handler'ları sırayla (veya `concurrent` ise aynı anda) çalıştırır.
+    /// Bus kilidi tutulmaz, bu sayede handler içinden yeni event yayınlanabilir.
+    /// Panic eden handler hata olarak sayılır, diğer handler'lar çalışmaya devam eder.
+    pub(crate) async fn run(&self, arg: &Arc<dyn RuntimeEventListenerHandlerArg>) {
+        let context = context::current().unwrap_or_else(Context::next);
+        let mut active = Vec::with_capacity(self.listeners.len());
+        for listener in &self.listeners {
+            if listener.is_paused() {
+                self.hold(&context, listener, arg, Guarantee::Queued);
+            } else {
+                active.push(listener);
+            }
+        }
+        if self.concurrent {
+            futures::future::join_all(active.into_iter().map(|listener| self.invoke(&context, listener, arg))).await;
+        } else {
+            let mut yielder = Yielder::new(self.yield_policy);
+            for listener in active {
+                yielder.tick().await;
+                self.invoke(&context, listener, arg).await;
+            }
+        }
+    }
+
+    async fn invoke(
+        &self,
+        context: &Context,
+        listener: &Arc<RuntimeEventListener>,
+        arg: &Arc<dyn RuntimeEventListenerHandlerArg>,
+    ) {
+        let _permit = acquire(listener).await;
+        let started = clock::real_now();
+        let handler = AssertUnwindSafe(async { (listener.handler)(&**arg).await }).catch_unwind();
+        #[cfg(feature = "tracing")]
+        let span = self.handler_span(context, listener);
+        #[cfg(feature = "tracing")]
+        let handler = crate::tracing::scoped(span.as_ref(), handler);
+        let outcome = self.sourced(listener, handler).await;
+        let failure = outcome
+            .err()
+            .map(|panic| HandlerFailure::new(&self.event, &listener.tag, context, (**arg).type_name(), self.attempt, &*panic));
+        #[cfg(feature = "tracing")]
+        crate::tracing::close(span, failure.as_ref());
+        self.finish(context, listener, started, failure.as_ref());
+        if let Some(failure) = failure {
+            // Onaylanmayan dayanıklı teslim tekrar verilmek üzere saklanır
+            self.hold(context, listener, arg, Guarantee::Durable);
+            self.report(failure).await;
+        }
+    }
+
+    /// Hatayı, dinleyicisi varsa `rumt.handler.failed` ile yayınlar; bu event'in kendi
+    /// handler'larının hataları döngü oluşmasın diye yayınlanmaz.
+    // Dispatch kendini çağırdığı için future kutulanır
+    fn report(&self, failure: HandlerFailure) -> MaybeSendFuture<'static, ()> {
+        let publish = crate::telemetry::event_name(&self.event) != HANDLER_FAILED;
+        Box::pin(async move {
+            if publish {
+                crate::global::emit_observed(RuntimeEvent::Static { event_name: HANDLER_FAILED.into() }, failure).await;
+            }
+        })
+    }
+
+    /// Event'in garantisi en az `required` ise payload'ı dinleyicinin bekleyenlerine ekler.
+    fn hold(
+        &self,
+        context: &Context,
+        listener: &Arc<RuntimeEventListener>,
+        arg: &Arc<dyn RuntimeEventListenerHandlerArg>,
+        required: Guarantee,
+    ) {
+        if self.guarantee < required {
+            return;
+        }
+        // Başarısız teslim bir sonraki denemede tekrar verilir; pause edilen hiç denenmemiştir
+        let attempt = if required == Guarantee::Durable { self.attempt + 1 } else { self.attempt };
+        listener.backlog.push(Pending {
+            context: context.clone(),
+            plan: self.single(listener, attempt),
+            payload: Arc::clone(arg),
+        });
+    }
+
+    /// Aynı event için yalnızca verilen dinleyiciyi, hemen çalıştıracak plan.
+    fn single(&self, listener: &Arc<RuntimeEventListener>, attempt: u32) -> DispatchPlan {
+        DispatchPlan {
+            event: self.event.clone(),
+            listeners: ListenerSnapshot::from_elem(Arc::clone(listener), 1),
+            replay: None,
+            batch: None,
+            attempt,
+            queue: None,
+            concurrent: false,
+            deadline: None,
+            trace: self.trace,
+            telemetry: Arc::clone(&self.telemetry),
+            #[cfg(feature = "tracing")]
+            spans: Arc::clone(&self.spans),
+            stats: Arc::clone(&self.stats),
+            map: None,
+            guarantee: self.guarantee,
+            tenant: self.tenant.clone(),
+            track_source: self.track_source,
+            inherit_priority: self.inherit_priority,
+            yield_policy: self.yield_policy,
+        }
+    }
+
+    async fn sourced<F: std::future::Future>(&self, listener: &RuntimeEventListener, f: F) -> F::Output {
+        let f = crate::rt::labeled(listener.label(&self.event), f);
+        if self.track_source {
+            SOURCE_TAG.scope(listener.tag.clone(), f).await
+        } else {
+            f.await
+        }
+    }
+
+    async fn run_ref<T: Clone + Send + Sync + 'static>(&self, arg: &T) {
+        let context = context::current().unwrap_or_else(Context::next);
+        let mut shared: Option<Arc<dyn RuntimeEventListenerHandlerArg>> = None;
+        let mut shared = || Arc::clone(shared.get_or_insert_with(|| Arc::new(Arc::new(arg.clone()))));
+        let mut yielder = Yielder::new(self.yield_policy);
+        for listener in &self.listeners {
+            if listener.is_paused() {
+                if self.guarantee >= Guarantee::Queued {
+                    self.hold(&context, listener, &shared(), Guarantee::Queued);
+                }
+                continue;
+            }
+            yielder.tick().await;
+            let _permit = acquire(listener).await;
+            let started = clock::real_now();
+            #[cfg(feature = "tracing")]
+            let span = self.handler_span(&context, listener);
+            let outcome = match &listener.borrowed {
+                Some(borrowed) => {
+                    let handler = || catch_unwind(AssertUnwindSafe(|| borrowed(arg)));
+                    #[cfg(feature = "tracing")]
+                    let handler = || crate::tracing::sync_scoped(span.as_ref(), handler);
+                    crate::rt::labeled_sync(listener.label(&self.event), handler)
+                }
+                None => {
+                    let shared = shared();
+                    let handler = AssertUnwindSafe(async { (listener.handler)(&*shared).await }).catch_unwind();
+                    #[cfg(feature = "tracing")]
+                    let handler = crate::tracing::scoped(span.as_ref(), handler);
+                    self.sourced(listener, handler).await
+                }
+            };
+            let failure = outcome.err().map(|panic| {
+                HandlerFailure::new(&self.event, &listener.tag, &context, std::any::type_name::<T>(), self.attempt, &*panic)
+            });
+            #[cfg(feature = "tracing")]
+            crate::tracing::close(span, failure.as_ref());
+            self.finish(&context, listener, started, failure.as_ref());
+            if let Some(failure) = failure {
+                if self.guarantee == Guarantee::Durable {
+                    self.hold(&context, listener, &shared(), Guarantee::Durable);
+                }
+                self.report(failure).await;
+            }
+        }
+    }
+
+    #[cfg(feature = "tracing")]
+    fn handler_span(&self, context: &Context, listener: &RuntimeEventListener) -> Option<crate::tracing::Entered> {
+        crate::tracing::Entered::handler(&self.spans, &self.event, context, &listener.tag, &listener.label(&self.event))
+    }
+
+    fn finish(&self, context: &Context, listener: &RuntimeEventListener, started: Instant, failure: Option<&HandlerFailure>) {
+        let elapsed = started.elapsed();
+        self.stats.record_handler(elapsed, failure.is_some());
+
+        let span = HandlerSpan {
+            event: &self.event,
+            context,
+            tag: &listener.tag,
+            label: &listener.label(&self.event),
+            elapsed,
+            failure,
+        };
+        for observer in self.telemetry.iter() {
+            observer.on_handler(&span);
+        }
+    }
+}
+
+// `YieldPolicy` sınırlarını bir handler zinciri boyunca izler
+struct Yielder {
+    policy: YieldPolicy,
+    ran: usize,
+    since: Instant,
+}
+
+impl Yielder {
+    fn new(policy: YieldPolicy) -> Self {
+        Self { policy, ran: 0, since: clock::real_now() }
+    }
+
+    // Her handler'dan önce çağrılır; sınır aşıldıysa executor bırakılır
+    async fn tick(&mut self) {
+        let by_count = self.policy.handlers.is_some_and(|every| self.ran >= every.max(1));
+        let by_time = self.policy.elapsed.is_some_and(|limit| self.since.elapsed() >= limit);
+        if by_count || by_time {
+            tokio::task::yield_now().await;
+            self.ran = 0;
+            self.since = clock::real_now();
+        }
+        self.ran += 1;
+    }
+}
+
+/// Tag'in `max_in_flight` limiti varsa sıra gelene kadar bekler.
+async fn acquire(listener: &RuntimeEventListener) -> Option<SemaphorePermit<'_>> {
+    // Semafor hiç kapatılmadığı için acquire hata dönmez
+    listener.limiter.as_ref()?.acquire().await.ok()
+}
+
+/// Future'ı süre sınırına kadar çalıştırır; zamanında biterse `true` döner.
+async fn run_until(deadline: Instant, fut: impl Future<Output = ()>) -> bool {
+    let fut = std::pin::pin!(fut);
+    let expired = std::pin::pin!(clock::sleep_until(deadline));
+    matches!(futures::future::select(fut, expired).await, futures::future::Either::Left(_))
+}
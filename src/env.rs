@@ -1,13 +1,45 @@
 use std::path::{Path, PathBuf};
-use std::{collections::HashMap, marker::PhantomData};
+use std::{collections::HashMap, marker::PhantomData, sync::Arc};
 
 use crate::app_info::AppInfo;
+use crate::clock::Clock;
+use crate::config::BusConfig;
+use crate::error::{Error, Result};
+use crate::event_bus::RuntimeEvent;
+use crate::guarantee::Guarantee;
+use crate::ids::IdGenerator;
+use crate::policy::{AuthorizationPolicy, NamePolicy};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::process::ProcessSpec;
+use crate::reload::{ConfigLoader, ConfigValidator, ConfigValues};
 use crate::state::{Locked, Unlocked};
 
 pub struct RuntimeModuleEnv<State> {
     pub state: PhantomData<State>,
     pub paths: HashMap<String, String>,
     pub app: Option<AppInfo>,
+    pub bus: BusConfig,
+    /// `enabled_if` ile bağlanan dinleyicilerin kontrol ettiği flag'ler (ör. `features.email`).
+    pub flags: HashMap<String, bool>,
+    /// Son payload'ları `replay` isteyen dinleyiciler için saklanan eventler ve tampon boyları.
+    pub retention: HashMap<RuntimeEvent, usize>,
+    /// Kayıt ve emit sırasında event adlarını doğrulayan kurallar.
+    pub policy: Option<NamePolicy>,
+    /// Emit ve kayıt sırasında danışılan yetkilendirme politikası.
+    pub authorization: Option<Arc<dyn AuthorizationPolicy>>,
+    /// Zamana bağlı özelliklerin saati; `None` ise gerçek zaman.
+    pub clock: Option<Arc<dyn Clock>>,
+    /// Emit ve korelasyon kimliklerinin üreticisi; `None` ise `UuidV7`.
+    pub id_generator: Option<Arc<dyn IdGenerator>>,
+    /// `BestEffort` dışında teslim garantisi tanımlanmış eventler.
+    pub guarantees: HashMap<RuntimeEvent, Guarantee>,
+    /// `init_runtime` ile başlatılıp izlenen harici süreçler.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub processes: Vec<ProcessSpec>,
+    /// Flag ve path'leri başlangıçta ve her reload'da yeniden okuyan fonksiyon.
+    pub config_loader: Option<ConfigLoader>,
+    /// Yüklenen değerleri env'e uygulanmadan önce kontrol eden fonksiyonlar.
+    pub validators: Vec<ConfigValidator>,
 }
 
 impl RuntimeModuleEnv<Unlocked> {
@@ -18,29 +50,41 @@ impl RuntimeModuleEnv<Unlocked> {
     /// nasıl başlatacağınızı göstermektedir.
     ///
     /// ```
+    /// use rumt::{Unlocked, runtime_env, try_init_runtime};
     ///
     /// // 1. Builder'ı Unlocked state ile başlatın
     /// async fn setup_runtime() {
-    /// 
-    /// let mut env_builder = rumt::env::RuntimeModuleEnv::<Unlocked>::new();
-    /// 
-    /// env_builder.add_app_info("MyApp", "MyCompany", "com");
-    /// env_builder.insert_path("db", "/tmp/test.db");
-    /// 
-    /// let locked_env = env_builder.lock_env();
-    /// init_runtime(locked_env).await;
-    /// 
-    /// // 4. Daha sonra global runtime'a erişin
-    /// let runtime_env_guard = runtime_env();
-    /// let runtime_env = runtime_env_guard.as_ref().unwrap();
-    /// assert_eq!(runtime_env.app.as_ref().unwrap().app_name, "MyApp");
+    ///     let env_builder = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+    ///         .add_app_info("MyApp", "MyCompany", "com")
+    ///         .insert_path("db", "/tmp/test.db");
+    ///
+    ///     let locked_env = env_builder.try_lock_env().expect("AppInfo eksik");
+    ///     try_init_runtime(locked_env).await.expect("runtime başlatılamadı");
+    ///
+    ///     // 4. Daha sonra global runtime'a erişin
+    ///     let runtime_env_guard = runtime_env();
+    ///     let runtime_env = runtime_env_guard.as_ref().unwrap();
+    ///     assert_eq!(runtime_env.app.as_ref().unwrap().app_name, "MyApp");
+    /// }
     /// ```
-    /// 
+    ///
     pub fn new() -> Self {
         Self {
             state: PhantomData,
             paths: HashMap::new(),
             app: None,
+            bus: BusConfig::default(),
+            flags: HashMap::new(),
+            retention: HashMap::new(),
+            policy: None,
+            authorization: None,
+            clock: None,
+            id_generator: None,
+            guarantees: HashMap::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            processes: Vec::new(),
+            config_loader: None,
+            validators: Vec::new(),
         }
     }
 
@@ -63,12 +107,125 @@ impl RuntimeModuleEnv<Unlocked> {
         self
     }
 
-    pub fn lock_env(mut self) -> RuntimeModuleEnv<Locked> {
-        let app = self.app.expect("AppInfo must be set before locking!");
-        RuntimeModuleEnv {
+    pub fn set_flag(mut self, name: impl Into<String>, enabled: bool) -> Self {
+        self.flags.insert(name.into(), enabled);
+        self
+    }
+
+    /// Event'in son `count` payload'ı bellekte tutulur; `replay` seçeneğiyle bağlanan
+    /// dinleyiciler (ör. sonradan açılan bir dashboard) bağlanırken bunları alır.
+    pub fn retain_last(mut self, event: RuntimeEvent, count: usize) -> Self {
+        self.retention.insert(event, count);
+        self
+    }
+
+    /// Event adları için namespace/allowlist kuralları. Verilmezse ad kontrolü yapılmaz.
+    pub fn name_policy(mut self, policy: NamePolicy) -> Self {
+        self.policy = Some(policy);
+        self
+    }
+
+    /// Kayıt ve emit'lerde danışılacak yetkilendirme politikası; ör. plugin'lerin kaydettiği
+    /// servisleri `SandboxPolicy` ile kendi namespace'lerine kapatmak için.
+    pub fn authorization(mut self, policy: impl AuthorizationPolicy + 'static) -> Self {
+        self.authorization = Some(Arc::new(policy));
+        self
+    }
+
+    /// Ticker'ların, süre sınırlarının, TTL'lerin ve beklemelerin kullanacağı saat; testlerde
+    /// `ManualClock` ile zaman elle ilerletilir.
+    pub fn clock(mut self, clock: impl Clock) -> Self {
+        self.clock = Some(Arc::new(clock));
+        self
+    }
+
+    /// Emit kimliklerini, korelasyon kimliklerini ve `rumt::ids::generate` değerlerini üretecek
+    /// kaynak; ör. mevcut araçlarla sıralanabilen Snowflake kimlikleri için.
+    pub fn id_generator(mut self, generator: impl IdGenerator) -> Self {
+        self.id_generator = Some(Arc::new(generator));
+        self
+    }
+
+    /// Event'in teslim garantisini tanımlar; `requires` seçeneğiyle daha güçlü garanti isteyen
+    /// handler'lar bu event'e bağlanamaz.
+    pub fn guarantee(mut self, event: RuntimeEvent, guarantee: Guarantee) -> Self {
+        self.guarantees.insert(event, guarantee);
+        self
+    }
+
+    /// Runtime başlatıldığında çalıştırılacak ve politikasına göre yeniden başlatılacak bir süreç.
+    /// Yaşam döngüsü `process.started`, `process.exited` ve `process.stdout.line` eventleriyle
+    /// yayınlanır; süreçler `shutdown_runtime` ile sonlandırılır.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn process(mut self, spec: ProcessSpec) -> Self {
+        self.processes.push(spec);
+        self
+    }
+
+    /// Flag ve path'leri okuyan fonksiyon. `init_runtime` sırasında ve her reload'da
+    /// (`rumt.control.reload`, `reload_config`) çağrılır; döndürdüğü değerler builder'da
+    /// verilenlerin üzerine yazılır.
+    pub fn config_loader(
+        mut self,
+        loader: impl Fn() -> std::result::Result<ConfigValues, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.config_loader = Some(Arc::new(loader));
+        self
+    }
+
+    /// Yüklenen değerler için bir kontrol; reddedilirse `init_runtime` `Error::Config` ile
+    /// başarısız olur, reload ise env'i değiştirmeden bırakır.
+    pub fn validate_config(
+        mut self,
+        validator: impl Fn(&ConfigValues) -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    pub fn bus_config(mut self, config: BusConfig) -> Self {
+        self.bus = config;
+        self
+    }
+
+    /// Env'i kilitler; `AppInfo` yoksa panic eder.
+    #[deprecated(note = "panics inside the library; use `try_lock_env` and handle the error")]
+    pub fn lock_env(self) -> RuntimeModuleEnv<Locked> {
+        self.try_lock_env().expect("AppInfo must be set before locking!")
+    }
+
+    /// `lock_env`'in panic etmeyen hâli; `AppInfo` yoksa `Error::MissingAppInfo` döner.
+    pub fn try_lock_env(self) -> Result<RuntimeModuleEnv<Locked>> {
+        let app = self.app.ok_or(Error::MissingAppInfo)?;
+        Ok(RuntimeModuleEnv {
             state: PhantomData,
             paths: self.paths,
             app: Some(app),
-        }
+            bus: self.bus,
+            flags: self.flags,
+            retention: self.retention,
+            policy: self.policy,
+            authorization: self.authorization,
+            clock: self.clock,
+            id_generator: self.id_generator,
+            guarantees: self.guarantees,
+            #[cfg(not(target_arch = "wasm32"))]
+            processes: self.processes,
+            config_loader: self.config_loader,
+            validators: self.validators,
+        })
+    }
+}
+
+impl Default for RuntimeModuleEnv<Unlocked> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl RuntimeModuleEnv<Locked> {
+    /// Flag tanımlı değilse kapalı kabul edilir.
+    pub fn flag(&self, name: &str) -> bool {
+        self.flags.get(name).copied().unwrap_or(false)
     }
-}
\ No newline at end of file
+}
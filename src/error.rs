@@ -0,0 +1,79 @@
+use std::fmt;
+
+use crate::codec::CodecError;
+use crate::guarantee::Guarantee;
+
+/// rumt'un fallible API'lerinin döndüğü hata tipi.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Error {
+    /// `init_runtime` henüz çağrılmadı (veya runtime kapatıldı).
+    NotInitialized,
+    /// `init_runtime` runtime kapatılmadan ikinci kez çağrıldı.
+    AlreadyInitialized,
+    /// Bloklayan bir çağrı bir tokio runtime thread'inden yapıldı; async karşılığı beklenmelidir.
+    BlockingInRuntime,
+    /// Env kilidi, kilidi tutan bir thread panic ettiği için zehirlendi.
+    EnvLockPoisoned,
+    /// `lock_env` öncesinde `add_app_info` çağrılmadı.
+    MissingAppInfo,
+    /// İstenen tipte bir kaynak `resources()` içine eklenmedi.
+    MissingResource(&'static str),
+    /// Event adı runtime'ın `NamePolicy` kurallarına uymuyor.
+    InvalidEventName { name: String, reason: String },
+    /// Handler'ın istediği teslim garantisi event için tanımlanandan güçlü.
+    GuaranteeMismatch { event: String, required: Guarantee, declared: Guarantee },
+    /// Aynı tag, aynı event için (aynı tenant ve instance ile, veya
+    /// `DuplicatePolicy::Reject` iken başka bir instance ile) ikinci kez kaydedilmek istendi.
+    DuplicateListener { event: String, tag: String },
+    Codec(CodecError),
+    Transport(String),
+    /// Gateway istemcisi doğrulanamadı veya event için izni yok.
+    Unauthorized(String),
+    Handler { tag: String, message: String },
+    /// Payload, event için kaydedilen `PayloadGuard`'a uymadı.
+    PayloadRejected { event: String, reason: String },
+    /// Env yükleyicisi başarısız oldu veya bir doğrulayıcı yüklenen değerleri reddetti.
+    Config(String),
+}
+
+pub type Result<T, E = Error> = std::result::Result<T, E>;
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotInitialized => write!(f, "runtime is not initialized, call init_runtime first"),
+            Error::AlreadyInitialized => write!(f, "runtime is already initialized, call shutdown_runtime first"),
+            Error::BlockingInRuntime => write!(f, "blocking call inside a tokio runtime, await the async variant instead"),
+            Error::EnvLockPoisoned => write!(f, "runtime env lock is poisoned"),
+            Error::MissingAppInfo => write!(f, "AppInfo must be set before locking the env"),
+            Error::MissingResource(type_name) => write!(f, "resource `{type_name}` is not registered"),
+            Error::InvalidEventName { name, reason } => write!(f, "invalid event name `{name}`: {reason}"),
+            Error::GuaranteeMismatch { event, required, declared } => write!(
+                f,
+                "listener of `{event}` requires {required} delivery but the event is declared {declared}"
+            ),
+            Error::DuplicateListener { event, tag } => write!(f, "`{tag}` is already listening to `{event}`"),
+            Error::Codec(e) => write!(f, "{e}"),
+            Error::Transport(message) => write!(f, "transport error: {message}"),
+            Error::Unauthorized(reason) => write!(f, "unauthorized: {reason}"),
+            Error::Handler { tag, message } => write!(f, "handler of `{tag}` failed: {message}"),
+            Error::PayloadRejected { event, reason } => write!(f, "payload of `{event}` rejected: {reason}"),
+            Error::Config(reason) => write!(f, "invalid configuration: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Codec(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<CodecError> for Error {
+    fn from(e: CodecError) -> Self {
+        Error::Codec(e)
+    }
+}
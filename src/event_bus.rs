@@ -1,5 +1,37 @@
-use futures::future::BoxFuture;
-use std::{any::Any, collections::HashMap, sync::Arc};
+use once_cell::sync::OnceCell;
+use smallvec::SmallVec;
+use std::{
+    any::Any,
+    collections::{BTreeMap, HashMap, HashSet},
+    sync::{
+        Arc,
+        atomic::{AtomicBool, Ordering},
+    },
+};
+use tokio::sync::Semaphore;
+
+use crate::batch::{Coalesce, Coalescer};
+use crate::clock::Instant;
+use crate::config::{BusConfig, DispatchMode, DuplicatePolicy, EmitOptions, YieldPolicy};
+use crate::context;
+use crate::controller::{InstanceId, ListenerController, ListenerHandle};
+use crate::dedup::DedupCache;
+use crate::dispatch::{DispatchPlan, Observers, PayloadMap};
+use crate::error::{Error, Result};
+use crate::guarantee::{Backlog, Guarantee};
+use crate::guard::{ErasedGuard, PayloadGuard};
+use crate::leak::RuntimeSnapshot;
+use crate::phase::{self, DeferredInit, Phase};
+use crate::policy::{self, Access, AuthorizationPolicy, NamePolicy, PayloadMeta};
+use crate::queue::{EventQueue, Fairness, Priority, spawn_workers};
+use crate::replay::{Replay, ReplayBuffer};
+use crate::rt::{MaybeSend, MaybeSendFuture, MaybeSync};
+use crate::snapshot::RegistrationSnapshot;
+use crate::stats::{BusStats, StatsRecorder};
+use crate::telemetry::{TelemetryObserver, event_name};
+use crate::ticker::TickerStop;
+use crate::topology::{EmitEdge, EventNode, SourceNode, Topology};
+use crate::transport::TransportStatus;
 
 // --- Temel Tipler ve Traitler ---
 
@@ -11,12 +43,18 @@ pub enum RuntimeEvent {
 
 pub trait RuntimeEventListenerHandlerArg: Any + Send + Sync {
     fn as_any(&self) -> &dyn Any;
+    /// Somut tipin adı; hata raporlarında payload'ı tanımlar.
+    fn type_name(&self) -> &'static str;
 }
 
 impl<T: Any + Send + Sync> RuntimeEventListenerHandlerArg for T {
     fn as_any(&self) -> &dyn Any {
         self
     }
+
+    fn type_name(&self) -> &'static str {
+        std::any::type_name::<T>()
+    }
 }
 
 impl dyn RuntimeEventListenerHandlerArg {
@@ -25,85 +63,1090 @@ impl dyn RuntimeEventListenerHandlerArg {
     }
 }
 
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
 pub(crate) type RuntimeEventListenerHandler =
-    Box<dyn Fn(&dyn RuntimeEventListenerHandlerArg) -> BoxFuture<'static, ()> + Send + Sync>;
+    Box<dyn Fn(&dyn RuntimeEventListenerHandlerArg) -> MaybeSendFuture<'static, ()> + Send + Sync>;
+// Tarayıcıda handler'lar ve servisleri `Send` olmak zorunda değildir
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub(crate) type RuntimeEventListenerHandler = Box<dyn Fn(&dyn RuntimeEventListenerHandlerArg) -> MaybeSendFuture<'static, ()>>;
+
+/// Veriyi ödünç alarak senkron çalışan handler; `emit_ref` bu yolu kullanır.
+/// Argümanın somut tipi `Arc<T>` değil, doğrudan `T`'dir.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub type BorrowedHandler = Box<dyn Fn(&dyn Any) + Send + Sync>;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub type BorrowedHandler = Box<dyn Fn(&dyn Any)>;
 
 pub struct RuntimeEventListener {
     pub(crate) tag: String,
     pub(crate) handler: RuntimeEventListenerHandler,
+    pub(crate) enabled_if: Option<String>,
+    // `init()` ile kaydedilen servislerde instance kimliği ve ortak pause bayrağı
+    pub(crate) instance: Option<InstanceId>,
+    pub(crate) paused: Arc<AtomicBool>,
+    pub(crate) borrowed: Option<BorrowedHandler>,
+    // Bağlanırken istenen geçmiş payload sayısı
+    pub(crate) replay: usize,
+    pub(crate) max_in_flight: Option<usize>,
+    // Bus'a eklenirken tag'in ortak semaforu atanır
+    pub(crate) limiter: Option<Arc<Semaphore>>,
+    pub(crate) requires: Guarantee,
+    // Instance'ın teslim edilmemiş emit'leri; `init()` ile kaydedilen servislerde paylaşılır
+    pub(crate) backlog: Arc<Backlog>,
+    // `None` ise dinleyici tüm tenant'ların emit'lerini alır
+    pub(crate) tenant: Option<String>,
+    pub(crate) handler_name: Option<&'static str>,
+    // İlk çalışmada bağlandığı event'le birlikte üretilir
+    label: OnceCell<Arc<str>>,
 }
 
+// Atomics'siz wasm32 tek iş parçacıklıdır: `!Send` handler'lar başka bir thread'e hiç
+// geçemez, bus'ın global'de durabilmesi için gereken `Send`/`Sync` bu yüzden güvenlidir.
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Send for RuntimeEventListener {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Sync for RuntimeEventListener {}
+
 impl RuntimeEventListener {
     pub fn new(tag: impl Into<String>, handler: RuntimeEventListenerHandler) -> Self {
         Self {
             tag: tag.into(),
             handler,
+            enabled_if: None,
+            instance: None,
+            paused: Arc::new(AtomicBool::new(false)),
+            borrowed: None,
+            replay: 0,
+            max_in_flight: None,
+            limiter: None,
+            requires: Guarantee::BestEffort,
+            backlog: Arc::default(),
+            tenant: None,
+            handler_name: None,
+            label: OnceCell::new(),
         }
     }
+
+    /// Makro senkron handler'lar için bunu ekler; `emit_ref` sırasında veri `Arc`'a alınmadan verilir.
+    #[doc(hidden)]
+    pub fn with_borrowed_handler(mut self, handler: BorrowedHandler) -> Self {
+        self.borrowed = Some(handler);
+        self
+    }
+
+    #[doc(hidden)]
+    pub fn without_borrowed_handler(mut self) -> Self {
+        self.borrowed = None;
+        self
+    }
+
+    /// Etikette görünecek handler fonksiyonunun adı; makro metod adını verir.
+    pub fn handler_name(mut self, name: &'static str) -> Self {
+        self.handler_name = Some(name);
+        self
+    }
+
+    /// `tag::handler_fn@event` (handler adı yoksa `tag@event`); bkz. `rumt::rt::current_label`.
+    pub(crate) fn label(&self, event: &RuntimeEvent) -> Arc<str> {
+        let label = self.label.get_or_init(|| match self.handler_name {
+            Some(handler) => format!("{}::{handler}@{}", self.tag, event_name(event)).into(),
+            None => format!("{}@{}", self.tag, event_name(event)).into(),
+        });
+        Arc::clone(label)
+    }
+
+    pub(crate) fn is_paused(&self) -> bool {
+        self.paused.load(Ordering::Acquire)
+    }
+
+    /// Dinleyici yalnızca bu tenant'a ait emit'leri alır; tenant'sız emit'ler ona ulaşmaz.
+    pub fn tenant(mut self, tenant: impl Into<String>) -> Self {
+        self.tenant = Some(tenant.into());
+        self
+    }
+
+    /// Tenant'a bağlı değilse her emit'i, bağlıysa yalnızca kendi tenant'ının emit'lerini kabul eder.
+    pub(crate) fn accepts(&self, tenant: Option<&str>) -> bool {
+        self.tenant.as_deref().is_none_or(|own| Some(own) == tenant)
+    }
+
+    /// `init` ile bağlanırken event'in saklanan son `count` payload'ı (en eskiden başlayarak)
+    /// bu listener'a yeniden verilir. Event için `retain_last` ayarlanmamışsa etkisizdir.
+    pub fn replay(mut self, count: usize) -> Self {
+        self.replay = count;
+        self
+    }
+
+    /// Aynı tag'e ait handler'lardan en fazla `limit` tanesi aynı anda çalışır; fazlası sırada
+    /// bekler. Limit tag başına tektir, servisin tüm handler'ları ve instance'ları paylaşır.
+    pub fn max_in_flight(mut self, limit: usize) -> Self {
+        self.max_in_flight = Some(limit.max(1));
+        self
+    }
+
+    /// Handler'ın ihtiyaç duyduğu en zayıf teslim garantisi. Event daha zayıf bir garantiyle
+    /// tanımlanmışsa kayıt `Error::GuaranteeMismatch` ile reddedilir.
+    pub fn requires(mut self, guarantee: Guarantee) -> Self {
+        self.requires = guarantee;
+        self
+    }
+
+    /// Listener yalnızca verilen flag açıkken bus'a bağlı olur. Flag kapalıyken
+    /// listener bekletilir ve flag açıldığında otomatik olarak yeniden bağlanır.
+    pub fn enabled_if(mut self, flag: impl Into<String>) -> Self {
+        self.enabled_if = Some(flag.into());
+        self
+    }
 }
 
+/// Bir servisin tüm handler'ları; `replace_by_tag` ile tek seferde değiştirilebilir.
+pub type ListenerBundle = Vec<(RuntimeEvent, RuntimeEventListener)>;
+
+/// Çoğu event'in 1-3 dinleyicisi olur; ilk 4 dinleyici heap'e çıkmadan saklanır.
+/// Dispatch sırasında bu liste klonlanır (yalnızca Arc sayaçları artar) ve kilit bırakılır.
+pub(crate) type ListenerSnapshot = SmallVec<[Arc<RuntimeEventListener>; 4]>;
+
 // --- Event Bus Merkezi ---
 #[doc(hidden)] // Kullanıcı dökümanında ve kod tamamlamada gözükmez
 pub struct RuntimeEventBus {
-    pub(crate) pairs: HashMap<RuntimeEvent, Vec<RuntimeEventListener>>,
+    pub(crate) pairs: HashMap<RuntimeEvent, ListenerSnapshot>,
+    // Flag'i kapalı olduğu için bağlanmamış dinleyiciler
+    pub(crate) parked: Vec<(RuntimeEvent, Arc<RuntimeEventListener>)>,
+    pub(crate) flags: HashMap<String, bool>,
+    pub(crate) config: BusConfig,
+    pub(crate) queue: Arc<EventQueue>,
+    pub(crate) telemetry: Observers,
+    #[cfg(feature = "tracing")]
+    pub(crate) spans: crate::tracing::Subscribers,
+    pub(crate) stats: Arc<StatsRecorder>,
+    // Dinleyicileri tüketilmiş tek seferlik eventler; tekrar emit edilirlerse "expired" sayılır
+    consumed: HashSet<RuntimeEvent>,
+    pub(crate) workers_started: bool,
+    // `retain_last` ile geçmişi tutulan eventler
+    pub(crate) retained: HashMap<RuntimeEvent, Arc<ReplayBuffer>>,
+    // `max_in_flight` kullanan tag'lerin limiti ve semaforu
+    limits: HashMap<String, (usize, Arc<Semaphore>)>,
+    pub(crate) policy: Option<Arc<NamePolicy>>,
+    pub(crate) authorization: Option<Arc<dyn AuthorizationPolicy>>,
+    // `init_runtime`'ın çalıştığı tokio runtime'ı; `drain_pending_blocking` handler'ları onda çalıştırır
+    #[cfg(not(target_arch = "wasm32"))]
+    pub(crate) runtime: Option<tokio::runtime::Handle>,
+    dedup: DedupCache,
+    // `init` ile kaydedilmiş servisler; dispose kancaları için tutulur
+    services: Vec<RegisteredService>,
+    // `map_payload` ile kaydedilen event başına payload dönüşümleri
+    maps: HashMap<RuntimeEvent, PayloadMap>,
+    // `coalesce` ile gruplanan eventlerin bekleyen grupları
+    pub(crate) batches: HashMap<RuntimeEvent, Arc<Coalescer>>,
+    // `guard_payload` ile kaydedilen event başına boyut ve biçim korumaları
+    pub(crate) guards: HashMap<RuntimeEvent, Box<dyn ErasedGuard>>,
+    // Env'de tanımlanan teslim garantileri; olmayanlar `BestEffort`
+    pub(crate) guarantees: HashMap<RuntimeEvent, Guarantee>,
+    // Runtime ile birlikte durdurulan arka plan kaynakları (ticker, dosya izleyici, süreçler)
+    pub(crate) sources: HashMap<String, Arc<TickerStop>>,
+    // Kaynakların yayınladığını bildirdiği eventler
+    pub(crate) source_events: HashMap<String, Vec<RuntimeEvent>>,
+    // Kaynaklardan `Scheduler` ile zamanlanmış olanlar
+    pub(crate) scheduled: HashSet<String>,
+    // `record_topology` açıksa (emit'i yapan handler tag'i, event) başına emit sayısı
+    emit_counts: Option<HashMap<(Option<String>, RuntimeEvent), u64>>,
+    // Başlatılmış transport manager'larının tag başına son bağlantı durumu
+    pub(crate) transports: BTreeMap<String, TransportStatus>,
 }
 
+#[derive(Clone)]
+pub(crate) struct RegisteredService {
+    pub(crate) instance: InstanceId,
+    pub(crate) tag: &'static str,
+    pub(crate) service: Arc<dyn RuntimeEventListenerTrait>,
+    // `shutdown_runtime` servisleri fazların tersi sırasıyla kapatır
+    pub(crate) phase: Phase,
+}
+
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Send for RegisteredService {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Sync for RegisteredService {}
+
 impl RuntimeEventBus {
-    pub(crate) fn new() -> Self {
+    pub(crate) fn new(config: BusConfig) -> Self {
         Self {
-            pairs: HashMap::new(),
+            dedup: DedupCache::new(config.dedup_capacity, config.dedup_ttl),
+            services: Vec::new(),
+            maps: HashMap::new(),
+            batches: HashMap::new(),
+            guards: HashMap::new(),
+            guarantees: HashMap::new(),
+            sources: HashMap::new(),
+            source_events: HashMap::new(),
+            scheduled: HashSet::new(),
+            emit_counts: config.record_topology.then(HashMap::new),
+            transports: BTreeMap::new(),
+            pairs: HashMap::with_capacity(config.event_capacity),
+            parked: Vec::new(),
+            flags: HashMap::new(),
+            queue: Arc::new(EventQueue::new(config.queue_capacity, config.fairness.clone())),
+            telemetry: Arc::new([]),
+            #[cfg(feature = "tracing")]
+            spans: Arc::new([]),
+            stats: Arc::new(StatsRecorder::new(config.latency_window)),
+            consumed: HashSet::new(),
+            workers_started: false,
+            retained: HashMap::new(),
+            limits: HashMap::new(),
+            policy: None,
+            authorization: None,
+            #[cfg(not(target_arch = "wasm32"))]
+            runtime: tokio::runtime::Handle::try_current().ok(),
+            config,
         }
     }
 
+    /// `Queued` ve `Deferred` modlarda emit'lerin bırakılacağı kuyruk.
+    pub(crate) fn dispatch_queue(&self) -> Option<Arc<EventQueue>> {
+        self.config.mode.is_queued().then(|| Arc::clone(&self.queue))
+    }
+
+    /// Kuyruk worker'larını ilk ihtiyaç anında bir kez başlatır.
+    pub(crate) fn ensure_workers(&mut self) {
+        if !self.workers_started {
+            self.workers_started = true;
+            spawn_workers(&self.queue, self.config.workers);
+        }
+    }
+
+    /// Global bus'a kilit altında erişir; bus yoksa `Error::NotInitialized` döner.
     /// Makronun kütüphane dışından erişebilmesi için teknik olarak pub olmalı.
     /// Ancak dökümantasyonda gizleyerek kullanıcıdan saklıyoruz.
     #[doc(hidden)]
-    pub async fn with_instance_mut<F, R>(f: F) -> R
+    pub async fn try_with_instance_mut<F, R>(f: F) -> Result<R>
     where
         F: FnOnce(&mut Self) -> R,
     {
         // Global'deki asenkron Mutex'i kilitliyoruz
         let mut guard = crate::global::RUNTIME_EVENT_BUS.lock().await;
-        let bus = guard.as_mut().expect("RuntimeEventBus Not initialized! Call init_runtime first.");
-        f(bus)
+        let bus = guard.as_mut().ok_or(Error::NotInitialized)?;
+        Ok(f(bus))
     }
 
     pub fn add_listener(&mut self, event: RuntimeEvent, listener: RuntimeEventListener) {
-        self.pairs.entry(event).or_insert(vec![]).push(listener);
+        let listener = self.prepare(listener);
+        self.insert_listener(event, listener);
+    }
+
+    /// Listener'a tag'inin eşzamanlılık semaforunu bağlar.
+    fn prepare(&mut self, mut listener: RuntimeEventListener) -> Arc<RuntimeEventListener> {
+        if let Some(limit) = listener.max_in_flight {
+            let entry = self
+                .limits
+                .entry(listener.tag.clone())
+                .or_insert_with(|| (limit, Arc::new(Semaphore::new(limit))));
+            // Limit değiştiyse (ör. reload) yeni handler'lar yeni semaforu kullanır
+            if entry.0 != limit {
+                *entry = (limit, Arc::new(Semaphore::new(limit)));
+            }
+            listener.limiter = Some(Arc::clone(&entry.1));
+        }
+        Arc::new(listener)
+    }
+
+    fn insert_listener(&mut self, event: RuntimeEvent, listener: Arc<RuntimeEventListener>) {
+        self.consumed.remove(&event);
+        if self.is_enabled(&listener) {
+            self.attach(event, listener);
+        } else {
+            self.parked.push((event, listener));
+        }
+    }
+
+    fn attach(&mut self, event: RuntimeEvent, listener: Arc<RuntimeEventListener>) {
+        let capacity = self.config.listener_capacity;
+        self.pairs
+            .entry(event)
+            .or_insert_with(|| ListenerSnapshot::with_capacity(capacity))
+            .push(listener);
+    }
+
+    fn is_enabled(&self, listener: &RuntimeEventListener) -> bool {
+        match &listener.enabled_if {
+            Some(flag) => self.flags.get(flag).copied().unwrap_or(false),
+            None => true,
+        }
+    }
+
+    /// Flag değerlerini günceller; `enabled_if` ile bağlanmış dinleyiciler buna göre
+    /// bağlanır veya bekletilir.
+    pub fn set_flags(&mut self, flags: impl IntoIterator<Item = (String, bool)>) {
+        self.flags.extend(flags);
+
+        let mut disabled = Vec::new();
+        for (event, listeners) in self.pairs.iter_mut() {
+            listeners.retain(|listener| {
+                let enabled = match &listener.enabled_if {
+                    Some(flag) => self.flags.get(flag).copied().unwrap_or(false),
+                    None => true,
+                };
+                if !enabled {
+                    disabled.push((event.clone(), Arc::clone(listener)));
+                }
+                enabled
+            });
+        }
+
+        let parked = std::mem::take(&mut self.parked);
+        for (event, listener) in parked {
+            if self.is_enabled(&listener) {
+                self.attach(event, listener);
+            } else {
+                self.parked.push((event, listener));
+            }
+        }
+        self.parked.extend(disabled);
+    }
+
+    /// Event'e bağlı dinleyicilerin ucuz bir kopyasını döner.
+    /// Tek seferlik eventler bu noktada bus'tan çıkarılır.
+    pub(crate) fn snapshot(&mut self, event: &RuntimeEvent) -> Option<ListenerSnapshot> {
+        match event {
+            RuntimeEvent::OnceTriggered { .. } => {
+                let listeners = self.pairs.remove(event);
+                if listeners.is_some() {
+                    self.consumed.insert(event.clone());
+                }
+                listeners
+            }
+            RuntimeEvent::Static { .. } => self.pairs.get(event).cloned(),
+        }
+    }
+
+    /// Emit için gereken her şeyi (snapshot, kuyruk, telemetri) kilit altında toplar.
+    /// Emit, handler içinden yapılıyorsa o anki context'in tenant'ına göre dağıtılır.
+    pub(crate) fn plan(&mut self, event: &RuntimeEvent) -> Option<DispatchPlan> {
+        self.plan_scoped(event, context::current_tenant())
+    }
+
+    /// `plan`'ın verilen tenant için hâli; tenant'a bağlı dinleyicilerden yalnızca bu tenant'a
+    /// ait olanlar plana girer.
+    pub(crate) fn plan_scoped(&mut self, event: &RuntimeEvent, tenant: Option<String>) -> Option<DispatchPlan> {
+        let expired = self.consumed.contains(event);
+        if let Some(counts) = &mut self.emit_counts {
+            *counts.entry((crate::dispatch::source_tag(), event.clone())).or_default() += 1;
+        }
+        let mut listeners = self.snapshot(event);
+        if let Some(listeners) = &mut listeners
+            && listeners.iter().any(|l| l.tenant.is_some())
+        {
+            listeners.retain(|l| l.accepts(tenant.as_deref()));
+        }
+        self.stats.record_emit(listeners.is_some());
+        if expired {
+            self.stats.record_expired();
+        }
+        let replay = self.retained.get(event).cloned();
+        let batch = self.batches.get(event).cloned();
+        // Geçmişi tutulan veya gruplanan eventler dinleyici olmasa da tampona yazılmak üzere plan üretir
+        let listeners = match listeners {
+            Some(listeners) => listeners,
+            None if replay.is_some() || batch.is_some() => ListenerSnapshot::new(),
+            None => return None,
+        };
+        Some(DispatchPlan {
+            event: event.clone(),
+            listeners,
+            replay,
+            batch,
+            attempt: 1,
+            queue: self.dispatch_queue(),
+            concurrent: self.config.mode.is_concurrent(),
+            deadline: None,
+            trace: true,
+            telemetry: Arc::clone(&self.telemetry),
+            #[cfg(feature = "tracing")]
+            spans: Arc::clone(&self.spans),
+            stats: Arc::clone(&self.stats),
+            map: self.maps.get(event).cloned(),
+            guarantee: self.guarantee(event),
+            tenant,
+            track_source: self.tracks_source(),
+            inherit_priority: self.config.inherit_priority,
+            yield_policy: self.config.yield_policy,
+        })
+    }
+
+    /// Event'in son `count` payload'ını bellekte tutar; `replay` isteyen yeni dinleyiciler
+    /// bağlanırken bunları alır. `0` tutmayı kapatır.
+    pub fn retain_last(&mut self, event: RuntimeEvent, count: usize) {
+        if count == 0 {
+            self.retained.remove(&event);
+        } else if self.retained.get(&event).is_none_or(|buffer| buffer.capacity() != count) {
+            self.retained.insert(event, Arc::new(ReplayBuffer::new(count)));
+        }
+    }
+
+    /// Event `Raw` payload ile yayınlandığında dinleyicilerden önce `f` ile `Enriched`'a
+    /// dönüştürülür. Event başına tek dönüşüm tutulur; yeniden kayıt öncekinin yerini alır.
+    /// Başka tipte payload ile yapılan emit'ler olduğu gibi iletilir.
+    pub fn map_payload<Raw, Enriched>(
+        &mut self,
+        event: RuntimeEvent,
+        f: impl Fn(Raw) -> Enriched + Send + Sync + 'static,
+    ) where
+        Raw: Send + Sync + 'static,
+        Enriched: Send + Sync + 'static,
+    {
+        let map: PayloadMap = Arc::new(move |slot: &mut dyn Any| {
+            let raw = slot.downcast_mut::<Option<Raw>>()?.take()?;
+            Some(Arc::new(Arc::new(f(raw))))
+        });
+        self.maps.insert(event, map);
+    }
+
+    /// Event'in `T` payload'larını `settings` penceresinde toplar ve `batch_event(&event)`
+    /// üzerinden tek `Vec<Arc<T>>` olarak yayınlar; olağan dinleyiciler her payload'ı yine ayrı
+    /// alır. Event başına tek ayar tutulur; yeniden kayıt, bekleyen grup teslim edildikten sonra
+    /// yeni ayarla başlar.
+    pub(crate) fn coalesce<T: Send + Sync + 'static>(&mut self, event: RuntimeEvent, settings: Coalesce) -> Option<Arc<Coalescer>> {
+        let coalescer = Arc::new(Coalescer::new::<T>(&event, settings));
+        self.batches.insert(event, coalescer)
+    }
+
+    /// Event `T` payload ile yayınlandığında dinleyicilerden önce `guard` ile kontrol edilir.
+    /// Event başına tek koruma tutulur; yeniden kayıt öncekinin yerini alır.
+    pub fn guard_payload<T: Send + Sync + 'static>(&mut self, event: RuntimeEvent, guard: PayloadGuard<T>) {
+        self.guards.insert(event, Box::new(guard));
+    }
+
+    /// Handler'ların tag'i, içlerinden yapılan emit'ler için yetkilendirmede veya topolojide
+    /// kullanılıyorsa izlenir.
+    fn tracks_source(&self) -> bool {
+        self.authorization.is_some() || self.emit_counts.is_some() || self.config.fairness != Fairness::Off
+    }
+
+    /// Kayıtlı dinleyicilerden, kaynaklardan ve (açıksa) gözlemlenen emit'lerden event akış grafiği.
+    pub fn topology(&self) -> Topology {
+        let attached = self.pairs.iter().flat_map(|(event, listeners)| listeners.iter().map(move |l| (event, l)));
+        let parked = self.parked.iter().map(|(event, listener)| (event, listener));
+        let mut events: BTreeMap<String, EventNode> = BTreeMap::new();
+        for (event, listener) in attached.chain(parked) {
+            let entry = events
+                .entry(event_name(event).to_string())
+                .or_insert_with(|| EventNode::new(event_name(event)));
+            if !entry.listeners.contains(&listener.tag) {
+                entry.listeners.push(listener.tag.clone());
+            }
+        }
+
+        let mut sources: Vec<SourceNode> = self
+            .source_events
+            .iter()
+            .map(|(name, emitted)| SourceNode {
+                name: name.clone(),
+                events: emitted.iter().map(|event| event_name(event).to_string()).collect(),
+            })
+            .collect();
+        sources.sort_by(|a, b| a.name.cmp(&b.name));
+        for source in &sources {
+            for event in &source.events {
+                events.entry(event.clone()).or_insert_with(|| EventNode::new(event));
+            }
+        }
+
+        let mut emits = Vec::new();
+        if let Some(counts) = &self.emit_counts {
+            for node in events.values_mut() {
+                node.emits = Some(0);
+            }
+            for ((source, event), count) in counts {
+                let node = events
+                    .entry(event_name(event).to_string())
+                    .or_insert_with(|| EventNode::new(event_name(event)));
+                *node.emits.get_or_insert(0) += count;
+                if let Some(source) = source {
+                    emits.push(EmitEdge { source: source.clone(), event: node.name.clone(), count: *count });
+                }
+            }
+            emits.sort_by(|a, b| (&a.source, &a.event).cmp(&(&b.source, &b.event)));
+        }
+
+        Topology { events: events.into_values().collect(), sources, emits }
+    }
+
+    /// Yetkilendirme politikası varsa dinleyicinin event'e bağlanabileceğini doğrular.
+    fn authorize_listen(&self, event: &RuntimeEvent, listener: &RuntimeEventListener) -> Result<()> {
+        let Some(authorization) = &self.authorization else {
+            return Ok(());
+        };
+        let meta = PayloadMeta {
+            access: Access::Listen,
+            type_name: None,
+            size: 0,
+            tenant: listener.tenant.as_deref(),
+        };
+        policy::authorize(&**authorization, Some(&listener.tag), event, &meta)
+    }
+
+    /// Yetkilendirme politikası varsa emit'i yapan handler'ın (veya uygulamanın) event'i
+    /// yayınlayabileceğini doğrular.
+    pub(crate) fn authorize_emit(&self, event: &RuntimeEvent, meta: &PayloadMeta<'_>) -> Result<()> {
+        match &self.authorization {
+            Some(authorization) => policy::authorize(&**authorization, crate::dispatch::source_tag().as_deref(), event, meta),
+            None => Ok(()),
+        }
+    }
+
+    /// Ad politikası varsa event'in uygulama tarafından yayınlanabileceğini doğrular.
+    pub(crate) fn check_emit(&self, event: &RuntimeEvent) -> Result<()> {
+        match &self.policy {
+            Some(policy) => policy.check_event(event),
+            None => Ok(()),
+        }
+    }
+
+    /// Anahtar bu event için yakın zamanda görüldüyse emit'i bastırılmış olarak sayar.
+    pub(crate) fn is_duplicate(&mut self, event: &RuntimeEvent, key: &str) -> bool {
+        let duplicate = self.dedup.is_duplicate(event, key, crate::clock::now());
+        if duplicate {
+            self.stats.record_suppressed();
+        }
+        duplicate
+    }
+
+    pub(crate) fn check_bundle(&self, bundle: &ListenerBundle) -> Result<()> {
+        if let Some(policy) = &self.policy {
+            policy.check_bundle(bundle)?;
+        }
+        for (event, listener) in bundle {
+            self.authorize_listen(event, listener)?;
+            let declared = self.guarantee(event);
+            if listener.requires > declared {
+                return Err(Error::GuaranteeMismatch {
+                    event: event_name(event).to_string(),
+                    required: listener.requires,
+                    declared,
+                });
+            }
+        }
+        Ok(())
+    }
+
+    pub(crate) fn guarantee(&self, event: &RuntimeEvent) -> Guarantee {
+        self.guarantees.get(event).copied().unwrap_or_default()
+    }
+
+    /// Bundle'ı ekler ve `replay` isteyen dinleyicilere verilecek geçmişi toplar.
+    pub(crate) fn attach_bundle(&mut self, bundle: ListenerBundle) -> Vec<Replay> {
+        let mut replays = Vec::new();
+        for (event, listener) in bundle {
+            let wants_replay = listener.replay > 0 && self.is_enabled(&listener);
+            let listener = self.prepare(listener);
+            if wants_replay && let Some(buffer) = self.retained.get(&event) {
+                let payloads = buffer.last(listener.replay, &listener);
+                if !payloads.is_empty() {
+                    let plan = DispatchPlan {
+                        event: event.clone(),
+                        listeners: ListenerSnapshot::from_elem(Arc::clone(&listener), 1),
+                        replay: None,
+                        batch: None,
+                        attempt: 1,
+                        queue: None,
+                        concurrent: false,
+                        deadline: None,
+                        trace: true,
+                        telemetry: Arc::clone(&self.telemetry),
+                        #[cfg(feature = "tracing")]
+                        spans: Arc::clone(&self.spans),
+                        stats: Arc::clone(&self.stats),
+                        map: None,
+                        guarantee: self.guarantee(&event),
+                        tenant: None,
+                        track_source: self.tracks_source(),
+                        inherit_priority: false,
+                        yield_policy: YieldPolicy::default(),
+                    };
+                    replays.push(Replay { plan, payloads });
+                }
+            }
+            self.insert_listener(event, listener);
+        }
+        replays
+    }
+
+    /// `plan`'ın emit başına ayarlarla geçersiz kılınmış hâli.
+    pub(crate) fn plan_with(&mut self, event: &RuntimeEvent, options: &EmitOptions) -> Option<DispatchPlan> {
+        let tenant = options.tenant.clone().or_else(context::current_tenant);
+        let plan = self.plan_scoped(event, tenant)?.with_options(options, &self.queue);
+        // Bus Queued modda değilken tek bir emit Queued istenirse worker'lar o an başlatılır
+        if options.mode == Some(DispatchMode::Queued) {
+            self.ensure_workers();
+        }
+        Some(plan)
+    }
+
+    /// Başlangıçtan bu yana biriken emit, handler ve dinleyici istatistikleri.
+    pub fn stats(&self) -> BusStats {
+        let mut listeners: Vec<(String, usize)> = self
+            .pairs
+            .iter()
+            .filter(|(_, listeners)| !listeners.is_empty())
+            .map(|(event, listeners)| (event_name(event).to_string(), listeners.len()))
+            .collect();
+        listeners.sort();
+        self.stats.snapshot(listeners)
+    }
+
+    /// Sızıntı takibi için büyüyebilen kaynakların sayımı; bkz. `RuntimeSnapshot`.
+    pub fn runtime_snapshot(&self) -> RuntimeSnapshot {
+        let mut metrics = BTreeMap::new();
+        let attached = self.pairs.iter().flat_map(|(event, listeners)| listeners.iter().map(move |l| (event, l)));
+        let parked = self.parked.iter().map(|(event, listener)| (event, listener));
+        let mut backlogs: Vec<&Arc<Backlog>> = Vec::new();
+        for (event, listener) in attached.chain(parked) {
+            *metrics.entry(format!("listeners:{}", event_name(event))).or_insert(0) += 1;
+            *metrics.entry(format!("listeners.tag:{}", listener.tag)).or_insert(0) += 1;
+            // Instance'ın dinleyicileri tek backlog'u paylaşır
+            if !backlogs.iter().any(|backlog| Arc::ptr_eq(backlog, &listener.backlog)) {
+                backlogs.push(&listener.backlog);
+            }
+        }
+        for (event, buffer) in &self.retained {
+            metrics.insert(format!("retained:{}", event_name(event)), buffer.len());
+        }
+        metrics.insert("services".into(), self.services.len());
+        metrics.insert("queued".into(), self.queue.len());
+        metrics.insert("backlog".into(), backlogs.iter().map(|backlog| backlog.len()).sum());
+        metrics.insert("sources".into(), self.sources.len());
+        RuntimeSnapshot { taken_at: crate::clock::now(), metrics }
+    }
+
+    pub fn add_telemetry_observer(&mut self, observer: Arc<dyn TelemetryObserver>) {
+        self.telemetry = self.telemetry.iter().cloned().chain([observer]).collect();
     }
 
     pub async fn emit<T: Send + Sync + 'static>(&mut self, event: &RuntimeEvent, arg: T) {
-        // Sıfır kopya: Veri bir kez Arc içine alınır
-        let shared_payload = std::sync::Arc::new(arg);
+        if let Some(plan) = self.plan(event) {
+            plan.deliver(Priority::Normal, arg).await;
+        }
+    }
 
-        if let Some(listeners) = self.pairs.get(event) {
-            for listener in listeners {
-                // Her handler'a verinin pointer'ı (Arc) gönderilir
-                (listener.handler)(&shared_payload).await;
+    pub fn add_bundle(&mut self, bundle: ListenerBundle) {
+        for (event, listener) in bundle {
+            self.add_listener(event, listener);
+        }
+    }
+
+    /// Çok sayıda dinleyiciyi (ör. bir plugin'in keşfedilen handler'ları) tek seferde ekler.
+    /// Önce tüm kayıtlar doğrulanır (ad ve yetkilendirme politikası, teslim garantisi, tekrar
+    /// eden kayıtlar); biri geçersizse hiçbiri eklenmez. `add_listener` gibi `replay` geçmişi
+    /// verilmez; geçmiş için `rumt::register_many` kullanılmalıdır.
+    pub fn register_many(&mut self, entries: impl IntoIterator<Item = (RuntimeEvent, RuntimeEventListener)>) -> Result<()> {
+        self.register_entries(entries.into_iter().collect()).map(drop)
+    }
+
+    pub(crate) fn register_entries(&mut self, bundle: ListenerBundle) -> Result<Vec<Replay>> {
+        self.check_bundle(&bundle)?;
+        self.check_duplicates(&bundle)?;
+        Ok(self.attach_bundle(bundle))
+    }
+
+    /// Aynı event için aynı tag, tenant ve instance ile ikinci bir kayıt varsa hata döner.
+    fn check_duplicates(&self, bundle: &ListenerBundle) -> Result<()> {
+        let same = |a: &RuntimeEventListener, b: &RuntimeEventListener| {
+            a.tag == b.tag && a.tenant == b.tenant && a.instance == b.instance
+        };
+        let mut seen: HashMap<&RuntimeEvent, Vec<&RuntimeEventListener>> = HashMap::new();
+        for (event, listener) in bundle {
+            let batch = seen.entry(event).or_default();
+            if self.registered(event).chain(batch.iter().copied()).any(|other| same(other, listener)) {
+                return Err(Error::DuplicateListener {
+                    event: event_name(event).to_string(),
+                    tag: listener.tag.clone(),
+                });
+            }
+            batch.push(listener);
+        }
+        Ok(())
+    }
+
+    /// Event'e bağlı dinleyiciler ile flag'i kapalı olduğu için bekletilenler.
+    fn registered<'a>(&'a self, event: &'a RuntimeEvent) -> impl Iterator<Item = &'a RuntimeEventListener> {
+        let attached = self.pairs.get(event).into_iter().flatten().map(|l| &**l);
+        attached.chain(self.parked.iter().filter(move |(e, _)| e == event).map(|(_, l)| &**l))
+    }
+
+    /// Bundle'daki dinleyicilerle aynı tag ve tenant'la aynı event'i dinleyen başka
+    /// instance'lara `BusConfig::duplicate_listeners` politikasını uygular. `Reject` iken
+    /// hiçbir şey değiştirilmeden hata döner.
+    pub(crate) fn resolve_duplicates(&mut self, bundle: &ListenerBundle) -> Result<DuplicateOutcome> {
+        let policy = self.config.duplicate_listeners;
+        let mut outcome = DuplicateOutcome::default();
+        let mut replaced = Vec::new();
+        for (event, listener) in bundle {
+            let other = |l: &RuntimeEventListener| l.tag == listener.tag && l.tenant == listener.tenant && l.instance != listener.instance;
+            let previous: Vec<Option<InstanceId>> = self.registered(event).filter(|l| other(l)).map(|l| l.instance).collect();
+            if previous.is_empty() {
+                continue;
+            }
+            let (tag, name) = (&listener.tag, event_name(event));
+            match policy {
+                DuplicatePolicy::Reject => {
+                    return Err(Error::DuplicateListener { event: name.to_string(), tag: tag.clone() });
+                }
+                DuplicatePolicy::Allow => {
+                    outcome.warnings.push(format!("`{tag}` is already listening to `{name}`; both registrations will be invoked"));
+                }
+                DuplicatePolicy::Replace => {
+                    outcome.warnings.push(format!("`{tag}` is already listening to `{name}`; replacing the previous registration"));
+                    if let Some(listeners) = self.pairs.get_mut(event) {
+                        listeners.retain(|l| !other(l));
+                    }
+                    self.parked.retain(|(e, l)| e != event || !other(l));
+                    for instance in previous.into_iter().flatten() {
+                        if !replaced.contains(&instance) {
+                            replaced.push(instance);
+                        }
+                    }
+                }
             }
         }
+        // Tüm handler'ları değiştirilen instance'lar kapatılır
+        for instance in replaced {
+            let remaining = self.pairs.values().flatten().chain(self.parked.iter().map(|(_, l)| l));
+            if !remaining.into_iter().any(|l| l.instance == Some(instance))
+                && let Some(index) = self.services.iter().position(|s| s.instance == instance)
+            {
+                outcome.replaced.push(self.services.remove(index));
+            }
+        }
+        Ok(outcome)
+    }
+
+    /// Tag'e ait tüm handler'ları tek kilit altında yenileriyle değiştirir. Araya giren emit
+    /// olmaz: her emit ya eski ya da yeni handler kümesini görür. Yeni bundle önce doğrulanır
+    /// (`register_many` gibi); reddedilirse eski handler'lar yerinde kalır ve hata döner.
+    /// Tag'in kayıtlı servisleri çıkarılır; `on_dispose` kancaları kilit altında çalışamayacağı
+    /// için bus bırakıldıktan sonra `ReplaceOutcome::finish` ile çalıştırılmalıdır.
+    ///
+    /// ```rust,ignore
+    /// let outcome = RuntimeEventBus::try_with_instance_mut(|bus| bus.replace_by_tag("Pricing", bundle)).await??;
+    /// outcome.finish().await;
+    /// ```
+    pub fn replace_by_tag(&mut self, tag: &str, bundle: ListenerBundle) -> Result<ReplaceOutcome> {
+        self.replace_tag(tag, bundle, None)
+    }
 
-        // Tek seferlik eventlerin temizlenmesi
-        if let RuntimeEvent::OnceTriggered { .. } = event {
-            self.pairs.remove(event);
+    /// `replace_by_tag`; `controller` verilirse yeni instance eski servislerin kapanış fazıyla
+    /// kaydedilir.
+    pub(crate) fn replace_tag(
+        &mut self,
+        tag: &str,
+        bundle: ListenerBundle,
+        controller: Option<&ListenerController>,
+    ) -> Result<ReplaceOutcome> {
+        self.check_bundle(&bundle)?;
+        let replaced = self.take_services_where(|s| s.tag == tag);
+        self.remove_all_listeners_by_tag(tag);
+        if let Some(controller) = controller {
+            let phase = replaced.first().map_or(Phase::Domain, |s| s.phase);
+            self.register_service(controller, phase);
         }
+        let replays = self.attach_bundle(bundle);
+        Ok(ReplaceOutcome { replaced, replays })
+    }
+
+    pub(crate) fn register_service(&mut self, controller: &ListenerController, phase: Phase) {
+        self.services.push(RegisteredService {
+            instance: controller.instance_id(),
+            tag: controller.tag(),
+            service: Arc::clone(controller.service()),
+            phase,
+        });
+    }
+
+    /// Instance hâlâ kayıtlıysa servis kaydını çıkarıp döner (dispose kancası bir kez çalışsın diye).
+    pub(crate) fn take_service(&mut self, instance: InstanceId) -> Option<Arc<dyn RuntimeEventListenerTrait>> {
+        let index = self.services.iter().position(|s| s.instance == instance)?;
+        Some(self.services.remove(index).service)
+    }
+
+    pub(crate) fn take_services_by_tag(&mut self, tag: &str) -> Vec<Arc<dyn RuntimeEventListenerTrait>> {
+        self.take_services_where(|s| s.tag == tag).into_iter().map(|s| s.service).collect()
+    }
+
+    /// Fazdaki servisler, kayıt sırasıyla.
+    pub(crate) fn take_services_in(&mut self, phase: Phase) -> Vec<RegisteredService> {
+        self.take_services_where(|s| s.phase == phase)
+    }
+
+    fn take_services_where(&mut self, f: impl Fn(&RegisteredService) -> bool) -> Vec<RegisteredService> {
+        let (taken, kept) = std::mem::take(&mut self.services).into_iter().partition(f);
+        self.services = kept;
+        taken
+    }
+
+    /// Adresi `service` olan servis instance'ının `on_dispose` kancasını çalıştırır, ardından
+    /// yalnızca o instance'ın handler'larını kaldırır; aynı tipteki diğer instance'lar etkilenmez.
+    /// Makronun ürettiği `dispose_self` bunu kullanır.
+    #[doc(hidden)]
+    pub async fn dispose_service(service: usize) {
+        // Runtime zaten kapatıldıysa veya servis kayıtlı değilse kaldırılacak dinleyici yoktur
+        let registered = Self::try_with_instance_mut(|bus| {
+            let instance = bus
+                .services
+                .iter()
+                .find(|s| Arc::as_ptr(&s.service) as *const () as usize == service)?
+                .instance;
+            Some((instance, bus.take_service(instance)?))
+        })
+        .await
+        .ok()
+        .flatten();
+        let Some((instance, service)) = registered else {
+            return;
+        };
+        service.on_dispose().await;
+        let _ = Self::try_with_instance_mut(|bus| bus.remove_listeners_by_instance(instance)).await;
+    }
+
+    /// Kayıtlı tüm dinleyicilerin (bekletilenler dahil) ve servislerin kopyası; bus değişmez.
+    pub fn export_registrations(&self) -> RegistrationSnapshot {
+        let attached = self
+            .pairs
+            .iter()
+            .flat_map(|(event, listeners)| listeners.iter().map(move |l| (event.clone(), Arc::clone(l))));
+        RegistrationSnapshot {
+            listeners: attached.chain(self.parked.iter().cloned()).collect(),
+            services: self.services.clone(),
+        }
+    }
+
+    /// Snapshot'taki kayıtları bu bus'a bağlar. Flag'ler bu bus'ın değerlerine göre yeniden
+    /// değerlendirilir; bus'ta zaten bulunan dinleyiciler tekrar eklenmez.
+    pub fn import_registrations(&mut self, snapshot: RegistrationSnapshot) -> Result<()> {
+        if let Some(policy) = &self.policy {
+            for (event, _) in &snapshot.listeners {
+                policy.check_listen(event_name(event))?;
+            }
+        }
+        for (event, listener) in &snapshot.listeners {
+            self.authorize_listen(event, listener)?;
+        }
+        for (event, listener) in snapshot.listeners {
+            let present = self
+                .pairs
+                .get(&event)
+                .is_some_and(|listeners| listeners.iter().any(|l| Arc::ptr_eq(l, &listener)))
+                || self.parked.iter().any(|(_, l)| Arc::ptr_eq(l, &listener));
+            if present {
+                continue;
+            }
+            // Tag'in semaforu dinleyicilerle birlikte taşınır, sonraki kayıtlar da onu paylaşır
+            if let (Some(limit), Some(limiter)) = (listener.max_in_flight, &listener.limiter) {
+                self.limits
+                    .entry(listener.tag.clone())
+                    .or_insert_with(|| (limit, Arc::clone(limiter)));
+            }
+            self.insert_listener(event, listener);
+        }
+        for service in snapshot.services {
+            if !self.services.iter().any(|s| s.instance == service.instance) {
+                self.services.push(service);
+            }
+        }
+        Ok(())
+    }
+
+    pub fn remove_listeners_by_instance(&mut self, instance: InstanceId) {
+        for listeners in self.pairs.values_mut() {
+            listeners.retain(|l| l.instance != Some(instance));
+        }
+        self.parked.retain(|(_, l)| l.instance != Some(instance));
     }
 
     pub fn remove_all_listeners_by_tag(&mut self, tag: &str) {
         for listeners in self.pairs.values_mut() {
             listeners.retain(|l| l.tag != tag);
         }
+        self.parked.retain(|(_, l)| l.tag != tag);
     }
 }
 
 // --- Trait Tanımları ---
 
-pub trait RuntimeEventListenerTrait: Send + Sync {
-    fn dispose_self(&self) -> BoxFuture<'static, ()>;
+pub trait RuntimeEventListenerTrait: MaybeSend + MaybeSync {
+    /// Yalnızca bu instance'ın handler'larını kaldırır; `ListenerController::dispose` ile aynıdır.
+    fn dispose_self(&self) -> MaybeSendFuture<'static, ()>;
+
+    /// Servisin handler'ları bus'tan kaldırılmadan hemen önce (dispose, reload veya
+    /// `shutdown_runtime` sırasında) çağrılır; buffer'ları boşaltmak, bağlantıları kapatmak içindir.
+    /// Makroda `Servis [on_dispose = metod];` ile bağlanır.
+    fn on_dispose(&self) -> MaybeSendFuture<'_, ()> {
+        Box::pin(async {})
+    }
 }
 
-pub trait RuntimeEventListenerInitializer: Sized {
-    fn init(self) -> BoxFuture<'static, Arc<dyn RuntimeEventListenerTrait>>;
+pub trait RuntimeEventListenerInitializer: RuntimeEventListenerTrait + Sized + 'static {
+    /// Servisin handler'larını bus'ta gruplayan tag (makroda tipin adı).
+    const TAG: &'static str;
+
+    /// Servise bağlı handler'ları bus'a eklemeden oluşturur.
+    fn listener_bundle(service: &Arc<Self>) -> ListenerBundle;
+
+    /// Servisi kaydeder; kayıt başarısızsa panic eder.
+    #[deprecated(note = "panics inside the library; use `try_init` and handle the error")]
+    fn init(self) -> MaybeSendFuture<'static, ListenerController> {
+        let registration = self.try_init();
+        Box::pin(async move {
+            match registration.await {
+                Ok(controller) => controller,
+                Err(Error::NotInitialized) => panic!("RuntimeEventBus Not initialized! Call init_runtime first."),
+                Err(e) => panic!("{}: {e}", Self::TAG),
+            }
+        })
+    }
+
+    /// `init`'in panic etmeyen hâli; runtime başlatılmamışsa `Error::NotInitialized`, event adı
+    /// `NamePolicy`'e uymuyorsa `Error::InvalidEventName` döner.
+    /// Servis kapanışta `Phase::Domain` grubunda sayılır.
+    fn try_init(self) -> MaybeSendFuture<'static, Result<ListenerController>> {
+        self.try_init_in(Phase::Domain)
+    }
+
+    /// `try_init` gibi hemen kaydeder; servis `shutdown_runtime` sırasında `phase` grubuyla
+    /// kapatılır.
+    fn try_init_in(self, phase: Phase) -> MaybeSendFuture<'static, Result<ListenerController>> {
+        let (bundle, controller) = instance_bundle(self);
+        register(bundle, controller, phase)
+    }
+
+    /// `try_init` gibi kaydeder, ancak servisin tüm handler'ları yalnızca `tenant`'a ait
+    /// emit'leri alır. Aynı servis tipi her tenant için ayrı instance olarak kaydedilebilir.
+    fn try_init_scoped(self, tenant: impl Into<String>) -> MaybeSendFuture<'static, Result<ListenerController>> {
+        let (mut bundle, controller) = instance_bundle(self);
+        let tenant = tenant.into();
+        for (_, listener) in bundle.iter_mut() {
+            listener.tenant = Some(tenant.clone());
+        }
+        register(bundle, controller, Phase::Domain)
+    }
+
+    /// Kaydı hemen yapmak yerine verilen faza sıraya alır; `init_runtime` fazları sırasıyla
+    /// çalıştırır. Böylece ör. altyapı dinleyicileri domain servislerinden önce bağlanmış olur.
+    /// `shutdown_runtime` fazları ters sırayla kapatır.
+    fn defer_init(self, phase: Phase) -> DeferredInit {
+        phase::defer(phase, Box::new(move || self.try_init_in(phase)))
+    }
+
+    /// Aynı tag'e kayıtlı tüm handler'ları bu yeni instance'a bağlı olanlarla atomik olarak
+    /// değiştirir (yeni config, yeni bağlantılarla servisi yeniden yüklemek için).
+    /// Değiştirilen eski instance'ların `on_dispose` kancaları geçişten sonra çağrılır.
+    /// Yeni instance eski instance'ın kapanış fazını devralır.
+    fn reload(self) -> MaybeSendFuture<'static, Result<ListenerController>> {
+        let (bundle, controller) = instance_bundle(self);
+        Box::pin(async move {
+            RuntimeEventBus::try_with_instance_mut(|bus| bus.replace_tag(Self::TAG, bundle, Some(&controller)))
+                .await??
+                .finish()
+                .await;
+            Ok(controller)
+        })
+    }
+}
+
+fn register(
+    bundle: ListenerBundle,
+    controller: ListenerController,
+    phase: Phase,
+) -> MaybeSendFuture<'static, Result<ListenerController>> {
+    Box::pin(async move {
+        // Kayıt sırasında global bus'a asenkron erişim
+        let registered = RuntimeEventBus::try_with_instance_mut(|bus| {
+            bus.check_bundle(&bundle)?;
+            let duplicates = bus.resolve_duplicates(&bundle)?;
+            bus.register_service(&controller, phase);
+            Ok::<_, Error>((duplicates, bus.attach_bundle(bundle)))
+        })
+        .await?;
+        let (duplicates, replays) = match registered {
+            Ok(registered) => registered,
+            Err(e @ Error::DuplicateListener { .. }) => {
+                crate::log(crate::log::Level::Warn, "rumt::listeners", format!("rejected duplicate listener: {e}")).await;
+                return Err(e);
+            }
+            Err(e) => return Err(e),
+        };
+        duplicates.finish().await;
+        for replay in replays {
+            replay.run().await;
+        }
+        Ok(controller)
+    })
+}
+
+/// Tekrar eden dinleyiciler için bus kilidi bırakıldıktan sonra yapılacaklar.
+#[derive(Default)]
+pub(crate) struct DuplicateOutcome {
+    warnings: Vec<String>,
+    replaced: Vec<RegisteredService>,
+}
+
+impl DuplicateOutcome {
+    async fn finish(self) {
+        for warning in self.warnings {
+            crate::log(crate::log::Level::Warn, "rumt::listeners", warning).await;
+        }
+        for replaced in self.replaced {
+            replaced.service.on_dispose().await;
+        }
+    }
+}
+
+/// `replace_by_tag` ile değiştirilen servisler için bus kilidi bırakıldıktan sonra yapılacaklar.
+#[must_use = "replaced services are only disposed by `finish`"]
+pub struct ReplaceOutcome {
+    replaced: Vec<RegisteredService>,
+    replays: Vec<Replay>,
+}
+
+impl ReplaceOutcome {
+    /// Değiştirilen servis sayısı.
+    pub fn replaced(&self) -> usize {
+        self.replaced.len()
+    }
+
+    /// Eski servislerin `on_dispose` kancalarını çalıştırır, ardından `replay` isteyen yeni
+    /// dinleyicilere geçmişi verir.
+    pub async fn finish(self) {
+        for replaced in self.replaced {
+            replaced.service.on_dispose().await;
+        }
+        for replay in self.replays {
+            replay.run().await;
+        }
+    }
+}
+
+/// Servisin handler'larını yeni bir instance kimliğiyle işaretler ve controller'ını oluşturur.
+fn instance_bundle<S: RuntimeEventListenerInitializer>(service: S) -> (ListenerBundle, ListenerController) {
+    let service = Arc::new(service);
+    let instance = InstanceId::generate();
+    let paused = Arc::new(AtomicBool::new(false));
+    let backlog = Arc::new(Backlog::default());
+
+    let mut bundle = S::listener_bundle(&service);
+    let mut handles = Vec::with_capacity(bundle.len());
+    for (event, listener) in bundle.iter_mut() {
+        listener.instance = Some(instance);
+        listener.paused = Arc::clone(&paused);
+        listener.backlog = Arc::clone(&backlog);
+        handles.push(ListenerHandle {
+            event: event.clone(),
+            tag: S::TAG,
+            instance,
+        });
+    }
+
+    let controller = ListenerController::new(instance, S::TAG, paused, backlog, handles, service);
+    (bundle, controller)
 }
 
 
@@ -131,6 +1174,7 @@ pub trait RuntimeEventListenerInitializer: Sized {
 /// İş mantığını yürütecek olan servis. İçerisinde hem senkron hem asenkron metodlar barındırabilir.
 ///
 /// ```rust
+/// # pub struct OrderEvent { pub order_id: u64, pub total_amount: f64, pub customer_email: String }
 /// pub struct NotificationService {
 ///     sender_name: String,
 /// }
@@ -156,9 +1200,18 @@ pub trait RuntimeEventListenerInitializer: Sized {
 ///
 /// ## 3. Makro ile Bağlantı
 ///
-/// `event_handlers!` makrosu ile metodlar olaylara bağlanır.
+/// `event_handlers!` makrosu ile metodlar olaylara bağlanır. Senkron ve asenkron handler'lar
+/// aynı tanımda karışık kullanılabilir. Senkron handler'lar `emit_ref` ile yapılan emit'lerde
+/// veriyi kopyalanmadan, doğrudan referans olarak alır.
 ///
 /// ```rust
+/// # use rumt::prelude::*;
+/// # pub struct OrderEvent { pub order_id: u64, pub total_amount: f64, pub customer_email: String }
+/// # pub struct NotificationService { sender_name: String }
+/// # impl NotificationService {
+/// #     fn log_order(&self, event: &OrderEvent) {}
+/// #     async fn send_email(&self, event: &OrderEvent) {}
+/// # }
 /// event_handlers! {
 ///     NotificationService;
 ///     RuntimeEvent::Static { event_name: "order.completed".into() } => log_order : OrderEvent,
@@ -166,20 +1219,70 @@ pub trait RuntimeEventListenerInitializer: Sized {
 /// }
 /// ```
 ///
+/// Handler'a ait seçenekler ödeme tipinden sonra köşeli parantez içinde verilir:
+///
+/// | Seçenek | Açıklama |
+/// |---|---|
+/// | `enabled_if = "features.email"` | Handler yalnızca env'deki flag açıkken kayıtlı olur |
+/// | `replay = 10` | Bağlanırken event'in saklanan son 10 payload'ı handler'a verilir (`retain_last`) |
+/// | `requires = Guarantee::Durable` | Event env'de en az bu teslim garantisiyle tanımlanmamışsa kayıt reddedilir (`Guarantee`) |
+/// | `isolated` | Handler rumt'un ayrı, tek thread'li tokio runtime'ında çalışır; future'ın `Send` olması gerekmez (`!Send` FFI kütüphaneleri için). Emit yine handler bitene kadar bekler |
+///
+/// `coalesce` ile gruplanan eventlerde handler türünden önce `batch` yazılırsa handler,
+/// pencerede biriken payload'ları tek çağrıda dilim olarak alır:
+///
+/// ```rust,ignore
+/// RuntimeEvent::Static { event_name: "market.quote".into() } => async batch apply : Quote // async fn apply(&self, quotes: &[Arc<Quote>])
+/// ```
+///
+/// Servisin tüm handler'larına uygulanan seçenekler ise tipten sonra verilir:
+///
+/// | Seçenek | Açıklama |
+/// |---|---|
+/// | `max_in_flight = 2` | Servisin handler'larından en fazla 2 tanesi aynı anda çalışır (`Concurrent`/`Queued` modlarda yavaş tüketicileri sınırlar) |
+/// | `on_dispose = flush` | Handler'lar kaldırılmadan önce servisin `async fn flush(&self)` metodu çağrılır |
+///
+/// ```rust,ignore
+/// rumt::event_handlers! {
+///     ReportService [max_in_flight = 2];
+///     RuntimeEvent::Static { event_name: "report.requested".into() } => async build : ReportRequest
+/// }
+/// ```
+///
+/// ```rust,ignore
+/// RuntimeEvent::Static { event_name: "order.completed".into() } => async send_email : OrderEvent [enabled_if = "features.email"]
+/// ```
+///
 /// ## 4. Uygulama Akışı
 ///
 /// Sistemin asenkron olarak başlatılması ve olayın tetiklenmesi.
 ///
 /// ```rust
+/// # use rumt::prelude::*;
+/// # use rumt::{RuntimeModuleEnv, Unlocked};
+/// # pub struct OrderEvent { pub order_id: u64, pub total_amount: f64, pub customer_email: String }
+/// # pub struct NotificationService { sender_name: String }
+/// # impl NotificationService {
+/// #     pub fn new(name: &str) -> Self { Self { sender_name: name.into() } }
+/// #     fn log_order(&self, event: &OrderEvent) {}
+/// #     async fn send_email(&self, event: &OrderEvent) {}
+/// # }
+/// # event_handlers! {
+/// #     NotificationService;
+/// #     RuntimeEvent::Static { event_name: "order.completed".into() } => log_order : OrderEvent,
+/// #     RuntimeEvent::Static { event_name: "order.completed".into() } => async send_email : OrderEvent
+/// # }
 /// #[tokio::main]
 /// async fn main() {
 ///     // I. Runtime ve Global State Başlatma
-///     let env = RuntimeModuleEnv::new(); // Örnek env
-///     global::init_runtime(env).await;
+///     let env = RuntimeModuleEnv::<Unlocked>::new()
+///         .add_app_info("MyApp", "MyCompany", "com")
+///         .try_lock_env().expect("AppInfo eksik");
+///     rumt::try_init_runtime(env).await.expect("runtime başlatılamadı");
 ///
 ///     // II. Servisin Kaydedilmesi
 ///     let service = NotificationService::new("Otomatik Servis");
-///     let _controller = NotificationService::init(service).await;
+///     let _controller = service.try_init().await.expect("servis kaydedilemedi");
 ///
 ///     // III. Olayın Tetiklenmesi
 ///     let order_info = OrderEvent {
@@ -189,11 +1292,11 @@ pub trait RuntimeEventListenerInitializer: Sized {
 ///     };
 ///
 ///     println!("Olay tetikleniyor...");
-///     
+///
 ///     // Tüm handler'lar (log_order ve send_email) sırayla çalışır.
-///     global::emit_event(
+///     rumt::emit_event(
 ///         RuntimeEvent::Static { event_name: "order.completed".into() },
-///         &order_info
+///         order_info
 ///     ).await;
 ///
 ///     println!("Tüm süreç tamamlandı.");
@@ -205,62 +1308,151 @@ pub trait RuntimeEventListenerInitializer: Sized {
 /// satırı ancak her iki işlem de tamamen bittiğinde bir alt satıra geçer.
 #[macro_export]
 macro_rules! event_handlers {
-    // Giriş kolları: Async veya normal yazımı destekler
-    ($struct_name:ty; $($event:expr => $handler:ident : $arg:ty),* $(,)?) => {
-        $crate::event_handlers!(@impl $struct_name; $($event => $handler : $arg),*);
+    // Giriş kolu: servis tipi ve handler listesi
+    ($struct_name:ty; $($entries:tt)*) => {
+        $crate::event_handlers!(@munch ($struct_name) [] [] $($entries)*);
     };
-    ($struct_name:ty; $($event:expr => async $handler:ident : $arg:ty),* $(,)?) => {
-        $crate::event_handlers!(@impl $struct_name; $($event => $handler : $arg),*);
+    // Servis seçenekleri tipten sonra köşeli parantez içinde verilir
+    ($struct_name:ty [$($service_opt:tt)*]; $($entries:tt)*) => {
+        $crate::event_handlers!(@munch ($struct_name) [$($service_opt)*] [] $($entries)*);
+    };
+
+    // Handler listesi tek tek okunur; her handler async veya senkron olabilir.
+    // `batch` handler'lar `coalesce` ile gruplanan payload'ları `&[Arc<T>]` olarak alır
+    (@munch $struct_name:tt $service_opts:tt [$($done:tt)*] $event:expr => async batch $handler:ident : $arg:ty $([$($opt:tt)*])? $(, $($rest:tt)*)?) => {
+        $crate::event_handlers!(@munch $struct_name $service_opts [$($done)* (async $crate::batch::batch_event(&$event), $handler, std::vec::Vec<std::sync::Arc<$arg>>, [$($($opt)*)?])] $($($rest)*)?);
+    };
+    (@munch $struct_name:tt $service_opts:tt [$($done:tt)*] $event:expr => batch $handler:ident : $arg:ty $([$($opt:tt)*])? $(, $($rest:tt)*)?) => {
+        $crate::event_handlers!(@munch $struct_name $service_opts [$($done)* (sync $crate::batch::batch_event(&$event), $handler, std::vec::Vec<std::sync::Arc<$arg>>, [$($($opt)*)?])] $($($rest)*)?);
+    };
+    (@munch $struct_name:tt $service_opts:tt [$($done:tt)*] $event:expr => async $handler:ident : $arg:ty $([$($opt:tt)*])? $(, $($rest:tt)*)?) => {
+        $crate::event_handlers!(@munch $struct_name $service_opts [$($done)* (async $event, $handler, $arg, [$($($opt)*)?])] $($($rest)*)?);
+    };
+    (@munch $struct_name:tt $service_opts:tt [$($done:tt)*] $event:expr => $handler:ident : $arg:ty $([$($opt:tt)*])? $(, $($rest:tt)*)?) => {
+        $crate::event_handlers!(@munch $struct_name $service_opts [$($done)* (sync $event, $handler, $arg, [$($($opt)*)?])] $($($rest)*)?);
+    };
+    (@munch ($struct_name:ty) $service_opts:tt [$($entry:tt)*]) => {
+        $crate::event_handlers!(@impl $struct_name; $service_opts; $($entry)*);
+    };
+
+    // Handler çağrısı
+    (@call async $service:ident . $handler:ident ($data:ident)) => {
+        $service.$handler(&$data).await
+    };
+    (@call sync $service:ident . $handler:ident ($data:ident)) => {
+        $service.$handler(&$data)
+    };
+
+    // Senkron handler'lar ayrıca ödünç alınmış veriyle çağrılabilir (`emit_ref`)
+    (@borrowed async $listener:ident, $service:ident, $handler:ident, $arg:ty) => {};
+    (@borrowed sync $listener:ident, $service:ident, $handler:ident, $arg:ty) => {
+        let borrowed_service = std::sync::Arc::clone($service);
+        $listener = $listener.with_borrowed_handler(std::boxed::Box::new(move |args: &dyn std::any::Any| {
+            if let Some(data) = args.downcast_ref::<$arg>() {
+                borrowed_service.$handler(data);
+            }
+        }));
+    };
+
+    // Handler future'ı: `isolated` seçeneği varsa rumt'un izole thread'inde çalışır (`Send` olması gerekmez)
+    (@future [] $body:block) => {
+        std::boxed::Box::pin(async move $body) as $crate::rt::MaybeSendFuture<'static, ()>
+    };
+    (@future [isolated $(, $($rest:tt)*)?] $body:block) => {
+        std::boxed::Box::pin($crate::isolated::run(std::boxed::Box::new(move || {
+            std::boxed::Box::pin(async move $body) as $crate::futures::future::LocalBoxFuture<'static, ()>
+        }))) as $crate::futures::future::BoxFuture<'static, ()>
+    };
+    (@future [$option:ident = $value:expr $(, $($rest:tt)*)?] $body:block) => {
+        $crate::event_handlers!(@future [$($($rest)*)?] $body)
+    };
+
+    // Handler seçenekleri
+    (@listener_options $listener:ident;) => {};
+    (@listener_options $listener:ident; isolated $(, $($rest:tt)*)?) => {
+        // `emit_ref` de handler'ı çağıran thread'de değil izole thread'de çalıştırmalı
+        $listener = $listener.without_borrowed_handler();
+        $crate::event_handlers!(@listener_options $listener; $($($rest)*)?);
+    };
+    (@listener_options $listener:ident; enabled_if = $flag:expr $(, $($rest:tt)*)?) => {
+        $listener = $listener.enabled_if($flag);
+        $crate::event_handlers!(@listener_options $listener; $($($rest)*)?);
+    };
+    (@listener_options $listener:ident; requires = $guarantee:expr $(, $($rest:tt)*)?) => {
+        $listener = $listener.requires($guarantee);
+        $crate::event_handlers!(@listener_options $listener; $($($rest)*)?);
+    };
+    (@listener_options $listener:ident; replay = $count:expr $(, $($rest:tt)*)?) => {
+        $listener = $listener.replay($count);
+        $crate::event_handlers!(@listener_options $listener; $($($rest)*)?);
+    };
+
+    // Servis seçenekleri (tag'e ait tüm handler'lara uygulanır)
+    (@service_options $listener:ident; []) => {};
+    (@service_options $listener:ident; [on_dispose = $hook:ident $(, $($rest:tt)*)?]) => {
+        $crate::event_handlers!(@service_options $listener; [$($($rest)*)?]);
+    };
+    (@service_options $listener:ident; [max_in_flight = $limit:expr $(, $($rest:tt)*)?]) => {
+        $listener = $listener.max_in_flight($limit);
+        $crate::event_handlers!(@service_options $listener; [$($($rest)*)?]);
+    };
+
+    // `on_dispose` kancası: servis seçenekleri arasında aranır, yoksa varsayılan (boş) kalır
+    (@on_dispose []) => {};
+    (@on_dispose [on_dispose = $hook:ident $(, $($rest:tt)*)?]) => {
+        fn on_dispose(&self) -> $crate::rt::MaybeSendFuture<'_, ()> {
+            std::boxed::Box::pin(self.$hook())
+        }
+    };
+    (@on_dispose [$option:ident = $value:expr $(, $($rest:tt)*)?]) => {
+        $crate::event_handlers!(@on_dispose [$($($rest)*)?]);
     };
 
     // Merkezi Uygulama Mantığı
-    (@impl $struct_name:ty; $( $event_variant:expr => $handler_fn:ident : $arg_type:ty ),*) => {
+    (@impl $struct_name:ty; $service_opts:tt; $( ($kind:ident $event_variant:expr, $handler_fn:ident, $arg_type:ty, [$($opt:tt)*]) )*) => {
         impl $crate::event_bus::RuntimeEventListenerTrait for $struct_name {
-            fn dispose_self(&self) -> $crate::futures::future::BoxFuture<'static, ()> {
-                let tag = std::stringify!($struct_name);
-                std::boxed::Box::pin(async move {
-                    // Dispose sırasında global bus'a güvenli asenkron erişim
-                    $crate::event_bus::RuntimeEventBus::with_instance_mut(|bus| {
-                        bus.remove_all_listeners_by_tag(tag);
-                    }).await;
-                })
+            fn dispose_self(&self) -> $crate::rt::MaybeSendFuture<'static, ()> {
+                // Instance kayıtlı servisin adresiyle bulunur; aynı tipteki diğer instance'lar kalır
+                let service = self as *const Self as *const () as usize;
+                std::boxed::Box::pin($crate::event_bus::RuntimeEventBus::dispose_service(service))
             }
+
+            $crate::event_handlers!(@on_dispose $service_opts);
         }
 
         impl $crate::event_bus::RuntimeEventListenerInitializer for $struct_name {
-            fn init(self) -> $crate::futures::future::BoxFuture<'static, std::sync::Arc<dyn $crate::event_bus::RuntimeEventListenerTrait>> {
-                let service = std::sync::Arc::new(self);
-                let service_clone = std::sync::Arc::clone(&service);
-                let struct_tag = std::stringify!($struct_name);
-
-                std::boxed::Box::pin(async move {
-                    // Kayıt sırasında global bus'a asenkron erişim
-                    $crate::event_bus::RuntimeEventBus::with_instance_mut(|bus| {
-                        $(
-                            let arc_clone = std::sync::Arc::clone(&service_clone);
-                            let event = $event_variant;
-
-                            let handler = std::boxed::Box::new(move |args: &dyn $crate::event_bus::RuntimeEventListenerHandlerArg| {
-                                let arc_inner = std::sync::Arc::clone(&arc_clone);
-                                // Veri downcast edilirken Arc<$arg_type> olarak karşılanır
-                                let maybe_shared = args.downcast::<std::sync::Arc<$arg_type>>().map(|a| std::sync::Arc::clone(a));
-                                
-                                std::boxed::Box::pin(async move {
-                                    if let Some(shared_data) = maybe_shared {
-                                        // Downcast başarılıysa servis metodunu çağır
-                                        arc_inner.$handler_fn(&shared_data).await;
-                                    }
-                                }) as $crate::futures::future::BoxFuture<'static, ()>
-                            });
-
-                            let listener = $crate::event_bus::RuntimeEventListener::new(struct_tag, handler);
-                            bus.add_listener(event, listener);
-                        )*
-                    }).await;
-
-                    service as std::sync::Arc<dyn $crate::event_bus::RuntimeEventListenerTrait>
-                })
+            const TAG: &'static str = std::stringify!($struct_name);
+
+            fn listener_bundle(service: &std::sync::Arc<Self>) -> $crate::event_bus::ListenerBundle {
+                #[allow(unused_mut)]
+                let mut bundle = $crate::event_bus::ListenerBundle::new();
+                $(
+                    let arc_clone = std::sync::Arc::clone(service);
+                    let event = $event_variant;
+
+                    let handler = std::boxed::Box::new(move |args: &dyn $crate::event_bus::RuntimeEventListenerHandlerArg| {
+                        let arc_inner = std::sync::Arc::clone(&arc_clone);
+                        // Veri downcast edilirken Arc<$arg_type> olarak karşılanır
+                        let maybe_shared = args.downcast::<std::sync::Arc<$arg_type>>().map(|a| std::sync::Arc::clone(a));
+
+                        $crate::event_handlers!(@future [$($opt)*] {
+                            if let Some(shared_data) = maybe_shared {
+                                // Downcast başarılıysa servis metodunu çağır
+                                $crate::event_handlers!(@call $kind arc_inner.$handler_fn(shared_data));
+                            }
+                        })
+                    });
+
+                    #[allow(unused_mut)]
+                    let mut listener = $crate::event_bus::RuntimeEventListener::new(Self::TAG, handler)
+                        .handler_name(std::stringify!($handler_fn));
+                    $crate::event_handlers!(@borrowed $kind listener, service, $handler_fn, $arg_type);
+                    $crate::event_handlers!(@listener_options listener; $($opt)*);
+                    $crate::event_handlers!(@service_options listener; $service_opts);
+                    bundle.push((event, listener));
+                )*
+                bundle
             }
         }
     };
-}
\ No newline at end of file
+}
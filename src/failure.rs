@@ -0,0 +1,77 @@
+use std::{any::Any, fmt};
+
+use crate::context::{Context, EventId};
+use crate::event_bus::RuntimeEvent;
+use crate::telemetry::event_name;
+
+/// Bir handler başarısız olduğunda yayınlanan event; payload'ı `HandlerFailure`.
+pub const HANDLER_FAILED: &str = "rumt.handler.failed";
+
+/// Başarısız bir handler çalışmasının bağlamı. Aynı değer `rumt.handler.failed` event'ine ve
+/// telemetri gözlemcilerine (`HandlerSpan::failure`) verilir; `Durable` eventlerin tekrar
+/// teslimleri `attempt` ile sayılır. Böylece hata hangi yoldan görülürse görülsün aynı bilgi taşınır.
+///
+/// ```rust,ignore
+/// event_handlers! {
+///     Alerts;
+///     RuntimeEvent::Static { event_name: "rumt.handler.failed".into() } => page : HandlerFailure
+/// }
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct HandlerFailure {
+    pub event: String,
+    pub tag: String,
+    /// Kataloğa kayıtlı (`register_events`) payload tipi, yoksa payload'ın Rust tip adı.
+    pub payload: String,
+    pub correlation_id: EventId,
+    pub event_id: EventId,
+    /// Kaçıncı deneme olduğu; 1'den başlar, her `redeliver` ile artar.
+    pub attempt: u32,
+    /// Panic mesajı.
+    pub message: String,
+}
+
+impl HandlerFailure {
+    pub(crate) fn new(
+        event: &RuntimeEvent,
+        tag: &str,
+        context: &Context,
+        payload_type: &str,
+        attempt: u32,
+        panic: &(dyn Any + Send),
+    ) -> Self {
+        let event = event_name(event).to_string();
+        let payload = match crate::catalog::payload_of(&event) {
+            Some(payload) => payload.to_string(),
+            None => {
+                // Dispatch payload'ı `Arc<T>` olarak taşır; adı `T`'ninki olmalı
+                let name = payload_type.strip_prefix("alloc::sync::Arc<").and_then(|name| name.strip_suffix('>'));
+                name.unwrap_or(payload_type).to_string()
+            }
+        };
+        let message = match (panic.downcast_ref::<&str>(), panic.downcast_ref::<String>()) {
+            (Some(message), _) => message.to_string(),
+            (_, Some(message)) => message.clone(),
+            _ => "handler panicked".to_string(),
+        };
+        Self {
+            event,
+            tag: tag.to_string(),
+            payload,
+            correlation_id: context.correlation_id,
+            event_id: context.event_id,
+            attempt,
+            message,
+        }
+    }
+}
+
+impl fmt::Display for HandlerFailure {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "`{}` failed on `{}` ({}, attempt {}, correlation {}): {}",
+            self.tag, self.event, self.payload, self.attempt, self.correlation_id.0, self.message
+        )
+    }
+}
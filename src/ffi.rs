@@ -0,0 +1,247 @@
+//! C ABI (`ffi` feature). Başlık dosyası `include/rumt.h` içindedir ve
+//! `cbindgen --config cbindgen.toml` ile yeniden üretilebilir.
+//!
+//! Tüm fonksiyonlar çağıran thread'i bloklar. Listener callback'leri rumt'un
+//! runtime thread'lerinden çağrılabilir; host tarafı buna göre senkronize etmelidir.
+//! Callback içinden (veya başka bir tokio runtime'ında çalışan bir thread'den) `rumt_*`
+//! çağrılamaz: runtime bloklanamayacağı için çağrı `RUMT_ERR_REENTRANT` ile reddedilir.
+//! Callback'te tetiklenecek işler host'un kendi thread'ine aktarılmalıdır.
+
+use once_cell::sync::Lazy;
+use std::{
+    ffi::{CStr, c_char, c_int, c_void},
+    sync::{
+        Mutex as StdMutex, MutexGuard, PoisonError,
+        atomic::{AtomicU64, Ordering},
+    },
+};
+
+use crate::codec::{CodecRegistry, PayloadCodec};
+use crate::error::Error;
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg};
+use crate::state::Unlocked;
+
+pub const RUMT_OK: c_int = 0;
+pub const RUMT_ERR_INVALID_ARGUMENT: c_int = -1;
+pub const RUMT_ERR_NOT_INITIALIZED: c_int = -2;
+pub const RUMT_ERR_UNKNOWN_CODEC: c_int = -3;
+pub const RUMT_ERR_CODEC: c_int = -4;
+/// `rumt_init` başarısız oldu (ör. env yükleyicisi veya env kilidi) ya da FFI runtime'ı
+/// başlatılamadı.
+pub const RUMT_ERR_INIT_FAILED: c_int = -5;
+/// Çağrı bir listener callback'inden veya bir tokio runtime thread'inden yapıldı.
+pub const RUMT_ERR_REENTRANT: c_int = -6;
+/// Event adı runtime'ın `NamePolicy` kurallarına uymuyor.
+pub const RUMT_ERR_INVALID_EVENT_NAME: c_int = -7;
+/// Runtime'ın `AuthorizationPolicy`'si kaydı reddetti.
+pub const RUMT_ERR_UNAUTHORIZED: c_int = -8;
+/// Kayıt bus'ın başka bir kuralıyla reddedildi (ör. teslim garantisi).
+pub const RUMT_ERR_REJECTED: c_int = -9;
+/// `rumt_init` runtime `rumt_shutdown` ile kapatılmadan tekrar çağrıldı.
+pub const RUMT_ERR_ALREADY_INITIALIZED: c_int = -10;
+
+/// Listener callback'i: `user_data`, event adı ve codec ile kodlanmış payload.
+/// Callback içinden `rumt_*` fonksiyonları çağrılamaz (`RUMT_ERR_REENTRANT`).
+pub type RumtListenerCallback =
+    extern "C" fn(user_data: *mut c_void, event_name: *const c_char, data: *const u8, len: usize);
+
+// Başlatılamazsa her çağrı `RUMT_ERR_INIT_FAILED` döner
+static FFI_RUNTIME: Lazy<Option<tokio::runtime::Runtime>> = Lazy::new(|| {
+    tokio::runtime::Builder::new_multi_thread()
+        .enable_all()
+        .thread_name("rumt-ffi")
+        .build()
+        .ok()
+});
+
+static CODECS: Lazy<StdMutex<CodecRegistry>> = Lazy::new(|| StdMutex::new(CodecRegistry::with_builtin()));
+static NEXT_LISTENER_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Rust tarafındaki bir payload tipini C host'a açar. `rumt_emit` ve
+/// `rumt_register_listener` bu isimle codec seçer.
+pub fn register_codec<T, C>(name: impl Into<String>, codec: C)
+where
+    T: Send + Sync + 'static,
+    C: PayloadCodec<T>,
+{
+    codecs().register::<T, C>(name, codec);
+}
+
+// Kilidi tutan thread panic etse de kayıtlar tutarlıdır; C fonksiyonlarında panic host'u sonlandırır
+fn codecs() -> MutexGuard<'static, CodecRegistry> {
+    CODECS.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+fn error_code(error: &Error) -> c_int {
+    match error {
+        Error::NotInitialized => RUMT_ERR_NOT_INITIALIZED,
+        Error::InvalidEventName { .. } => RUMT_ERR_INVALID_EVENT_NAME,
+        Error::Unauthorized(_) => RUMT_ERR_UNAUTHORIZED,
+        Error::Codec(_) => RUMT_ERR_CODEC,
+        _ => RUMT_ERR_REJECTED,
+    }
+}
+
+/// `future`'ı FFI runtime'ında çalıştırır. Çağıran thread zaten bir tokio runtime'ındaysa
+/// (ör. rumt'un çağırdığı bir callback) `block_on` panic edeceği için `RUMT_ERR_REENTRANT`,
+/// runtime başlatılamadıysa `RUMT_ERR_INIT_FAILED` döner.
+fn block_on<F: std::future::Future>(future: F) -> Result<F::Output, c_int> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(RUMT_ERR_REENTRANT);
+    }
+    match &*FFI_RUNTIME {
+        Some(runtime) => Ok(runtime.block_on(future)),
+        None => Err(RUMT_ERR_INIT_FAILED),
+    }
+}
+
+fn is_initialized() -> Result<bool, c_int> {
+    block_on(async { crate::global::RUNTIME_EVENT_BUS.lock().await.is_some() })
+}
+
+/// # Safety
+/// `ptr` null veya geçerli, null ile biten bir C string olmalıdır.
+unsafe fn read_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        return None;
+    }
+    unsafe { CStr::from_ptr(ptr) }.to_str().ok()
+}
+
+/// Runtime'ı başlatır. Başlatılamazsa `RUMT_ERR_INIT_FAILED`, runtime zaten çalışıyorsa
+/// `RUMT_ERR_ALREADY_INITIALIZED` döner.
+///
+/// # Safety
+/// Parametreler geçerli, null ile biten UTF-8 C string'leri olmalıdır.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rumt_init(
+    app_name: *const c_char,
+    company: *const c_char,
+    qualifier: *const c_char,
+) -> c_int {
+    let (Some(app_name), Some(company), Some(qualifier)) =
+        (unsafe { read_str(app_name) }, unsafe { read_str(company) }, unsafe { read_str(qualifier) })
+    else {
+        return RUMT_ERR_INVALID_ARGUMENT;
+    };
+
+    let Ok(env) = crate::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info(app_name, company, qualifier)
+        .try_lock_env()
+    else {
+        return RUMT_ERR_INIT_FAILED;
+    };
+    match block_on(crate::global::try_init_runtime(env)) {
+        Ok(Ok(())) => RUMT_OK,
+        Ok(Err(Error::AlreadyInitialized)) => RUMT_ERR_ALREADY_INITIALIZED,
+        Ok(Err(_)) => RUMT_ERR_INIT_FAILED,
+        Err(code) => code,
+    }
+}
+
+/// `data` byte'larını `codec` ile çözüp `event_name` event'i olarak yayınlar.
+/// Handler'lar tamamlanana kadar döner.
+///
+/// # Safety
+/// String parametreler geçerli C string'leri, `data` en az `len` byte okunabilir olmalıdır
+/// (`len == 0` ise null olabilir).
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rumt_emit(
+    event_name: *const c_char,
+    data: *const u8,
+    len: usize,
+    codec: *const c_char,
+) -> c_int {
+    let (Some(event_name), Some(codec)) = (unsafe { read_str(event_name) }, unsafe { read_str(codec) }) else {
+        return RUMT_ERR_INVALID_ARGUMENT;
+    };
+    if data.is_null() && len > 0 {
+        return RUMT_ERR_INVALID_ARGUMENT;
+    }
+    let bytes = if len == 0 { &[][..] } else { unsafe { std::slice::from_raw_parts(data, len) } };
+
+    let Some(codec) = codecs().get(codec) else {
+        return RUMT_ERR_UNKNOWN_CODEC;
+    };
+    match is_initialized() {
+        Ok(true) => {}
+        Ok(false) => return RUMT_ERR_NOT_INITIALIZED,
+        Err(code) => return code,
+    }
+
+    let event = RuntimeEvent::Static { event_name: event_name.into() };
+    match codec.emit(event, bytes) {
+        Ok(emit) => match block_on(emit) {
+            Ok(()) => RUMT_OK,
+            Err(code) => code,
+        },
+        Err(_) => RUMT_ERR_CODEC,
+    }
+}
+
+/// `event_name` event'ine callback bağlar. Payload `codec` ile kodlanır; tipi uyuşmayan
+/// payload'lar callback'e iletilmez. Kayıt Rust tarafındaki kayıtlar gibi ad ve yetkilendirme
+/// politikasıyla denetlenir. Başarıda pozitif listener kimliği, hatada negatif kod döner.
+///
+/// # Safety
+/// String parametreler geçerli C string'leri olmalıdır. `user_data` listener kaldırılana
+/// kadar geçerli kalmalı ve callback'in çağrıldığı thread'lerden erişilebilir olmalıdır.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn rumt_register_listener(
+    event_name: *const c_char,
+    codec: *const c_char,
+    callback: RumtListenerCallback,
+    user_data: *mut c_void,
+) -> i64 {
+    let (Some(event_name), Some(codec_name)) = (unsafe { read_str(event_name) }, unsafe { read_str(codec) }) else {
+        return RUMT_ERR_INVALID_ARGUMENT as i64;
+    };
+    let Some(codec) = codecs().get(codec_name) else {
+        return RUMT_ERR_UNKNOWN_CODEC as i64;
+    };
+    let id = NEXT_LISTENER_ID.fetch_add(1, Ordering::Relaxed);
+    let c_event_name = std::ffi::CString::new(event_name).unwrap_or_default();
+    // Ham pointer Send değildir; host bu pointer'ın thread'ler arası kullanımını garanti eder
+    let user_data = user_data as usize;
+
+    let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+        let encoded = codec.encode(args).and_then(Result::ok);
+        let c_event_name = c_event_name.clone();
+        Box::pin(async move {
+            if let Some(bytes) = encoded {
+                callback(user_data as *mut c_void, c_event_name.as_ptr(), bytes.as_ptr(), bytes.len());
+            }
+        }) as futures::future::BoxFuture<'static, ()>
+    });
+
+    let event = RuntimeEvent::Static { event_name: event_name.into() };
+    let listener = RuntimeEventListener::new(format!("ffi:{id}"), handler);
+    match block_on(crate::global::register_many([(event, listener)])) {
+        Ok(Ok(())) => id as i64,
+        Ok(Err(e)) => error_code(&e) as i64,
+        Err(code) => code as i64,
+    }
+}
+
+/// `rumt_register_listener` ile eklenen listener'ı kaldırır.
+#[unsafe(no_mangle)]
+pub extern "C" fn rumt_unregister_listener(listener_id: i64) -> c_int {
+    if listener_id <= 0 {
+        return RUMT_ERR_INVALID_ARGUMENT;
+    }
+    let tag = format!("ffi:{listener_id}");
+    match block_on(RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_all_listeners_by_tag(&tag))) {
+        Ok(Ok(())) => RUMT_OK,
+        Ok(Err(_)) => RUMT_ERR_NOT_INITIALIZED,
+        Err(code) => code,
+    }
+}
+
+/// Runtime'ı kapatır; tüm listener'lar kaldırılır.
+#[unsafe(no_mangle)]
+pub extern "C" fn rumt_shutdown() -> c_int {
+    match block_on(crate::global::shutdown_runtime()) {
+        Ok(()) => RUMT_OK,
+        Err(code) => code,
+    }
+}
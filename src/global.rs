@@ -1,8 +1,29 @@
 use once_cell::sync::Lazy;
-use std::sync::{Mutex as StdMutex, MutexGuard as StdMutexGuard};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex, MutexGuard as StdMutexGuard};
 use tokio::sync::{Mutex};
 
-use crate::{Locked, RuntimeModuleEnv, event_bus::{RuntimeEventBus,RuntimeEvent}}; // Sadece Mutex yeterli
+use crate::{Locked, RuntimeModuleEnv, event_bus::{RuntimeEventBus,RuntimeEvent,RuntimeEventListener}}; // Sadece Mutex yeterli
+use crate::clock::{self, SystemClock};
+use crate::config::DispatchMode;
+use crate::error::{Error, Result};
+use crate::config::EmitOptions;
+use crate::context;
+use crate::queue::{Priority, drain};
+use crate::dispatch::DispatchPlan;
+use crate::leak::RuntimeSnapshot;
+use crate::ids::{self, UuidV7};
+use crate::guard::{GuardAction, PAYLOAD_VIOLATION, PayloadGuard, PayloadViolation, Target};
+use crate::log::Level;
+use crate::phase::{self, Phase, PhaseStopped};
+use crate::policy::{Access, PayloadMeta};
+use crate::resources::Resources;
+use crate::batch::Coalesce;
+use crate::cache::Cache;
+use crate::snapshot::RegistrationSnapshot;
+use crate::stats::BusStats;
+use crate::telemetry::TelemetryObserver;
+use crate::topology::Topology;
 
 // ... diğer importlar
 
@@ -12,25 +33,491 @@ static RUNTIME_MODULE_ENV: Lazy<StdMutex<Option<RuntimeModuleEnv<Locked>>>> =
 // Option kullanman doğru, çünkü bus sonradan init ediliyor.
 pub(crate) static RUNTIME_EVENT_BUS: Lazy<Mutex<Option<RuntimeEventBus>>> = Lazy::new(|| Mutex::new(None));
 
+static RUNTIME_RESOURCES: Lazy<Resources> = Lazy::new(Resources::new);
+static RUNTIME_CACHE: Lazy<Cache> = Lazy::new(Cache::new);
+
+/// Runtime'ı başlatır ve `defer_init` ile sıraya alınmış kayıtları faz sırasıyla çalıştırır.
+/// Env'de tanımlı süreçler, kayıtlar tamamlandıktan sonra başlatılır. Başlatılamazsa panic eder.
+#[deprecated(note = "panics inside the library; use `try_init_runtime` and handle the error")]
 pub async fn init_runtime(env: RuntimeModuleEnv<Locked>) {
-    let mut guard = RUNTIME_MODULE_ENV.lock().unwrap();
-    *guard = Some(env);
-    
+    if let Err(e) = try_init_runtime(env).await {
+        panic!("rumt: init_runtime failed: {e}");
+    }
+}
+
+/// `init_runtime`'ın hata dönen hâli; env kilidi zehirlenmişse `Error::EnvLockPoisoned`, env
+/// yükleyicisi veya doğrulayıcıları başarısızsa `Error::Config`, runtime `shutdown_runtime`
+/// ile kapatılmadan tekrar başlatılmak istenirse `Error::AlreadyInitialized` döner.
+pub async fn try_init_runtime(mut env: RuntimeModuleEnv<Locked>) -> Result<()> {
+    // Kilit zehirliyse bus oluşturulmadan dönülür
+    drop(try_env_guard()?);
+    let values = crate::reload::load(&env)?;
+    // Bus kilidi kurulum bitene kadar tutulur; eşzamanlı iki başlatmadan biri hata alır
     let mut event_bus_guard = RUNTIME_EVENT_BUS.lock().await;
-    // Eğer zaten init edilmişse tekrar etmemek için kontrol
-    if event_bus_guard.is_none() {
-        *event_bus_guard = Some(RuntimeEventBus::new());
+    if event_bus_guard.is_some() {
+        return Err(Error::AlreadyInitialized);
+    }
+    crate::reload::apply(&mut env, values);
+    clock::install(env.clock.clone().unwrap_or_else(|| Arc::new(SystemClock)));
+    ids::install(env.id_generator.clone().unwrap_or_else(|| Arc::new(UuidV7::new())));
+    *event_bus_guard = Some(new_event_bus(&env));
+    drop(event_bus_guard);
+    #[cfg(not(target_arch = "wasm32"))]
+    let processes = env.processes.clone();
+    *try_env_guard()? = Some(env);
+    phase::run_pending().await?;
+    #[cfg(not(target_arch = "wasm32"))]
+    for spec in processes {
+        crate::process::spawn_process(spec).await?;
+    }
+    Ok(())
+}
+
+type EnvGuard = StdMutexGuard<'static, Option<RuntimeModuleEnv<Locked>>>;
+
+fn try_env_guard() -> Result<EnvGuard> {
+    RUNTIME_MODULE_ENV.lock().map_err(|_| Error::EnvLockPoisoned)
+}
+
+// Env yalnızca bütün olarak yazıldığı için zehirlenmiş kilidin içeriği hâlâ tutarlıdır
+fn env_guard() -> EnvGuard {
+    RUNTIME_MODULE_ENV.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+fn new_event_bus(env: &RuntimeModuleEnv<Locked>) -> RuntimeEventBus {
+    let mut bus = RuntimeEventBus::new(env.bus.clone());
+    crate::ticker::begin_lifetime();
+    if bus.config.mode == DispatchMode::Queued {
+        bus.ensure_workers();
+    }
+    bus.set_flags(env.flags.clone());
+    for (event, count) in &env.retention {
+        bus.retain_last(event.clone(), *count);
+    }
+    bus.policy = env.policy.clone().map(Arc::new);
+    bus.authorization = env.authorization.clone();
+    bus.guarantees = env.guarantees.clone();
+    if env.config_loader.is_some() {
+        let (event, listener) = crate::reload::control_listener();
+        bus.add_listener(event, listener);
+    }
+    bus
+}
+
+/// Env'deki bir flag'i çalışma anında değiştirir. `enabled_if` ile bu flag'e bağlı
+/// dinleyiciler flag açıldığında bağlanır, kapandığında ayrılır.
+pub async fn set_flag(name: impl Into<String>, enabled: bool) -> Result<()> {
+    let name = name.into();
+    match try_env_guard()?.as_mut() {
+        Some(env) => env.flags.insert(name.clone(), enabled),
+        None => return Err(Error::NotInitialized),
+    };
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.set_flags([(name, enabled)])).await
+}
+
+/// Runtime'a ait paylaşılan kaynaklar. Handler'lar içinden `resources().get::<T>()` ile erişilir.
+/// `init_runtime` öncesinde de doldurulabilir; `shutdown_runtime` ile temizlenir.
+pub fn resources() -> &'static Resources {
+    &RUNTIME_RESOURCES
+}
+
+/// Yazmaları `rumt.cache.invalidated` ile duyurulan paylaşılan anahtar/değer önbelleği.
+/// Bkz. `Cache`.
+pub fn cache() -> &'static Cache {
+    &RUNTIME_CACHE
+}
+
+/// Runtime'ı kapatır. Servisler başlangıç fazlarının tersi sırasıyla (önce `Api`, sonra `Domain`,
+/// en son `Infrastructure`) kapatılır: fazın servislerinin `on_dispose` kancaları kayıt sırasının
+/// tersiyle çağrılır, handler'ları kaldırılır ve kuyruktaki emit'ler alt fazlar hâlâ bağlıyken
+/// işlenir; ardından `rumt.phase.<ad>.stopped` yayınlanır. Böylece katmanlar arasında yolda olan
+/// emit'ler kaybolmaz. Son olarak ticker'lar ve kuyruk worker'ları durur; kalan emit'ler, tüm
+/// dinleyiciler ve kaynaklar bırakılır. Ardından `init_runtime` ile yeniden başlatılabilir.
+pub async fn shutdown_runtime() {
+    // Bekleyen gruplar dinleyiciler hâlâ bağlıyken teslim edilir
+    let batches = RuntimeEventBus::try_with_instance_mut(|bus| bus.batches.values().cloned().collect::<Vec<_>>()).await;
+    for batch in batches.unwrap_or_default() {
+        batch.flush_now().await;
+    }
+    for phase in Phase::ALL.into_iter().rev() {
+        stop_phase(phase).await;
+    }
+    // Arka planda süren gönderimler bus kapanmadan tamamlanır
+    let timeout = RuntimeEventBus::try_with_instance_mut(|bus| bus.config.shutdown_drain_timeout).await;
+    if let Ok(timeout) = timeout
+        && clock::timeout(timeout, crate::rt::tracked_settled()).await.is_none()
+    {
+        let message = format!("background deliveries did not finish within {timeout:?}");
+        crate::log(Level::Warn, "rumt::shutdown", message).await;
+    }
+
+    let bus = RUNTIME_EVENT_BUS.lock().await.take();
+    if let Some(bus) = bus {
+        for source in bus.sources.values() {
+            source.stop();
+        }
+        crate::ticker::end_lifetime();
+        let dropped = bus.queue.close();
+        bus.stats.record_dropped(dropped);
+    }
+    RUNTIME_RESOURCES.clear();
+    RUNTIME_CACHE.clear();
+    env_guard().take();
+    clock::install(Arc::new(SystemClock));
+    ids::install(Arc::new(UuidV7::new()));
+}
+
+async fn stop_phase(phase: Phase) {
+    let stopping = RuntimeEventBus::try_with_instance_mut(|bus| {
+        let services = bus.take_services_in(phase);
+        (services, Arc::clone(&bus.queue), bus.workers_started, bus.config.shutdown_drain_timeout)
+    })
+    .await;
+    let Ok((services, queue, workers, timeout)) = stopping else { return };
+
+    // Dispose kancaları dinleyiciler hâlâ bağlıyken çalışır
+    for registered in services.iter().rev() {
+        registered.service.on_dispose().await;
+    }
+    let _ = RuntimeEventBus::try_with_instance_mut(|bus| {
+        for registered in &services {
+            bus.remove_listeners_by_instance(registered.instance);
+        }
+    })
+    .await;
+
+    // Worker yoksa (ör. `Deferred` modda) kuyruk burada, sırayla boşaltılır
+    let drained = if !workers {
+        while drain(&queue).await > 0 {}
+        true
+    } else {
+        let settled = std::pin::pin!(queue.settled());
+        let expired = std::pin::pin!(clock::sleep(timeout));
+        matches!(futures::future::select(settled, expired).await, futures::future::Either::Left(_))
+    };
+    if !drained {
+        let message = format!("{} phase did not drain within {timeout:?}", phase.name());
+        crate::log(Level::Warn, "rumt::shutdown", message).await;
+    }
+    // Onay kuyruğa bırakılmaz; dinleyiciler sonraki faz kapanmadan çalışır
+    let event = phase.stopped_event();
+    let plan = RUNTIME_EVENT_BUS.lock().await.as_mut().and_then(|bus| bus.plan(&event));
+    if let Some(mut plan) = plan {
+        plan.queue = None;
+        plan.deliver(Priority::Normal, PhaseStopped { phase, services: services.len(), drained }).await;
     }
 }
+
 pub fn runtime_env() -> StdMutexGuard<'static, Option<RuntimeModuleEnv<Locked>>> {
-    RUNTIME_MODULE_ENV.lock().unwrap()
+    env_guard()
+}
+
+/// Runtime başlatılmamışsa `Error::NotInitialized`, kilit zehirlenmişse
+/// `Error::EnvLockPoisoned` döner. Başarılı dönüşte guard içindeki değer `Some`'dır.
+pub fn try_runtime_env() -> Result<StdMutexGuard<'static, Option<RuntimeModuleEnv<Locked>>>> {
+    let guard = try_env_guard()?;
+    if guard.is_none() {
+        return Err(Error::NotInitialized);
+    }
+    Ok(guard)
 }
 /// Event Arg mutlaka Debug trait'ini derive etmelidir. Aksi halde rust kodu compile edemez!
 pub async fn emit_event<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T) {
+    emit_event_with_priority(event, arg, Priority::Normal).await;
+}
+
+/// `emit_event`'in hata dönen hâli. Runtime başlatılmamışsa event sessizce yok sayılmaz,
+/// `Error::NotInitialized` döner.
+/// Event adı `NamePolicy` kurallarına uymuyorsa `Error::InvalidEventName` döner.
+pub async fn try_emit_event<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T) -> Result<()> {
+    try_emit_with(event, arg, EmitOptions::default()).await
+}
+
+/// `Queued` modda event'i verilen öncelikle kuyruğa bırakır.
+/// Diğer modlarda öncelik dikkate alınmaz ve `emit_event` ile aynı davranır.
+pub async fn emit_event_with_priority<T: Send + Sync + 'static>(
+    event: RuntimeEvent,
+    arg: T,
+    priority: Priority,
+) {
+    emit_with(event, arg, EmitOptions { priority, ..Default::default() }).await;
+}
+
+/// Bus ayarlarını yalnızca bu emit için geçersiz kılarak yayınlar; ör. bus `Concurrent`
+/// iken tutarlılık gerektiren bir event `DispatchMode::Sequential` ile sırayla işlenebilir.
+pub async fn emit_with<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T, options: EmitOptions) {
+    let _ = try_emit_with(event, arg, options).await;
+}
+
+/// `emit_with`'in hata dönen hâli.
+pub async fn try_emit_with<T: Send + Sync + 'static>(
+    event: RuntimeEvent,
+    mut arg: T,
+    options: EmitOptions,
+) -> Result<()> {
+    if let Some(plan) = checked_plan::<T>(&event, &options, Target::Owned(&mut arg)).await? {
+        plan.deliver(options.priority, arg).await;
+    }
+    Ok(())
+}
+
+/// Event'i verilen tenant için yayınlar. Tenant'a bağlı dinleyicilerden yalnızca bu tenant'a
+/// ait olanlar ve tenant'a bağlı olmayan (global) dinleyiciler çağrılır. Handler'lar içinden
+/// yapılan emit'ler tenant'ı devralır; event adı değişmediği için politika, wildcard ve
+/// istatistikler tenant'tan bağımsız çalışır.
+///
+/// ```rust,ignore
+/// rumt::emit_scoped("acme", ORDER_CREATED, order).await;
+/// ```
+pub async fn emit_scoped<T: Send + Sync + 'static>(tenant: impl Into<String>, event: RuntimeEvent, arg: T) {
+    let _ = try_emit_scoped(tenant, event, arg).await;
+}
+
+/// `emit_scoped`'ın hata dönen hâli.
+pub async fn try_emit_scoped<T: Send + Sync + 'static>(
+    tenant: impl Into<String>,
+    event: RuntimeEvent,
+    arg: T,
+) -> Result<()> {
+    try_emit_with(event, arg, EmitOptions { tenant: Some(tenant.into()), ..Default::default() }).await
+}
+
+/// Runtime'ın kendi eventleri (`rumt.*`) için; ad politikası uygulanmaz.
+pub(crate) async fn emit_internal<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T) {
+    let plan = {
+        let mut guard = RUNTIME_EVENT_BUS.lock().await;
+        guard.as_mut().and_then(|bus| bus.plan(&event))
+    };
+    if let Some(plan) = plan {
+        plan.deliver(Priority::Normal, arg).await;
+    }
+}
+
+/// `emit_internal` gibi, ancak event'in dinleyicisi yoksa plan hiç oluşturulmaz; hata
+/// raporları gibi ikincil eventler emit istatistiklerine dinlenmedikçe karışmaz.
+pub(crate) async fn emit_observed<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T) {
+    let plan = {
+        let mut guard = RUNTIME_EVENT_BUS.lock().await;
+        guard.as_mut().filter(|bus| bus.pairs.contains_key(&event)).and_then(|bus| bus.plan(&event))
+    };
+    if let Some(plan) = plan {
+        plan.deliver(Priority::Normal, arg).await;
+    }
+}
+
+// Kilit yalnızca ad kontrolü ve dinleyici listesinin kopyası alınırken tutulur.
+// Koruma ihlali kilit bırakıldıktan sonra `payload.violation` ile bildirilir.
+async fn checked_plan<T: 'static>(
+    event: &RuntimeEvent,
+    options: &EmitOptions,
+    payload: Target<'_>,
+) -> Result<Option<DispatchPlan>> {
+    let mut violation = None;
+    let plan = match RUNTIME_EVENT_BUS.lock().await.as_mut() {
+        Some(bus) => plan_locked::<T>(bus, event, options, payload, &mut violation),
+        None => Err(Error::NotInitialized),
+    };
+    if let Some(violation) = violation {
+        emit_internal(RuntimeEvent::Static { event_name: PAYLOAD_VIOLATION.into() }, violation).await;
+    }
+    plan
+}
+
+fn plan_locked<T: 'static>(
+    bus: &mut RuntimeEventBus,
+    event: &RuntimeEvent,
+    options: &EmitOptions,
+    payload: Target<'_>,
+    violation: &mut Option<PayloadViolation>,
+) -> Result<Option<DispatchPlan>> {
+    bus.check_emit(event)?;
+    let mut size = std::mem::size_of::<T>();
+    if let Some(inspection) = bus.guards.get(event).and_then(|guard| guard.inspect(event, payload)) {
+        size = inspection.size;
+        *violation = inspection.violation;
+        if let Some(rejected) = violation.as_ref().filter(|v| v.action == GuardAction::Rejected) {
+            return Err(Error::PayloadRejected { event: rejected.event.clone(), reason: rejected.reason.clone() });
+        }
+    }
+    let tenant = options.tenant.clone().or_else(context::current_tenant);
+    let meta = PayloadMeta {
+        access: Access::Emit,
+        type_name: Some(std::any::type_name::<T>()),
+        size,
+        tenant: tenant.as_deref(),
+    };
+    bus.authorize_emit(event, &meta)?;
+    if let Some(key) = &options.idempotency_key {
+        // Anahtarlar tenant başına ayrıdır; farklı tenant'lar aynı anahtarı kullanabilir
+        let scoped;
+        let key = match tenant {
+            Some(tenant) => {
+                scoped = format!("{tenant}\0{key}");
+                &scoped
+            }
+            None => key,
+        };
+        if bus.is_duplicate(event, key) {
+            return Ok(None);
+        }
+    }
+    Ok(bus.plan_with(event, options))
+}
+
+/// Veriyi sahiplenmeden, emit süresince ödünç vererek dağıtır. Kuyruk modlarında bile
+/// handler'lar beklemeden, çağıranın görevi üzerinde çalışır.
+///
+/// Senkron handler'lar veriyi `Arc` ayırmadan `&T` olarak alır; her frame tetiklenen
+/// eventler için uygundur. Async handler'lar referansı tutamayacağından, en az bir async
+/// dinleyici varsa veri emit başına bir kez klonlanır.
+pub async fn emit_ref<T: Clone + Send + Sync + 'static>(event: RuntimeEvent, arg: &T) {
+    let options = EmitOptions::default();
+    if let Ok(Some(plan)) = checked_plan::<T>(&event, &options, Target::Borrowed(arg)).await {
+        plan.deliver_ref(arg).await;
+    }
+}
+
+/// `Deferred` modda biriken emit'leri çalıştırır ve işlenen emit sayısını döner.
+/// Oyun döngüsünde her frame'de bir kez çağrılması amaçlanmıştır.
+pub async fn drain_pending() -> usize {
+    let queue = {
+        let guard = RUNTIME_EVENT_BUS.lock().await;
+        guard.as_ref().map(|bus| std::sync::Arc::clone(&bus.queue))
+    };
+    match queue {
+        Some(queue) => drain(&queue).await,
+        None => 0,
+    }
+}
+
+/// `drain_pending`'in senkron karşılığı. Async context dışından (ör. senkron bir oyun sistemi)
+/// çağrılmak içindir: çağıran thread bloklanır ve handler'lar `init_runtime`'ın çalıştığı tokio
+/// runtime'ının içinde çalıştırılır, böylece `tokio::time`, `spawn` ve kilitler kullanılabilir.
+/// O runtime `current_thread` ise ve başka bir thread onu sürmüyorsa zamanlayıcılar ilerlemez.
+///
+/// Bir tokio runtime thread'inden (ör. bir handler'dan) çağrılırsa runtime'ı bloklamamak için
+/// `Error::BlockingInRuntime` döner; orada `drain_pending` beklenmelidir. Tarayıcıda thread
+/// bloklanamadığı için wasm32'de yoktur.
+#[cfg(not(target_arch = "wasm32"))]
+pub fn drain_pending_blocking() -> Result<usize> {
+    if tokio::runtime::Handle::try_current().is_ok() {
+        return Err(Error::BlockingInRuntime);
+    }
+    let runtime = match RUNTIME_EVENT_BUS.blocking_lock().as_ref() {
+        Some(bus) => bus.runtime.clone(),
+        None => return Ok(0),
+    };
+    Ok(match runtime {
+        Some(runtime) => runtime.block_on(drain_pending()),
+        // Runtime bir tokio context'i dışında başlatıldıysa girilecek runtime yoktur
+        None => futures::executor::block_on(drain_pending()),
+    })
+}
+
+/// Emit ve handler çalışmalarını izleyecek bir telemetri gözlemcisi ekler
+/// (ör. OpenTelemetry span/metric üreten bir exporter).
+pub async fn add_telemetry_observer(observer: std::sync::Arc<dyn TelemetryObserver>) {
     let mut guard = RUNTIME_EVENT_BUS.lock().await;
     if let Some(bus) = guard.as_mut() {
-        // Burada bus.emit asenkron olduğu için guard'ı tutarken await ediyoruz.
-        // tokio Mutex kullandığın için bu güvenlidir.
-        bus.emit(&event, arg).await;
+        bus.add_telemetry_observer(observer);
+    }
+}
+/// Event'in `Raw` payload'ını dinleyicilerden önce `Enriched`'a dönüştüren bir fonksiyon kaydeder
+/// (ör. ham bir webhook event'ine çözümlenmiş müşteri bilgisini eklemek). Zenginleştirme mantığı
+/// böylece her dinleyicide tekrarlanmaz; handler'lar `Enriched` tipini dinler.
+///
+/// ```rust,ignore
+/// rumt::map_payload(webhook_event(), |raw: RawWebhook| EnrichedWebhook {
+///     customer: customers.resolve(raw.customer_id),
+///     raw,
+/// })
+/// .await?;
+/// ```
+pub async fn map_payload<Raw, Enriched>(
+    event: RuntimeEvent,
+    f: impl Fn(Raw) -> Enriched + Send + Sync + 'static,
+) -> Result<()>
+where
+    Raw: Send + Sync + 'static,
+    Enriched: Send + Sync + 'static,
+{
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.map_payload(event, f)).await
+}
+
+/// Event'in `T` payload'larını kısa bir pencerede toplayıp batch handler'lara tek çağrıda
+/// (`&[Arc<T>]`) verir; yüksek frekanslı akışlarda emit başına dispatch maliyetini düşürür.
+/// Olağan handler'lar her emit'i yine ayrı alır. Bekleyen gruplar `shutdown_runtime` ile
+/// teslim edilir.
+///
+/// ```rust,ignore
+/// rumt::coalesce::<Quote>(QUOTE, Coalesce::new(Duration::from_millis(1)).max_items(512)).await?;
+///
+/// event_handlers! {
+///     OrderBook;
+///     QUOTE => batch apply_quotes : Quote // fn apply_quotes(&self, quotes: &[Arc<Quote>])
+/// }
+/// ```
+pub async fn coalesce<T: Send + Sync + 'static>(event: RuntimeEvent, settings: Coalesce) -> Result<()> {
+    let previous = RuntimeEventBus::try_with_instance_mut(|bus| bus.coalesce::<T>(event, settings)).await?;
+    if let Some(previous) = previous {
+        previous.flush_now().await;
+    }
+    Ok(())
+}
+
+/// Event'in `T` payload'ları için boyut sınırı ve biçim kuralları kaydeder; bkz. `PayloadGuard`.
+/// Reddedilen emit'ler `Error::PayloadRejected` döner, ihlaller `payload.violation` eventiyle
+/// bildirilir.
+///
+/// ```rust,ignore
+/// rumt::guard_payload(BLOB_STORED, PayloadGuard::new(|blob: &Blob| blob.data.len()).max_size(4 << 20)).await?;
+/// ```
+pub async fn guard_payload<T: Send + Sync + 'static>(event: RuntimeEvent, guard: PayloadGuard<T>) -> Result<()> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.guard_payload(event, guard)).await
+}
+
+/// Dinleyicileri tek kilit altında doğrulayıp ekler; bkz. `RuntimeEventBus::register_many`.
+/// `replay` isteyen dinleyiciler geçmişi kayıttan sonra alır.
+///
+/// ```rust,ignore
+/// let entries = plugin.handlers().map(|h| (h.event(), RuntimeEventListener::new(plugin.tag(), h.into_handler())));
+/// rumt::register_many(entries).await?;
+/// ```
+pub async fn register_many(
+    entries: impl IntoIterator<Item = (RuntimeEvent, RuntimeEventListener)>,
+) -> Result<()> {
+    let bundle = entries.into_iter().collect();
+    let replays = RuntimeEventBus::try_with_instance_mut(|bus| bus.register_entries(bundle)).await??;
+    for replay in replays {
+        replay.run().await;
     }
-}
\ No newline at end of file
+    Ok(())
+}
+
+/// Bus'a kayıtlı dinleyicilerin ve servislerin kopyasını alır; bkz. `RegistrationSnapshot`.
+pub async fn export_registrations() -> Result<RegistrationSnapshot> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.export_registrations()).await
+}
+
+/// `export_registrations` ile alınan kayıtları (ör. yeniden başlatılmış) runtime'a bağlar.
+/// Ad politikasına uymayan bir event varsa hiçbir kayıt eklenmez.
+pub async fn import_registrations(snapshot: RegistrationSnapshot) -> Result<()> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.import_registrations(snapshot)).await?
+}
+
+/// Event'lerin, dinleyici tag'lerinin ve kaynakların (transport, ingress, ticker) grafiği;
+/// `to_dot()` ile Graphviz'e, `to_json()` ile bir arayüze verilebilir. Bkz. `Topology`.
+pub async fn export_topology() -> Result<Topology> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.topology()).await
+}
+
+/// Dinleyici, kuyruk, arka plan task'ı ve geçmiş tamponu sayılarının anlık görüntüsü;
+/// zaman içinde alınan snapshot'lar `leak_report` ile karşılaştırılır.
+pub async fn runtime_snapshot() -> Result<RuntimeSnapshot> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.runtime_snapshot()).await
+}
+
+/// Bus istatistiklerinin anlık görüntüsü (ör. bir `/debug/bus` endpoint'i için `to_json()` ile).
+pub async fn bus_stats() -> Result<BusStats> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.stats()).await
+}
@@ -0,0 +1,75 @@
+use std::{
+    fmt,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use crate::context::{self, Context};
+use crate::dispatch::DispatchPlan;
+use crate::event_bus::RuntimeEventListenerHandlerArg;
+
+/// Bir event'in dinleyicilerine verdiği teslim garantisi. `RuntimeModuleEnv::guarantee` ile
+/// event başına tanımlanır; tanımlanmayan eventler `BestEffort`'tur.
+///
+/// Handler'lar `requires = Guarantee::Durable` seçeneğiyle ihtiyaç duydukları garantiyi belirtir;
+/// daha zayıf garantili bir event'e bağlanmaya çalışan kayıt `Error::GuaranteeMismatch` ile reddedilir.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Guarantee {
+    /// Dinleyici o an pause edilmişse emit'i kaçırır (varsayılan).
+    #[default]
+    BestEffort,
+    /// Pause edilmiş dinleyicilere gelen payload'lar bekletilir ve `resume` ile sırayla teslim edilir.
+    Queued,
+    /// `Queued`'a ek olarak teslim onay ister: handler panic etmeden bitene kadar payload
+    /// dinleyici için saklanır ve `ListenerController::redeliver` ile tekrar verilir.
+    /// Payload'lar serileştirilmediği için saklama süreç ömrüyle sınırlıdır.
+    Durable,
+}
+
+impl Guarantee {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Guarantee::BestEffort => "best-effort",
+            Guarantee::Queued => "queued",
+            Guarantee::Durable => "durable",
+        }
+    }
+}
+
+impl fmt::Display for Guarantee {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Bir servis instance'ının henüz teslim edilmemiş (pause sırasında gelen veya onaylanmamış) emit'leri.
+#[derive(Default)]
+pub(crate) struct Backlog {
+    items: StdMutex<Vec<Pending>>,
+}
+
+/// Tek dinleyiciye, emit anındaki context ile verilecek payload.
+pub(crate) struct Pending {
+    pub(crate) context: Context,
+    pub(crate) plan: DispatchPlan,
+    pub(crate) payload: Arc<dyn RuntimeEventListenerHandlerArg>,
+}
+
+impl Backlog {
+    pub(crate) fn push(&self, pending: Pending) {
+        self.items.lock().unwrap_or_else(|e| e.into_inner()).push(pending);
+    }
+
+    pub(crate) fn take(&self) -> Vec<Pending> {
+        std::mem::take(&mut *self.items.lock().unwrap_or_else(|e| e.into_inner()))
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.items.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+}
+
+impl Pending {
+    pub(crate) async fn run(self) {
+        context::scope(self.context, self.plan.execute(&self.payload)).await;
+    }
+}
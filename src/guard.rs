@@ -0,0 +1,148 @@
+use std::any::{Any, type_name};
+
+use crate::event_bus::RuntimeEvent;
+use crate::telemetry::event_name;
+
+/// Koruması ihlal edilen emit'lerin bildirildiği event adı; payload'ı `PayloadViolation`.
+pub const PAYLOAD_VIOLATION: &str = "payload.violation";
+
+/// Korumaya uymayan payload'a ne yapıldığı.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GuardAction {
+    /// Emit dinleyicilere ulaşmadan `Error::PayloadRejected` ile reddedildi.
+    Rejected,
+    /// Payload sınıra kadar kırpılıp yayınlandı.
+    Truncated,
+}
+
+/// `payload.violation` payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct PayloadViolation {
+    pub event: String,
+    pub type_name: &'static str,
+    /// Payload'ın kırpılmadan önceki boyutu.
+    pub size: usize,
+    pub limit: Option<usize>,
+    pub reason: String,
+    pub action: GuardAction,
+}
+
+/// Bir event'in `T` payload'ı için boyut sınırı ve biçim kuralları; `rumt::guard_payload` ile
+/// kaydedilir. Boyut, verilen fonksiyonla ölçülür (ör. bir tamponun uzunluğu), bu yüzden
+/// ucuz olmalıdır: kontrol bus kilidi altında yapılır. Ölçülen boyut yetkilendirme
+/// politikasına da `PayloadMeta::size` olarak verilir.
+///
+/// Sınırı aşan payload `truncate_with` verilmişse kırpılır, verilmemişse reddedilir; her iki
+/// durumda `payload.violation` yayınlanır. `emit_ref` payload'ı değiştiremediği için orada
+/// sınırı aşan payload'lar her zaman reddedilir. Başka tipte payload ile yapılan emit'ler
+/// kontrol edilmez.
+///
+/// ```rust,ignore
+/// rumt::guard_payload(
+///     UPLOAD_RECEIVED,
+///     PayloadGuard::new(|upload: &Upload| upload.bytes.len())
+///         .max_size(1 << 20)
+///         .shape(|upload| if upload.mime.is_empty() { Err("mime is empty".into()) } else { Ok(()) }),
+/// )
+/// .await?;
+/// ```
+pub struct PayloadGuard<T> {
+    size: Box<dyn Fn(&T) -> usize + Send + Sync>,
+    max_size: Option<usize>,
+    truncate: Option<Truncate<T>>,
+    shapes: Vec<Shape<T>>,
+}
+
+type Truncate<T> = Box<dyn Fn(&mut T, usize) + Send + Sync>;
+type Shape<T> = Box<dyn Fn(&T) -> Result<(), String> + Send + Sync>;
+
+impl<T: Send + Sync + 'static> PayloadGuard<T> {
+    pub fn new(size: impl Fn(&T) -> usize + Send + Sync + 'static) -> Self {
+        Self { size: Box::new(size), max_size: None, truncate: None, shapes: Vec::new() }
+    }
+
+    /// Ölçülen boyutun üst sınırı.
+    pub fn max_size(mut self, limit: usize) -> Self {
+        self.max_size = Some(limit);
+        self
+    }
+
+    /// Sınırı aşan payload reddedilmek yerine `f(payload, limit)` ile kırpılır.
+    pub fn truncate_with(mut self, f: impl Fn(&mut T, usize) + Send + Sync + 'static) -> Self {
+        self.truncate = Some(Box::new(f));
+        self
+    }
+
+    /// Payload'ın uyması gereken bir kural; `Err` içindeki metin ret nedeni olarak döner.
+    /// Kurallar boyut kontrolünden (ve varsa kırpmadan) sonra çalışır.
+    pub fn shape(mut self, check: impl Fn(&T) -> Result<(), String> + Send + Sync + 'static) -> Self {
+        self.shapes.push(Box::new(check));
+        self
+    }
+
+    fn violation(&self, event: &RuntimeEvent, size: usize, reason: String, action: GuardAction) -> PayloadViolation {
+        PayloadViolation {
+            event: event_name(event).to_string(),
+            type_name: type_name::<T>(),
+            size,
+            limit: self.max_size,
+            reason,
+            action,
+        }
+    }
+
+    fn check_shape(&self, event: &RuntimeEvent, payload: &T, size: usize) -> Option<PayloadViolation> {
+        let reason = self.shapes.iter().find_map(|shape| shape(payload).err())?;
+        Some(self.violation(event, size, reason, GuardAction::Rejected))
+    }
+}
+
+/// Kontrol edilen payload; yalnızca sahiplenilmiş payload kırpılabilir.
+pub(crate) enum Target<'a> {
+    Owned(&'a mut (dyn Any + Send + Sync)),
+    Borrowed(&'a (dyn Any + Send + Sync)),
+}
+
+/// Kontrolün sonucu: ölçülen (kırpıldıysa kırpılmış) boyut ve varsa ihlal.
+pub(crate) struct Inspection {
+    pub(crate) size: usize,
+    pub(crate) violation: Option<PayloadViolation>,
+}
+
+pub(crate) trait ErasedGuard: Send + Sync {
+    /// Payload bu korumanın tipinde değilse `None` döner.
+    fn inspect(&self, event: &RuntimeEvent, target: Target<'_>) -> Option<Inspection>;
+}
+
+impl<T: Send + Sync + 'static> ErasedGuard for PayloadGuard<T> {
+    fn inspect(&self, event: &RuntimeEvent, target: Target<'_>) -> Option<Inspection> {
+        let size = match &target {
+            Target::Owned(payload) => (self.size)(payload.downcast_ref::<T>()?),
+            Target::Borrowed(payload) => (self.size)(payload.downcast_ref::<T>()?),
+        };
+        let oversize = self.max_size.filter(|limit| size > *limit);
+        match (target, oversize, &self.truncate) {
+            (Target::Owned(payload), Some(limit), Some(truncate)) => {
+                let payload = payload.downcast_mut::<T>()?;
+                truncate(payload, limit);
+                let violation = self.check_shape(event, payload, size).unwrap_or_else(|| {
+                    let reason = format!("payload of {size} bytes exceeds the limit of {limit}");
+                    self.violation(event, size, reason, GuardAction::Truncated)
+                });
+                Some(Inspection { size: (self.size)(payload), violation: Some(violation) })
+            }
+            (_, Some(limit), _) => {
+                let reason = format!("payload of {size} bytes exceeds the limit of {limit}");
+                Some(Inspection { size, violation: Some(self.violation(event, size, reason, GuardAction::Rejected)) })
+            }
+            (Target::Owned(payload), None, _) => {
+                let violation = self.check_shape(event, payload.downcast_ref::<T>()?, size);
+                Some(Inspection { size, violation })
+            }
+            (Target::Borrowed(payload), None, _) => {
+                let violation = self.check_shape(event, payload.downcast_ref::<T>()?, size);
+                Some(Inspection { size, violation })
+            }
+        }
+    }
+}
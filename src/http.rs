@@ -0,0 +1,208 @@
+use std::time::Duration;
+
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+
+use crate::clock;
+use crate::error::{Error, Result};
+
+/// `http://host[:port]/path` biçimindeki adres. TLS desteklenmediği için `https` reddedilir.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub(crate) struct HttpUrl {
+    pub(crate) host: String,
+    pub(crate) port: u16,
+    pub(crate) path: String,
+}
+
+impl HttpUrl {
+    pub(crate) fn parse(url: &str) -> Result<Self> {
+        let invalid = |reason: &str| Error::Transport(format!("invalid url `{}`: {reason}", url.escape_debug()));
+        // İstek satırına ve `Host` başlığına aynen yazıldığı için boşluk ve kontrol karakteri olamaz
+        if url.bytes().any(|byte| byte <= b' ' || byte == 0x7f) {
+            return Err(invalid("contains whitespace or control characters"));
+        }
+        let rest = match url.split_once("://") {
+            Some(("http", rest)) => rest,
+            Some(("https", _)) => return Err(invalid("https is not supported, use a TLS-terminating proxy")),
+            _ => return Err(invalid("expected an http:// url")),
+        };
+        let (authority, path) = match rest.find('/') {
+            Some(index) => (&rest[..index], &rest[index..]),
+            None => (rest, "/"),
+        };
+        let (host, port) = match authority.rsplit_once(':') {
+            Some((host, port)) => (host, port.parse().map_err(|_| invalid("invalid port"))?),
+            None => (authority, 80),
+        };
+        if host.is_empty() {
+            return Err(invalid("missing host"));
+        }
+        Ok(Self { host: host.to_string(), port, path: path.to_string() })
+    }
+}
+
+/// Başlık adı olarak kullanılabilen (RFC 9110 `token`) dizgi mi.
+pub(crate) fn is_token(text: &str) -> bool {
+    !text.is_empty() && text.bytes().all(|byte| byte.is_ascii_alphanumeric() || b"!#$%&'*+-.^_`|~".contains(&byte))
+}
+
+/// Başlık değeri olarak aynen yazılabilir mi: CR, LF ve diğer kontrol karakterleri (sekme hariç)
+/// yeni başlık veya gövde enjekte edebileceği için reddedilir.
+pub(crate) fn is_header_value(text: &str) -> bool {
+    text.bytes().all(|byte| byte == b'\t' || (byte >= b' ' && byte != 0x7f))
+}
+
+pub(crate) struct HttpResponse {
+    pub(crate) status: u16,
+}
+
+/// Tek bir `POST` isteği gönderir ve durum kodunu döner (`Connection: close`).
+pub(crate) async fn post(
+    url: &HttpUrl,
+    headers: &[(String, String)],
+    body: &[u8],
+    timeout: Duration,
+) -> Result<HttpResponse> {
+    if let Some((name, _)) = headers.iter().find(|(name, value)| !is_token(name) || !is_header_value(value)) {
+        return Err(Error::Transport(format!("invalid header `{}`", name.escape_debug())));
+    }
+    let request = async {
+        let mut stream = TcpStream::connect((url.host.as_str(), url.port)).await?;
+        let mut head = format!(
+            "POST {} HTTP/1.1\r\nHost: {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+            url.path,
+            url.host,
+            body.len()
+        );
+        for (name, value) in headers {
+            head.push_str(&format!("{name}: {value}\r\n"));
+        }
+        head.push_str("\r\n");
+        stream.write_all(head.as_bytes()).await?;
+        stream.write_all(body).await?;
+        stream.flush().await?;
+
+        // Yalnızca durum satırı gerekir; gövde okunmaz
+        let mut buffer = Vec::with_capacity(256);
+        let mut chunk = [0u8; 256];
+        while !buffer.windows(2).any(|w| w == b"\r\n") {
+            let read = stream.read(&mut chunk).await?;
+            if read == 0 {
+                break;
+            }
+            buffer.extend_from_slice(&chunk[..read]);
+        }
+        Ok::<_, std::io::Error>(buffer)
+    };
+    let response = clock::timeout(timeout, request)
+        .await
+        .ok_or_else(|| Error::Transport(format!("request to {}:{} timed out", url.host, url.port)))?
+        .map_err(|e| Error::Transport(e.to_string()))?;
+
+    let line = String::from_utf8_lossy(&response);
+    let status = line
+        .split_whitespace()
+        .nth(1)
+        .and_then(|code| code.parse().ok())
+        .ok_or_else(|| Error::Transport("malformed http response".into()))?;
+    Ok(HttpResponse { status })
+}
+
+/// Sunucu tarafında okunan istek. Başlık adları küçük harfe çevrilir.
+pub(crate) struct HttpRequest {
+    pub(crate) method: String,
+    pub(crate) path: String,
+    pub(crate) headers: Vec<(String, String)>,
+    pub(crate) body: Vec<u8>,
+}
+
+impl HttpRequest {
+    pub(crate) fn header(&self, name: &str) -> Option<&str> {
+        let name = name.to_ascii_lowercase();
+        self.headers.iter().find(|(key, _)| *key == name).map(|(_, value)| value.as_str())
+    }
+}
+
+/// Okuma başarısız olduğunda istemciye dönülecek durum kodu.
+pub(crate) enum ReadError {
+    Malformed,
+    TooLarge,
+    Io,
+}
+
+const MAX_HEAD: usize = 16 * 1024;
+
+/// Tek bir isteği okur; gövde `Content-Length` ile sınırlıdır, `chunked` desteklenmez.
+pub(crate) async fn read_request(stream: &mut TcpStream, max_body: usize) -> std::result::Result<HttpRequest, ReadError> {
+    let mut buffer = Vec::with_capacity(1024);
+    let mut chunk = [0u8; 1024];
+    let head_end = loop {
+        if let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") {
+            break end;
+        }
+        if buffer.len() > MAX_HEAD {
+            return Err(ReadError::TooLarge);
+        }
+        let read = stream.read(&mut chunk).await.map_err(|_| ReadError::Io)?;
+        if read == 0 {
+            return Err(ReadError::Malformed);
+        }
+        buffer.extend_from_slice(&chunk[..read]);
+    };
+
+    let head = std::str::from_utf8(&buffer[..head_end]).map_err(|_| ReadError::Malformed)?;
+    let mut lines = head.split("\r\n");
+    let mut request_line = lines.next().unwrap_or_default().split_whitespace();
+    let (Some(method), Some(path)) = (request_line.next(), request_line.next()) else {
+        return Err(ReadError::Malformed);
+    };
+    let mut headers = Vec::new();
+    for line in lines {
+        let (name, value) = line.split_once(':').ok_or(ReadError::Malformed)?;
+        headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+    }
+    let mut request = HttpRequest { method: method.to_string(), path: path.to_string(), headers, body: Vec::new() };
+    if request.header("transfer-encoding").is_some() {
+        return Err(ReadError::Malformed);
+    }
+    let length: usize = match request.header("content-length") {
+        Some(value) => value.parse().map_err(|_| ReadError::Malformed)?,
+        None => 0,
+    };
+    if length > max_body {
+        return Err(ReadError::TooLarge);
+    }
+
+    let mut body = buffer.split_off(head_end + 4);
+    while body.len() < length {
+        let read = stream.read(&mut chunk).await.map_err(|_| ReadError::Io)?;
+        if read == 0 {
+            return Err(ReadError::Malformed);
+        }
+        body.extend_from_slice(&chunk[..read]);
+    }
+    body.truncate(length);
+    request.body = body;
+    Ok(request)
+}
+
+pub(crate) async fn write_response(stream: &mut TcpStream, status: u16, body: &str) {
+    let reason = match status {
+        200 => "OK",
+        202 => "Accepted",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        408 => "Request Timeout",
+        413 => "Payload Too Large",
+        _ => "Service Unavailable",
+    };
+    let response = format!(
+        "HTTP/1.1 {status} {reason}\r\nContent-Type: text/plain\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+        body.len()
+    );
+    let _ = stream.write_all(response.as_bytes()).await;
+    let _ = stream.shutdown().await;
+}
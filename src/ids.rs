@@ -0,0 +1,97 @@
+use std::{
+    sync::{Arc, Mutex as StdMutex, RwLock},
+    time::UNIX_EPOCH,
+};
+
+use once_cell::sync::Lazy;
+
+use crate::clock;
+use crate::context::{EventId, random_u64};
+
+/// Emit kimliklerini (`Context::event_id`, `correlation_id`), transport kökenlerini ve
+/// `rumt::ids::generate` ile üretilen anahtarları (ör. idempotency anahtarları) üreten kaynak.
+///
+/// Varsayılan `UuidV7` zamana göre sıralanabilir UUIDv7 değerleri üretir. Kurumsal bir biçim
+/// (ör. Snowflake) için `RuntimeModuleEnv::id_generator` ile başka bir üretici verilir:
+///
+/// ```rust,ignore
+/// struct Snowflake { worker: u128, sequence: AtomicU64 }
+///
+/// impl IdGenerator for Snowflake {
+///     fn generate(&self) -> EventId {
+///         let millis = SystemTime::now().duration_since(EPOCH).unwrap().as_millis();
+///         let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) as u128 & 0xfff;
+///         EventId(millis << 22 | self.worker << 12 | sequence)
+///     }
+/// }
+/// ```
+pub trait IdGenerator: Send + Sync + 'static {
+    fn generate(&self) -> EventId;
+}
+
+/// RFC 9562 UUIDv7: 48 bit Unix milisaniyesi, ardından rastgele bitler. Aynı milisaniye içinde
+/// üretilen değerler de artan sırada döner.
+#[derive(Default)]
+pub struct UuidV7 {
+    last: StdMutex<u128>,
+}
+
+impl UuidV7 {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for UuidV7 {
+    fn generate(&self) -> EventId {
+        let millis = clock::real_system_time().duration_since(UNIX_EPOCH).map(|since| since.as_millis()).unwrap_or(0);
+        let random = (random_u64() as u128) << 64 | random_u64() as u128;
+        let candidate = (millis & 0xffff_ffff_ffff) << 80
+            | 0x7 << 76
+            | (random >> 64 & 0xfff) << 64
+            | 0b10 << 62
+            | (random & 0x3fff_ffff_ffff_ffff);
+        let mut last = self.last.lock().unwrap_or_else(|e| e.into_inner());
+        // Saat geri gittiğinde veya aynı milisaniyede sıranın bozulmaması için öncekinden büyük tutulur
+        *last = if candidate > *last { candidate } else { *last + 1 };
+        EventId(*last)
+    }
+}
+
+/// 1'den başlayan ardışık kimlikler; çıktıların tahmin edilebilir olması gereken testler için.
+#[derive(Default)]
+pub struct SequentialIds {
+    next: StdMutex<u128>,
+}
+
+impl SequentialIds {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl IdGenerator for SequentialIds {
+    fn generate(&self) -> EventId {
+        let mut next = self.next.lock().unwrap_or_else(|e| e.into_inner());
+        *next += 1;
+        EventId(*next)
+    }
+}
+
+static GENERATOR: Lazy<RwLock<Arc<dyn IdGenerator>>> = Lazy::new(|| RwLock::new(Arc::new(UuidV7::new())));
+
+/// Runtime'ın kullandığı üretici; `init_runtime` env'dekini kurar, `shutdown_runtime` varsayılana
+/// döner.
+pub(crate) fn install(generator: Arc<dyn IdGenerator>) {
+    *GENERATOR.write().unwrap_or_else(|e| e.into_inner()) = generator;
+}
+
+/// Runtime'ın üreticisinden yeni bir kimlik.
+///
+/// ```rust,ignore
+/// let options = EmitOptions { idempotency_key: Some(rumt::ids::generate().to_string()), ..Default::default() };
+/// ```
+pub fn generate() -> EventId {
+    let generator = Arc::clone(&GENERATOR.read().unwrap_or_else(|e| e.into_inner()));
+    generator.generate()
+}
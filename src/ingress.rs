@@ -0,0 +1,235 @@
+use std::{net::SocketAddr, sync::Arc, time::Duration};
+
+use tokio::net::{TcpListener, TcpStream, ToSocketAddrs};
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::auth::{Action, Denied, Gate, GatewayAuth, bearer_token};
+use crate::clock;
+use crate::crypto::{constant_time_eq, hmac_sha256, to_hex};
+use crate::error::{Error, Result};
+use crate::event_bus::RuntimeEvent;
+use crate::http::{self, HttpRequest, ReadError};
+use crate::json::{self, JsonValue};
+use crate::schema::PayloadSchema;
+use crate::telemetry::event_name;
+use crate::ticker::{self, TickerStop};
+
+/// Gelen webhook'un yayınlanan payload'ı.
+#[derive(Clone, Debug, PartialEq)]
+pub struct IncomingWebhook {
+    pub path: String,
+    /// `auth` ayarlandıysa doğrulanan istemcinin kimliği.
+    pub client: Option<String>,
+    /// Gönderenin verdiyse `X-Rumt-Delivery` başlığı; tekrar gelen istekleri ayıklamak için.
+    pub delivery: Option<String>,
+    /// Küçük harfli başlık adlarıyla tüm istek başlıkları.
+    pub headers: Vec<(String, String)>,
+    pub body: JsonValue,
+}
+
+struct IngressRoute {
+    path: String,
+    event: RuntimeEvent,
+    schema: PayloadSchema,
+}
+
+/// İmzalı JSON webhook'larını kabul edip bus event'i olarak yayınlayan küçük bir HTTP sunucusu.
+///
+/// Yalnızca `POST` kabul edilir. `secret` verildiyse gövdenin HMAC-SHA256 imzası
+/// `sha256=<hex>` biçiminde imza başlığında beklenir (`rumt::webhook` aynı biçimde imzalar).
+/// Gövde JSON olarak okunup rotanın şemasıyla doğrulanır; geçerli istekler `IncomingWebhook`
+/// payload'ıyla yayınlanır ve `202` döner. Yanıtlar: bilinmeyen yol `404`, yanlış metot `405`,
+/// imza veya token hatası `401`, izinsiz event `403`, geçersiz gövde `400`, sınırı aşan gövde
+/// `413`, `read_timeout` içinde tamamlanmayan istek `408`, yayınlanamayan event `503`.
+/// Aynı anda en fazla `max_connections` bağlantı işlenir; fazlası sırası gelene kadar kabul
+/// edilmez.
+/// TLS desteklenmez; dışa açık kurulumlarda önüne TLS sonlandıran bir proxy konmalıdır.
+///
+/// ```rust,ignore
+/// let addr = WebhookIngress::new()
+///     .secret("s3cr3t")
+///     .signature_header("X-Hub-Signature-256")
+///     .route("/hooks/payments", PAYMENT_RECEIVED, PayloadSchema::new().field("amount", "u64"))
+///     .start("payments", "0.0.0.0:8080")
+///     .await?;
+/// ```
+pub struct WebhookIngress {
+    routes: Vec<IngressRoute>,
+    secret: Option<String>,
+    gate: Gate,
+    signature_header: String,
+    max_body: usize,
+    read_timeout: Duration,
+    max_connections: usize,
+}
+
+impl Default for WebhookIngress {
+    fn default() -> Self {
+        Self {
+            routes: Vec::new(),
+            secret: None,
+            gate: Gate::default(),
+            signature_header: "X-Rumt-Signature".into(),
+            max_body: 1024 * 1024,
+            read_timeout: Duration::from_secs(10),
+            max_connections: 256,
+        }
+    }
+}
+
+impl WebhookIngress {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+
+    /// İstekler ayrıca `Authorization: Bearer <token>` ile doğrulanır; istemcinin rotanın
+    /// event'ini yayınlama izni yoksa `403` döner.
+    pub fn auth(mut self, auth: GatewayAuth) -> Self {
+        self.gate = Gate::new(Some(auth));
+        self
+    }
+
+    /// İmzanın okunduğu başlık; varsayılanı `X-Rumt-Signature`.
+    pub fn signature_header(mut self, name: impl Into<String>) -> Self {
+        self.signature_header = name.into();
+        self
+    }
+
+    /// Kabul edilen en büyük gövde (bayt); varsayılanı 1 MiB.
+    pub fn max_body(mut self, bytes: usize) -> Self {
+        self.max_body = bytes;
+        self
+    }
+
+    /// İsteğin başlıkları ve gövdesiyle birlikte okunması için tanınan süre; varsayılanı 10 sn.
+    /// Süre `rumt::clock` ile ölçülür.
+    pub fn read_timeout(mut self, timeout: Duration) -> Self {
+        self.read_timeout = timeout;
+        self
+    }
+
+    /// Aynı anda işlenen en fazla bağlantı; varsayılanı 256, en az 1.
+    pub fn max_connections(mut self, connections: usize) -> Self {
+        self.max_connections = connections.max(1);
+        self
+    }
+
+    /// `path` yoluna gelen istekleri `event` olarak yayınlar. Sorgu dizgisi eşleşmeye katılmaz.
+    pub fn route(mut self, path: impl Into<String>, event: impl Into<RuntimeEvent>, schema: PayloadSchema) -> Self {
+        self.routes.push(IngressRoute { path: path.into(), event: event.into(), schema });
+        self
+    }
+
+    /// Sunucuyu `addr` adresinde başlatır ve dinlenen adresi döner. Sunucu runtime'a aittir:
+    /// `stop_webhook_ingress(name)` ile veya `shutdown_runtime` sırasında kapatılır.
+    /// Rotalardaki eventler politikaya uymuyorsa sunucu başlatılmaz.
+    pub async fn start(self, name: &str, addr: impl ToSocketAddrs) -> Result<SocketAddr> {
+        let listener = TcpListener::bind(addr).await.map_err(|e| Error::Transport(e.to_string()))?;
+        let local_addr = listener.local_addr().map_err(|e| Error::Transport(e.to_string()))?;
+        let events: Vec<RuntimeEvent> = self.routes.iter().map(|route| route.event.clone()).collect();
+        let stop = ticker::register_source(source_name(name), &events).await?;
+        crate::rt::spawn(serve(Arc::new(self), listener, stop));
+        Ok(local_addr)
+    }
+}
+
+/// Çalışan sunucuyu kapatır; bu adda bir sunucu yoksa `false` döner. Süren istekler tamamlanır.
+pub async fn stop_webhook_ingress(name: &str) -> bool {
+    ticker::stop_ticker(&source_name(name)).await
+}
+
+fn source_name(name: &str) -> String {
+    format!("ingress:{name}")
+}
+
+async fn serve(ingress: Arc<WebhookIngress>, listener: TcpListener, stop: Arc<TickerStop>) {
+    let slots = Arc::new(Semaphore::new(ingress.max_connections));
+    loop {
+        // Yer açılana kadar yeni bağlantı kabul edilmez; bekleyenler işletim sisteminin
+        // kuyruğunda kalır
+        let permit = tokio::select! {
+            permit = Arc::clone(&slots).acquire_owned() => match permit {
+                Ok(permit) => permit,
+                Err(_) => return,
+            },
+            _ = stop.stopped() => return,
+        };
+        let stream = tokio::select! {
+            accepted = listener.accept() => accepted,
+            _ = stop.stopped() => return,
+        };
+        match stream {
+            Ok((stream, _)) => {
+                crate::rt::spawn(handle(Arc::clone(&ingress), stream, permit));
+            }
+            // Dosya tanımlayıcısı tükenmesi gibi geçici hatalarda döngü meşgul edilmez
+            Err(_) => clock::sleep(Duration::from_millis(50)).await,
+        }
+    }
+}
+
+async fn handle(ingress: Arc<WebhookIngress>, mut stream: TcpStream, _permit: OwnedSemaphorePermit) {
+    let read = clock::timeout(ingress.read_timeout, http::read_request(&mut stream, ingress.max_body)).await;
+    let (status, message) = match read {
+        Some(Ok(request)) => ingress.accept(request).await,
+        Some(Err(ReadError::TooLarge)) => (413, "payload too large".to_string()),
+        Some(Err(ReadError::Malformed)) => (400, "malformed request".to_string()),
+        Some(Err(ReadError::Io)) => return,
+        None => (408, "request timeout".to_string()),
+    };
+    http::write_response(&mut stream, status, &message).await;
+}
+
+impl WebhookIngress {
+    async fn accept(&self, request: HttpRequest) -> (u16, String) {
+        let path = request.path.split('?').next().unwrap_or_default();
+        let Some(route) = self.routes.iter().find(|route| route.path == path) else {
+            return (404, "unknown webhook".into());
+        };
+        if request.method != "POST" {
+            return (405, "only POST is accepted".into());
+        }
+        let token = bearer_token(request.header("Authorization"));
+        let client = match self.gate.admit(token, Action::Emit, event_name(&route.event)) {
+            Ok(client) => client.map(|client| client.id.clone()),
+            Err(Denied::Unauthenticated(e)) => return (401, e.to_string()),
+            Err(Denied::Forbidden(e)) => return (403, e.to_string()),
+        };
+        if let Some(secret) = &self.secret {
+            let expected = format!("sha256={}", to_hex(&hmac_sha256(secret.as_bytes(), &request.body)));
+            let signature = request.header(&self.signature_header).unwrap_or_default();
+            if !constant_time_eq(signature.as_bytes(), expected.as_bytes()) {
+                return (401, "invalid signature".into());
+            }
+        }
+
+        let parsed = match std::str::from_utf8(&request.body) {
+            Ok(text) => json::parse(text).map_err(|e| e.to_string()),
+            Err(e) => Err(e.to_string()),
+        };
+        let body = match parsed {
+            Ok(body) => body,
+            Err(e) => return (400, e),
+        };
+        if let Err(e) = route.schema.validate(&body) {
+            return (400, e.message);
+        }
+
+        let incoming = IncomingWebhook {
+            path: path.to_string(),
+            client,
+            delivery: request.header("X-Rumt-Delivery").map(str::to_string),
+            headers: request.headers,
+            body,
+        };
+        match crate::try_emit_event(route.event.clone(), incoming).await {
+            Ok(()) => (202, "accepted".into()),
+            Err(e) => (503, e.to_string()),
+        }
+    }
+}
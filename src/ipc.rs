@@ -0,0 +1,492 @@
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use std::{
+    fmt::Write as _,
+    os::unix::fs::{DirBuilderExt, PermissionsExt},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
+
+use tokio::io::{AsyncBufReadExt, AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt, BufReader, ReadHalf};
+use tokio::net::{UnixListener, UnixStream};
+use tokio::sync::mpsc;
+
+use crate::auth::{Action, Client, Gate, GatewayAuth};
+use crate::clock;
+use crate::context::random_u64;
+use crate::error::{Error, Result};
+use crate::session::{escape, unescape};
+use crate::ticker::{self, TickerStop};
+use crate::transport::{EventTransport, Frame, FrameFilter, FrameStream, TransportHealth};
+
+/// Bus'ı aynı makinedeki süreçlere Unix soketiyle açan gateway. `TransportManager` ile
+/// kullanılır: `route_out` edilen eventler abone olan istemcilere gönderilir, istemcilerin
+/// yayınladığı frame'ler `route_in` kurallarıyla bus'a emit edilir.
+///
+/// Protokol satır tabanlıdır; alanlar sekmeyle ayrılır, payload hex kodludur:
+///
+/// ```text
+/// -> AUTH <token>                         <- OK | ERR <sebep>
+/// -> SUB <event> (önek için `order.*`, hepsi için `*`)   <- OK | ERR <sebep>
+/// -> EMIT <event> <payload> [ad=değer ...]               <- OK | ERR <sebep>
+/// <- EVENT <event> <payload> [ad=değer ...]
+/// ```
+///
+/// `auth` verilirse istemci önce `AUTH` göndermelidir; doğrulanamayan bağlantı kapatılır.
+/// `auth` verilmezse `AUTH` gerekmez ve istemciler her event'e erişir.
+/// `SUB` ve `EMIT` istemcinin izin listesiyle denetlenir (bkz. `rumt::auth::GatewayAuth`);
+/// önekli aboneliklerde izin her frame için ayrıca kontrol edilir. `max_line`'ı aşan satır
+/// bağlantıyı kapatır.
+///
+/// Soket dosyası varsayılan olarak yalnızca sahibinin erişebileceği `0o600` izniyle
+/// oluşturulur (`mode`); dosya `0o700` izinli geçici bir dizinde bağlanıp izinleri
+/// daraltıldıktan sonra yerine konur. Bağlantılar varsayılan olarak şifrelenmez; `encryption`
+/// ile TLS veya noise katmanı eklenir (bkz. `IpcEncryption`).
+///
+/// Her istemcinin gönderilmeyi bekleyen satırları `queue` ile sınırlıdır; kuyruğu dolan yavaş
+/// istemcinin bağlantısı kesilir. İstemcilerin yayınladığı frame'ler de aynı sınırla bus'a
+/// aktarılır; bus yetişemezse istemcinin `EMIT`'i yanıtlanmadan bekler. Gateway runtime'a aittir
+/// ve `shutdown_runtime` ile kapanır; soket dosyası silinir.
+///
+/// ```rust,ignore
+/// let gateway = IpcGateway::new("/run/pos/bus.sock").auth(
+///     GatewayAuth::new().client("printer", &token, ClientPermissions::none()
+///         .subscribe(FrameFilter::Prefix("receipt.".into()))
+///         .emit(FrameFilter::events(["printer.status"]))),
+/// );
+/// let ipc = TransportManager::new("printer-ipc", gateway);
+/// ipc.route_out(RECEIPT_READY, "receipt.ready", ReceiptJsonCodec).await?;
+/// ipc.route_in("printer.status", PRINTER_STATUS, PrinterStatusCodec);
+/// ipc.start().await?;
+/// ```
+pub struct IpcGateway {
+    path: PathBuf,
+    gate: Gate,
+    mode: u32,
+    max_line: usize,
+    queue: usize,
+    encryption: Option<Arc<dyn IpcEncryption>>,
+    hub: Arc<StdMutex<Hub>>,
+}
+
+/// Kabul edilen bağlantıyı protokol başlamadan önce şifreli bir akışa saran katman. rumt
+/// kendi kriptografisini içermez; TLS için `tokio-rustls`, noise için `snow` gibi bir crate ile
+/// uygulanır. Handshake hata dönerse bağlantı kapatılır; istemci doğrulaması yine `AUTH` ile
+/// yapılır.
+///
+/// ```rust,ignore
+/// struct Tls(tokio_rustls::TlsAcceptor);
+///
+/// impl IpcEncryption for Tls {
+///     fn accept(&self, stream: UnixStream) -> BoxFuture<'static, std::io::Result<Box<dyn IpcStream>>> {
+///         let acceptor = self.0.clone();
+///         Box::pin(async move { Ok(Box::new(acceptor.accept(stream).await?) as Box<dyn IpcStream>) })
+///     }
+/// }
+///
+/// let gateway = IpcGateway::new("/run/pos/bus.sock").encryption(Tls(acceptor)).auth(auth);
+/// ```
+pub trait IpcEncryption: Send + Sync + 'static {
+    fn accept(&self, stream: UnixStream) -> BoxFuture<'static, std::io::Result<Box<dyn IpcStream>>>;
+}
+
+/// Gateway'in satırları okuyup yazdığı, şifrelenmiş veya ham bağlantı.
+pub trait IpcStream: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> IpcStream for T {}
+
+#[derive(Default)]
+struct Hub {
+    listening: bool,
+    next_peer: u64,
+    peers: Vec<Peer>,
+    // Gateway'e `subscribe` ile abone olan manager'lar
+    subscribers: Vec<(FrameFilter, mpsc::Sender<Frame>)>,
+}
+
+struct Peer {
+    id: u64,
+    client: Option<Arc<Client>>,
+    filters: Vec<FrameFilter>,
+    lines: Lines,
+}
+
+// Bir istemcinin giden satır kuyruğu; `kick` kuyruğu dolan istemcinin bağlantısını keser
+#[derive(Clone)]
+struct Lines {
+    sender: mpsc::Sender<String>,
+    kick: Arc<TickerStop>,
+}
+
+impl Lines {
+    // Kuyruk doluysa istemci atılır; istemci atılmışsa veya bağlantı kapanmışsa `false` döner
+    fn send(&self, line: String) -> bool {
+        match self.sender.try_send(line) {
+            Ok(()) => true,
+            Err(mpsc::error::TrySendError::Full(_)) => {
+                self.kick.stop();
+                false
+            }
+            Err(mpsc::error::TrySendError::Closed(_)) => false,
+        }
+    }
+}
+
+impl IpcGateway {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            gate: Gate::default(),
+            mode: 0o600,
+            max_line: 1024 * 1024,
+            queue: 1024,
+            encryption: None,
+            hub: Arc::new(StdMutex::new(Hub::default())),
+        }
+    }
+
+    /// İstemciler token ile doğrulanır ve izin listelerine göre sınırlanır.
+    pub fn auth(mut self, auth: GatewayAuth) -> Self {
+        self.gate = Gate::new(Some(auth));
+        self
+    }
+
+    /// Soket dosyasının izinleri; varsayılanı `0o600`.
+    pub fn mode(mut self, mode: u32) -> Self {
+        self.mode = mode;
+        self
+    }
+
+    /// Bir satırın en fazla bayt sayısı; varsayılanı 1 MiB.
+    pub fn max_line(mut self, bytes: usize) -> Self {
+        self.max_line = bytes;
+        self
+    }
+
+    /// Bir istemci için gönderilmeyi bekleyebilecek en fazla satır; varsayılanı 1024. Kuyruğu
+    /// dolan istemcinin bağlantısı kesilir.
+    pub fn queue(mut self, lines: usize) -> Self {
+        self.queue = lines.max(1);
+        self
+    }
+
+    /// Her bağlantı protokol başlamadan önce `encryption` ile sarılır.
+    pub fn encryption(mut self, encryption: impl IpcEncryption) -> Self {
+        self.encryption = Some(Arc::new(encryption));
+        self
+    }
+
+    /// Bağlı istemci sayısı.
+    pub fn clients(&self) -> usize {
+        self.lock().peers.len()
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Hub> {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn listen(&self) -> Result<()> {
+        if self.lock().listening {
+            return Ok(());
+        }
+        let bind_error = |e: std::io::Error| Error::Transport(format!("cannot bind `{}`: {e}", self.path.display()));
+        // Önceki çalışmadan kalan soket yalnızca dinleyeni yoksa silinir
+        if self.path.exists() && UnixStream::connect(&self.path).await.is_err() {
+            let _ = std::fs::remove_file(&self.path);
+        }
+        let listener = bind_private(&self.path, self.mode).map_err(bind_error)?;
+        let stop = ticker::register_source(format!("ipc:{}", self.path.display()), &[]).await?;
+        self.lock().listening = true;
+        let server = Server {
+            path: self.path.clone(),
+            gate: self.gate.clone(),
+            max_line: self.max_line,
+            queue: self.queue,
+            encryption: self.encryption.clone(),
+            hub: Arc::clone(&self.hub),
+        };
+        crate::rt::spawn(Arc::new(server).serve(listener, stop));
+        Ok(())
+    }
+}
+
+impl EventTransport for IpcGateway {
+    fn name(&self) -> &str {
+        "ipc"
+    }
+
+    fn connect(&self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(self.listen())
+    }
+
+    fn publish(&self, frame: Frame) -> BoxFuture<'_, Result<()>> {
+        let mut hub = self.lock();
+        let result = if hub.listening {
+            let line = encode("EVENT", &frame);
+            hub.peers.retain(|peer| {
+                let allowed = Gate::check(peer.client.as_deref(), Action::Subscribe, &frame.event).is_ok();
+                !(allowed && peer.filters.iter().any(|filter| filter.matches(&frame.event)))
+                    || peer.lines.send(line.clone())
+            });
+            Ok(())
+        } else {
+            Err(not_listening())
+        };
+        Box::pin(async move { result })
+    }
+
+    fn subscribe(&self, filter: FrameFilter) -> BoxFuture<'_, Result<FrameStream>> {
+        let (sender, frames) = mpsc::channel(self.queue);
+        self.lock().subscribers.push((filter, sender));
+        let stream = futures::stream::unfold(frames, |mut frames| async move {
+            frames.recv().await.map(|frame| (frame, frames))
+        });
+        Box::pin(async move { Ok(stream.boxed()) })
+    }
+
+    fn health(&self) -> TransportHealth {
+        if self.lock().listening {
+            TransportHealth::Connected
+        } else {
+            TransportHealth::Disconnected(not_listening().to_string())
+        }
+    }
+}
+
+// Soket `0o700` izinli bir dizinde bağlanır, izinleri daraltılır ve sonra `path`'e bağlanır;
+// böylece soket hiçbir an umask izinleriyle erişilebilir olmaz. `hard_link` hedefi ezmez, dinleyeni
+// olan bir soket yerinde kalır.
+fn bind_private(path: &Path, mode: u32) -> std::io::Result<UnixListener> {
+    let name = path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default();
+    let staging = path.with_file_name(format!(".{name}.{:016x}", random_u64()));
+    std::fs::DirBuilder::new().mode(0o700).create(&staging)?;
+    let staged = staging.join("sock");
+    let bound = UnixListener::bind(&staged).and_then(|listener| {
+        std::fs::set_permissions(&staged, std::fs::Permissions::from_mode(mode))?;
+        std::fs::hard_link(&staged, path)?;
+        Ok(listener)
+    });
+    let _ = std::fs::remove_file(&staged);
+    let _ = std::fs::remove_dir(&staging);
+    bound
+}
+
+fn not_listening() -> Error {
+    Error::Transport("ipc gateway is not listening".into())
+}
+
+struct Server {
+    path: PathBuf,
+    gate: Gate,
+    max_line: usize,
+    queue: usize,
+    encryption: Option<Arc<dyn IpcEncryption>>,
+    hub: Arc<StdMutex<Hub>>,
+}
+
+impl Server {
+    fn lock(&self) -> std::sync::MutexGuard<'_, Hub> {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    async fn serve(self: Arc<Self>, listener: UnixListener, stop: Arc<TickerStop>) {
+        loop {
+            let accepted = tokio::select! {
+                accepted = listener.accept() => accepted,
+                _ = stop.stopped() => break,
+            };
+            match accepted {
+                Ok((stream, _)) => {
+                    crate::rt::spawn(Arc::clone(&self).handle(stream, Arc::clone(&stop)));
+                }
+                // Dosya tanımlayıcısı tükenmesi gibi geçici hatalarda döngü meşgul edilmez
+                Err(_) => clock::sleep(Duration::from_millis(50)).await,
+            }
+        }
+        let mut hub = self.lock();
+        hub.listening = false;
+        hub.peers.clear();
+        hub.subscribers.clear();
+        drop(hub);
+        let _ = std::fs::remove_file(&self.path);
+    }
+
+    async fn handle(self: Arc<Self>, stream: UnixStream, stop: Arc<TickerStop>) {
+        let stream: Box<dyn IpcStream> = match &self.encryption {
+            Some(encryption) => {
+                let handshake = tokio::select! {
+                    handshake = encryption.accept(stream) => handshake,
+                    _ = stop.stopped() => return,
+                };
+                match handshake {
+                    Ok(stream) => stream,
+                    Err(_) => return,
+                }
+            }
+            None => Box::new(stream),
+        };
+        let (reader, mut writer) = tokio::io::split(stream);
+        let mut reader = BufReader::new(reader);
+        let (sender, mut outgoing) = mpsc::channel::<String>(self.queue);
+        let lines = Lines { sender, kick: Arc::new(TickerStop::new()) };
+        let id = {
+            let mut hub = self.lock();
+            hub.next_peer += 1;
+            hub.next_peer
+        };
+        let kick = Arc::clone(&lines.kick);
+        crate::rt::spawn(async move {
+            loop {
+                let line = tokio::select! {
+                    line = outgoing.recv() => line,
+                    _ = kick.stopped() => None,
+                };
+                let Some(line) = line else { break };
+                // Okumayan istemciye yazarken takılı kalınmaz
+                let written = tokio::select! {
+                    written = writer.write_all(line.as_bytes()) => written.is_ok(),
+                    _ = kick.stopped() => false,
+                };
+                if !written {
+                    break;
+                }
+            }
+            let _ = writer.shutdown().await;
+        });
+
+        // Açık gateway'de istemci bağlanır bağlanmaz anonim olarak kaydedilir
+        let mut client = None;
+        let mut authenticated = !self.gate.requires_token();
+        if authenticated {
+            self.register(id, None, lines.clone());
+        }
+        loop {
+            let line = tokio::select! {
+                line = self.read_line(&mut reader) => line,
+                _ = stop.stopped() => None,
+                _ = lines.kick.stopped() => None,
+            };
+            let Some(line) = line else { break };
+            let mut fields = line.split('\t');
+            let command = fields.next().unwrap_or_default();
+            let reply = match (command, authenticated) {
+                ("AUTH", false) => match self.gate.authenticate(fields.next()) {
+                    Ok(authorized) => {
+                        client = authorized;
+                        authenticated = true;
+                        self.register(id, client.clone(), lines.clone());
+                        Ok(())
+                    }
+                    Err(e) => {
+                        lines.send(format!("ERR\t{}\n", escape(&e.to_string())));
+                        break;
+                    }
+                },
+                ("AUTH", true) => Err(Error::Transport("already authenticated".into())),
+                (_, false) => {
+                    lines.send("ERR\tauthenticate with AUTH first\n".into());
+                    break;
+                }
+                ("SUB", true) => self.subscribe(id, client.as_deref(), fields.next()),
+                ("EMIT", true) => tokio::select! {
+                    emitted = self.emit(client.as_deref(), &line) => emitted,
+                    _ = stop.stopped() => break,
+                },
+                (command, true) => Err(Error::Transport(format!("unknown command `{}`", escape(command)))),
+            };
+            let reply = match reply {
+                Ok(()) => "OK\n".to_string(),
+                Err(e) => format!("ERR\t{}\n", escape(&e.to_string())),
+            };
+            if !lines.send(reply) {
+                break;
+            }
+        }
+        self.lock().peers.retain(|peer| peer.id != id);
+    }
+
+    fn register(&self, id: u64, client: Option<Arc<Client>>, lines: Lines) {
+        self.lock().peers.push(Peer { id, client, filters: Vec::new(), lines });
+    }
+
+    // Bağlantı kapanınca, satır bozuksa veya `max_line`'ı aşarsa `None` döner
+    async fn read_line(&self, reader: &mut BufReader<ReadHalf<Box<dyn IpcStream>>>) -> Option<String> {
+        let mut buffer = Vec::new();
+        let read = reader.take(self.max_line as u64 + 1).read_until(b'\n', &mut buffer).await.ok()?;
+        if read == 0 || buffer.last() != Some(&b'\n') {
+            return None;
+        }
+        buffer.pop();
+        if buffer.last() == Some(&b'\r') {
+            buffer.pop();
+        }
+        String::from_utf8(buffer).ok()
+    }
+
+    fn subscribe(&self, id: u64, client: Option<&Client>, pattern: Option<&str>) -> Result<()> {
+        let pattern = pattern.and_then(unescape).ok_or_else(|| Error::Transport("missing event name".into()))?;
+        let filter = match pattern.strip_suffix('*') {
+            Some("") => FrameFilter::All,
+            Some(prefix) => FrameFilter::Prefix(prefix.to_string()),
+            None => {
+                Gate::check(client, Action::Subscribe, &pattern)?;
+                FrameFilter::Events(vec![pattern])
+            }
+        };
+        if let Some(peer) = self.lock().peers.iter_mut().find(|peer| peer.id == id) {
+            peer.filters.push(filter);
+        }
+        Ok(())
+    }
+
+    // Abonelerin kuyruğu doluysa yer açılana kadar bekler; istemci bu sürede yanıt almaz
+    async fn emit(&self, client: Option<&Client>, line: &str) -> Result<()> {
+        let frame = decode(line).ok_or_else(|| Error::Transport("malformed EMIT".into()))?;
+        Gate::check(client, Action::Emit, &frame.event)?;
+        let senders: Vec<_> = self
+            .lock()
+            .subscribers
+            .iter()
+            .filter(|(filter, _)| filter.matches(&frame.event))
+            .map(|(_, sender)| sender.clone())
+            .collect();
+        for sender in senders {
+            let _ = sender.send(frame.clone()).await;
+        }
+        self.lock().subscribers.retain(|(_, sender)| !sender.is_closed());
+        Ok(())
+    }
+}
+
+fn encode(command: &str, frame: &Frame) -> String {
+    let mut out = format!("{command}\t{}\t", escape(&frame.event));
+    for byte in &frame.payload {
+        let _ = write!(out, "{byte:02x}");
+    }
+    for (name, value) in &frame.headers {
+        let _ = write!(out, "\t{}={}", escape(name), escape(value));
+    }
+    out.push('\n');
+    out
+}
+
+// `EMIT <event> <payload> [ad=değer ...]`
+fn decode(line: &str) -> Option<Frame> {
+    let mut fields = line.split('\t').skip(1);
+    let event = unescape(fields.next()?)?;
+    let hex = fields.next().unwrap_or_default();
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    let payload = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let mut frame = Frame::new(event, payload);
+    for header in fields {
+        let (name, value) = header.split_once('=')?;
+        frame = frame.with_header(unescape(name)?, unescape(value)?);
+    }
+    Some(frame)
+}
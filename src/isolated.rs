@@ -0,0 +1,67 @@
+use std::panic::AssertUnwindSafe;
+use std::thread;
+
+use futures::FutureExt;
+use futures::future::LocalBoxFuture;
+use once_cell::sync::Lazy;
+use tokio::sync::{mpsc, oneshot};
+
+use crate::context::{self, Context};
+
+/// İzole thread'de future'ı üreten iş. Kendisi `Send`'dir, ürettiği future olmak zorunda değildir.
+pub type IsolatedJob = Box<dyn FnOnce() -> LocalBoxFuture<'static, ()> + Send>;
+
+struct Task {
+    job: IsolatedJob,
+    context: Option<Context>,
+    done: oneshot::Sender<bool>,
+}
+
+// rumt'a ait tek thread'li runtime; ilk izole handler çağrıldığında başlatılır
+static ISOLATED: Lazy<mpsc::UnboundedSender<Task>> = Lazy::new(|| {
+    let (sender, mut receiver) = mpsc::unbounded_channel::<Task>();
+    thread::Builder::new()
+        .name("rumt-isolated".into())
+        .spawn(move || {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .enable_all()
+                .build()
+                .expect("rumt: failed to build the isolated runtime");
+            let local = tokio::task::LocalSet::new();
+            local.block_on(&runtime, async move {
+                while let Some(task) = receiver.recv().await {
+                    tokio::task::spawn_local(async move {
+                        let fut = (task.job)();
+                        let fut = async move {
+                            match task.context {
+                                Some(context) => context::scope(context, fut).await,
+                                None => fut.await,
+                            }
+                        };
+                        // Panic eden handler thread'i öldürmez, çağırana hata olarak bildirilir
+                        let ok = AssertUnwindSafe(fut).catch_unwind().await.is_ok();
+                        let _ = task.done.send(ok);
+                    });
+                }
+            });
+        })
+        .expect("rumt: failed to spawn the isolated runtime thread");
+    sender
+});
+
+/// İşi rumt'un izole thread'inde çalıştırır ve bitmesini bekler. Emit'in context'i izole
+/// thread'e taşınır; iş panic ederse panic çağıran tarafta yeniden oluşur (hata olarak sayılır).
+#[doc(hidden)]
+pub async fn run(job: IsolatedJob) {
+    let (done, finished) = oneshot::channel();
+    let task = Task {
+        job,
+        context: context::current(),
+        done,
+    };
+    // Thread yalnızca runtime kurulamazsa kapanır; bu durumda da başarısızlık bildirilir
+    let ok = ISOLATED.send(task).is_ok() && finished.await.unwrap_or(false);
+    if !ok {
+        panic!("rumt: isolated handler panicked");
+    }
+}
@@ -0,0 +1,94 @@
+use std::{collections::BTreeMap, fmt};
+
+use crate::clock::Instant;
+
+/// Bus'ın büyüyebilen kaynaklarının anlık sayımı; `runtime_snapshot` ile alınır ve
+/// `leak_report` ile karşılaştırılır.
+///
+/// Ölçüler ada göre sıralıdır:
+/// - `listeners:<event>` ve `listeners.tag:<tag>`: bağlı ve flag'i kapalı olduğu için
+///   bekletilen dinleyiciler, event ve tag başına,
+/// - `services`: `init` ile kaydedilip henüz dispose edilmemiş servis instance'ları,
+/// - `queued`: kuyrukta bekleyen emit'ler,
+/// - `backlog`: duraklatılmış instance'lara teslim edilmeyi bekleyen emit'ler,
+/// - `sources`: runtime'a ait arka plan task'ları (ticker, izleyici, süreç, transport),
+/// - `retained:<event>`: `retain_last` tamponlarında tutulan payload'lar.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RuntimeSnapshot {
+    pub taken_at: Instant,
+    pub metrics: BTreeMap<String, usize>,
+}
+
+impl RuntimeSnapshot {
+    /// Ölçünün değeri; snapshot'ta yoksa 0.
+    pub fn get(&self, metric: &str) -> usize {
+        self.metrics.get(metric).copied().unwrap_or(0)
+    }
+}
+
+/// Snapshot'lar boyunca hiç azalmadan büyüyen bir ölçü.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Growth {
+    pub metric: String,
+    pub first: usize,
+    pub last: usize,
+}
+
+/// `leak_report` sonucu.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct LeakReport {
+    /// Büyüyen ölçüler, büyüme miktarına göre çoktan aza sıralı.
+    pub growing: Vec<Growth>,
+}
+
+impl LeakReport {
+    pub fn is_clean(&self) -> bool {
+        self.growing.is_empty()
+    }
+}
+
+impl fmt::Display for LeakReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.growing.is_empty() {
+            return write!(f, "no monotonic growth");
+        }
+        for (i, growth) in self.growing.iter().enumerate() {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{}: {} -> {} (+{})", growth.metric, growth.first, growth.last, growth.last - growth.first)?;
+        }
+        Ok(())
+    }
+}
+
+/// Zaman sırasıyla alınmış snapshot'ları karşılaştırır ve ilk snapshot'tan son snapshot'a
+/// büyüyen, arada hiç azalmayan ölçüleri işaretler. Unutulan `dispose_self` çağrıları
+/// böylece ilgili tag'in dinleyici sayısının sürekli artması olarak görünür.
+///
+/// İki snapshot ile düz bir fark alınır; daha fazla snapshot, geçici yük artışlarını
+/// (ör. kuyruğun dolup boşalması) sızıntıdan ayırır.
+///
+/// ```rust,ignore
+/// let before = rumt::runtime_snapshot().await?;
+/// for _ in 0..100 { handle_request().await; }
+/// let after = rumt::runtime_snapshot().await?;
+/// let report = rumt::leak_report(&[before, after]);
+/// assert!(report.is_clean(), "{report}");
+/// ```
+pub fn leak_report(snapshots: &[RuntimeSnapshot]) -> LeakReport {
+    let (Some(first), Some(last)) = (snapshots.first(), snapshots.last()) else {
+        return LeakReport::default();
+    };
+    let mut growing: Vec<Growth> = last
+        .metrics
+        .keys()
+        .filter(|metric| {
+            let values: Vec<usize> = snapshots.iter().map(|snapshot| snapshot.get(metric)).collect();
+            values.windows(2).all(|pair| pair[0] <= pair[1]) && values[values.len() - 1] > values[0]
+        })
+        .map(|metric| Growth { metric: metric.clone(), first: first.get(metric), last: last.get(metric) })
+        .collect();
+    growing.sort_by(|a, b| (b.last - b.first).cmp(&(a.last - a.first)).then_with(|| a.metric.cmp(&b.metric)));
+    LeakReport { growing }
+}
@@ -1,22 +1,132 @@
 #![allow(unused)]
 
+#[cfg(all(feature = "actix", not(target_arch = "wasm32")))]
+pub mod actix;
+pub mod actor;
 pub mod app_info;
+pub mod auth;
+pub mod batch;
+pub mod bridge;
+pub mod cache;
+pub mod catalog;
+pub mod clock;
+pub mod codec;
+pub mod compression;
+pub mod config;
+pub mod context;
+pub mod contracts;
+pub mod controller;
+pub mod crypto;
+pub(crate) mod dedup;
+pub(crate) mod dispatch;
 pub mod env;
+pub mod error;
 pub mod event_bus;
+pub mod failure;
+#[cfg(feature = "ffi")]
+pub mod ffi;
 pub mod global;
+pub mod guarantee;
+pub mod guard;
+#[cfg(all(any(feature = "webhook", feature = "otel"), not(target_arch = "wasm32")))]
+pub(crate) mod http;
+pub mod ids;
+#[cfg(all(feature = "webhook", not(target_arch = "wasm32")))]
+pub mod ingress;
+#[cfg(all(feature = "ipc", unix, not(target_arch = "wasm32")))]
+pub mod ipc;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod isolated;
+pub mod json;
+pub mod leak;
+pub mod log;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod loopback;
+#[cfg(all(feature = "otel", not(target_arch = "wasm32")))]
+pub mod otel;
+pub mod phase;
+pub mod policy;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod process;
+pub mod queue;
+#[cfg(all(feature = "ractor", not(target_arch = "wasm32")))]
+pub mod ractor;
+pub mod reload;
+pub(crate) mod replay;
+pub mod resources;
+pub mod rt;
+pub mod schedule;
+pub mod schema;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod session;
+pub mod snapshot;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod spool;
 pub mod state;
+pub mod stats;
+#[cfg(all(feature = "tauri", not(target_arch = "wasm32")))]
+pub mod tauri;
+pub mod telemetry;
+pub mod ticker;
+pub mod topology;
+pub mod trace;
+#[cfg(feature = "tracing")]
+pub mod tracing;
+pub mod transport;
+#[cfg(all(feature = "fs-watch", not(target_arch = "wasm32")))]
+pub mod watch;
+#[cfg(all(feature = "webhook", not(target_arch = "wasm32")))]
+pub mod webhook;
 
 pub use app_info::AppInfo;
+pub use batch::Coalesce;
+pub use cache::{Cache, CacheInvalidated};
+pub use catalog::{EventCatalog, EventDescriptor, describe_event, event_catalog, register_events};
+pub use config::{BusConfig, DispatchMode, DuplicatePolicy, EmitOptions, RetryPolicy, YieldPolicy};
+pub use context::{Context, EventId};
+pub use contracts::Contract;
+pub use controller::{InstanceId, ListenerController, ListenerHandle};
 pub use env::RuntimeModuleEnv;
-pub use global::{emit_event, init_runtime, runtime_env};
+pub use error::{Error, Result};
+pub use failure::HandlerFailure;
+pub use global::{
+    add_telemetry_observer, bus_stats, cache, coalesce, drain_pending, emit_event,
+    emit_event_with_priority, emit_ref, emit_scoped, emit_with, export_registrations, export_topology,
+    guard_payload, import_registrations, map_payload, register_many, resources, runtime_env,
+    runtime_snapshot, set_flag, shutdown_runtime, try_emit_event, try_emit_scoped, try_emit_with, try_init_runtime,
+    try_runtime_env,
+};
+#[cfg(not(target_arch = "wasm32"))]
+pub use global::drain_pending_blocking;
+#[allow(deprecated)]
+pub use global::init_runtime;
+pub use guarantee::Guarantee;
+pub use guard::PayloadGuard;
+pub use ids::IdGenerator;
+pub use leak::{LeakReport, RuntimeSnapshot, leak_report};
+pub use log::log;
+pub use phase::{DeferredInit, Phase, PhaseStopped};
+pub use policy::{AuthorizationPolicy, NamePolicy};
+pub use queue::{Fairness, Priority};
+pub use reload::{ConfigChanged, ConfigValues, reload_config};
+pub use resources::Resources;
+pub use schedule::{EmitHandle, Scheduler, emit_event_after, scheduler};
+pub use schema::EventName;
+pub use snapshot::{Registration, RegistrationSnapshot};
+pub use stats::{BusStats, LatencyStats};
+pub use ticker::{Tick, interval, start_ticker, stop_ticker};
+pub use topology::Topology;
 pub use state::{Locked, Unlocked};
+pub use trace::TraceContext;
+pub use transport::transport_status;
 pub use futures; 
 pub use std::sync::Arc;
 
 pub mod prelude {
     pub use crate::event_bus::{
-        RuntimeEvent, RuntimeEventListenerHandlerArg, RuntimeEventListenerInitializer,
-        RuntimeEventListenerTrait,
+        ListenerBundle, RuntimeEvent, RuntimeEventListenerHandlerArg,
+        RuntimeEventListenerInitializer, RuntimeEventListenerTrait,
     };
-    pub use crate::event_handlers; // Makro
+    pub use crate::schema::EventName;
+    pub use crate::{define_events, event_handlers}; // Makrolar
 }
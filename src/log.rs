@@ -0,0 +1,94 @@
+use std::{fmt, sync::Arc, time::SystemTime};
+
+use crate::context::{self, EventId};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus};
+
+/// `log` ile yazılan kayıtların yayınlandığı event adı; payload'ı `LogRecord`.
+pub const LOG_EVENT: &str = "rumt.log";
+
+/// Kayıt seviyesi; küçükten büyüğe sıralıdır.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Level {
+    Trace,
+    Debug,
+    #[default]
+    Info,
+    Warn,
+    Error,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Level::Trace => "TRACE",
+            Level::Debug => "DEBUG",
+            Level::Info => "INFO",
+            Level::Warn => "WARN",
+            Level::Error => "ERROR",
+        })
+    }
+}
+
+/// `rumt.log` payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct LogRecord {
+    pub level: Level,
+    /// Kaydı yazan bileşen (ör. `"billing"`, `"rumt::transport"`).
+    pub target: String,
+    pub message: String,
+    pub at: SystemTime,
+    /// Bir handler içinden yazıldıysa emit zincirinin korelasyon kimliği ve tenant'ı.
+    pub correlation_id: Option<EventId>,
+    pub tenant: Option<String>,
+    /// Bir handler içinden yazıldıysa handler'ın etiketi (bkz. `rumt::rt::current_label`).
+    pub handler: Option<Arc<str>>,
+}
+
+impl fmt::Display for LogRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {}: {}", self.level, self.target, self.message)
+    }
+}
+
+tokio::task_local! {
+    // `rumt.log` dinleyicilerinin yazdığı kayıtlar tekrar yayınlanmaz
+    static LOGGING: ();
+}
+
+/// Kaydı `rumt.log` event'i olarak yayınlar. Dosyaya, syslog'a veya OTLP'ye yönlendirme,
+/// bu event'i dinleyen sıradan servislerle yapılır; rumt üzerine kurulan kütüphaneler böylece
+/// uygulamaya bir logger seçimi dayatmaz.
+///
+/// `BusConfig::log_level` altındaki kayıtlar ve runtime başlatılmamışken yazılanlar bırakılır.
+/// `rumt.log` dinleyicilerinin (aynı task üzerinde) yazdığı kayıtlar sonsuz döngü oluşmasın
+/// diye yayınlanmaz.
+///
+/// ```rust,ignore
+/// rumt::log(Level::Warn, "billing", format!("invoice {id} retried")).await;
+///
+/// event_handlers! {
+///     StderrSink;
+///     RuntimeEvent::Static { event_name: "rumt.log".into() } => write : LogRecord
+/// }
+/// ```
+pub async fn log(level: Level, target: impl Into<String>, message: impl Into<String>) {
+    if LOGGING.try_with(|_| ()).is_ok() {
+        return;
+    }
+    let enabled = RuntimeEventBus::try_with_instance_mut(|bus| level >= bus.config.log_level).await;
+    if enabled != Ok(true) {
+        return;
+    }
+    let context = context::current();
+    let record = LogRecord {
+        level,
+        target: target.into(),
+        message: message.into(),
+        at: crate::clock::real_system_time(),
+        correlation_id: context.as_ref().map(|c| c.correlation_id),
+        tenant: context.and_then(|c| c.tenant),
+        handler: crate::rt::current_label(),
+    };
+    let event = RuntimeEvent::Static { event_name: LOG_EVENT.into() };
+    LOGGING.scope((), crate::global::emit_internal(event, record)).await;
+}
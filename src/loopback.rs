@@ -0,0 +1,265 @@
+use futures::StreamExt;
+use futures::future::BoxFuture;
+use std::{
+    cmp::{Ordering, Reverse},
+    collections::BinaryHeap,
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
+
+use tokio::sync::mpsc;
+
+use crate::clock::{self, Instant};
+use crate::error::{Error, Result};
+use crate::transport::{EventTransport, Frame, FrameFilter, FrameStream, TransportHealth};
+
+/// `LoopbackTransport`'un ağ koşullarını taklit eden ayarları.
+#[derive(Clone, Debug)]
+pub struct LoopbackConfig {
+    /// Her frame'e eklenen sabit gecikme.
+    pub latency: Duration,
+    /// Sabit gecikmenin üstüne eklenen `0..jitter` arası rastgele gecikme.
+    pub jitter: Duration,
+    /// Bir frame'in kaybolma olasılığı (`0.0..=1.0`).
+    pub drop_rate: f64,
+    /// Bir frame'in `reorder_delay` kadar geciktirilip sonrakilerin arkasına düşme olasılığı.
+    pub reorder_rate: f64,
+    pub reorder_delay: Duration,
+    /// Aynı tohumla aynı kayıp/sıra senaryosu tekrar üretilir.
+    pub seed: u64,
+}
+
+impl Default for LoopbackConfig {
+    fn default() -> Self {
+        Self {
+            latency: Duration::ZERO,
+            jitter: Duration::ZERO,
+            drop_rate: 0.0,
+            reorder_rate: 0.0,
+            reorder_delay: Duration::from_millis(10),
+            seed: 0x5eed,
+        }
+    }
+}
+
+/// Tamamen bellek içinde çalışan transport. Yayınlanan frame'ler aynı transport'un (ve
+/// klonlarının) eşleşen tüm aboneliklerine iletilir; gecikme, kayıp ve sıra bozulması
+/// `LoopbackConfig` ile denetlenir. Süreçler arası event akışlarını dış altyapı olmadan
+/// test etmek içindir.
+///
+/// ```rust,ignore
+/// let transport = LoopbackTransport::with_config(LoopbackConfig {
+///     latency: Duration::from_millis(5),
+///     drop_rate: 0.1,
+///     ..Default::default()
+/// });
+/// let mut frames = transport.subscribe(FrameFilter::Prefix("order.".into())).await?;
+/// transport.publish(Frame::new("order.created", b"42".to_vec())).await?;
+/// ```
+#[derive(Clone)]
+pub struct LoopbackTransport {
+    hub: Arc<StdMutex<Hub>>,
+}
+
+struct Hub {
+    config: LoopbackConfig,
+    rng: u64,
+    connected: bool,
+    // `false` iken `connect` hata döner (broker'a ulaşılamıyor)
+    reachable: bool,
+    sequence: u64,
+    published: u64,
+    dropped: u64,
+    subscribers: Vec<(FrameFilter, mpsc::UnboundedSender<Scheduled>)>,
+}
+
+impl Hub {
+    // splitmix64
+    fn next_f64(&mut self) -> f64 {
+        self.rng = self.rng.wrapping_add(0x9e3779b97f4a7c15);
+        let mut z = self.rng;
+        z = (z ^ (z >> 30)).wrapping_mul(0xbf58476d1ce4e5b9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94d049bb133111eb);
+        (z ^ (z >> 31)) as f64 / u64::MAX as f64
+    }
+}
+
+impl Default for LoopbackTransport {
+    fn default() -> Self {
+        Self::with_config(LoopbackConfig::default())
+    }
+}
+
+impl LoopbackTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_config(config: LoopbackConfig) -> Self {
+        let hub = Hub {
+            rng: config.seed,
+            config,
+            connected: true,
+            reachable: true,
+            sequence: 0,
+            published: 0,
+            dropped: 0,
+            subscribers: Vec::new(),
+        };
+        Self { hub: Arc::new(StdMutex::new(hub)) }
+    }
+
+    /// Ayarları çalışırken değiştirir; yalnızca sonraki frame'leri etkiler.
+    pub fn set_config(&self, config: LoopbackConfig) {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner()).config = config;
+    }
+
+    /// Bağlantı kopmasını taklit eder: abonelik akışları sona erer, `connect` çağrılana kadar
+    /// `publish` ve `subscribe` hata döner. Yoldaki frame'ler kaybolur.
+    pub fn disconnect(&self) {
+        let mut hub = self.hub.lock().unwrap_or_else(|e| e.into_inner());
+        hub.connected = false;
+        hub.subscribers.clear();
+    }
+
+    /// Broker kesintisini taklit eder: `false` iken bağlantı kopar ve `connect` de hata döner;
+    /// `true` yapıldıktan sonraki ilk `connect` bağlantıyı yeniden kurar.
+    pub fn set_reachable(&self, reachable: bool) {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner()).reachable = reachable;
+        if !reachable {
+            self.disconnect();
+        }
+    }
+
+    /// Kabul edilen (kaybolanlar dahil) frame sayısı.
+    pub fn published(&self) -> u64 {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner()).published
+    }
+
+    pub fn dropped(&self) -> u64 {
+        self.hub.lock().unwrap_or_else(|e| e.into_inner()).dropped
+    }
+}
+
+impl EventTransport for LoopbackTransport {
+    fn name(&self) -> &str {
+        "loopback"
+    }
+
+    fn connect(&self) -> BoxFuture<'_, Result<()>> {
+        let mut hub = self.hub.lock().unwrap_or_else(|e| e.into_inner());
+        hub.connected = hub.reachable;
+        let result = if hub.reachable { Ok(()) } else { Err(Error::Transport("loopback broker is unreachable".into())) };
+        Box::pin(async move { result })
+    }
+
+    fn publish(&self, frame: Frame) -> BoxFuture<'_, Result<()>> {
+        let result = (|| {
+            let mut hub = self.hub.lock().unwrap_or_else(|e| e.into_inner());
+            if !hub.connected {
+                return Err(disconnected());
+            }
+            hub.published += 1;
+            if hub.next_f64() < hub.config.drop_rate {
+                hub.dropped += 1;
+                return Ok(());
+            }
+            let mut delay = hub.config.latency + hub.config.jitter.mul_f64(hub.next_f64());
+            if hub.next_f64() < hub.config.reorder_rate {
+                delay += hub.config.reorder_delay;
+            }
+            hub.sequence += 1;
+            let (deliver_at, sequence) = (clock::now() + delay, hub.sequence);
+            hub.subscribers.retain(|(filter, sender)| {
+                !filter.matches(&frame.event)
+                    || sender.send(Scheduled { deliver_at, sequence, frame: frame.clone() }).is_ok()
+            });
+            Ok(())
+        })();
+        Box::pin(async move { result })
+    }
+
+    fn subscribe(&self, filter: FrameFilter) -> BoxFuture<'_, Result<FrameStream>> {
+        let result = (|| {
+            let mut hub = self.hub.lock().unwrap_or_else(|e| e.into_inner());
+            if !hub.connected {
+                return Err(disconnected());
+            }
+            let (input, scheduled) = mpsc::unbounded_channel();
+            let (output, frames) = mpsc::unbounded_channel();
+            hub.subscribers.push((filter, input));
+            crate::rt::spawn(pump(scheduled, output));
+            let stream = futures::stream::unfold(frames, |mut frames| async move {
+                frames.recv().await.map(|frame| (frame, frames))
+            });
+            Ok(stream.boxed())
+        })();
+        Box::pin(async move { result })
+    }
+
+    fn health(&self) -> TransportHealth {
+        if self.hub.lock().unwrap_or_else(|e| e.into_inner()).connected {
+            TransportHealth::Connected
+        } else {
+            TransportHealth::Disconnected("loopback disconnected".into())
+        }
+    }
+}
+
+fn disconnected() -> Error {
+    Error::Transport("loopback transport is disconnected".into())
+}
+
+struct Scheduled {
+    deliver_at: Instant,
+    sequence: u64,
+    frame: Frame,
+}
+
+impl Scheduled {
+    fn key(&self) -> (Instant, u64) {
+        (self.deliver_at, self.sequence)
+    }
+}
+
+impl PartialEq for Scheduled {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for Scheduled {}
+
+impl PartialOrd for Scheduled {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Scheduled {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+// Frame'leri teslim zamanına göre sıralayıp abonelik akışına aktarır. Aynı gecikmedeki
+// frame'ler yayın sırasını korur; abonelik kapanınca (veya bağlantı koparsa) sonlanır.
+async fn pump(mut scheduled: mpsc::UnboundedReceiver<Scheduled>, output: mpsc::UnboundedSender<Frame>) {
+    let mut pending = BinaryHeap::new();
+    loop {
+        let next = pending.peek().map(|Reverse(item): &Reverse<Scheduled>| item.deliver_at);
+        tokio::select! {
+            received = scheduled.recv() => match received {
+                Some(item) => pending.push(Reverse(item)),
+                None => return,
+            },
+            _ = clock::sleep_until(next.unwrap_or_else(clock::now)), if next.is_some() => {
+                if let Some(Reverse(item)) = pending.pop()
+                    && output.send(item.frame).is_err()
+                {
+                    return;
+                }
+            }
+        }
+    }
+}
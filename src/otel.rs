@@ -0,0 +1,277 @@
+use std::{
+    fmt::Write as _,
+    sync::{
+        Arc, Mutex as StdMutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::{Duration, SystemTime, UNIX_EPOCH},
+};
+
+use tokio::sync::Notify;
+
+use crate::clock;
+use crate::context::random_u64;
+use crate::error::{Error, Result};
+use crate::http::{self, HttpUrl};
+use crate::log::Level;
+use crate::stats::push_json_string;
+use crate::telemetry::{EmitSpan, HandlerSpan, SpanAttributes, TelemetryObserver, span_ids};
+use crate::ticker::{self, TickerStop};
+
+/// `OtlpExporter` ayarları.
+#[derive(Clone, Debug)]
+pub struct OtlpConfig {
+    /// Collector'ın OTLP/HTTP trace adresi; TLS desteklenmez.
+    pub endpoint: String,
+    /// Span'lerin `service.name` resource attribute'u.
+    pub service_name: String,
+    /// Her isteğe eklenen başlıklar (ör. collector token'ı).
+    pub headers: Vec<(String, String)>,
+    /// Bu kadar span birikince süre beklenmeden gönderilir.
+    pub batch_size: usize,
+    /// Gönderilmeyi bekleyen en fazla span; dolu kuyruğa gelen span bırakılır (`dropped`).
+    pub max_queue: usize,
+    pub flush_interval: Duration,
+    /// Tek bir gönderimin süre sınırı.
+    pub timeout: Duration,
+}
+
+impl Default for OtlpConfig {
+    fn default() -> Self {
+        Self {
+            endpoint: "http://127.0.0.1:4318/v1/traces".into(),
+            service_name: "rumt".into(),
+            headers: Vec::new(),
+            batch_size: 512,
+            max_queue: 4096,
+            flush_interval: Duration::from_secs(5),
+            timeout: Duration::from_secs(10),
+        }
+    }
+}
+
+/// Emit ve handler span'lerini OTLP/HTTP (JSON) ile bir OpenTelemetry collector'ına
+/// (Jaeger, Tempo, otel-collector) gönderen gözlemci.
+///
+/// Emit'ler `PRODUCER` türünde `<event> publish`, handler'lar `CONSUMER` türünde
+/// `<event> process` span'i olur; attribute'lar `EmitSpan::attributes` ve
+/// `HandlerSpan::attributes` ile aynıdır. Emit'in `TraceContext`'i varsa span'ler o trace'e
+/// bağlanır; yoksa trace kimliği korelasyon kimliği, span kimliği event kimliğidir. Başarısız
+/// handler'ların span durumu `ERROR` olur.
+///
+/// Span'ler `flush_interval` aralıklarla veya `batch_size` dolunca gönderilir. Gönderilemeyen
+/// grup loglanıp bırakılır. Exporter runtime'a aittir ve `shutdown_runtime` ile durur; son
+/// span'lerin kaybolmaması için kapanıştan önce `flush` çağrılmalıdır.
+///
+/// ```rust,ignore
+/// let exporter = OtlpExporter::start("tempo", OtlpConfig {
+///     endpoint: "http://tempo.internal:4318/v1/traces".into(),
+///     service_name: "billing".into(),
+///     ..Default::default()
+/// })
+/// .await?;
+/// // ...
+/// exporter.flush().await?;
+/// rumt::shutdown_runtime().await;
+/// ```
+pub struct OtlpExporter {
+    config: OtlpConfig,
+    address: HttpUrl,
+    // JSON olarak kodlanmış, gönderilmeyi bekleyen span'ler
+    pending: StdMutex<Vec<String>>,
+    full: Notify,
+    dropped: AtomicU64,
+}
+
+impl OtlpExporter {
+    /// Exporter'ı telemetri gözlemcisi olarak ekler ve gönderim döngüsünü başlatır. Adres
+    /// geçersizse `Error::Transport`, runtime başlatılmamışsa `Error::NotInitialized` döner.
+    pub async fn start(name: &str, config: OtlpConfig) -> Result<Arc<Self>> {
+        let address = HttpUrl::parse(&config.endpoint)?;
+        let stop = ticker::register_source(format!("otel:{name}"), &[]).await?;
+        let exporter = Arc::new(Self {
+            config,
+            address,
+            pending: StdMutex::new(Vec::new()),
+            full: Notify::new(),
+            dropped: AtomicU64::new(0),
+        });
+        crate::add_telemetry_observer(exporter.clone()).await;
+        crate::rt::spawn(Arc::clone(&exporter).run(stop));
+        Ok(exporter)
+    }
+
+    /// Bekleyen span'leri hemen gönderir. Collector hata dönerse veya ulaşılamazsa
+    /// `Error::Transport` döner; span'ler yine de bırakılır.
+    pub async fn flush(&self) -> Result<()> {
+        let spans = std::mem::take(&mut *self.lock());
+        if spans.is_empty() {
+            return Ok(());
+        }
+        let body = self.encode(&spans);
+        let mut headers = vec![("Content-Type".to_string(), "application/json".to_string())];
+        headers.extend(self.config.headers.iter().cloned());
+        let response = http::post(&self.address, &headers, body.as_bytes(), self.config.timeout).await?;
+        match response.status {
+            200..=299 => Ok(()),
+            status => Err(Error::Transport(format!("otlp collector returned http status {status}"))),
+        }
+    }
+
+    /// Gönderilmeyi bekleyen span sayısı.
+    pub fn pending(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Kuyruk dolu olduğu için bırakılan span sayısı.
+    pub fn dropped(&self) -> u64 {
+        self.dropped.load(Ordering::Relaxed)
+    }
+
+    async fn run(self: Arc<Self>, stop: Arc<TickerStop>) {
+        loop {
+            let stopped = tokio::select! {
+                _ = clock::sleep(self.config.flush_interval) => false,
+                _ = self.full.notified() => false,
+                _ = stop.stopped() => true,
+            };
+            if let Err(e) = self.flush().await {
+                crate::log(Level::Warn, "rumt::otel", format!("dropped spans: {e}")).await;
+            }
+            if stopped {
+                return;
+            }
+        }
+    }
+
+    fn push(&self, span: String) {
+        let mut pending = self.lock();
+        if pending.len() >= self.config.max_queue {
+            self.dropped.fetch_add(1, Ordering::Relaxed);
+            return;
+        }
+        pending.push(span);
+        if pending.len() >= self.config.batch_size {
+            self.full.notify_one();
+        }
+    }
+
+    // Kuyruk yalnızca tek adımlık işlemlerle değiştiği için zehirlenmiş kilit hâlâ tutarlıdır
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<String>> {
+        self.pending.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn encode(&self, spans: &[String]) -> String {
+        let mut out = String::from("{\"resourceSpans\":[{\"resource\":{\"attributes\":[");
+        push_attribute(&mut out, "service.name", &self.config.service_name);
+        out.push_str("]},\"scopeSpans\":[{\"scope\":{\"name\":\"rumt\",\"version\":");
+        push_json_string(&mut out, env!("CARGO_PKG_VERSION"));
+        out.push_str("},\"spans\":[");
+        out.push_str(&spans.join(","));
+        out.push_str("]}]}]}");
+        out
+    }
+}
+
+impl TelemetryObserver for OtlpExporter {
+    fn on_emit(&self, span: &EmitSpan<'_>) {
+        let (trace_id, span_id) = span_ids(span.context);
+        // Kök olmayan emit'ler, trace yoksa tetikleyen emit'in span'ine bağlanır
+        let parent = match (&span.context.trace, span.context.causation_id) {
+            (None, Some(causation)) => Some(format!("{:016x}", causation.0 as u64)),
+            _ => None,
+        };
+        let now = clock::real_system_time();
+        let record = SpanRecord {
+            trace_id,
+            span_id,
+            parent,
+            name: span.span_name(),
+            kind: SPAN_KIND_PRODUCER,
+            start: now,
+            end: now,
+            attributes: span.attributes(),
+            error: None,
+        };
+        self.push(record.encode());
+    }
+
+    fn on_handler(&self, span: &HandlerSpan<'_>) {
+        let (trace_id, parent) = span_ids(span.context);
+        let end = clock::real_system_time();
+        let record = SpanRecord {
+            trace_id,
+            span_id: format!("{:016x}", random_u64().max(1)),
+            parent: Some(parent),
+            name: span.span_name(),
+            kind: SPAN_KIND_CONSUMER,
+            start: end.checked_sub(span.elapsed).unwrap_or(end),
+            end,
+            attributes: span.attributes(),
+            error: span.failure.map(|failure| failure.message.clone()),
+        };
+        self.push(record.encode());
+    }
+}
+
+const SPAN_KIND_PRODUCER: u8 = 4;
+const SPAN_KIND_CONSUMER: u8 = 5;
+const STATUS_ERROR: u8 = 2;
+
+struct SpanRecord {
+    trace_id: String,
+    span_id: String,
+    parent: Option<String>,
+    name: String,
+    kind: u8,
+    start: SystemTime,
+    end: SystemTime,
+    attributes: SpanAttributes,
+    error: Option<String>,
+}
+
+impl SpanRecord {
+    fn encode(&self) -> String {
+        let mut out = String::with_capacity(512);
+        let _ = write!(out, "{{\"traceId\":\"{}\",\"spanId\":\"{}\"", self.trace_id, self.span_id);
+        if let Some(parent) = &self.parent {
+            let _ = write!(out, ",\"parentSpanId\":\"{parent}\"");
+        }
+        out.push_str(",\"name\":");
+        push_json_string(&mut out, &self.name);
+        let _ = write!(
+            out,
+            ",\"kind\":{},\"startTimeUnixNano\":\"{}\",\"endTimeUnixNano\":\"{}\",\"attributes\":[",
+            self.kind,
+            unix_nanos(self.start),
+            unix_nanos(self.end)
+        );
+        for (index, (key, value)) in self.attributes.iter().enumerate() {
+            if index > 0 {
+                out.push(',');
+            }
+            push_attribute(&mut out, key, value);
+        }
+        out.push(']');
+        if let Some(message) = &self.error {
+            let _ = write!(out, ",\"status\":{{\"code\":{STATUS_ERROR},\"message\":");
+            push_json_string(&mut out, message);
+            out.push('}');
+        }
+        out.push('}');
+        out
+    }
+}
+
+fn push_attribute(out: &mut String, key: &str, value: &str) {
+    out.push_str("{\"key\":");
+    push_json_string(out, key);
+    out.push_str(",\"value\":{\"stringValue\":");
+    push_json_string(out, value);
+    out.push_str("}}");
+}
+
+// OTLP JSON'da 64 bitlik zaman damgaları dizgi olarak yazılır
+fn unix_nanos(time: SystemTime) -> u128 {
+    time.duration_since(UNIX_EPOCH).map(|since| since.as_nanos()).unwrap_or(0)
+}
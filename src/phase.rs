@@ -0,0 +1,152 @@
+use once_cell::sync::Lazy;
+use std::sync::{Arc, Mutex as StdMutex, OnceLock};
+
+use crate::controller::ListenerController;
+use crate::error::Result;
+use crate::event_bus::RuntimeEvent;
+use crate::rt::MaybeSendFuture;
+
+/// Dinleyici kayıtlarının runtime başlangıcında çalıştırılma sırası.
+/// Bir fazdaki tüm kayıtlar bitmeden sonraki faz başlamaz.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Phase {
+    /// Loglama, kalıcılık gibi diğer servislerin dayandığı altyapı dinleyicileri.
+    Infrastructure,
+    /// İş mantığını yürüten servisler.
+    Domain,
+    /// Dış dünyaya açılan katman (HTTP, IPC, UI köprüleri).
+    Api,
+}
+
+impl Phase {
+    pub const ALL: [Phase; 3] = [Phase::Infrastructure, Phase::Domain, Phase::Api];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Phase::Infrastructure => "infrastructure",
+            Phase::Domain => "domain",
+            Phase::Api => "api",
+        }
+    }
+
+    /// Fazın tüm kayıtları tamamlandığında bir kez yayınlanan event (`rumt.phase.<ad>`),
+    /// payload olarak `Phase` taşır. Bir sonraki fazın servisleri emit etmeye bunu bekleyebilir.
+    pub fn ready_event(self) -> RuntimeEvent {
+        RuntimeEvent::OnceTriggered {
+            event_name: format!("rumt.phase.{}", self.name()),
+        }
+    }
+
+    /// `shutdown_runtime` fazın servislerini kapatıp kuyruğu boşalttığında yayınlanan event
+    /// (`rumt.phase.<ad>.stopped`), payload olarak `PhaseStopped` taşır. Sonraki (daha alt)
+    /// fazların dinleyicileri bu sırada hâlâ bağlıdır.
+    pub fn stopped_event(self) -> RuntimeEvent {
+        RuntimeEvent::Static {
+            event_name: format!("rumt.phase.{}.stopped", self.name()),
+        }
+    }
+}
+
+/// `rumt.phase.<ad>.stopped` payload'ı.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PhaseStopped {
+    pub phase: Phase,
+    /// Kapatılan servis sayısı.
+    pub services: usize,
+    /// `false` ise kuyruk `BusConfig::shutdown_drain_timeout` içinde boşalmadı; kalan emit'ler
+    /// sonraki fazlarla birlikte işlenmeye devam eder.
+    pub drained: bool,
+}
+
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+type Registration = Box<dyn FnOnce() -> MaybeSendFuture<'static, Result<ListenerController>> + Send>;
+// Kayıt servisi taşır; tarayıcıda servisin `Send` olması gerekmez
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+type Registration = Box<dyn FnOnce() -> MaybeSendFuture<'static, Result<ListenerController>>>;
+
+struct Pending {
+    phase: Phase,
+    register: Registration,
+    slot: Arc<OnceLock<ListenerController>>,
+}
+
+// Atomics'siz wasm32'de tek thread vardır; bkz. `RuntimeEventListener`
+#[cfg(all(feature = "wasm", target_arch = "wasm32", not(target_feature = "atomics")))]
+unsafe impl Send for Pending {}
+
+static PENDING: Lazy<StdMutex<Vec<Pending>>> = Lazy::new(|| StdMutex::new(Vec::new()));
+
+/// `defer_init` ile sıraya alınmış bir kayıt. Runtime fazı çalıştırdığında controller dolar.
+#[derive(Clone)]
+pub struct DeferredInit {
+    phase: Phase,
+    slot: Arc<OnceLock<ListenerController>>,
+}
+
+impl DeferredInit {
+    pub fn phase(&self) -> Phase {
+        self.phase
+    }
+
+    pub fn is_registered(&self) -> bool {
+        self.slot.get().is_some()
+    }
+
+    pub fn controller(&self) -> Option<&ListenerController> {
+        self.slot.get()
+    }
+}
+
+pub(crate) fn defer(phase: Phase, register: Registration) -> DeferredInit {
+    let slot = Arc::new(OnceLock::new());
+    pending().push(Pending {
+        phase,
+        register,
+        slot: Arc::clone(&slot),
+    });
+    DeferredInit { phase, slot }
+}
+
+// Liste yalnızca push/take ile değiştiği için zehirlenmiş kilit hâlâ tutarlıdır
+fn pending() -> std::sync::MutexGuard<'static, Vec<Pending>> {
+    PENDING.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+/// Bekleyen kayıtları faz sırasıyla çalıştırır; aynı fazdakiler eklenme sırasını korur.
+/// Her fazın sonunda (o fazda kayıt olmasa da) fazın `ready_event`'i yayınlanır.
+/// Kayıt sırasında yeni `defer_init` yapılırsa onlar da aynı çağrıda işlenir.
+/// Başarısız bir kayıt diğerlerini durdurmaz: hatası loglanır, kalan kayıtlar ve fazlar
+/// çalıştırılır, sonunda ilk hata döner.
+pub(crate) async fn run_pending() -> Result<()> {
+    let mut first_error = None;
+    let mut batch = std::mem::take(&mut *pending());
+    loop {
+        batch.sort_by_key(|pending| pending.phase);
+
+        let mut registrations = batch.into_iter().peekable();
+        for phase in Phase::ALL {
+            while let Some(current) = registrations.next_if(|pending| pending.phase == phase) {
+                match (current.register)().await {
+                    Ok(controller) => {
+                        let _ = current.slot.set(controller);
+                    }
+                    Err(e) => {
+                        crate::log(
+                            crate::log::Level::Error,
+                            "rumt::phase",
+                            format!("deferred registration in phase `{}` failed: {e}", phase.name()),
+                        )
+                        .await;
+                        first_error.get_or_insert(e);
+                    }
+                }
+            }
+            crate::global::emit_internal(phase.ready_event(), phase).await;
+        }
+
+        batch = std::mem::take(&mut *pending());
+        if batch.is_empty() {
+            return first_error.map_or(Ok(()), Err);
+        }
+    }
+}
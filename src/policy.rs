@@ -0,0 +1,285 @@
+use std::{fmt, sync::Arc};
+
+use crate::error::{Error, Result};
+use crate::event_bus::{ListenerBundle, RuntimeEvent};
+use crate::telemetry::event_name;
+
+type Validator = Arc<dyn Fn(&str) -> std::result::Result<(), String> + Send + Sync>;
+
+/// Event adlarını kayıt ve emit anında doğrulayan kurallar. `RuntimeModuleEnv::name_policy`
+/// ile runtime'a verilir; kurala uymayan kayıt ve emit'ler `Error::InvalidEventName` döner.
+///
+/// Kurallar `*` (herhangi bir dizi) ve `?` (tek karakter) içeren glob desenleridir:
+///
+/// ```rust
+/// use rumt::policy::NamePolicy;
+///
+/// let policy = NamePolicy::new()
+///     .allow("orders.*")
+///     .allow("billing.*")
+///     .reserve("billing.internal.*");
+///
+/// assert!(policy.check_emit("orders.created").is_ok());
+/// assert!(policy.check_emit("order.created").is_err());
+/// // Ayrılmış adlar dinlenebilir ama uygulama tarafından yayınlanamaz
+/// assert!(policy.check_listen("billing.internal.sync").is_ok());
+/// assert!(policy.check_emit("billing.internal.sync").is_err());
+/// ```
+#[derive(Clone)]
+pub struct NamePolicy {
+    allowed: Vec<String>,
+    reserved: Vec<String>,
+    validators: Vec<Validator>,
+}
+
+impl NamePolicy {
+    /// Runtime'ın kendi eventleri için `rumt.*` önekini ayırır; başka kural içermez.
+    pub fn new() -> Self {
+        Self {
+            allowed: Vec::new(),
+            reserved: vec!["rumt.*".to_string()],
+            validators: Vec::new(),
+        }
+    }
+
+    /// Yalnızca verilen desenlerden birine uyan adlara izin verir. Hiç `allow` yoksa her ad
+    /// kabul edilir. Ayrılmış adlar allow listesinden bağımsız olarak dinlenebilir.
+    pub fn allow(mut self, pattern: impl Into<String>) -> Self {
+        self.allowed.push(pattern.into());
+        self
+    }
+
+    /// Desene uyan adlar dinlenebilir ancak uygulama tarafından emit edilemez.
+    pub fn reserve(mut self, pattern: impl Into<String>) -> Self {
+        self.reserved.push(pattern.into());
+        self
+    }
+
+    /// Ek bir kural ekler (ör. adlandırma biçimi, en az iki segment). `Err` içindeki metin
+    /// hatanın nedeni olarak döner.
+    pub fn validate(
+        mut self,
+        validator: impl Fn(&str) -> std::result::Result<(), String> + Send + Sync + 'static,
+    ) -> Self {
+        self.validators.push(Arc::new(validator));
+        self
+    }
+
+    /// Bir event'e dinleyici bağlanmadan önce çağrılır.
+    pub fn check_listen(&self, name: &str) -> Result<()> {
+        if self.is_reserved(name) {
+            return Ok(());
+        }
+        self.check_rules(name)
+    }
+
+    /// Bir event yayınlanmadan önce çağrılır.
+    pub fn check_emit(&self, name: &str) -> Result<()> {
+        if self.is_reserved(name) {
+            return Err(invalid(name, "name is reserved"));
+        }
+        self.check_rules(name)
+    }
+
+    pub(crate) fn check_bundle(&self, bundle: &ListenerBundle) -> Result<()> {
+        bundle
+            .iter()
+            .try_for_each(|(event, _)| self.check_listen(event_name(event)))
+    }
+
+    pub(crate) fn check_event(&self, event: &RuntimeEvent) -> Result<()> {
+        self.check_emit(event_name(event))
+    }
+
+    fn is_reserved(&self, name: &str) -> bool {
+        self.reserved.iter().any(|pattern| glob_match(pattern, name))
+    }
+
+    fn check_rules(&self, name: &str) -> Result<()> {
+        if name.is_empty() {
+            return Err(invalid(name, "name is empty"));
+        }
+        if name.chars().any(|c| c.is_whitespace() || c.is_control()) {
+            return Err(invalid(name, "name contains whitespace or control characters"));
+        }
+        if !self.allowed.is_empty() && !self.allowed.iter().any(|pattern| glob_match(pattern, name)) {
+            return Err(invalid(name, "name does not match any allowed namespace"));
+        }
+        for validator in &self.validators {
+            validator(name).map_err(|reason| invalid(name, reason))?;
+        }
+        Ok(())
+    }
+}
+
+impl Default for NamePolicy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl fmt::Debug for NamePolicy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NamePolicy")
+            .field("allowed", &self.allowed)
+            .field("reserved", &self.reserved)
+            .field("validators", &self.validators.len())
+            .finish()
+    }
+}
+
+/// Yetkilendirme kararının istendiği işlem.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Access {
+    Emit,
+    Listen,
+}
+
+/// Karar verilirken kullanılabilecek payload bilgisi. Payload'ın kendisi verilmez; politika
+/// tipi ve boyutu üzerinden karar verir.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PayloadMeta<'a> {
+    pub access: Access,
+    /// Emit edilen payload'ın tip adı; kayıtta `None`.
+    pub type_name: Option<&'static str>,
+    /// Payload tipinin bellekteki boyutu (bayt); kayıtta `0`.
+    pub size: usize,
+    pub tenant: Option<&'a str>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Decision {
+    Allow,
+    /// Metin hatanın nedeni olarak `Error::Unauthorized` içinde döner.
+    Deny(String),
+}
+
+/// Emit ve kayıt sırasında danışılan yetkilendirme kancası. `RuntimeModuleEnv::authorization`
+/// ile runtime'a verilir.
+///
+/// `source_tag` kayıtta dinleyicinin tag'i, emit'te emit'i yapan handler'ın tag'idir; handler
+/// dışından (uygulama kodu, ingress, transport) yapılan emit'lerde `None`'dır. Reddedilen kayıt
+/// ve emit'ler `Error::Unauthorized` döner. Runtime'ın kendi eventleri (`rumt.*`) politikaya
+/// sorulmaz. Kapanışlar da politika olarak kullanılabilir.
+pub trait AuthorizationPolicy: Send + Sync {
+    fn allow(&self, source_tag: Option<&str>, event: &RuntimeEvent, meta: &PayloadMeta<'_>) -> Decision;
+}
+
+impl<F> AuthorizationPolicy for F
+where
+    F: Fn(Option<&str>, &RuntimeEvent, &PayloadMeta<'_>) -> Decision + Send + Sync,
+{
+    fn allow(&self, source_tag: Option<&str>, event: &RuntimeEvent, meta: &PayloadMeta<'_>) -> Decision {
+        self(source_tag, event, meta)
+    }
+}
+
+/// Belirli tag'leri (ör. plugin servisleri) event namespace'leriyle sınırlayan hazır politika.
+/// Kısıtlanmamış tag'ler ve handler dışından yapılan emit'ler serbesttir.
+///
+/// ```rust
+/// use rumt::policy::{Access, AuthorizationPolicy, Decision, PayloadMeta, SandboxPolicy};
+/// use rumt::prelude::RuntimeEvent;
+///
+/// let policy = SandboxPolicy::new().sandbox("WeatherPlugin", ["plugins.weather.*", "ui.render"]);
+/// let meta = PayloadMeta { access: Access::Emit, type_name: None, size: 0, tenant: None };
+/// let event = |name: &str| RuntimeEvent::Static { event_name: name.into() };
+///
+/// assert_eq!(policy.allow(Some("WeatherPlugin"), &event("ui.render"), &meta), Decision::Allow);
+/// assert!(matches!(policy.allow(Some("WeatherPlugin"), &event("billing.charge"), &meta), Decision::Deny(_)));
+/// assert_eq!(policy.allow(Some("Billing"), &event("billing.charge"), &meta), Decision::Allow);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct SandboxPolicy {
+    sandboxes: Vec<(String, Vec<String>)>,
+}
+
+impl SandboxPolicy {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// `tag` yalnızca desenlerden birine uyan eventleri dinleyebilir ve yayınlayabilir.
+    /// Aynı tag tekrar verilirse desenler birleştirilir.
+    pub fn sandbox<I>(mut self, tag: impl Into<String>, patterns: I) -> Self
+    where
+        I: IntoIterator,
+        I::Item: Into<String>,
+    {
+        let tag = tag.into();
+        let patterns = patterns.into_iter().map(Into::into);
+        match self.sandboxes.iter_mut().find(|(existing, _)| *existing == tag) {
+            Some((_, existing)) => existing.extend(patterns),
+            None => self.sandboxes.push((tag, patterns.collect())),
+        }
+        self
+    }
+}
+
+impl AuthorizationPolicy for SandboxPolicy {
+    fn allow(&self, source_tag: Option<&str>, event: &RuntimeEvent, meta: &PayloadMeta<'_>) -> Decision {
+        let Some((tag, patterns)) = source_tag.and_then(|tag| self.sandboxes.iter().find(|(t, _)| t == tag)) else {
+            return Decision::Allow;
+        };
+        let name = event_name(event);
+        if patterns.iter().any(|pattern| glob_match(pattern, name)) {
+            return Decision::Allow;
+        }
+        let action = match meta.access {
+            Access::Emit => "emit",
+            Access::Listen => "listen to",
+        };
+        Decision::Deny(format!("`{tag}` may not {action} `{name}` outside its sandbox"))
+    }
+}
+
+/// Politikaya danışır; ret `Error::Unauthorized` olarak döner.
+pub(crate) fn authorize(
+    policy: &dyn AuthorizationPolicy,
+    source_tag: Option<&str>,
+    event: &RuntimeEvent,
+    meta: &PayloadMeta<'_>,
+) -> Result<()> {
+    match policy.allow(source_tag, event, meta) {
+        Decision::Allow => Ok(()),
+        Decision::Deny(reason) => Err(Error::Unauthorized(reason)),
+    }
+}
+
+fn invalid(name: &str, reason: impl Into<String>) -> Error {
+    Error::InvalidEventName {
+        name: name.to_string(),
+        reason: reason.into(),
+    }
+}
+
+/// `*` herhangi bir karakter dizisiyle (boş dahil), `?` tek karakterle eşleşir.
+pub(crate) fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    // Son görülen `*` ve o anda eşleştiği metin konumu; uyuşmazlıkta buraya geri dönülür
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        match pattern.get(p) {
+            Some('*') => {
+                backtrack = Some((p, t));
+                p += 1;
+            }
+            Some(&c) if c == '?' || c == text[t] => {
+                p += 1;
+                t += 1;
+            }
+            _ => match backtrack {
+                Some((star, matched)) => {
+                    p = star + 1;
+                    t = matched + 1;
+                    backtrack = Some((star, matched + 1));
+                }
+                None => return false,
+            },
+        }
+    }
+    pattern[p..].iter().all(|&c| c == '*')
+}
@@ -0,0 +1,226 @@
+use std::{path::PathBuf, process::Stdio, sync::Arc, time::Duration};
+
+use tokio::io::{AsyncBufReadExt, BufReader};
+use tokio::process::{Child, ChildStdout, Command};
+
+use crate::clock;
+use crate::error::{Error, Result};
+use crate::event_bus::RuntimeEvent;
+use crate::ticker::{self, TickerStop};
+
+pub const PROCESS_STARTED: &str = "process.started";
+pub const PROCESS_EXITED: &str = "process.exited";
+pub const PROCESS_STDOUT_LINE: &str = "process.stdout.line";
+
+/// Süreç sonlandığında yeniden başlatılıp başlatılmayacağı.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum RestartPolicy {
+    #[default]
+    Never,
+    /// Yalnızca başarısız çıkışta (sıfır olmayan kod, sinyal veya başlatılamama) yeniden başlatılır.
+    OnFailure,
+    Always,
+}
+
+/// Runtime'ın başlatıp izleyeceği harici bir süreç. `RuntimeModuleEnv::process` ile tanımlanır.
+///
+/// ```rust
+/// # use std::time::Duration;
+/// # use rumt::process::{ProcessSpec, RestartPolicy};
+/// let worker = ProcessSpec::new("transcoder", "ffmpeg")
+///     .args(["-i", "pipe:0", "-f", "mp3", "pipe:1"])
+///     .restart(RestartPolicy::OnFailure)
+///     .max_restarts(5)
+///     .backoff(Duration::from_secs(2));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessSpec {
+    pub name: String,
+    pub program: String,
+    pub args: Vec<String>,
+    pub current_dir: Option<PathBuf>,
+    pub restart: RestartPolicy,
+    /// `None` ise sınırsız.
+    pub max_restarts: Option<u32>,
+    /// Yeniden başlatmadan önce beklenen süre.
+    pub backoff: Duration,
+}
+
+impl ProcessSpec {
+    pub fn new(name: impl Into<String>, program: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            program: program.into(),
+            args: Vec::new(),
+            current_dir: None,
+            restart: RestartPolicy::Never,
+            max_restarts: None,
+            backoff: Duration::from_secs(1),
+        }
+    }
+
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    pub fn args<I: IntoIterator<Item = S>, S: Into<String>>(mut self, args: I) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    pub fn restart(mut self, policy: RestartPolicy) -> Self {
+        self.restart = policy;
+        self
+    }
+
+    pub fn max_restarts(mut self, count: u32) -> Self {
+        self.max_restarts = Some(count);
+        self
+    }
+
+    pub fn backoff(mut self, backoff: Duration) -> Self {
+        self.backoff = backoff;
+        self
+    }
+
+    fn should_restart(&self, success: bool, restarts: u32) -> bool {
+        let wanted = match self.restart {
+            RestartPolicy::Never => false,
+            RestartPolicy::OnFailure => !success,
+            RestartPolicy::Always => true,
+        };
+        wanted && self.max_restarts.is_none_or(|max| restarts < max)
+    }
+}
+
+/// `process.started` payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessStarted {
+    pub name: String,
+    pub pid: Option<u32>,
+    /// Bu başlatmadan önce yapılan yeniden başlatma sayısı.
+    pub restarts: u32,
+}
+
+/// `process.exited` payload'ı. Süreç hiç başlatılamadıysa `error` doludur.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessExited {
+    pub name: String,
+    /// Sinyalle sonlanan süreçlerde `None`.
+    pub code: Option<i32>,
+    pub success: bool,
+    pub error: Option<String>,
+    pub will_restart: bool,
+}
+
+/// `process.stdout.line` payload'ı; satır sonu karakteri içermez.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ProcessLine {
+    pub name: String,
+    pub line: String,
+}
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+/// Süreci başlatır ve politikasına göre izler. `init_runtime` env'deki süreçler için bunu
+/// kendisi çağırır; süreçler `stop_process` veya `shutdown_runtime` ile sonlandırılır.
+/// Aynı adla izlenen bir süreç varsa önce o durdurulur.
+pub async fn spawn_process(spec: ProcessSpec) -> Result<()> {
+    let stop = ticker::register_source(format!("process:{}", spec.name), &[event(PROCESS_STARTED)]).await?;
+    crate::rt::spawn(supervise(spec, stop));
+    Ok(())
+}
+
+/// İzlenen süreci sonlandırır (yeniden başlatılmaz); bu adda süreç yoksa `false` döner.
+pub async fn stop_process(name: &str) -> bool {
+    ticker::stop_ticker(&format!("process:{name}")).await
+}
+
+async fn supervise(spec: ProcessSpec, stop: Arc<TickerStop>) {
+    let mut restarts = 0;
+    loop {
+        let (success, code, error) = match start(&spec) {
+            Ok(mut child) => {
+                let started = ProcessStarted { name: spec.name.clone(), pid: child.id(), restarts };
+                if emit(PROCESS_STARTED, started).await.is_err() {
+                    return;
+                }
+                // Çıkış, tüm stdout satırları yayınlandıktan sonra bildirilir
+                let stdout = child.stdout.take();
+                let run = async {
+                    let (status, ()) = tokio::join!(child.wait(), forward_stdout(&spec.name, stdout));
+                    status
+                };
+                let status = tokio::select! {
+                    status = run => Some(status),
+                    _ = stop.stopped() => None,
+                };
+                match status {
+                    Some(Ok(status)) => (status.success(), status.code(), None),
+                    Some(Err(e)) => (false, None, Some(e.to_string())),
+                    None => {
+                        // Durdurulan süreç beklenerek sonlandırılır, zombi bırakılmaz
+                        let _ = child.kill().await;
+                        return;
+                    }
+                }
+            }
+            Err(e) => (false, None, Some(e.to_string())),
+        };
+
+        let will_restart = spec.should_restart(success, restarts);
+        let exited = ProcessExited { name: spec.name.clone(), code, success, error, will_restart };
+        if emit(PROCESS_EXITED, exited).await.is_err() || !will_restart {
+            return;
+        }
+        restarts += 1;
+        tokio::select! {
+            _ = clock::sleep(spec.backoff) => {}
+            _ = stop.stopped() => return,
+        }
+    }
+}
+
+fn start(spec: &ProcessSpec) -> std::io::Result<Child> {
+    let mut command = Command::new(&spec.program);
+    command
+        .args(&spec.args)
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .kill_on_drop(true);
+    if let Some(dir) = &spec.current_dir {
+        command.current_dir(dir);
+    }
+    command.spawn()
+}
+
+async fn forward_stdout(name: &str, stdout: Option<ChildStdout>) {
+    let Some(stdout) = stdout else {
+        return;
+    };
+    let mut lines = BufReader::new(stdout).lines();
+    let mut forwarding = true;
+    // Runtime kapandıktan sonra da okunmaya devam edilir ki süreç dolu pipe'ta bloklanmasın
+    while let Ok(Some(line)) = lines.next_line().await {
+        if forwarding {
+            let line = ProcessLine { name: name.to_string(), line };
+            forwarding = emit(PROCESS_STDOUT_LINE, line).await.is_ok();
+        }
+    }
+}
+
+// Runtime kapatıldıysa (NotInitialized) izleme sonlanır
+async fn emit<T: Send + Sync + 'static>(name: &str, payload: T) -> Result<()> {
+    match crate::try_emit_event(event(name), payload).await {
+        Err(Error::NotInitialized) => Err(Error::NotInitialized),
+        _ => Ok(()),
+    }
+}
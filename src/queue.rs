@@ -0,0 +1,290 @@
+use std::{
+    cmp::Ordering,
+    collections::{BTreeMap, BinaryHeap, HashMap, VecDeque},
+    sync::{
+        Arc, Mutex as StdMutex,
+        atomic::{AtomicBool, AtomicU64, AtomicUsize, Ordering as AtomicOrdering},
+    },
+};
+use tokio::sync::Notify;
+
+use crate::context::{self, Context};
+use crate::dispatch::DispatchPlan;
+use crate::event_bus::RuntimeEventListenerHandlerArg;
+
+/// Kuyruklu dispatch'te eventlerin işlenme önceliği.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Priority {
+    Low,
+    #[default]
+    Normal,
+    High,
+    Critical,
+}
+
+/// Kuyruklu dispatch'te worker'ların emit'i yapan tag'ler arasında paylaştırılması.
+///
+/// Emit'in tag'i, onu yapan handler'ın tag'idir; handler dışından yapılan emit'ler (ticker,
+/// transport, uygulama kodu) tek bir ortak grupta toplanır. Öncelik her zaman önce gelir:
+/// paylaştırma yalnızca aynı öncelikte bekleyen emit'ler arasında yapılır.
+///
+/// ```rust
+/// # use std::collections::BTreeMap;
+/// # use rumt::{BusConfig, DispatchMode, Fairness};
+/// // Her turda sipariş servisi 4, telemetri 1 emit işletir
+/// let weights = BTreeMap::from([("OrderService".to_string(), 4), ("Telemetry".to_string(), 1)]);
+/// let config = BusConfig {
+///     mode: DispatchMode::Queued,
+///     fairness: Fairness::Weighted(weights),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum Fairness {
+    /// Yalnızca öncelik ve geliş sırası (varsayılan); yoğun bir tag diğerlerini bekletebilir.
+    #[default]
+    Off,
+    /// Bekleyen emit'i olan tag'ler sırayla birer emit işletir.
+    RoundRobin,
+    /// Tag'ler sırayla, ağırlıkları kadar emit işletir; listede olmayan tag'lerin ağırlığı 1'dir.
+    Weighted(BTreeMap<String, u32>),
+}
+
+impl Fairness {
+    fn weight(&self, lane: &str) -> u32 {
+        match self {
+            Fairness::Weighted(weights) => weights.get(lane).copied().unwrap_or(1).max(1),
+            _ => 1,
+        }
+    }
+}
+
+/// Kuyrukta bekleyen tek bir emit. Dinleyiciler emit anındaki snapshot'tır.
+pub(crate) struct QueuedEmit {
+    pub(crate) priority: Priority,
+    pub(crate) seq: u64,
+    pub(crate) context: Context,
+    pub(crate) plan: DispatchPlan,
+    // İçerideki değer `Arc<T>`'dir; handler'lar bunu downcast eder.
+    pub(crate) payload: Arc<dyn RuntimeEventListenerHandlerArg>,
+}
+
+impl PartialEq for QueuedEmit {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for QueuedEmit {}
+
+impl PartialOrd for QueuedEmit {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for QueuedEmit {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Önce öncelik, aynı öncelikte ise önce gelen (küçük seq) önde
+        self.priority
+            .cmp(&other.priority)
+            .then_with(|| other.seq.cmp(&self.seq))
+    }
+}
+
+// Tag başına bekleyen emit'ler. `Fairness::Off` iken tek grup kullanılır.
+struct Lanes {
+    fairness: Fairness,
+    lanes: HashMap<String, Lane>,
+    // Sıradaki grup önde; hakkını bitiren grup sona geçer
+    turn: VecDeque<String>,
+    len: usize,
+}
+
+struct Lane {
+    jobs: BinaryHeap<QueuedEmit>,
+    credit: u32,
+}
+
+impl Lanes {
+    fn push(&mut self, lane: Option<String>, job: QueuedEmit) {
+        let lane = match self.fairness {
+            Fairness::Off => String::new(),
+            _ => lane.unwrap_or_default(),
+        };
+        if !self.lanes.contains_key(&lane) {
+            let credit = self.fairness.weight(&lane);
+            self.lanes.insert(lane.clone(), Lane { jobs: BinaryHeap::new(), credit });
+            self.turn.push_back(lane.clone());
+        }
+        self.lanes.get_mut(&lane).unwrap().jobs.push(job);
+        self.len += 1;
+    }
+
+    fn pop(&mut self) -> Option<QueuedEmit> {
+        let top = self.lanes.values().filter_map(|lane| lane.jobs.peek()).map(|job| job.priority).max()?;
+        // Sırası en yakın olan ve en yüksek öncelikte işi bekleyen grup
+        let position = self
+            .turn
+            .iter()
+            .position(|name| self.lanes[name].jobs.peek().is_some_and(|job| job.priority == top))?;
+        let name = self.turn[position].clone();
+        let lane = self.lanes.get_mut(&name).unwrap();
+        let job = lane.jobs.pop();
+        self.len -= 1;
+        lane.credit -= 1;
+        // Tek grup (`Fairness::Off`) ayrılan yeriyle birlikte tutulur
+        if lane.jobs.is_empty() && self.fairness != Fairness::Off {
+            self.lanes.remove(&name);
+            self.turn.remove(position);
+        } else if lane.credit == 0 {
+            lane.credit = self.fairness.weight(&name);
+            self.turn.remove(position);
+            self.turn.push_back(name);
+        }
+        job
+    }
+
+    fn clear(&mut self) -> usize {
+        self.lanes.values_mut().for_each(|lane| lane.jobs.clear());
+        if self.fairness != Fairness::Off {
+            self.lanes.clear();
+            self.turn.clear();
+        }
+        std::mem::take(&mut self.len)
+    }
+}
+
+pub(crate) struct EventQueue {
+    heap: StdMutex<Lanes>,
+    notify: Notify,
+    seq: AtomicU64,
+    closed: AtomicBool,
+    // Kuyruktan alınmış, çalışması bitmemiş emit'ler
+    running: AtomicUsize,
+    settled: Notify,
+}
+
+impl EventQueue {
+    pub(crate) fn new(capacity: usize, fairness: Fairness) -> Self {
+        let mut lanes = Lanes { fairness, lanes: HashMap::new(), turn: VecDeque::new(), len: 0 };
+        if lanes.fairness == Fairness::Off {
+            let lane = Lane { jobs: BinaryHeap::with_capacity(capacity), credit: 1 };
+            lanes.lanes.insert(String::new(), lane);
+            lanes.turn.push_back(String::new());
+        }
+        Self {
+            heap: StdMutex::new(lanes),
+            notify: Notify::new(),
+            seq: AtomicU64::new(0),
+            closed: AtomicBool::new(false),
+            running: AtomicUsize::new(0),
+            settled: Notify::new(),
+        }
+    }
+
+    /// `lane` emit'i yapan handler'ın tag'idir (bkz. `Fairness`).
+    pub(crate) fn push(
+        &self,
+        lane: Option<String>,
+        priority: Priority,
+        context: Context,
+        plan: DispatchPlan,
+        payload: Arc<dyn RuntimeEventListenerHandlerArg>,
+    ) {
+        let seq = self.seq.fetch_add(1, AtomicOrdering::Relaxed);
+        let job = QueuedEmit {
+            priority,
+            seq,
+            context,
+            plan,
+            payload,
+        };
+        self.heap.lock().unwrap().push(lane, job);
+        self.notify.notify_one();
+    }
+
+    /// Alınan emit çalıştırıldıktan sonra `finish` çağrılmalıdır.
+    pub(crate) fn pop(&self) -> Option<QueuedEmit> {
+        let job = self.heap.lock().unwrap().pop()?;
+        self.running.fetch_add(1, AtomicOrdering::AcqRel);
+        Some(job)
+    }
+
+    pub(crate) fn finish(&self) {
+        self.running.fetch_sub(1, AtomicOrdering::AcqRel);
+        self.settled.notify_waiters();
+    }
+
+    /// Kuyruk boşalana ve alınmış emit'lerin çalışması bitene kadar bekler.
+    pub(crate) async fn settled(&self) {
+        loop {
+            let notified = self.settled.notified();
+            let idle = self.running.load(AtomicOrdering::Acquire) == 0;
+            if idle && self.len() == 0 {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Kuyrukta iş olana kadar bekler. Kuyruk kapatıldığında `None` döner.
+    pub(crate) async fn next(&self) -> Option<QueuedEmit> {
+        loop {
+            // Bildirim kaçmasın diye future kontrollerden önce oluşturulur
+            let notified = self.notify.notified();
+            if self.closed.load(AtomicOrdering::Acquire) {
+                return None;
+            }
+            if let Some(job) = self.pop() {
+                return Some(job);
+            }
+            notified.await;
+        }
+    }
+
+    /// Worker'ları durdurur ve bekleyen emit'leri bırakır. Bırakılan emit sayısını döner.
+    pub(crate) fn close(&self) -> usize {
+        self.closed.store(true, AtomicOrdering::Release);
+        self.notify.notify_waiters();
+        self.heap.lock().unwrap().clear()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.heap.lock().unwrap().len
+    }
+}
+
+impl QueuedEmit {
+    pub(crate) async fn run(self) {
+        // Emit anındaki context worker üzerinde geri yüklenir
+        context::scope(self.context, self.plan.execute(&self.payload)).await;
+    }
+}
+
+/// Kuyruğu tüketen dispatcher worker'larını başlatır (varsayılan olarak mevcut tokio runtime'ında).
+pub(crate) fn spawn_workers(queue: &Arc<EventQueue>, workers: usize) {
+    for _ in 0..workers.max(1) {
+        let queue = Arc::clone(queue);
+        crate::rt::spawn(async move {
+            while let Some(job) = queue.next().await {
+                job.run().await;
+                queue.finish();
+            }
+        });
+    }
+}
+
+/// Çağrı anında kuyrukta olan emit'leri öncelik sırasıyla çalıştırır.
+/// Handler'ların bu sırada yaptığı emit'ler bir sonraki drain'e kalır.
+pub(crate) async fn drain(queue: &EventQueue) -> usize {
+    let pending = queue.len();
+    let mut processed = 0;
+    while processed < pending {
+        let Some(job) = queue.pop() else { break };
+        job.run().await;
+        queue.finish();
+        processed += 1;
+    }
+    processed
+}
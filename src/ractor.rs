@@ -0,0 +1,121 @@
+use futures::future::BoxFuture;
+use std::{marker::PhantomData, sync::Arc, time::Duration};
+
+use tokio::sync::oneshot;
+
+use crate::actor::{ActorSubscription, AskMailbox, Mailbox, subscribe_actor, subscribe_actor_replies};
+use crate::clock;
+use crate::error::Result;
+use crate::event_bus::RuntimeEvent;
+use crate::log::Level;
+
+/// ractor `ActorRef<M>`'in rumt'un kullandığı kısmı. rumt `ractor` crate'ine bağlı değildir;
+/// uygulama bu trait'i kendi aktörünün referansı için birkaç satırla uygular:
+///
+/// ```rust,ignore
+/// struct Pricing(ractor::ActorRef<PricingMsg>);
+///
+/// impl RactorRef<PricingMsg> for Pricing {
+///     fn send_message(&self, message: PricingMsg) -> Result<(), String> {
+///         self.0.send_message(message).map_err(|e| e.to_string())
+///     }
+/// }
+/// ```
+pub trait RactorRef<M>: Send + Sync + 'static {
+    /// `ActorRef::send_message` (`cast`); hata `MessagingErr`'in metnidir.
+    fn send_message(&self, message: M) -> std::result::Result<(), String>;
+}
+
+struct Cast<A>(A);
+
+impl<M, A: RactorRef<M>> Mailbox<M> for Cast<A> {
+    fn deliver(&self, message: M) -> BoxFuture<'static, ()> {
+        let sent = self.0.send_message(message);
+        Box::pin(async move {
+            if let Err(e) = sent {
+                crate::log(Level::Warn, "rumt::ractor", format!("cannot send to actor: {e}")).await;
+            }
+        })
+    }
+}
+
+// ractor `call`: mesaj cevap kanalıyla kurulur ve cevap süre sınırıyla beklenir
+struct Call<A, M, R, B> {
+    actor: A,
+    build: B,
+    timeout: Duration,
+    _message: PhantomData<fn() -> (M, R)>,
+}
+
+impl<T, M, R, A, B> AskMailbox<Arc<T>, R> for Call<A, M, R, B>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    R: Send + 'static,
+    A: RactorRef<M>,
+    B: Fn(Arc<T>, oneshot::Sender<R>) -> M + Send + Sync + 'static,
+{
+    fn ask(&self, payload: Arc<T>) -> BoxFuture<'static, Option<R>> {
+        let (reply, replied) = oneshot::channel();
+        let sent = self.actor.send_message((self.build)(payload, reply));
+        let timeout = self.timeout;
+        Box::pin(async move {
+            let failure = match sent {
+                Ok(()) => match clock::timeout(timeout, replied).await {
+                    Some(Ok(reply)) => return Some(reply),
+                    Some(Err(_)) => "actor dropped the reply port".to_string(),
+                    None => format!("actor did not reply within {timeout:?}"),
+                },
+                Err(e) => format!("cannot send to actor: {e}"),
+            };
+            crate::log(Level::Warn, "rumt::ractor", failure).await;
+            None
+        })
+    }
+}
+
+/// `event` payload'larını `map` ile mesaja çevirip aktöre `send_message` ile iletir. Runtime
+/// başlatılmamışsa `Error::NotInitialized` döner.
+pub async fn subscribe<T, M, F>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    actor: impl RactorRef<M>,
+    map: F,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    F: Fn(Arc<T>) -> M + Send + Sync + 'static,
+{
+    subscribe_actor(tag, event, Cast(actor), map).await
+}
+
+/// ractor `call` gibi: `build` payload'dan ve cevap kanalından mesajı kurar (ractor'da kanal
+/// `RpcReplyPort::from(sender)` ile port'a çevrilir). Cevap `timeout` içinde gelirse
+/// `replies` event'i olarak yayınlanır; gelmezse `rumt::ractor` hedefiyle loglanır. Süre
+/// `rumt::clock` ile ölçülür.
+///
+/// ```rust,ignore
+/// rumt::ractor::subscribe_calls("pricing", QUOTE_REQUESTED, PRICE_QUOTED, Pricing(actor), |req: Arc<QuoteRequested>, reply| {
+///     PricingMsg::Quote { sku: req.sku.clone(), reply: reply.into() }
+/// }, Duration::from_secs(2))
+/// .await?;
+/// ```
+pub async fn subscribe_calls<T, M, R, A, B>(
+    tag: impl Into<String>,
+    event: RuntimeEvent,
+    replies: RuntimeEvent,
+    actor: A,
+    build: B,
+    timeout: Duration,
+) -> Result<ActorSubscription>
+where
+    T: Send + Sync + 'static,
+    M: 'static,
+    R: Send + Sync + 'static,
+    A: RactorRef<M>,
+    B: Fn(Arc<T>, oneshot::Sender<R>) -> M + Send + Sync + 'static,
+{
+    let call = Call { actor, build, timeout, _message: PhantomData };
+    subscribe_actor_replies(tag, event, replies, call, |payload: Arc<T>| payload).await
+}
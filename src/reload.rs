@@ -0,0 +1,127 @@
+use std::{
+    collections::{BTreeMap, HashMap},
+    sync::Arc,
+};
+
+use crate::env::RuntimeModuleEnv;
+use crate::error::{Error, Result};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListener};
+use crate::global::{emit_internal, try_runtime_env};
+use crate::log::Level;
+use crate::state::Locked;
+
+/// Yayınlandığında env'i yeniden yükleyen kontrol event'i. Payload'ı önemsizdir; transport
+/// üzerinden `Utf8Codec` ile (ör. gerekçe metni taşıyarak) yönlendirilebilir.
+pub const CONTROL_RELOAD: &str = "rumt.control.reload";
+/// Reload env'de bir değeri değiştirdiğinde yayınlanır; payload'ı `ConfigChanged`.
+pub const CONFIG_CHANGED: &str = "rumt.config.changed";
+
+/// Env'in çalışma anında yeniden yüklenebilen kısmı.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigValues {
+    pub flags: HashMap<String, bool>,
+    pub paths: HashMap<String, String>,
+}
+
+/// Değerleri kaynağından (dosya, ortam değişkenleri, uzak servis...) okuyan fonksiyon.
+pub type ConfigLoader = Arc<dyn Fn() -> std::result::Result<ConfigValues, String> + Send + Sync>;
+/// Yüklenen değerlerin env'e uygulanmadan önceki hâlini kontrol eden fonksiyon.
+pub type ConfigValidator = Arc<dyn Fn(&ConfigValues) -> std::result::Result<(), String> + Send + Sync>;
+
+/// `rumt.config.changed` payload'ı: reload ile değişen (veya eklenen) değerler.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConfigChanged {
+    pub flags: BTreeMap<String, bool>,
+    pub paths: BTreeMap<String, String>,
+}
+
+impl ConfigChanged {
+    pub fn is_empty(&self) -> bool {
+        self.flags.is_empty() && self.paths.is_empty()
+    }
+}
+
+/// Env'i yükleyicisiyle yeniden okur, doğrulayıcıları çalıştırır ve değişen değerleri uygular.
+/// Değişen flag'lere bağlı (`enabled_if`) dinleyiciler bağlanır veya ayrılır, ardından
+/// `rumt.config.changed` yayınlanır. Yükleme veya doğrulama başarısızsa env değişmez ve
+/// `Error::Config` döner. `rumt.control.reload` event'i de bu fonksiyonu çağırır.
+///
+/// ```rust,ignore
+/// let env = RuntimeModuleEnv::new()
+///     .add_app_info("Pricing", "MyCompany", "com")
+///     .config_loader(|| read_settings("/etc/pricing.toml"))
+///     .validate_config(|values| match values.paths.contains_key("feed") {
+///         true => Ok(()),
+///         false => Err("`feed` path is required".into()),
+///     })
+///     .try_lock_env()?;
+///
+/// // Operatör: yerelden veya bir transport üzerinden
+/// rumt::emit_event(RuntimeEvent::Static { event_name: CONTROL_RELOAD.into() }, String::from("rotated feed")).await;
+/// ```
+pub async fn reload_config() -> Result<ConfigChanged> {
+    let reloaded = {
+        let mut guard = try_runtime_env()?;
+        let env = guard.as_mut().ok_or(Error::NotInitialized)?;
+        load(env).map(|values| apply(env, values))
+    };
+    let changed = match reloaded {
+        Ok(changed) => changed,
+        Err(e) => {
+            crate::log(Level::Warn, "rumt::config", format!("reload rejected: {e}")).await;
+            return Err(e);
+        }
+    };
+    let flags: Vec<(String, bool)> = changed.flags.iter().map(|(name, enabled)| (name.clone(), *enabled)).collect();
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.set_flags(flags)).await?;
+    if !changed.is_empty() {
+        emit_internal(RuntimeEvent::Static { event_name: CONFIG_CHANGED.into() }, changed.clone()).await;
+    }
+    Ok(changed)
+}
+
+/// Env'in mevcut değerleri üzerine yükleyicinin döndürdüklerini yazar ve sonucu doğrular.
+/// Yükleyicinin döndürmediği anahtarlar (ör. `set_flag` ile değiştirilenler) korunur.
+pub(crate) fn load(env: &RuntimeModuleEnv<Locked>) -> Result<ConfigValues> {
+    let mut values = ConfigValues { flags: env.flags.clone(), paths: env.paths.clone() };
+    if let Some(loader) = &env.config_loader {
+        let loaded = loader().map_err(Error::Config)?;
+        values.flags.extend(loaded.flags);
+        values.paths.extend(loaded.paths);
+    }
+    for validator in &env.validators {
+        validator(&values).map_err(Error::Config)?;
+    }
+    Ok(values)
+}
+
+/// Değerleri env'e yazar ve farkı döner.
+pub(crate) fn apply(env: &mut RuntimeModuleEnv<Locked>, values: ConfigValues) -> ConfigChanged {
+    let mut changed = ConfigChanged::default();
+    for (name, enabled) in values.flags {
+        if env.flags.insert(name.clone(), enabled) != Some(enabled) {
+            changed.flags.insert(name, enabled);
+        }
+    }
+    for (name, path) in values.paths {
+        if env.paths.get(&name) != Some(&path) {
+            env.paths.insert(name.clone(), path.clone());
+            changed.paths.insert(name, path);
+        }
+    }
+    changed
+}
+
+/// `rumt.control.reload`'u dinleyen yerleşik listener; env'de yükleyici varsa bağlanır.
+pub(crate) fn control_listener() -> (RuntimeEvent, RuntimeEventListener) {
+    let listener = RuntimeEventListener::new(
+        "rumt",
+        Box::new(|_| {
+            // Hata `reload_config` içinde loglanır
+            Box::pin(async {
+                let _ = reload_config().await;
+            })
+        }),
+    );
+    (RuntimeEvent::Static { event_name: CONTROL_RELOAD.into() }, listener)
+}
@@ -0,0 +1,76 @@
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex as StdMutex},
+};
+
+use crate::context::{self, Context};
+use crate::dispatch::DispatchPlan;
+use crate::event_bus::{RuntimeEventListener, RuntimeEventListenerHandlerArg};
+
+/// Tampondaki bir payload ve yayınlandığı tenant.
+pub(crate) type Retained = (Option<String>, Arc<dyn RuntimeEventListenerHandlerArg>);
+
+/// Bir event'in son `capacity` payload'ını bellekte tutan halka tampon.
+/// Payload'lar dispatch'teki gibi `Arc<T>` sarmalayan `Arc<dyn Arg>` olarak saklanır.
+pub(crate) struct ReplayBuffer {
+    capacity: usize,
+    items: StdMutex<VecDeque<Retained>>,
+}
+
+impl ReplayBuffer {
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            items: StdMutex::new(VecDeque::with_capacity(capacity)),
+        }
+    }
+
+    pub(crate) fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.items.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub(crate) fn push(&self, tenant: Option<String>, payload: Arc<dyn RuntimeEventListenerHandlerArg>) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+        if items.len() == self.capacity {
+            items.pop_front();
+        }
+        items.push_back((tenant, payload));
+    }
+
+    /// En eski olandan başlayarak dinleyicinin alabileceği son `count` payload.
+    pub(crate) fn last(&self, count: usize, listener: &RuntimeEventListener) -> Vec<Retained> {
+        let items = self.items.lock().unwrap_or_else(|e| e.into_inner());
+        let mut last: Vec<Retained> = items
+            .iter()
+            .rev()
+            .filter(|(tenant, _)| listener.accepts(tenant.as_deref()))
+            .take(count)
+            .cloned()
+            .collect();
+        last.reverse();
+        last
+    }
+}
+
+/// Yeni bağlanan bir dinleyiciye, kilit bırakıldıktan sonra verilecek geçmiş payload'lar.
+pub(crate) struct Replay {
+    pub(crate) plan: DispatchPlan,
+    pub(crate) payloads: Vec<Retained>,
+}
+
+impl Replay {
+    pub(crate) async fn run(self) {
+        for (tenant, payload) in self.payloads {
+            let mut context = Context::next();
+            context.tenant = tenant;
+            context::scope(context, self.plan.execute(&payload)).await;
+        }
+    }
+}
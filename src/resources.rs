@@ -0,0 +1,83 @@
+use std::{
+    any::{Any, TypeId, type_name},
+    collections::HashMap,
+    sync::{Arc, RwLock},
+};
+
+use crate::error::{Error, Result};
+
+/// Runtime'a ait paylaşılan canlı nesneler (DB havuzları, HTTP istemcileri vb.) için tip haritası.
+/// Env yapılandırmayı taşır, `Resources` ise çalışan nesneleri. Her tipten tek bir değer tutulur.
+///
+/// Handler'lar bağımlılıklarını constructor'da almak yerine çalışma anında çekebilir:
+///
+/// ```rust
+/// # struct PgPool;
+/// rumt::resources().insert(PgPool);
+/// let pool = rumt::resources().get::<PgPool>().expect("PgPool kayıtlı değil");
+/// ```
+#[derive(Default)]
+pub struct Resources {
+    entries: RwLock<HashMap<TypeId, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl Resources {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Değeri ekler; aynı tipte önceki bir değer varsa onu döner.
+    pub fn insert<T: Send + Sync + 'static>(&self, value: T) -> Option<Arc<T>> {
+        self.insert_arc(Arc::new(value))
+    }
+
+    /// Zaten `Arc` içinde tutulan bir değeri kopyalamadan ekler.
+    pub fn insert_arc<T: Send + Sync + 'static>(&self, value: Arc<T>) -> Option<Arc<T>> {
+        self.write()
+            .insert(TypeId::of::<T>(), value)
+            .and_then(|previous| previous.downcast::<T>().ok())
+    }
+
+    pub fn get<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        let entries = self.read();
+        let entry = entries.get(&TypeId::of::<T>())?;
+        Arc::clone(entry).downcast::<T>().ok()
+    }
+
+    /// `get`'in hata dönen hâli; kayıt yoksa `Error::MissingResource` döner.
+    pub fn try_get<T: Send + Sync + 'static>(&self) -> Result<Arc<T>> {
+        self.get::<T>()
+            .ok_or(Error::MissingResource(type_name::<T>()))
+    }
+
+    pub fn contains<T: Send + Sync + 'static>(&self) -> bool {
+        self.read().contains_key(&TypeId::of::<T>())
+    }
+
+    pub fn remove<T: Send + Sync + 'static>(&self) -> Option<Arc<T>> {
+        self.write()
+            .remove(&TypeId::of::<T>())
+            .and_then(|previous| previous.downcast::<T>().ok())
+    }
+
+    pub fn len(&self) -> usize {
+        self.read().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.read().is_empty()
+    }
+
+    pub(crate) fn clear(&self) {
+        self.write().clear();
+    }
+
+    // Harita yalnızca tek adımlık işlemlerle değiştiği için zehirlenmiş kilit hâlâ tutarlıdır
+    fn read(&self) -> std::sync::RwLockReadGuard<'_, HashMap<TypeId, Arc<dyn Any + Send + Sync>>> {
+        self.entries.read().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+
+    fn write(&self) -> std::sync::RwLockWriteGuard<'_, HashMap<TypeId, Arc<dyn Any + Send + Sync>>> {
+        self.entries.write().unwrap_or_else(std::sync::PoisonError::into_inner)
+    }
+}
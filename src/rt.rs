@@ -0,0 +1,130 @@
+use once_cell::sync::{Lazy, OnceCell};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use tokio::sync::Notify;
+
+/// Handler'ların ve handler çalıştıran rumt future'larının tipi: `BoxFuture`. Tarayıcıda
+/// (wasm32 hedefi ve `wasm` feature) tek thread olduğundan `LocalBoxFuture`'dır; handler'lar
+/// `!Send` JS değerlerini `await` boyunca tutabilir.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub type MaybeSendFuture<'a, T> = futures::future::BoxFuture<'a, T>;
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub type MaybeSendFuture<'a, T> = futures::future::LocalBoxFuture<'a, T>;
+
+/// Tarayıcıda her tipin, diğer hedeflerde `Send` tiplerin uyguladığı işaret; servisler ve
+/// handler closure'ları bununla sınırlanır. Payload'lar her hedefte `Send + Sync` kalır.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub trait MaybeSend: Send {}
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+impl<T: Send + ?Sized> MaybeSend for T {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub trait MaybeSend {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl<T: ?Sized> MaybeSend for T {}
+
+/// `MaybeSend` gibi, `Sync` için.
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+pub trait MaybeSync: Sync {}
+#[cfg(not(all(feature = "wasm", target_arch = "wasm32")))]
+impl<T: Sync + ?Sized> MaybeSync for T {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+pub trait MaybeSync {}
+#[cfg(all(feature = "wasm", target_arch = "wasm32"))]
+impl<T: ?Sized> MaybeSync for T {}
+
+/// rumt'un kendi başlattığı task'ları (kuyruk worker'ları vb.) çalıştıran fonksiyon.
+pub type Spawner = Arc<dyn Fn(MaybeSendFuture<'static, ()>) + Send + Sync>;
+
+static SPAWNER: OnceCell<Spawner> = OnceCell::new();
+
+// `spawn_tracked` ile başlatılıp bitmemiş task'lar
+static TRACKED: AtomicUsize = AtomicUsize::new(0);
+static SETTLED: Lazy<Notify> = Lazy::new(Notify::new);
+
+tokio::task_local! {
+    static LABEL: Arc<str>;
+}
+
+/// Varsayılan `tokio::spawn` yerine kullanılacak spawner'ı ayarlar. Yalnızca bir kez ayarlanabilir,
+/// sonraki çağrılar `false` döner.
+///
+/// Tarayıcıda (`wasm32` hedefi) tokio runtime'ı olmadığından bu çağrı `init_runtime`'dan önce
+/// yapılmalıdır:
+///
+/// ```rust,ignore
+/// rumt::rt::set_spawner(|task| wasm_bindgen_futures::spawn_local(task));
+/// ```
+///
+/// `Sequential` ve `Deferred` dispatch modları spawner'a ihtiyaç duymaz.
+pub fn set_spawner(spawner: impl Fn(MaybeSendFuture<'static, ()>) + Send + Sync + 'static) -> bool {
+    SPAWNER.set(Arc::new(spawner)).is_ok()
+}
+
+pub(crate) fn spawn(task: impl std::future::Future<Output = ()> + MaybeSend + 'static) {
+    if let Some(spawner) = SPAWNER.get() {
+        spawner(Box::pin(task));
+        return;
+    }
+
+    // Native hedeflerde `wasm` feature açık olsa da tokio runtime'ı kullanılabilir
+    #[cfg(target_arch = "wasm32")]
+    panic!("wasm32 requires a spawner: call rumt::rt::set_spawner before init_runtime");
+
+    #[cfg(not(target_arch = "wasm32"))]
+    tokio::spawn(task);
+}
+
+/// `spawn` gibi, ama task `shutdown_runtime` sırasında beklenir (bkz. `tracked_settled`).
+/// Emit'ten sonra arka planda süren işler (ör. webhook gönderimleri) bununla başlatılır.
+pub(crate) fn spawn_tracked(task: impl std::future::Future<Output = ()> + MaybeSend + 'static) {
+    TRACKED.fetch_add(1, Ordering::AcqRel);
+    let guard = Tracked;
+    spawn(async move {
+        let _guard = guard;
+        task.await;
+    });
+}
+
+// Task panik etse veya bırakılsa da sayaç düşer
+struct Tracked;
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        TRACKED.fetch_sub(1, Ordering::AcqRel);
+        SETTLED.notify_waiters();
+    }
+}
+
+/// `spawn_tracked` ile başlatılan tüm task'lar bitene kadar bekler.
+pub(crate) async fn tracked_settled() {
+    loop {
+        let notified = SETTLED.notified();
+        if TRACKED.load(Ordering::Acquire) == 0 {
+            return;
+        }
+        notified.await;
+    }
+}
+
+/// Handler içinden çağrıldığında çalışan handler'ın etiketi: `tag::handler_fn@event`
+/// (ör. `Billing::charge@billing.charge`); elle kurulan listener'larda handler adı yoksa
+/// `tag@event`. Handler'lar ayrı task'larda değil emit'i yapan task'ta (veya kuyruk
+/// worker'ında) çalıştığından etiket task adı yerine task-local olarak taşınır; takılan bir
+/// handler'ı log'larda ve teşhis dökümlerinde adıyla göstermek için kullanılır. Aynı etiket
+/// `HandlerSpan::label` ve `LogRecord::handler` ile de verilir.
+///
+/// ```rust,ignore
+/// let watchdog = rumt::rt::current_label().unwrap_or_else(|| "main".into());
+/// eprintln!("{watchdog} waiting for lock");
+/// ```
+pub fn current_label() -> Option<Arc<str>> {
+    LABEL.try_with(Arc::clone).ok()
+}
+
+pub(crate) async fn labeled<F: std::future::Future>(label: Arc<str>, f: F) -> F::Output {
+    LABEL.scope(label, f).await
+}
+
+pub(crate) fn labeled_sync<R>(label: Arc<str>, f: impl FnOnce() -> R) -> R {
+    LABEL.sync_scope(label, f)
+}
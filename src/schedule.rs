@@ -0,0 +1,171 @@
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::Duration;
+
+use crate::error::{Error, Result};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus};
+use crate::stats::StatsRecorder;
+use crate::ticker::{Interval, TickerStop, register_source};
+
+/// `emit_event_after` ile zamanlanan emit'i iptal etmeye yarar. Handle'ın düşürülmesi emit'i
+/// iptal etmez.
+pub struct EmitHandle {
+    job: Arc<DelayedEmit>,
+}
+
+struct DelayedEmit {
+    stop: TickerStop,
+    // Emit veya iptalden yalnızca biri gerçekleşir
+    settled: AtomicBool,
+    stats: Arc<StatsRecorder>,
+}
+
+impl DelayedEmit {
+    fn settle(&self) -> bool {
+        !self.settled.swap(true, Ordering::AcqRel)
+    }
+}
+
+impl EmitHandle {
+    /// Emit henüz yapılmadıysa iptal eder ve `true` döner; iptal `BusStats::cancelled`'a yansır.
+    pub fn cancel(&self) -> bool {
+        if !self.job.settle() {
+            return false;
+        }
+        self.job.stop.stop();
+        self.job.stats.record_cancelled();
+        true
+    }
+
+    /// Emit ne yapıldı ne de iptal edildi.
+    pub fn is_pending(&self) -> bool {
+        !self.job.settled.load(Ordering::Acquire)
+    }
+}
+
+/// Event'i `delay` sonra yayınlar; dönen handle ile o ana kadar iptal edilebilir (ör. "gönderimi
+/// geri al"). Süre runtime saatiyle ölçülür, bekleyen emit'ler `shutdown_runtime` ile bırakılır.
+/// Event adı `NamePolicy`'ye uymuyorsa zamanlanmaz.
+///
+/// ```rust,ignore
+/// let send = rumt::emit_event_after(MAIL_SEND, mail, Duration::from_secs(10)).await?;
+/// // Kullanıcı "geri al"a bastı
+/// send.cancel();
+/// ```
+pub async fn emit_event_after<T: Send + Sync + 'static>(event: RuntimeEvent, arg: T, delay: Duration) -> Result<EmitHandle> {
+    let stats = RuntimeEventBus::try_with_instance_mut(|bus| {
+        bus.check_emit(&event)?;
+        Ok::<_, Error>(Arc::clone(&bus.stats))
+    })
+    .await??;
+    let job = Arc::new(DelayedEmit { stop: TickerStop::new(), settled: AtomicBool::new(false), stats });
+    let pending = Arc::clone(&job);
+    crate::rt::spawn(async move {
+        if pending.stop.sleep(delay).await && pending.settle() {
+            let _ = crate::try_emit_event(event, arg).await;
+        }
+    });
+    Ok(EmitHandle { job })
+}
+
+/// Runtime'a ait, adlandırılmış zamanlanmış emit'ler. İşler ticker'lar gibi kaynak olarak
+/// kaydedilir (topolojide görünür, `shutdown_runtime` ile durur); aynı adla yeniden zamanlama
+/// öncekinin yerini alır.
+///
+/// ```rust,ignore
+/// rumt::scheduler().every("nightly-cleanup", Duration::from_secs(86_400), CLEANUP, Cleanup::default()).await?;
+/// rumt::scheduler().cancel("nightly-cleanup").await;
+/// ```
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Scheduler;
+
+/// Bkz. `Scheduler`.
+pub fn scheduler() -> Scheduler {
+    Scheduler
+}
+
+impl Scheduler {
+    /// Her `period` sürede payload'ın bir kopyasını yayınlar; ilk emit bir periyot sonradır.
+    pub async fn every<T: Clone + Send + Sync + 'static>(
+        &self,
+        name: impl Into<String>,
+        period: Duration,
+        event: RuntimeEvent,
+        payload: T,
+    ) -> Result<()> {
+        let stop = schedule(name.into(), &event).await?;
+        crate::rt::spawn(async move {
+            let mut interval = Interval::new(period);
+            while stop.tick(&mut interval).await {
+                if crate::try_emit_event(event.clone(), payload.clone()).await == Err(Error::NotInitialized) {
+                    break;
+                }
+            }
+        });
+        Ok(())
+    }
+
+    /// `delay` sonra bir kez yayınlar; emit'ten sonra iş listeden çıkar.
+    pub async fn after<T: Send + Sync + 'static>(
+        &self,
+        name: impl Into<String>,
+        delay: Duration,
+        event: RuntimeEvent,
+        payload: T,
+    ) -> Result<()> {
+        let name = name.into();
+        let stop = schedule(name.clone(), &event).await?;
+        crate::rt::spawn(async move {
+            if stop.sleep(delay).await && unschedule(&name, &stop).await {
+                let _ = crate::try_emit_event(event, payload).await;
+            }
+        });
+        Ok(())
+    }
+
+    /// İşi durdurur; bu adda zamanlanmış bir iş yoksa `false` döner. İptal `BusStats::cancelled`'a
+    /// yansır.
+    pub async fn cancel(&self, name: &str) -> bool {
+        RuntimeEventBus::try_with_instance_mut(|bus| {
+            if !bus.scheduled.remove(name) {
+                return false;
+            }
+            bus.source_events.remove(name);
+            if let Some(stop) = bus.sources.remove(name) {
+                stop.stop();
+            }
+            bus.stats.record_cancelled();
+            true
+        })
+        .await
+        .unwrap_or(false)
+    }
+
+    pub async fn is_scheduled(&self, name: &str) -> bool {
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.scheduled.contains(name)).await.unwrap_or(false)
+    }
+}
+
+async fn schedule(name: String, event: &RuntimeEvent) -> Result<Arc<TickerStop>> {
+    let stop = register_source(name.clone(), std::slice::from_ref(event)).await?;
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.scheduled.insert(name)).await?;
+    Ok(stop)
+}
+
+// Zamanı gelen tek seferlik işi listeden çıkarır; bu arada iptal edildiyse veya aynı adla
+// yeniden zamanlandıysa `false` döner
+async fn unschedule(name: &str, stop: &Arc<TickerStop>) -> bool {
+    RuntimeEventBus::try_with_instance_mut(|bus| {
+        if !bus.sources.get(name).is_some_and(|current| Arc::ptr_eq(current, stop)) {
+            return false;
+        }
+        bus.sources.remove(name);
+        bus.source_events.remove(name);
+        bus.scheduled.remove(name);
+        true
+    })
+    .await
+    .unwrap_or(false)
+}
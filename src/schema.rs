@@ -0,0 +1,363 @@
+use std::{collections::HashSet, fmt, fs, path::Path};
+
+use crate::event_bus::RuntimeEvent;
+use crate::json::{self, JsonValue};
+
+/// Derleme zamanında tanımlanan bir event adı. Servisler arasında event adlarını
+/// string olarak tekrar yazmak yerine tek bir sabit paylaşılır.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct EventName {
+    name: &'static str,
+    once: bool,
+    doc: &'static str,
+    payload: Option<&'static str>,
+    fields: &'static [(&'static str, &'static str)],
+}
+
+impl EventName {
+    /// `RuntimeEvent::Static` olarak yayınlanan event.
+    pub const fn new(name: &'static str) -> Self {
+        Self { name, once: false, doc: "", payload: None, fields: &[] }
+    }
+
+    /// `RuntimeEvent::OnceTriggered` olarak yayınlanan event.
+    pub const fn once(name: &'static str) -> Self {
+        Self { name, once: true, doc: "", payload: None, fields: &[] }
+    }
+
+    /// Event kataloğunda gösterilecek açıklama; `define_events!` bunu `///` yorumlarından alır.
+    pub const fn with_doc(mut self, doc: &'static str) -> Self {
+        self.doc = doc;
+        self
+    }
+
+    /// Payload tipinin adı ve alanları (`(ad, Rust tipi)`).
+    pub const fn with_payload(mut self, type_name: &'static str, fields: &'static [(&'static str, &'static str)]) -> Self {
+        self.payload = Some(type_name);
+        self.fields = fields;
+        self
+    }
+
+    pub const fn name(&self) -> &'static str {
+        self.name
+    }
+
+    pub const fn is_once(&self) -> bool {
+        self.once
+    }
+
+    pub const fn doc(&self) -> &'static str {
+        self.doc
+    }
+
+    pub const fn payload(&self) -> Option<&'static str> {
+        self.payload
+    }
+
+    pub const fn fields(&self) -> &'static [(&'static str, &'static str)] {
+        self.fields
+    }
+
+    pub fn event(&self) -> RuntimeEvent {
+        let event_name = self.name.to_string();
+        if self.once {
+            RuntimeEvent::OnceTriggered { event_name }
+        } else {
+            RuntimeEvent::Static { event_name }
+        }
+    }
+}
+
+impl From<EventName> for RuntimeEvent {
+    fn from(name: EventName) -> Self {
+        name.event()
+    }
+}
+
+/// Event sabitlerini ve payload tiplerini tek yerde tanımlar.
+///
+/// ```rust
+/// rumt::define_events! {
+///     /// Sipariş oluşturulduğunda yayınlanır.
+///     ORDER_CREATED = "order.created" => OrderCreated { order_id: u64, total: f64 },
+///     APP_READY = once "app.ready",
+/// }
+///
+/// assert_eq!(ORDER_CREATED.name(), "order.created");
+/// assert_eq!(ORDER_CREATED.payload(), Some("OrderCreated"));
+/// assert!(APP_READY.is_once());
+/// let payload = OrderCreated { order_id: 7, total: 12.5 };
+/// rumt::register_events(EVENTS);
+/// ```
+///
+/// `///` yorumları ve payload alanları sabite eklenir; çağrıdaki tüm sabitler ayrıca `EVENTS`
+/// listesinde toplanır (bu yüzden modül başına tek çağrı kullanılır). `register_events(EVENTS)`
+/// ile bunlar `describe_event` ve `event_catalog` tarafından görülür.
+///
+/// Aynı tanım bir JSON şemasından `rumt::schema::generate_module` ile de üretilebilir.
+#[macro_export]
+macro_rules! define_events {
+    ($($(#[$($meta:tt)*])* $name:ident = $($kind:ident)? $event:literal $(=> $payload:ident { $($field:ident : $ty:ty),* $(,)? })?),* $(,)?) => {
+        $(
+            $(#[$($meta)*])*
+            pub const $name: $crate::schema::EventName = $crate::define_events!(@name $($kind)? $event)
+                .with_doc($crate::define_events!(@doc [] $([$($meta)*])*))
+                $(.with_payload(stringify!($payload), &[$((stringify!($field), stringify!($ty))),*]))?;
+            $(
+                #[derive(Clone, Debug)]
+                pub struct $payload {
+                    $(pub $field: $ty,)*
+                }
+            )?
+        )*
+
+        /// Bu `define_events!` çağrısındaki tüm eventler; bkz. `rumt::register_events`.
+        pub const EVENTS: &[$crate::schema::EventName] = &[$($name),*];
+    };
+
+    (@name $event:literal) => { $crate::schema::EventName::new($event) };
+    (@name once $event:literal) => { $crate::schema::EventName::once($event) };
+
+    // `doc` dışındaki attribute'lar atlanır; satırlar `\n` ile birleştirilir
+    (@doc [$($doc:tt)*] [doc = $line:literal] $($rest:tt)*) => { $crate::define_events!(@doc [$($doc)* $line, "\n",] $($rest)*) };
+    (@doc [$($doc:tt)*] [$($other:tt)*] $($rest:tt)*) => { $crate::define_events!(@doc [$($doc)*] $($rest)*) };
+    (@doc [$($doc:tt)*]) => { concat!($($doc)*) };
+}
+
+/// Şema okunurken veya doğrulanırken oluşan hata.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SchemaError {
+    pub message: String,
+}
+
+impl SchemaError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            message: message.into(),
+        }
+    }
+}
+
+impl fmt::Display for SchemaError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "event schema error: {}", self.message)
+    }
+}
+
+impl std::error::Error for SchemaError {}
+
+/// JSON şemasından `define_events!` çağrısı içeren bir Rust modülü üretir.
+///
+/// Şema biçimi:
+///
+/// ```json
+/// {
+///   "events": [
+///     { "name": "order.created", "doc": "Sipariş oluşturuldu",
+///       "payload": { "type": "OrderCreated", "fields": { "order_id": "u64" } } },
+///     { "name": "app.ready", "const": "APP_READY", "once": true }
+///   ]
+/// }
+/// ```
+///
+/// `const` verilmezse event adından türetilir (`order.created` → `ORDER_CREATED`).
+pub fn generate_module(schema: &str) -> Result<String, SchemaError> {
+    let root = json::parse(schema).map_err(|e| SchemaError::new(e.to_string()))?;
+    let events = root
+        .get("events")
+        .and_then(JsonValue::as_array)
+        .ok_or_else(|| SchemaError::new("`events` array is missing"))?;
+
+    let mut out = String::from("// rumt::schema tarafından üretilmiştir, elle düzenlemeyin.\n\nrumt::define_events! {\n");
+    let mut constants = HashSet::new();
+    for (index, event) in events.iter().enumerate() {
+        let field = |key: &str| event.get(key);
+        let name = field("name")
+            .and_then(JsonValue::as_str)
+            .ok_or_else(|| SchemaError::new(format!("events[{index}]: `name` is missing")))?;
+        let constant = match field("const") {
+            Some(value) => value
+                .as_str()
+                .ok_or_else(|| SchemaError::new(format!("`{name}`: `const` must be a string")))?
+                .to_string(),
+            None => constant_name(name),
+        };
+        check_ident(&constant, name)?;
+        if !constants.insert(constant.clone()) {
+            return Err(SchemaError::new(format!("`{name}`: duplicate constant `{constant}`")));
+        }
+        let once = field("once").and_then(JsonValue::as_bool).unwrap_or(false);
+
+        if let Some(doc) = field("doc").and_then(JsonValue::as_str) {
+            for line in doc.lines() {
+                out.push_str(&format!("    /// {line}\n"));
+            }
+        }
+        out.push_str(&format!(
+            "    {constant} = {}{name:?}",
+            if once { "once " } else { "" }
+        ));
+
+        if let Some(payload) = field("payload") {
+            let type_name = payload
+                .get("type")
+                .and_then(JsonValue::as_str)
+                .ok_or_else(|| SchemaError::new(format!("`{name}`: payload `type` is missing")))?;
+            check_ident(type_name, name)?;
+            let fields = match payload.get("fields") {
+                Some(fields) => fields
+                    .as_object()
+                    .ok_or_else(|| SchemaError::new(format!("`{name}`: payload `fields` must be an object")))?,
+                None => &[],
+            };
+            out.push_str(&format!(" => {type_name} {{"));
+            for (i, (field_name, ty)) in fields.iter().enumerate() {
+                check_ident(field_name, name)?;
+                let ty = ty
+                    .as_str()
+                    .filter(|ty| !ty.trim().is_empty())
+                    .ok_or_else(|| SchemaError::new(format!("`{name}`: type of `{field_name}` must be a string")))?;
+                out.push_str(if i == 0 { " " } else { ", " });
+                out.push_str(&format!("{field_name}: {ty}"));
+            }
+            out.push_str(if fields.is_empty() { "}" } else { " }" });
+        }
+        out.push_str(",\n");
+    }
+    out.push_str("}\n");
+    Ok(out)
+}
+
+/// Build script yardımcısı: şema dosyasını okur, modülü `out` yoluna yazar ve şema
+/// değiştiğinde yeniden derleme için cargo'ya bildirir.
+///
+/// ```rust,ignore
+/// // build.rs
+/// let out = std::path::Path::new(&std::env::var("OUT_DIR").unwrap()).join("events.rs");
+/// rumt::schema::generate_file("events.json", out).unwrap();
+///
+/// // src/lib.rs
+/// pub mod events { include!(concat!(env!("OUT_DIR"), "/events.rs")); }
+/// ```
+pub fn generate_file(schema: impl AsRef<Path>, out: impl AsRef<Path>) -> Result<(), SchemaError> {
+    let schema = schema.as_ref();
+    let source = fs::read_to_string(schema)
+        .map_err(|e| SchemaError::new(format!("{}: {e}", schema.display())))?;
+    let module = generate_module(&source)?;
+    fs::write(out.as_ref(), module)
+        .map_err(|e| SchemaError::new(format!("{}: {e}", out.as_ref().display())))?;
+    println!("cargo:rerun-if-changed={}", schema.display());
+    Ok(())
+}
+
+/// Dışarıdan gelen JSON payload'larının beklenen alanları. Alan tipleri şema dosyasındaki
+/// Rust tip adlarıdır: tamsayı tipleri, `f32`/`f64`, `bool`, `String`, `Option<..>` ve `Vec<..>`.
+/// Bilinmeyen tipler (ör. iç içe struct'lar) yalnızca alanın varlığı açısından denetlenir.
+///
+/// ```rust
+/// # use rumt::schema::PayloadSchema;
+/// let schema = PayloadSchema::new().field("order_id", "u64").field("note", "Option<String>");
+/// let payload = rumt::json::parse(r#"{ "order_id": 7 }"#).unwrap();
+/// assert!(schema.validate(&payload).is_ok());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct PayloadSchema {
+    fields: Vec<(String, String)>,
+}
+
+impl PayloadSchema {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn field(mut self, name: impl Into<String>, ty: impl Into<String>) -> Self {
+        self.fields.push((name.into(), ty.into()));
+        self
+    }
+
+    /// `generate_module` ile aynı biçimdeki şemadan `event_name` event'inin payload alanlarını okur.
+    pub fn from_schema(schema: &str, event_name: &str) -> Result<Self, SchemaError> {
+        let root = json::parse(schema).map_err(|e| SchemaError::new(e.to_string()))?;
+        let event = root
+            .get("events")
+            .and_then(JsonValue::as_array)
+            .ok_or_else(|| SchemaError::new("`events` array is missing"))?
+            .iter()
+            .find(|event| event.get("name").and_then(JsonValue::as_str) == Some(event_name))
+            .ok_or_else(|| SchemaError::new(format!("`{event_name}` is not defined")))?;
+        let fields = match event.get("payload").and_then(|payload| payload.get("fields")) {
+            Some(fields) => fields
+                .as_object()
+                .ok_or_else(|| SchemaError::new(format!("`{event_name}`: payload `fields` must be an object")))?,
+            None => &[],
+        };
+        fields.iter().try_fold(Self::new(), |schema, (name, ty)| {
+            let ty = ty
+                .as_str()
+                .ok_or_else(|| SchemaError::new(format!("`{event_name}`: type of `{name}` must be a string")))?;
+            Ok(schema.field(name.clone(), ty))
+        })
+    }
+
+    /// `value` bir nesne olmalı ve tanımlı her alan tipine uymalıdır; fazla alanlar yok sayılır.
+    pub fn validate(&self, value: &JsonValue) -> Result<(), SchemaError> {
+        if value.as_object().is_none() {
+            return Err(SchemaError::new("payload must be an object"));
+        }
+        for (name, ty) in &self.fields {
+            let field = value.get(name).unwrap_or(&JsonValue::Null);
+            if !matches_type(field, ty.trim()) {
+                return Err(SchemaError::new(format!("field `{name}` must be `{ty}`")));
+            }
+        }
+        Ok(())
+    }
+}
+
+fn matches_type(value: &JsonValue, ty: &str) -> bool {
+    if let Some(inner) = ty.strip_prefix("Option<").and_then(|ty| ty.strip_suffix('>')) {
+        return *value == JsonValue::Null || matches_type(value, inner.trim());
+    }
+    if let Some(inner) = ty.strip_prefix("Vec<").and_then(|ty| ty.strip_suffix('>')) {
+        return value.as_array().is_some_and(|items| items.iter().all(|item| matches_type(item, inner.trim())));
+    }
+    let integer = |min: f64, max: f64| value.as_f64().is_some_and(|n| n.fract() == 0.0 && (min..=max).contains(&n));
+    match ty {
+        "bool" => value.as_bool().is_some(),
+        "String" => value.as_str().is_some(),
+        "f32" | "f64" => value.as_f64().is_some(),
+        "u8" => integer(0.0, u8::MAX as f64),
+        "u16" => integer(0.0, u16::MAX as f64),
+        "u32" => integer(0.0, u32::MAX as f64),
+        "u64" | "u128" | "usize" => integer(0.0, f64::MAX),
+        "i8" => integer(i8::MIN as f64, i8::MAX as f64),
+        "i16" => integer(i16::MIN as f64, i16::MAX as f64),
+        "i32" => integer(i32::MIN as f64, i32::MAX as f64),
+        "i64" | "i128" | "isize" => integer(f64::MIN, f64::MAX),
+        _ => *value != JsonValue::Null,
+    }
+}
+
+fn constant_name(event_name: &str) -> String {
+    let mut constant: String = event_name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c.to_ascii_uppercase() } else { '_' })
+        .collect();
+    if constant.starts_with(|c: char| c.is_ascii_digit()) {
+        constant.insert(0, '_');
+    }
+    constant
+}
+
+fn check_ident(ident: &str, event_name: &str) -> Result<(), SchemaError> {
+    let mut chars = ident.chars();
+    let valid = chars
+        .next()
+        .is_some_and(|c| c == '_' || c.is_ascii_alphabetic())
+        && chars.all(|c| c == '_' || c.is_ascii_alphanumeric());
+    if valid {
+        Ok(())
+    } else {
+        Err(SchemaError::new(format!("`{event_name}`: `{ident}` is not a valid identifier")))
+    }
+}
@@ -0,0 +1,297 @@
+use std::{
+    collections::HashMap,
+    fmt::Write as _,
+    io,
+    path::Path,
+    sync::{Arc, Mutex as StdMutex},
+    time::Duration,
+};
+
+use futures::future::BoxFuture;
+
+use crate::clock::{self, Instant};
+use crate::codec::{CodecError, PayloadCodec};
+use crate::config::EmitOptions;
+use crate::context;
+use crate::error::{Error, Result};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg};
+
+const HEADER: &str = "rumt-session 1";
+
+/// Kaydedilmiş tek bir emit.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RecordedEmit {
+    /// Kaydın başlangıcından emit'e kadar geçen süre.
+    pub offset: Duration,
+    pub event: RuntimeEvent,
+    pub tenant: Option<String>,
+    /// Event için verilen codec ile kodlanmış payload.
+    pub payload: Vec<u8>,
+}
+
+/// `SessionRecorder` ile kaydedilen, zaman sırasına göre emit'ler.
+///
+/// Dosya biçimi satır tabanlıdır: `rumt-session 1` başlığından sonra her satırda sekmeyle
+/// ayrılmış mikrosaniye cinsinden süre, event türü (`S`/`O`), event adı, tenant (`-` yoksa)
+/// ve hex kodlu payload bulunur. Böylece olay kayıtları gözle incelenip düzenlenebilir.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Session {
+    pub entries: Vec<RecordedEmit>,
+}
+
+impl Session {
+    pub fn to_text(&self) -> String {
+        let mut out = String::from(HEADER);
+        out.push('\n');
+        for entry in &self.entries {
+            let (kind, name) = match &entry.event {
+                RuntimeEvent::Static { event_name } => ('S', event_name),
+                RuntimeEvent::OnceTriggered { event_name } => ('O', event_name),
+            };
+            let _ = write!(out, "{}\t{kind}\t{}\t", entry.offset.as_micros(), escape(name));
+            match &entry.tenant {
+                Some(tenant) => out.push_str(&escape(tenant)),
+                None => out.push('-'),
+            }
+            out.push('\t');
+            for byte in &entry.payload {
+                let _ = write!(out, "{byte:02x}");
+            }
+            out.push('\n');
+        }
+        out
+    }
+
+    /// `to_text` çıktısını okur; biçim bozuksa hatalı satırı içeren `InvalidData` döner.
+    pub fn from_text(text: &str) -> io::Result<Self> {
+        let mut lines = text.lines();
+        if lines.next() != Some(HEADER) {
+            return Err(invalid("missing `rumt-session 1` header"));
+        }
+        let mut entries = Vec::new();
+        for (index, line) in lines.enumerate().filter(|(_, line)| !line.is_empty()) {
+            let entry = parse_line(line).ok_or_else(|| invalid(format!("malformed entry on line {}", index + 2)))?;
+            entries.push(entry);
+        }
+        Ok(Self { entries })
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        std::fs::write(path, self.to_text())
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_text(&std::fs::read_to_string(path)?)
+    }
+}
+
+/// Bir oturumda yayınlanan eventleri zamanlarıyla kaydeder; `Session` bir dosyaya yazılıp
+/// `SessionReplayer` ile yeni bir runtime'a tekrar oynatılabilir (ör. bir üretim olayının
+/// kaydından regresyon testi üretmek için).
+///
+/// Yalnızca zincirin kökü olan emit'ler kaydedilir; handler'ların içinden yapılan emit'ler
+/// oynatma sırasında handler'lar tarafından yeniden üretilir. Payload'lar event başına verilen
+/// codec ile kodlanır; kodlanamayan payload'lar atlanır.
+///
+/// ```rust,ignore
+/// let recorder = SessionRecorder::new("incident-4711");
+/// recorder.record(order_created(), OrderJsonCodec).await?;
+/// recorder.record(payment_failed(), PaymentJsonCodec).await?;
+/// // ... uygulama çalışır ...
+/// recorder.finish().await?.save("incident-4711.session")?;
+/// ```
+pub struct SessionRecorder {
+    tag: String,
+    started: Instant,
+    entries: Arc<StdMutex<Vec<RecordedEmit>>>,
+}
+
+impl SessionRecorder {
+    /// Süreler bu andan itibaren ölçülür.
+    pub fn new(name: &str) -> Self {
+        Self {
+            tag: format!("recorder:{name}"),
+            started: clock::now(),
+            entries: Arc::default(),
+        }
+    }
+
+    /// `event`in `T` payload'lı emit'lerini kaydetmeye başlar.
+    pub async fn record<T, C>(&self, event: RuntimeEvent, codec: C) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let started = self.started;
+        let entries = Arc::clone(&self.entries);
+        let recorded = event.clone();
+        let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+            let context = context::current();
+            let root = context.as_ref().is_none_or(|c| c.causation_id.is_none());
+            let encoded = args
+                .downcast::<Arc<T>>()
+                .filter(|_| root)
+                .and_then(|payload| codec.encode(payload).ok());
+            if let Some(payload) = encoded {
+                let entry = RecordedEmit {
+                    // Dosyadaki çözünürlükle aynı olsun diye mikrosaniyeye yuvarlanır
+                    offset: Duration::from_micros((clock::now() - started).as_micros() as u64),
+                    event: recorded.clone(),
+                    tenant: context.and_then(|c| c.tenant),
+                    payload,
+                };
+                entries.lock().unwrap_or_else(|e| e.into_inner()).push(entry);
+            }
+            Box::pin(async {}) as BoxFuture<'static, ()>
+        });
+        let listener = RuntimeEventListener::new(self.tag.clone(), handler);
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await
+    }
+
+    /// O ana kadar kaydedilenlerin kopyası; kayıt sürer.
+    pub fn session(&self) -> Session {
+        let mut entries = self.entries.lock().unwrap_or_else(|e| e.into_inner()).clone();
+        // Eşzamanlı dispatch'te kayıtlar süre sırasıyla eklenmemiş olabilir
+        entries.sort_by_key(|entry| entry.offset);
+        Session { entries }
+    }
+
+    /// Kaydı durdurur (dinleyicileri bus'tan kaldırır) ve oturumu döner.
+    pub async fn finish(self) -> Result<Session> {
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_all_listeners_by_tag(&self.tag)).await?;
+        Ok(self.session())
+    }
+}
+
+type ReplayRoute = Box<dyn Fn(&[u8], EmitOptions) -> std::result::Result<BoxFuture<'static, Result<()>>, CodecError> + Send + Sync>;
+
+/// Kaydedilmiş bir `Session`'ı bus'a tekrar yayınlar. Her event için payload tipini çözecek
+/// codec `route` ile verilir; route'u olmayan kayıtlar atlanır. Emit'ler asıl aralıklarıyla
+/// veya `speed` ile hızlandırılarak yapılır.
+///
+/// ```rust,ignore
+/// let session = Session::load("incident-4711.session")?;
+/// let emitted = SessionReplayer::new(session)
+///     .route(order_created(), OrderJsonCodec)
+///     .route(payment_failed(), PaymentJsonCodec)
+///     .speed(20.0)
+///     .run()
+///     .await?;
+/// ```
+pub struct SessionReplayer {
+    session: Session,
+    routes: HashMap<RuntimeEvent, ReplayRoute>,
+    speed: f64,
+}
+
+impl SessionReplayer {
+    pub fn new(session: Session) -> Self {
+        Self { session, routes: HashMap::new(), speed: 1.0 }
+    }
+
+    pub fn route<T, C>(mut self, event: RuntimeEvent, codec: C) -> Self
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let target = event.clone();
+        let route: ReplayRoute = Box::new(move |bytes: &[u8], options: EmitOptions| {
+            let payload = codec.decode(bytes)?;
+            Ok(Box::pin(crate::try_emit_with(target.clone(), payload, options)) as BoxFuture<'static, _>)
+        });
+        self.routes.insert(event, route);
+        self
+    }
+
+    /// Aralıkların kaç kat hızlı oynatılacağı; `1.0` asıl zamanlama, `f64::INFINITY` beklemesiz.
+    /// Sıfır ve negatif değerler `1.0` kabul edilir.
+    pub fn speed(mut self, factor: f64) -> Self {
+        self.speed = if factor > 0.0 { factor } else { 1.0 };
+        self
+    }
+
+    /// Kayıtları sırayla yayınlar ve yayınlanan emit sayısını döner. Çözülemeyen bir payload
+    /// `Error::Codec`, yayınlanamayan bir emit ilgili hatayı döndürerek oynatmayı durdurur.
+    pub async fn run(self) -> Result<usize> {
+        let started = clock::now();
+        let mut emitted = 0;
+        for entry in &self.session.entries {
+            let Some(route) = self.routes.get(&entry.event) else {
+                continue;
+            };
+            let due = started + entry.offset.div_f64(self.speed);
+            clock::sleep_until(due).await;
+            let options = EmitOptions { tenant: entry.tenant.clone(), ..Default::default() };
+            route(&entry.payload, options).map_err(Error::Codec)?.await?;
+            emitted += 1;
+        }
+        Ok(emitted)
+    }
+}
+
+fn parse_line(line: &str) -> Option<RecordedEmit> {
+    let mut fields = line.split('\t');
+    let offset = Duration::from_micros(fields.next()?.parse().ok()?);
+    let kind = fields.next()?;
+    let event_name = unescape(fields.next()?)?;
+    let event = match kind {
+        "S" => RuntimeEvent::Static { event_name },
+        "O" => RuntimeEvent::OnceTriggered { event_name },
+        _ => return None,
+    };
+    let tenant = match fields.next()? {
+        "-" => None,
+        tenant => Some(unescape(tenant)?),
+    };
+    let hex = fields.next()?;
+    if fields.next().is_some() || hex.len() % 2 != 0 {
+        return None;
+    }
+    let payload = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    Some(RecordedEmit { offset, event, tenant, payload })
+}
+
+// Ad ve tenant alanlarında ayırıcı karakterler kaçışlanır; `-` tek başına "tenant yok" demektir
+pub(crate) fn escape(value: &str) -> String {
+    let mut out = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\t' => out.push_str("\\t"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            c => out.push(c),
+        }
+    }
+    if out == "-" {
+        out.insert(0, '\\');
+    }
+    out
+}
+
+pub(crate) fn unescape(value: &str) -> Option<String> {
+    let mut out = String::with_capacity(value.len());
+    let mut chars = value.chars();
+    while let Some(c) = chars.next() {
+        if c != '\\' {
+            out.push(c);
+            continue;
+        }
+        match chars.next()? {
+            '\\' => out.push('\\'),
+            't' => out.push('\t'),
+            'n' => out.push('\n'),
+            'r' => out.push('\r'),
+            '-' => out.push('-'),
+            _ => return None,
+        }
+    }
+    Some(out)
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -0,0 +1,57 @@
+use std::sync::Arc;
+
+use crate::controller::InstanceId;
+use crate::event_bus::{RegisteredService, RuntimeEvent, RuntimeEventListener};
+
+/// Bus'a kayıtlı dinleyicilerin ve servislerin, yeni bir bus'a aynen bağlanabilecek kopyası.
+///
+/// Handler'lar servislerine `Arc` ile bağlı olduğundan, runtime yeniden başlatıldığında servislerin
+/// `init` yolunu tekrar çalıştırmak gerekmez. Mevcut `ListenerController`'lar geçerli kalır
+/// (pause, resume ve dispose yeni bus üzerinde çalışır).
+///
+/// ```rust,ignore
+/// let snapshot = rumt::export_registrations().await?;
+/// rumt::shutdown_runtime().await;
+/// rumt::try_init_runtime(env).await?;
+/// rumt::import_registrations(snapshot).await?;
+/// ```
+pub struct RegistrationSnapshot {
+    pub(crate) listeners: Vec<(RuntimeEvent, Arc<RuntimeEventListener>)>,
+    pub(crate) services: Vec<RegisteredService>,
+}
+
+/// Snapshot'taki tek bir dinleyicinin bilgisi.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Registration {
+    pub event: RuntimeEvent,
+    pub tag: String,
+    /// `init` ile kaydedilmemiş (ör. `add_listener`) dinleyicilerde `None`.
+    pub instance: Option<InstanceId>,
+    pub enabled_if: Option<String>,
+    /// Dinleyici bir tenant'a bağlıysa o tenant.
+    pub tenant: Option<String>,
+}
+
+impl RegistrationSnapshot {
+    /// Dinleyicilerin bilgisi; bağlı olanlar önce, flag'i kapalı olduğu için bekletilenler sonra.
+    pub fn registrations(&self) -> Vec<Registration> {
+        self.listeners
+            .iter()
+            .map(|(event, listener)| Registration {
+                event: event.clone(),
+                tag: listener.tag.clone(),
+                instance: listener.instance,
+                enabled_if: listener.enabled_if.clone(),
+                tenant: listener.tenant.clone(),
+            })
+            .collect()
+    }
+
+    pub fn len(&self) -> usize {
+        self.listeners.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.listeners.is_empty()
+    }
+}
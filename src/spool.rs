@@ -0,0 +1,213 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write as _,
+    fs::{File, OpenOptions},
+    io::{self, Write as _},
+    path::PathBuf,
+    time::{Duration, UNIX_EPOCH},
+};
+
+use crate::session::{escape, unescape};
+use crate::transport::{Frame, FrameFilter};
+
+/// Bağlantı geri geldiğinde biriken frame'ler gönderilmeye başlanırken yayınlanır; payload'ı
+/// `SyncReport`.
+pub const SYNC_STARTED: &str = "rumt.sync.started";
+/// Senkronizasyon bittiğinde (veya bağlantı yine koptuğunda) yayınlanır; payload'ı `SyncReport`.
+pub const SYNC_COMPLETED: &str = "rumt.sync.completed";
+
+const HEADER: &str = "rumt-spool 1";
+
+/// `TransportOptions::spool` ayarları: bağlantı yokken seçilen giden frame'lerin yazılacağı
+/// dosya, sınırı ve ömrü.
+///
+/// Dosya satır tabanlıdır: `rumt-spool 1` başlığından sonra her satırda sekmeyle ayrılmış
+/// Unix milisaniyesi, event adı, hex kodlu payload ve `ad=değer` biçiminde başlıklar bulunur.
+///
+/// ```rust,ignore
+/// let options = TransportOptions {
+///     spool: Some(SpoolConfig::new(data_dir.join("outbox.spool"))
+///         .events(FrameFilter::Prefix("pos.sale.".into()))
+///         .max_frames(50_000)
+///         .ttl(Duration::from_secs(7 * 86_400))),
+///     ..Default::default()
+/// };
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SpoolConfig {
+    pub path: PathBuf,
+    /// Biriktirilecek frame'lerin dış adları; diğerleri eskisi gibi denenir ve bırakılır.
+    pub events: FrameFilter,
+    /// Dosyadaki en fazla frame; dolu spool'a gelen frame bırakılır (`TransportManager::dropped`).
+    pub max_frames: usize,
+    /// Bu süreden eski frame'ler gönderilmeden atılır.
+    pub ttl: Option<Duration>,
+}
+
+impl SpoolConfig {
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self { path: path.into(), events: FrameFilter::All, max_frames: 10_000, ttl: None }
+    }
+
+    pub fn events(mut self, events: FrameFilter) -> Self {
+        self.events = events;
+        self
+    }
+
+    /// En az 1.
+    pub fn max_frames(mut self, max_frames: usize) -> Self {
+        self.max_frames = max_frames.max(1);
+        self
+    }
+
+    pub fn ttl(mut self, ttl: Duration) -> Self {
+        self.ttl = Some(ttl);
+        self
+    }
+}
+
+/// `rumt.sync.started` ve `rumt.sync.completed` payload'ı.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SyncReport {
+    /// Manager'ın tag'i.
+    pub transport: String,
+    /// Başlarken gönderilecek, biterken gönderilemeden spool'da kalan frame sayısı.
+    pub pending: usize,
+    pub sent: usize,
+    /// Ömrü dolduğu için atılan frame sayısı.
+    pub expired: usize,
+}
+
+/// Diskteki spool'un bellekteki kopyası; frame'ler geliş sırasıyla tutulur.
+pub(crate) struct Spool {
+    config: SpoolConfig,
+    entries: VecDeque<(u64, Frame)>,
+}
+
+impl Spool {
+    /// Önceki çalışmadan kalan frame'lerle birlikte açar; dosya yoksa boştur.
+    pub(crate) fn open(config: SpoolConfig) -> io::Result<Self> {
+        let text = match std::fs::read_to_string(&config.path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => String::new(),
+            Err(e) => return Err(e),
+        };
+        let mut lines = text.lines();
+        let mut entries = VecDeque::new();
+        if let Some(header) = lines.next() {
+            if header != HEADER {
+                return Err(invalid("missing `rumt-spool 1` header"));
+            }
+            for (index, line) in lines.enumerate().filter(|(_, line)| !line.is_empty()) {
+                let entry = parse_line(line).ok_or_else(|| invalid(format!("malformed entry on line {}", index + 2)))?;
+                entries.push_back(entry);
+            }
+        }
+        Ok(Self { config, entries })
+    }
+
+    pub(crate) fn accepts(&self, frame: &Frame) -> bool {
+        self.config.events.matches(&frame.event)
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Frame'i dosyanın sonuna ekler; spool doluysa `false` döner.
+    pub(crate) fn push(&mut self, frame: Frame) -> io::Result<bool> {
+        if self.entries.len() >= self.config.max_frames {
+            return Ok(false);
+        }
+        let entry = (now_millis(), frame);
+        let mut out = String::new();
+        if self.entries.is_empty() {
+            // Boşalan dosya baştan yazılır
+            out.push_str(HEADER);
+            out.push('\n');
+        }
+        write_line(&mut out, &entry);
+        let mut file = OpenOptions::new()
+            .create(true)
+            .write(true)
+            .append(!self.entries.is_empty())
+            .truncate(self.entries.is_empty())
+            .open(&self.config.path)?;
+        file.write_all(out.as_bytes())?;
+        self.entries.push_back(entry);
+        Ok(true)
+    }
+
+    /// Ömrü dolan frame'leri atar ve sayısını döner.
+    pub(crate) fn expire(&mut self) -> usize {
+        let Some(ttl) = self.config.ttl else { return 0 };
+        let oldest = now_millis().saturating_sub(ttl.as_millis() as u64);
+        let before = self.entries.len();
+        self.entries.retain(|(spooled_at, _)| *spooled_at >= oldest);
+        before - self.entries.len()
+    }
+
+    pub(crate) fn front(&self) -> Option<Frame> {
+        self.entries.front().map(|(_, frame)| frame.clone())
+    }
+
+    pub(crate) fn pop_front(&mut self) {
+        self.entries.pop_front();
+    }
+
+    /// Dosyayı kalan frame'lerle yeniden yazar. Önce yanındaki `.tmp` dosyasına yazılıp üzerine
+    /// taşınır; yazma yarıda kesilirse eski dosya olduğu gibi kalır.
+    pub(crate) fn persist(&self) -> io::Result<()> {
+        let mut out = String::from(HEADER);
+        out.push('\n');
+        for entry in &self.entries {
+            write_line(&mut out, entry);
+        }
+        let mut temp = self.config.path.clone().into_os_string();
+        temp.push(".tmp");
+        let temp = PathBuf::from(temp);
+        let mut file = File::create(&temp)?;
+        file.write_all(out.as_bytes())?;
+        file.sync_all()?;
+        std::fs::rename(&temp, &self.config.path)
+    }
+}
+
+fn now_millis() -> u64 {
+    crate::clock::system_time().duration_since(UNIX_EPOCH).map(|since| since.as_millis() as u64).unwrap_or(0)
+}
+
+fn write_line(out: &mut String, (spooled_at, frame): &(u64, Frame)) {
+    let _ = write!(out, "{spooled_at}\t{}\t", escape(&frame.event));
+    for byte in &frame.payload {
+        let _ = write!(out, "{byte:02x}");
+    }
+    for (name, value) in &frame.headers {
+        let _ = write!(out, "\t{}={}", escape(name), escape(value));
+    }
+    out.push('\n');
+}
+
+fn parse_line(line: &str) -> Option<(u64, Frame)> {
+    let mut fields = line.split('\t');
+    let spooled_at = fields.next()?.parse().ok()?;
+    let event = unescape(fields.next()?)?;
+    let hex = fields.next()?;
+    if hex.len() % 2 != 0 {
+        return None;
+    }
+    let payload = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect::<Option<Vec<u8>>>()?;
+    let mut frame = Frame::new(event, payload);
+    for header in fields {
+        let (name, value) = header.split_once('=')?;
+        frame = frame.with_header(unescape(name)?, unescape(value)?);
+    }
+    Some((spooled_at, frame))
+}
+
+fn invalid(message: impl Into<String>) -> io::Error {
+    io::Error::new(io::ErrorKind::InvalidData, message.into())
+}
@@ -0,0 +1,213 @@
+use std::{
+    collections::VecDeque,
+    fmt::Write,
+    sync::{
+        Mutex as StdMutex,
+        atomic::{AtomicU64, Ordering},
+    },
+    time::Duration,
+};
+
+use crate::clock::{self, Instant};
+
+/// `bus_stats()` ile alınan, runtime başlangıcından bu yana biriken bus istatistikleri.
+#[derive(Clone, Debug, PartialEq)]
+pub struct BusStats {
+    pub uptime: Duration,
+    /// Dinleyicisi olsun olmasın yapılan tüm emit'ler.
+    pub emits: u64,
+    /// Dinleyicisi olmadığı için hiçbir handler'a ulaşmayan emit'ler.
+    pub unhandled: u64,
+    /// Panic ile sonlanan handler çalışmaları.
+    pub failures: u64,
+    /// Kuyruktayken (ör. `shutdown_runtime` sırasında) bırakılan emit'ler.
+    pub dropped: u64,
+    /// Süresi dolduğu için çalıştırılmadan atılan emit'ler.
+    pub expired: u64,
+    /// Idempotency anahtarı daha önce görüldüğü için bastırılan emit'ler.
+    pub suppressed: u64,
+    /// Zamanı gelmeden iptal edilen gecikmeli emit'ler ve zamanlanmış işler.
+    pub cancelled: u64,
+    /// Event adı ve bağlı dinleyici sayısı, ada göre sıralı.
+    pub listeners: Vec<(String, usize)>,
+    pub latency: LatencyStats,
+}
+
+/// Handler çalışma süreleri. Yüzdelikler son `BusConfig::latency_window` çalışmadan hesaplanır.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct LatencyStats {
+    pub handled: u64,
+    pub average: Duration,
+    pub p50: Duration,
+    pub p95: Duration,
+    pub p99: Duration,
+    pub max: Duration,
+}
+
+impl BusStats {
+    /// `/debug/bus` gibi bir endpoint'ten dönülebilecek JSON gösterimi. Süreler mikrosaniyedir.
+    pub fn to_json(&self) -> String {
+        let mut out = String::new();
+        let _ = write!(
+            out,
+            "{{\"uptime_ms\":{},\"emits\":{},\"unhandled\":{},\"failures\":{},\"dropped\":{},\"expired\":{},\"suppressed\":{},\"cancelled\":{},\"listeners\":{{",
+            self.uptime.as_millis(),
+            self.emits,
+            self.unhandled,
+            self.failures,
+            self.dropped,
+            self.expired,
+            self.suppressed,
+            self.cancelled,
+        );
+        for (i, (event, count)) in self.listeners.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            push_json_string(&mut out, event);
+            let _ = write!(out, ":{count}");
+        }
+        let latency = &self.latency;
+        let _ = write!(
+            out,
+            "}},\"latency_us\":{{\"handled\":{},\"avg\":{},\"p50\":{},\"p95\":{},\"p99\":{},\"max\":{}}}}}",
+            latency.handled,
+            latency.average.as_micros(),
+            latency.p50.as_micros(),
+            latency.p95.as_micros(),
+            latency.p99.as_micros(),
+            latency.max.as_micros(),
+        );
+        out
+    }
+}
+
+pub(crate) fn push_json_string(out: &mut String, value: &str) {
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// Dispatch yolunda güncellenen sayaçlar. Bus ve dispatch planları arasında paylaşılır.
+pub(crate) struct StatsRecorder {
+    started: Instant,
+    emits: AtomicU64,
+    unhandled: AtomicU64,
+    failures: AtomicU64,
+    dropped: AtomicU64,
+    expired: AtomicU64,
+    suppressed: AtomicU64,
+    cancelled: AtomicU64,
+    handled: AtomicU64,
+    total_micros: AtomicU64,
+    window: StdMutex<VecDeque<Duration>>,
+    window_size: usize,
+}
+
+impl StatsRecorder {
+    pub(crate) fn new(window_size: usize) -> Self {
+        let window_size = window_size.max(1);
+        Self {
+            started: clock::real_now(),
+            emits: AtomicU64::new(0),
+            unhandled: AtomicU64::new(0),
+            failures: AtomicU64::new(0),
+            dropped: AtomicU64::new(0),
+            expired: AtomicU64::new(0),
+            suppressed: AtomicU64::new(0),
+            cancelled: AtomicU64::new(0),
+            handled: AtomicU64::new(0),
+            total_micros: AtomicU64::new(0),
+            window: StdMutex::new(VecDeque::with_capacity(window_size)),
+            window_size,
+        }
+    }
+
+    pub(crate) fn record_emit(&self, has_listeners: bool) {
+        self.emits.fetch_add(1, Ordering::Relaxed);
+        if !has_listeners {
+            self.unhandled.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    pub(crate) fn record_handler(&self, elapsed: Duration, failed: bool) {
+        self.handled.fetch_add(1, Ordering::Relaxed);
+        self.total_micros
+            .fetch_add(elapsed.as_micros() as u64, Ordering::Relaxed);
+        if failed {
+            self.failures.fetch_add(1, Ordering::Relaxed);
+        }
+        let mut window = self.window.lock().unwrap_or_else(|e| e.into_inner());
+        if window.len() == self.window_size {
+            window.pop_front();
+        }
+        window.push_back(elapsed);
+    }
+
+    pub(crate) fn record_dropped(&self, count: usize) {
+        self.dropped.fetch_add(count as u64, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_expired(&self) {
+        self.expired.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_suppressed(&self) {
+        self.suppressed.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_cancelled(&self) {
+        self.cancelled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    pub(crate) fn snapshot(&self, listeners: Vec<(String, usize)>) -> BusStats {
+        BusStats {
+            uptime: self.started.elapsed(),
+            emits: self.emits.load(Ordering::Relaxed),
+            unhandled: self.unhandled.load(Ordering::Relaxed),
+            failures: self.failures.load(Ordering::Relaxed),
+            dropped: self.dropped.load(Ordering::Relaxed),
+            expired: self.expired.load(Ordering::Relaxed),
+            suppressed: self.suppressed.load(Ordering::Relaxed),
+            cancelled: self.cancelled.load(Ordering::Relaxed),
+            listeners,
+            latency: self.latency(),
+        }
+    }
+
+    fn latency(&self) -> LatencyStats {
+        let handled = self.handled.load(Ordering::Relaxed);
+        if handled == 0 {
+            return LatencyStats::default();
+        }
+        let mut samples: Vec<Duration> = self
+            .window
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .iter()
+            .copied()
+            .collect();
+        samples.sort_unstable();
+        let percentile = |p: usize| samples[(samples.len() - 1) * p / 100];
+        LatencyStats {
+            handled,
+            average: Duration::from_micros(self.total_micros.load(Ordering::Relaxed) / handled),
+            p50: percentile(50),
+            p95: percentile(95),
+            p99: percentile(99),
+            max: samples[samples.len() - 1],
+        }
+    }
+}
@@ -0,0 +1,159 @@
+use futures::future::BoxFuture;
+use std::sync::{
+    Arc,
+    atomic::{AtomicBool, Ordering},
+};
+
+use crate::bridge::{BridgeSink, EventBridge, colon_separated};
+use crate::codec::PayloadCodec;
+use crate::error::Result;
+use crate::event_bus::RuntimeEvent;
+use crate::log::Level;
+use crate::telemetry::event_name;
+
+/// Tauri'nin event API'sinden rumt'un kullandığı kısım. rumt `tauri` crate'ine bağlı
+/// değildir; uygulama bu trait'i kendi `AppHandle`'ı için birkaç satırla uygular:
+///
+/// ```rust,ignore
+/// use tauri::{Emitter, Listener};
+///
+/// struct App(tauri::AppHandle);
+///
+/// impl TauriEvents for App {
+///     fn emit_json(&self, event: &str, json: String) -> Result<(), String> {
+///         self.0.emit_str(event, json).map_err(|e| e.to_string())
+///     }
+///
+///     fn listen_json(&self, event: &str, handler: Box<dyn Fn(String) + Send + Sync>) {
+///         self.0.listen_any(event, move |e| handler(e.payload().to_string()));
+///     }
+/// }
+/// ```
+pub trait TauriEvents: Send + Sync + 'static {
+    /// Webview'e JSON payload'lı event gönderir (`Emitter::emit_str`).
+    fn emit_json(&self, event: &str, json: String) -> std::result::Result<(), String>;
+
+    /// Webview'den gelen event'i dinler (`Listener::listen_any`); `handler` payload'ın JSON
+    /// metnini alır.
+    fn listen_json(&self, event: &str, handler: Box<dyn Fn(String) + Send + Sync>);
+}
+
+/// rumt eventlerini Tauri'nin event sistemiyle iki yönlü eşleştiren `EventBridge`.
+///
+/// Tauri event adlarında `.` kullanılamadığı için adlar varsayılan olarak `colon_separated`
+/// ile eşlenir (`order.created` <-> `order:created`); `_as` metotlarıyla ad ayrıca verilebilir.
+/// Tauri payload'ları JSON olduğundan codec'ler JSON üretmeli ve çözmelidir; serde kullanan
+/// uygulamalar için:
+///
+/// ```rust,ignore
+/// fn json<T: Serialize + DeserializeOwned>() -> impl PayloadCodec<T> {
+///     codec_fn(
+///         |p: &T| serde_json::to_vec(p).map_err(|e| CodecError::new(e.to_string())),
+///         |bytes: &[u8]| serde_json::from_slice(bytes).map_err(|e| CodecError::new(e.to_string())),
+///     )
+/// }
+///
+/// let bridge = TauriBridge::new("webview", App(app.handle().clone()));
+/// bridge.emit_to_webview(ORDER_STATUS, json::<OrderStatus>()).await?;
+/// bridge.listen_from_webview(CART_ITEM_ADDED, json::<CartItem>());
+/// ```
+///
+/// Webview'den gelip bus'a emit edilen event webview'e geri yansıtılmaz. Çözülemeyen veya
+/// UTF-8 olmayan payload'lar `rumt::tauri` hedefiyle loglanıp bırakılır.
+pub struct TauriBridge {
+    bridge: Arc<EventBridge>,
+    events: Arc<dyn TauriEvents>,
+    // Tauri dinleyicileri kaldırılamadığı için `dispose`'dan sonra gelenler bununla yok sayılır
+    disposed: Arc<AtomicBool>,
+}
+
+struct WebviewSink(Arc<dyn TauriEvents>);
+
+impl BridgeSink for WebviewSink {
+    fn send(&self, name: &str, payload: Vec<u8>) -> BoxFuture<'static, ()> {
+        let sent = String::from_utf8(payload)
+            .map_err(|_| "payload is not UTF-8".to_string())
+            .and_then(|json| self.0.emit_json(name, json));
+        let name = name.to_string();
+        Box::pin(async move {
+            if let Err(e) = sent {
+                crate::log(Level::Warn, "rumt::tauri", format!("cannot emit `{name}` to the webview: {e}")).await;
+            }
+        })
+    }
+}
+
+impl TauriBridge {
+    pub fn new(tag: impl Into<String>, events: impl TauriEvents) -> Arc<Self> {
+        let events: Arc<dyn TauriEvents> = Arc::new(events);
+        let bridge = EventBridge::new(tag, WebviewSink(Arc::clone(&events)));
+        Arc::new(Self { bridge, events, disposed: Arc::new(AtomicBool::new(false)) })
+    }
+
+    /// Bus'taki `event`i webview'e `colon_separated` adıyla iletir. Runtime başlatılmamışsa
+    /// `Error::NotInitialized` döner.
+    pub async fn emit_to_webview<T, C>(&self, event: RuntimeEvent, codec: C) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let name = colon_separated(event_name(&event));
+        self.emit_to_webview_as(event, name, codec).await
+    }
+
+    pub async fn emit_to_webview_as<T, C>(&self, event: RuntimeEvent, webview_name: impl Into<String>, codec: C) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        self.bridge.forward(event, webview_name, codec).await
+    }
+
+    /// Webview'den `colon_separated` adıyla gelen eventleri bus'a `event` olarak emit eder.
+    /// Tokio runtime'ı içinden çağrılmalıdır; gelen eventler o runtime'da işlenir.
+    pub fn listen_from_webview<T, C>(&self, event: RuntimeEvent, codec: C)
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let name = colon_separated(event_name(&event));
+        self.listen_from_webview_as(name, event, codec);
+    }
+
+    pub fn listen_from_webview_as<T, C>(&self, webview_name: impl Into<String>, event: RuntimeEvent, codec: C)
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let webview_name: String = webview_name.into();
+        self.bridge.receive(webview_name.clone(), event, codec);
+        let (bridge, disposed) = (Arc::clone(&self.bridge), Arc::clone(&self.disposed));
+        let name = webview_name.clone();
+        // Tauri dinleyicileri kendi thread'inde çağırır; event'ler kaydın yapıldığı runtime'da işlenir
+        let runtime = tokio::runtime::Handle::try_current().ok();
+        self.events.listen_json(
+            &webview_name,
+            Box::new(move |json| {
+                if disposed.load(Ordering::Acquire) {
+                    return;
+                }
+                let (bridge, name) = (Arc::clone(&bridge), name.clone());
+                let task = async move {
+                    if let Err(e) = bridge.handle_incoming(&name, json.as_bytes()).await {
+                        crate::log(Level::Warn, "rumt::tauri", format!("dropped webview event `{name}`: {e}")).await;
+                    }
+                };
+                match &runtime {
+                    Some(runtime) => drop(runtime.spawn(task)),
+                    None => crate::rt::spawn(task),
+                }
+            }),
+        );
+    }
+
+    /// Bus'a eklenen dinleyicileri kaldırır; webview'den sonradan gelen eventler yok sayılır.
+    pub async fn dispose(&self) {
+        self.disposed.store(true, Ordering::Release);
+        self.bridge.dispose().await;
+    }
+}
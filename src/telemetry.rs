@@ -0,0 +1,103 @@
+use std::time::Duration;
+
+use crate::context::Context;
+use crate::crypto::to_hex;
+use crate::event_bus::RuntimeEvent;
+use crate::failure::HandlerFailure;
+
+/// OpenTelemetry messaging semantic convention'larındaki `messaging.system` değeri.
+pub const MESSAGING_SYSTEM: &str = "rumt";
+
+/// Span'e eklenecek `anahtar = değer` attribute listesi.
+pub type SpanAttributes = Vec<(&'static str, String)>;
+
+/// Bir emit'in yayınlanması (OpenTelemetry'de `publish` span'i).
+pub struct EmitSpan<'a> {
+    pub event: &'a RuntimeEvent,
+    pub context: &'a Context,
+    pub listener_count: usize,
+}
+
+/// Tek bir handler'ın çalıştırılması (OpenTelemetry'de `process` span'i).
+pub struct HandlerSpan<'a> {
+    pub event: &'a RuntimeEvent,
+    pub context: &'a Context,
+    pub tag: &'a str,
+    /// `tag::handler_fn@event`; bkz. `rumt::rt::current_label`.
+    pub label: &'a str,
+    pub elapsed: Duration,
+    /// Handler başarısız olduysa bağlamı.
+    pub failure: Option<&'a HandlerFailure>,
+}
+
+/// Bus üzerindeki emit ve handler çalışmalarını dışarıya (OpenTelemetry, metrik sistemleri vb.)
+/// aktarmak için kancalar. Metodlar dispatch yolunda çağrılır, hızlı dönmelidir.
+pub trait TelemetryObserver: Send + Sync {
+    fn on_emit(&self, span: &EmitSpan<'_>) {}
+    fn on_handler(&self, span: &HandlerSpan<'_>) {}
+}
+
+pub(crate) fn event_name(event: &RuntimeEvent) -> &str {
+    match event {
+        RuntimeEvent::OnceTriggered { event_name } | RuntimeEvent::Static { event_name } => {
+            event_name
+        }
+    }
+}
+
+/// Emit'in trace ve span kimlikleri (hex). Emit'in `TraceContext`'i yoksa trace kimliği
+/// korelasyon kimliği, span kimliği event kimliğidir.
+pub(crate) fn span_ids(context: &Context) -> (String, String) {
+    match &context.trace {
+        Some(trace) => (to_hex(&trace.trace_id), to_hex(&trace.parent_id)),
+        None => (context.correlation_id.to_string(), format!("{:016x}", context.event_id.0 as u64)),
+    }
+}
+
+fn common_attributes(
+    event: &RuntimeEvent,
+    context: &Context,
+    operation: &'static str,
+) -> SpanAttributes {
+    let mut attributes = vec![
+        ("messaging.system", MESSAGING_SYSTEM.to_string()),
+        ("messaging.destination.name", event_name(event).to_string()),
+        ("messaging.operation", operation.to_string()),
+        ("messaging.message.id", context.event_id.to_string()),
+        ("messaging.message.conversation_id", context.correlation_id.to_string()),
+    ];
+    if let Some(trace) = &context.trace {
+        attributes.push(("rumt.traceparent", trace.traceparent()));
+    }
+    attributes
+}
+
+impl EmitSpan<'_> {
+    pub fn span_name(&self) -> String {
+        format!("{} publish", event_name(self.event))
+    }
+
+    /// Semantic convention'a uygun span attribute'ları.
+    pub fn attributes(&self) -> SpanAttributes {
+        let mut attributes = common_attributes(self.event, self.context, "publish");
+        attributes.push(("rumt.listener.count", self.listener_count.to_string()));
+        attributes
+    }
+}
+
+impl HandlerSpan<'_> {
+    pub fn span_name(&self) -> String {
+        format!("{} process", event_name(self.event))
+    }
+
+    pub fn attributes(&self) -> SpanAttributes {
+        let mut attributes = common_attributes(self.event, self.context, "process");
+        attributes.push(("messaging.consumer.group.name", self.tag.to_string()));
+        attributes.push(("rumt.handler.label", self.label.to_string()));
+        if let Some(failure) = self.failure {
+            attributes.push(("error.type", "panic".to_string()));
+            attributes.push(("rumt.handler.attempt", failure.attempt.to_string()));
+        }
+        attributes
+    }
+}
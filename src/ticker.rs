@@ -0,0 +1,215 @@
+use std::sync::{
+    Arc, Mutex as StdMutex,
+    atomic::{AtomicBool, Ordering},
+};
+use std::time::Duration;
+
+use crate::clock::Instant;
+
+use tokio::sync::Notify;
+
+use crate::clock;
+use crate::error::{Error, Result};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus};
+
+/// `start_ticker` ile başlatılan periyodik event'in payload'ı.
+#[derive(Clone, Debug)]
+pub struct Tick {
+    /// Ticker başladığından bu yana kaçıncı tick olduğu (1'den başlar).
+    pub sequence: u64,
+    pub at: Instant,
+}
+
+/// Çalışan bir ticker'ı durdurmak için bus'ta tutulan sinyal.
+pub(crate) struct TickerStop {
+    stopped: AtomicBool,
+    notify: Notify,
+}
+
+impl TickerStop {
+    pub(crate) fn new() -> Self {
+        Self { stopped: AtomicBool::new(false), notify: Notify::new() }
+    }
+
+    pub(crate) fn stop(&self) {
+        self.stopped.store(true, Ordering::Release);
+        // Birden fazla task bekliyor olabilir; bekleyen yoksa izin `tick` için saklanır
+        self.notify.notify_waiters();
+        self.notify.notify_one();
+    }
+
+    fn is_stopped(&self) -> bool {
+        self.stopped.load(Ordering::Acquire)
+    }
+
+    /// Kaynak durdurulana kadar bekler.
+    pub(crate) async fn stopped(&self) {
+        loop {
+            let notified = self.notify.notified();
+            tokio::pin!(notified);
+            notified.as_mut().enable();
+            if self.is_stopped() {
+                return;
+            }
+            notified.await;
+        }
+    }
+
+    /// Sonraki tick'i bekler; kaynak durdurulduysa `false` döner.
+    pub(crate) async fn tick(&self, interval: &mut Interval) -> bool {
+        tokio::select! {
+            _ = interval.tick() => {}
+            _ = self.notify.notified() => {}
+        }
+        !self.is_stopped()
+    }
+
+    /// `duration` kadar bekler; kaynak veya runtime daha önce durdurulursa `false` döner.
+    pub(crate) async fn sleep(&self, duration: Duration) -> bool {
+        let lifetime = lifetime().clone();
+        let runtime_stopped = async {
+            if let Some(lifetime) = &lifetime {
+                lifetime.stopped().await;
+            }
+        };
+        tokio::select! {
+            _ = clock::sleep(duration) => {}
+            _ = self.stopped() => {}
+            _ = runtime_stopped => {}
+        }
+        !self.is_stopped() && lifetime.is_some_and(|lifetime| !lifetime.is_stopped())
+    }
+}
+
+// Çalışan runtime'ın ömrü; `interval` akışları bu sinyal durdurulunca biter
+static LIFETIME: StdMutex<Option<Arc<TickerStop>>> = StdMutex::new(None);
+
+fn lifetime() -> std::sync::MutexGuard<'static, Option<Arc<TickerStop>>> {
+    LIFETIME.lock().unwrap_or_else(std::sync::PoisonError::into_inner)
+}
+
+pub(crate) fn begin_lifetime() {
+    lifetime().get_or_insert_with(|| Arc::new(TickerStop::new()));
+}
+
+pub(crate) fn end_lifetime() {
+    if let Some(stop) = lifetime().take() {
+        stop.stop();
+    }
+}
+
+/// Arka plan kaynağını `name` adıyla bus'a kaydeder; aynı adlı eski kaynak durdurulur.
+/// Kaynağın yayınlayacağı eventlerden biri politikaya uymuyorsa kaydedilmez.
+pub(crate) async fn register_source(name: String, events: &[RuntimeEvent]) -> Result<Arc<TickerStop>> {
+    let stop = Arc::new(TickerStop::new());
+    RuntimeEventBus::try_with_instance_mut(|bus| {
+        events.iter().try_for_each(|event| bus.check_emit(event))?;
+        bus.source_events.insert(name.clone(), events.to_vec());
+        bus.scheduled.remove(&name);
+        if let Some(previous) = bus.sources.insert(name, Arc::clone(&stop)) {
+            previous.stop();
+        }
+        Ok(Arc::clone(&stop))
+    })
+    .await?
+}
+
+/// Runtime saatine göre çalışan periyodik zamanlayıcı.
+pub(crate) struct Interval {
+    next: Instant,
+    period: Duration,
+}
+
+impl Interval {
+    /// Baştaki tick hemen değil, bir periyot sonra gelir; geciken tick'ler biriktirilmez.
+    pub(crate) fn new(period: Duration) -> Self {
+        let period = period.max(Duration::from_millis(1));
+        Self { next: clock::now() + period, period }
+    }
+
+    /// Sonraki tick anına kadar bekler.
+    pub(crate) async fn tick(&mut self) {
+        clock::sleep_until(self.next).await;
+        self.next += self.period;
+        // Geciken tick'ler biriktirilmez, sıradaki tick şimdiden bir periyot sonraya kayar
+        let now = clock::now();
+        if self.next <= now {
+            self.next = now + self.period;
+        }
+    }
+}
+
+/// Her `period` sürede bir o anki zamanı veren akış; runtime kapatıldığında kendiliğinden biter.
+/// Servislerin periyodik döngüleri böylece ayrı bir iptal mekanizması gerektirmeden runtime ile
+/// birlikte sonlanır. Runtime başlatılmamışken oluşturulan akış hemen biter.
+///
+/// Zaman runtime saatinden alınır (bkz. `clock`); ilk değer bir periyot sonra gelir, geciken
+/// tick'ler biriktirilmez ve sıfır süre 1 ms kabul edilir.
+///
+/// ```rust,ignore
+/// use futures::StreamExt;
+///
+/// let mut ticks = rumt::interval(Duration::from_secs(30));
+/// while let Some(at) = ticks.next().await {
+///     self.evict_expired(at).await;
+/// }
+/// // shutdown_runtime sonrası buraya gelinir
+/// ```
+pub fn interval(period: Duration) -> impl futures::Stream<Item = Instant> + Send + Unpin + 'static {
+    let stop = lifetime().clone();
+    let ticks = futures::stream::unfold((stop, Interval::new(period)), |(stop, mut interval)| async move {
+        let stop = stop.filter(|stop| !stop.is_stopped())?;
+        if !stop.tick(&mut interval).await {
+            return None;
+        }
+        Some((clock::now(), (Some(stop), interval)))
+    });
+    Box::pin(ticks)
+}
+
+/// `name` adında, her `period` sürede bir `Tick` payload'ı ile yayınlanan bir `Static` event başlatır.
+/// Ticker runtime'a aittir: `stop_ticker` ile veya `shutdown_runtime` sırasında durdurulur.
+/// Aynı adla çalışan bir ticker varsa durdurulup yenisi başlatılır. Geciken tick'ler biriktirilmez;
+/// sıfır süre 1 ms kabul edilir.
+///
+/// ```rust,ignore
+/// rumt::start_ticker("tick.1s", Duration::from_secs(1)).await?;
+///
+/// event_handlers! {
+///     CacheService;
+///     RuntimeEvent::Static { event_name: "tick.1s".into() } => async evict : Tick
+/// }
+/// ```
+pub async fn start_ticker(name: impl Into<String>, period: Duration) -> Result<()> {
+    let name = name.into();
+    let event = RuntimeEvent::Static { event_name: name.clone() };
+    let stop = register_source(name, std::slice::from_ref(&event)).await?;
+
+    crate::rt::spawn(async move {
+        let mut interval = Interval::new(period);
+        let mut sequence = 0;
+        while stop.tick(&mut interval).await {
+            sequence += 1;
+            let tick = Tick { sequence, at: clock::now() };
+            // Runtime kapatıldıysa ticker kendiliğinden sonlanır
+            if crate::try_emit_event(event.clone(), tick).await == Err(Error::NotInitialized) {
+                break;
+            }
+        }
+    });
+    Ok(())
+}
+
+/// Çalışan ticker'ı durdurur; bu adda bir ticker yoksa `false` döner.
+pub async fn stop_ticker(name: &str) -> bool {
+    RuntimeEventBus::try_with_instance_mut(|bus| {
+        bus.source_events.remove(name);
+        bus.scheduled.remove(name);
+        bus.sources.remove(name)
+    })
+        .await
+        .ok()
+        .flatten()
+        .map(|stop| stop.stop())
+        .is_some()
+}
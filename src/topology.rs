@@ -0,0 +1,188 @@
+use std::fmt::Write;
+
+use crate::stats::push_json_string;
+
+/// Bus'ın çalışma anındaki event akış grafiği; `export_topology()` ile alınır.
+///
+/// Dinleyiciler event'ten tag'e, kaynaklar (ticker, ingress, transport, süreç) ve handler'lar
+/// tag'den event'e giden kenarlarla gösterilir. Handler'ların yaptığı emit'ler yalnızca
+/// `BusConfig::record_topology` açıkken gözlemlenir; o durumda eventler emit sayılarıyla yazılır.
+///
+/// ```rust,ignore
+/// let topology = rumt::export_topology().await?;
+/// std::fs::write("bus.dot", topology.to_dot())?;
+/// // dot -Tsvg bus.dot -o bus.svg
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Topology {
+    /// Ada göre sıralı.
+    pub events: Vec<EventNode>,
+    pub sources: Vec<SourceNode>,
+    pub emits: Vec<EmitEdge>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EventNode {
+    pub name: String,
+    /// Event'i dinleyen tag'ler, kayıt sırasıyla ve tekrarsız.
+    pub listeners: Vec<String>,
+    /// Runtime başlangıcından bu yana emit sayısı; `record_topology` kapalıysa `None`.
+    pub emits: Option<u64>,
+}
+
+impl EventNode {
+    pub(crate) fn new(name: &str) -> Self {
+        Self { name: name.to_string(), listeners: Vec::new(), emits: None }
+    }
+}
+
+/// Runtime'a ait bir arka plan kaynağı ve yayınladığını bildirdiği eventler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SourceNode {
+    pub name: String,
+    pub events: Vec<String>,
+}
+
+/// `source` tag'li handler'ların içinden `event`e yapılan emit'ler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct EmitEdge {
+    pub source: String,
+    pub event: String,
+    pub count: u64,
+}
+
+impl Topology {
+    /// Graphviz (DOT) gösterimi: eventler elips, tag'ler kutu, kaynaklar ok biçiminde çizilir.
+    pub fn to_dot(&self) -> String {
+        let mut out = String::from("digraph rumt {\n    rankdir=LR;\n");
+        let mut tags: Vec<&str> = self
+            .events
+            .iter()
+            .flat_map(|event| event.listeners.iter())
+            .chain(self.emits.iter().map(|edge| &edge.source))
+            .map(String::as_str)
+            .collect();
+        tags.sort_unstable();
+        tags.dedup();
+
+        for event in &self.events {
+            let label = match event.emits {
+                Some(count) => format!("{}\n{count} emits", event.name),
+                None => event.name.clone(),
+            };
+            let _ = writeln!(out, "    {} [label={}, shape=ellipse];", dot_id("event", &event.name), dot_string(&label));
+        }
+        for tag in tags {
+            let _ = writeln!(out, "    {} [label={}, shape=box];", dot_id("tag", tag), dot_string(tag));
+        }
+        for source in &self.sources {
+            let _ = writeln!(
+                out,
+                "    {} [label={}, shape=cds];",
+                dot_id("source", &source.name),
+                dot_string(&source.name)
+            );
+        }
+        for event in &self.events {
+            for tag in &event.listeners {
+                let _ = writeln!(out, "    {} -> {};", dot_id("event", &event.name), dot_id("tag", tag));
+            }
+        }
+        for source in &self.sources {
+            for event in &source.events {
+                let _ = writeln!(
+                    out,
+                    "    {} -> {} [style=dashed];",
+                    dot_id("source", &source.name),
+                    dot_id("event", event)
+                );
+            }
+        }
+        for edge in &self.emits {
+            let _ = writeln!(
+                out,
+                "    {} -> {} [label=\"{}\"];",
+                dot_id("tag", &edge.source),
+                dot_id("event", &edge.event),
+                edge.count
+            );
+        }
+        out.push_str("}\n");
+        out
+    }
+
+    /// `{"events":[...],"sources":[...],"emits":[...]}` biçiminde JSON gösterimi.
+    pub fn to_json(&self) -> String {
+        let mut out = String::from("{\"events\":[");
+        for (i, event) in self.events.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            push_json_string(&mut out, &event.name);
+            out.push_str(",\"listeners\":");
+            push_json_list(&mut out, &event.listeners);
+            match event.emits {
+                Some(count) => {
+                    let _ = write!(out, ",\"emits\":{count}}}");
+                }
+                None => out.push_str(",\"emits\":null}"),
+            }
+        }
+        out.push_str("],\"sources\":[");
+        for (i, source) in self.sources.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"name\":");
+            push_json_string(&mut out, &source.name);
+            out.push_str(",\"events\":");
+            push_json_list(&mut out, &source.events);
+            out.push('}');
+        }
+        out.push_str("],\"emits\":[");
+        for (i, edge) in self.emits.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push_str("{\"source\":");
+            push_json_string(&mut out, &edge.source);
+            out.push_str(",\"event\":");
+            push_json_string(&mut out, &edge.event);
+            let _ = write!(out, ",\"count\":{}}}", edge.count);
+        }
+        out.push_str("]}");
+        out
+    }
+}
+
+fn push_json_list(out: &mut String, values: &[String]) {
+    out.push('[');
+    for (i, value) in values.iter().enumerate() {
+        if i > 0 {
+            out.push(',');
+        }
+        push_json_string(out, value);
+    }
+    out.push(']');
+}
+
+// Aynı ad hem event hem tag olabileceği için düğüm kimlikleri türle öneklenir
+fn dot_id(kind: &str, name: &str) -> String {
+    dot_string(&format!("{kind}:{name}"))
+}
+
+fn dot_string(value: &str) -> String {
+    let mut out = String::with_capacity(value.len() + 2);
+    out.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
@@ -0,0 +1,108 @@
+use std::fmt::Write;
+
+pub const TRACEPARENT_HEADER: &str = "traceparent";
+pub const TRACESTATE_HEADER: &str = "tracestate";
+
+/// W3C Trace Context (`traceparent` / `tracestate`) bilgisi.
+///
+/// Emit [`Context`](crate::Context)'i ile birlikte taşınır; process sınırını geçen
+/// transport'lar bunu header olarak yazar ve karşı tarafta geri yükler.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TraceContext {
+    pub trace_id: [u8; 16],
+    pub parent_id: [u8; 8],
+    pub flags: u8,
+    /// `tracestate` header'ı olduğu gibi taşınır.
+    pub state: Option<String>,
+}
+
+impl TraceContext {
+    /// `traceparent` (ve varsa `tracestate`) değerlerini ayrıştırır. Geçersiz değerler `None` döner.
+    pub fn parse(traceparent: &str, tracestate: Option<&str>) -> Option<Self> {
+        let mut parts = traceparent.trim().split('-');
+        let version = decode_hex::<1>(parts.next()?)?;
+        let trace_id = decode_hex::<16>(parts.next()?)?;
+        let parent_id = decode_hex::<8>(parts.next()?)?;
+        let flags = decode_hex::<1>(parts.next()?)?;
+
+        // 00 sürümünde fazladan alan olamaz, ff geçersiz sürümdür
+        if version[0] == 0xff || (version[0] == 0 && parts.next().is_some()) {
+            return None;
+        }
+        if trace_id == [0; 16] || parent_id == [0; 8] {
+            return None;
+        }
+
+        Some(Self {
+            trace_id,
+            parent_id,
+            flags: flags[0],
+            state: tracestate.map(str::trim).filter(|s| !s.is_empty()).map(Into::into),
+        })
+    }
+
+    pub fn traceparent(&self) -> String {
+        let mut out = String::with_capacity(55);
+        out.push_str("00-");
+        encode_hex(&mut out, &self.trace_id);
+        out.push('-');
+        encode_hex(&mut out, &self.parent_id);
+        let _ = write!(out, "-{:02x}", self.flags);
+        out
+    }
+
+    pub fn is_sampled(&self) -> bool {
+        self.flags & 0x01 == 0x01
+    }
+
+    /// Aynı trace içinde yeni bir span kimliğiyle devam eden context.
+    pub(crate) fn child(&self) -> Self {
+        Self {
+            parent_id: crate::context::random_u64().max(1).to_be_bytes(),
+            ..self.clone()
+        }
+    }
+
+    /// Transport header'larına yazılacak anahtar/değer çiftleri.
+    pub fn to_headers(&self) -> Vec<(String, String)> {
+        let mut headers = vec![(TRACEPARENT_HEADER.to_string(), self.traceparent())];
+        if let Some(state) = &self.state {
+            headers.push((TRACESTATE_HEADER.to_string(), state.clone()));
+        }
+        headers
+    }
+
+    /// Header listesinden trace context'i geri yükler. Header adları büyük/küçük harf duyarsızdır.
+    pub fn from_headers<'a, I>(headers: I) -> Option<Self>
+    where
+        I: IntoIterator<Item = (&'a str, &'a str)>,
+    {
+        let mut traceparent = None;
+        let mut tracestate = None;
+        for (name, value) in headers {
+            if name.eq_ignore_ascii_case(TRACEPARENT_HEADER) {
+                traceparent = Some(value);
+            } else if name.eq_ignore_ascii_case(TRACESTATE_HEADER) {
+                tracestate = Some(value);
+            }
+        }
+        Self::parse(traceparent?, tracestate)
+    }
+}
+
+fn decode_hex<const N: usize>(s: &str) -> Option<[u8; N]> {
+    if s.len() != N * 2 || !s.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+        return None;
+    }
+    let mut out = [0u8; N];
+    for (i, byte) in out.iter_mut().enumerate() {
+        *byte = u8::from_str_radix(&s[i * 2..i * 2 + 2], 16).ok()?;
+    }
+    Some(out)
+}
+
+fn encode_hex(out: &mut String, bytes: &[u8]) {
+    for byte in bytes {
+        let _ = write!(out, "{byte:02x}");
+    }
+}
@@ -0,0 +1,176 @@
+use std::{future::Future, sync::Arc, time::Duration};
+
+use crate::clock::{self, Instant};
+use crate::context::{self, Context, random_u64};
+use crate::crypto::to_hex;
+use crate::error::Result;
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus};
+use crate::failure::HandlerFailure;
+use crate::telemetry::{EmitSpan, HandlerSpan, SpanAttributes, span_ids};
+
+pub(crate) type Subscribers = Arc<[Arc<dyn SpanSubscriber>]>;
+
+tokio::task_local! {
+    // O an çalışan handler'ın span'i; içinden yapılan emit'ler buna bağlanır
+    static CURRENT: Arc<Span>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpanKind {
+    /// Bir emit'in yayınlanması; kuyruklu modda yalnızca kuyruğa bırakılmasını kapsar.
+    Publish,
+    /// Tek bir handler'ın çalıştırılması.
+    Process,
+}
+
+/// Emit veya handler çalışması süresince açık kalan span.
+///
+/// Kimlikler `rumt::otel` ile aynı şekilde üretilir: emit'in `TraceContext`'i varsa trace
+/// kimliği onunkidir ve emit span'inin kimliği `traceparent`'taki parent id'dir; yoksa trace
+/// kimliği korelasyon kimliği, emit span'inin kimliği event kimliğidir. Handler span'leri
+/// emit span'ine, handler içinden yapılan emit'ler de handler span'ine bağlanır.
+#[derive(Clone, Debug)]
+pub struct Span {
+    pub kind: SpanKind,
+    /// `<event> publish` veya `<event> process`.
+    pub name: String,
+    /// 32 haneli hex.
+    pub trace_id: String,
+    /// 16 haneli hex.
+    pub span_id: String,
+    pub parent_id: Option<String>,
+    /// `EmitSpan::attributes` / `HandlerSpan::attributes` ile aynıdır.
+    pub attributes: SpanAttributes,
+}
+
+/// Span'lerin açılıp kapanmasını izleyen kancalar; `tracing` crate'inin `Subscriber`'ına
+/// köprü kurmak veya span'leri kendi sistemine aktarmak için uygulanır. `enter` ve `exit`
+/// dispatch yolunda çağrılır, hızlı dönmelidir. `exit` handler iptal edilse veya süre sınırına
+/// takılsa da çağrılır.
+///
+/// ```rust,ignore
+/// struct Log;
+///
+/// impl SpanSubscriber for Log {
+///     fn enter(&self, span: &Span) {
+///         tracing::debug!(trace_id = %span.trace_id, span_id = %span.span_id, "{} started", span.name);
+///     }
+///
+///     fn exit(&self, span: &Span, elapsed: Duration, error: Option<&str>) {
+///         tracing::debug!(trace_id = %span.trace_id, span_id = %span.span_id, ?elapsed, error, "{} finished", span.name);
+///     }
+/// }
+///
+/// rumt::tracing::add_subscriber(Arc::new(Log)).await?;
+/// ```
+pub trait SpanSubscriber: Send + Sync {
+    fn enter(&self, span: &Span);
+    /// `error` panic eden handler'ın mesajıdır.
+    fn exit(&self, span: &Span, elapsed: Duration, error: Option<&str>);
+}
+
+/// Bus'a span izleyicisi ekler. Runtime başlatılmamışsa `Error::NotInitialized` döner.
+pub async fn add_subscriber(subscriber: Arc<dyn SpanSubscriber>) -> Result<()> {
+    RuntimeEventBus::try_with_instance_mut(|bus| {
+        bus.spans = bus.spans.iter().cloned().chain([subscriber]).collect();
+    })
+    .await
+}
+
+/// Handler içinden çağrıldığında handler'ın span'i; izleyici eklenmemişse `None`.
+pub fn current_span() -> Option<Arc<Span>> {
+    CURRENT.try_with(Arc::clone).ok()
+}
+
+/// Açık span; bırakıldığında izleyicilerin `exit`'i çağrılır.
+pub(crate) struct Entered {
+    span: Arc<Span>,
+    subscribers: Subscribers,
+    started: Instant,
+    error: Option<String>,
+}
+
+impl Entered {
+    fn open(subscribers: &Subscribers, span: Span) -> Option<Self> {
+        if subscribers.is_empty() {
+            return None;
+        }
+        let span = Arc::new(span);
+        for subscriber in subscribers.iter() {
+            subscriber.enter(&span);
+        }
+        Some(Self { span, subscribers: Arc::clone(subscribers), started: clock::real_now(), error: None })
+    }
+
+    /// Emit'in context'i altına girilmeden çağrılır; o anki context tetikleyen emit'inkidir.
+    pub(crate) fn emit(subscribers: &Subscribers, event: &RuntimeEvent, context: &Context, listener_count: usize) -> Option<Self> {
+        if subscribers.is_empty() {
+            return None;
+        }
+        let (trace_id, span_id) = span_ids(context);
+        let parent_id = current_span().map(|span| span.span_id.clone()).or_else(|| match context::current()?.trace {
+            Some(trace) => Some(to_hex(&trace.parent_id)),
+            None => context.causation_id.map(|causation| format!("{:016x}", causation.0 as u64)),
+        });
+        let span = EmitSpan { event, context, listener_count };
+        let (name, attributes) = (span.span_name(), span.attributes());
+        Self::open(subscribers, Span { kind: SpanKind::Publish, name, trace_id, span_id, parent_id, attributes })
+    }
+
+    pub(crate) fn handler(
+        subscribers: &Subscribers,
+        event: &RuntimeEvent,
+        context: &Context,
+        tag: &str,
+        label: &str,
+    ) -> Option<Self> {
+        if subscribers.is_empty() {
+            return None;
+        }
+        let (trace_id, parent) = span_ids(context);
+        let span = HandlerSpan { event, context, tag, label, elapsed: Duration::ZERO, failure: None };
+        let (name, attributes) = (span.span_name(), span.attributes());
+        Self::open(
+            subscribers,
+            Span {
+                kind: SpanKind::Process,
+                name,
+                trace_id,
+                span_id: format!("{:016x}", random_u64().max(1)),
+                parent_id: Some(parent),
+                attributes,
+            },
+        )
+    }
+}
+
+impl Drop for Entered {
+    fn drop(&mut self) {
+        let elapsed = self.started.elapsed();
+        for subscriber in self.subscribers.iter() {
+            subscriber.exit(&self.span, elapsed, self.error.as_deref());
+        }
+    }
+}
+
+/// `f`'yi handler span'i `current_span` olarak çalıştırır.
+pub(crate) async fn scoped<F: Future>(span: Option<&Entered>, f: F) -> F::Output {
+    match span {
+        Some(entered) => CURRENT.scope(Arc::clone(&entered.span), f).await,
+        None => f.await,
+    }
+}
+
+pub(crate) fn sync_scoped<R>(span: Option<&Entered>, f: impl FnOnce() -> R) -> R {
+    match span {
+        Some(entered) => CURRENT.sync_scope(Arc::clone(&entered.span), f),
+        None => f(),
+    }
+}
+
+/// Handler span'ini sonucuyla kapatır.
+pub(crate) fn close(span: Option<Entered>, failure: Option<&HandlerFailure>) {
+    if let (Some(mut entered), Some(failure)) = (span, failure) {
+        entered.error = Some(failure.message.clone());
+    }
+}
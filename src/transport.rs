@@ -0,0 +1,768 @@
+use futures::future::BoxFuture;
+use futures::stream::BoxStream;
+use std::{fmt, time::Duration};
+
+use crate::compression::{Compression, CompressionConfig};
+use crate::config::RetryPolicy;
+use crate::error::{Error, Result};
+use crate::event_bus::RuntimeEventBus;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::spool::SpoolConfig;
+
+/// Transport üzerinden taşınan tek bir event: event adı, başlıklar ve codec ile kodlanmış payload.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Frame {
+    pub event: String,
+    pub headers: Vec<(String, String)>,
+    pub payload: Vec<u8>,
+}
+
+impl Frame {
+    pub fn new(event: impl Into<String>, payload: Vec<u8>) -> Self {
+        Self { event: event.into(), headers: Vec::new(), payload }
+    }
+
+    pub fn with_header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Başlık adları büyük/küçük harf duyarsız karşılaştırılır.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(key, _)| key.eq_ignore_ascii_case(name))
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// Payload eşiği aşıyorsa sıkıştırır; algoritma ve orijinal uzunluk başlıklara yazılır.
+    /// Sıkıştırma boyutu küçültmüyorsa frame olduğu gibi kalır.
+    pub fn compress(mut self, config: &CompressionConfig) -> Self {
+        if self.payload.len() < config.threshold || self.header(ENCODING_HEADER).is_some() {
+            return self;
+        }
+        let compressed = config.algorithm.compress(&self.payload);
+        if compressed.len() >= self.payload.len() {
+            return self;
+        }
+        let decoded_len = std::mem::replace(&mut self.payload, compressed).len();
+        self.with_header(ENCODING_HEADER, config.algorithm.name())
+            .with_header(DECODED_LENGTH_HEADER, decoded_len.to_string())
+    }
+
+    /// `rumt-encoding` başlığı varsa payload'ı açar ve sıkıştırma başlıklarını kaldırır.
+    /// Bilinmeyen algoritma veya bozuk veri `Error::Transport` döner.
+    pub fn decompress(mut self) -> Result<Self> {
+        let Some(encoding) = self.header(ENCODING_HEADER) else {
+            return Ok(self);
+        };
+        let algorithm = Compression::from_name(encoding)
+            .ok_or_else(|| Error::Transport(format!("unsupported frame encoding `{encoding}`")))?;
+        let decoded_len = self
+            .header(DECODED_LENGTH_HEADER)
+            .and_then(|length| length.parse::<usize>().ok())
+            .filter(|length| *length <= MAX_DECODED_LEN)
+            .ok_or_else(|| Error::Transport("missing or invalid decoded length".into()))?;
+        self.payload = algorithm
+            .decompress(&self.payload, decoded_len)
+            .map_err(|e| Error::Transport(e.to_string()))?;
+        self.headers.retain(|(name, _)| {
+            !name.eq_ignore_ascii_case(ENCODING_HEADER) && !name.eq_ignore_ascii_case(DECODED_LENGTH_HEADER)
+        });
+        Ok(self)
+    }
+}
+
+/// Payload'ın sıkıştırma algoritması (bkz. `Compression::name`).
+pub const ENCODING_HEADER: &str = "rumt-encoding";
+/// Sıkıştırılmamış payload'ın bayt cinsinden uzunluğu.
+pub const DECODED_LENGTH_HEADER: &str = "rumt-decoded-length";
+/// `rumt.transport.hello` frame'inde gönderenin açabildiği algoritmalar, virgülle ayrılmış.
+pub const ACCEPT_ENCODING_HEADER: &str = "rumt-accept-encoding";
+/// `TransportManager`'ların bağlanınca yayınladığı kontrol frame'i; payload'ı boştur, başlıkları
+/// `rumt-origin` ve `rumt-accept-encoding`'dir. Bus'a emit edilmez.
+pub const HELLO_EVENT: &str = "rumt.transport.hello";
+// Bozuk veya kötü niyetli başlıklar yüzünden aşırı bellek ayrılmasın diye
+const MAX_DECODED_LEN: usize = 256 * 1024 * 1024;
+
+/// Bir aboneliğin hangi frame'leri alacağı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum FrameFilter {
+    All,
+    /// Yalnızca adı listede olan eventler.
+    Events(Vec<String>),
+    /// Adı verilen önekle başlayan eventler (ör. `order.`).
+    Prefix(String),
+}
+
+impl FrameFilter {
+    pub fn events<I: IntoIterator<Item = S>, S: Into<String>>(names: I) -> Self {
+        FrameFilter::Events(names.into_iter().map(Into::into).collect())
+    }
+
+    pub fn matches(&self, event: &str) -> bool {
+        match self {
+            FrameFilter::All => true,
+            FrameFilter::Events(names) => names.iter().any(|name| name == event),
+            FrameFilter::Prefix(prefix) => event.starts_with(prefix.as_str()),
+        }
+    }
+}
+
+/// Transport'un bağlantı durumu.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum TransportHealth {
+    Connected,
+    /// Bağlı ama sorunlu (ör. yavaş broker, kuyruk dolmak üzere).
+    Degraded(String),
+    Disconnected(String),
+}
+
+impl fmt::Display for TransportHealth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TransportHealth::Connected => write!(f, "connected"),
+            TransportHealth::Degraded(reason) => write!(f, "degraded: {reason}"),
+            TransportHealth::Disconnected(reason) => write!(f, "disconnected: {reason}"),
+        }
+    }
+}
+
+/// `TransportManager` bağlantısı kurulduğunda (ilk kez veya kopmadan sonra) yayınlanır.
+pub const TRANSPORT_CONNECTED: &str = "rumt.transport.connected";
+/// Yayın veya abonelik koptuğunda ve yeniden deneme hakkı tükendiğinde yayınlanır.
+pub const TRANSPORT_DISCONNECTED: &str = "rumt.transport.disconnected";
+/// Her yeniden bağlanma denemesinden önce, bekleme süresiyle birlikte yayınlanır.
+pub const TRANSPORT_RETRYING: &str = "rumt.transport.retrying";
+
+/// `TransportManager` bağlantısının durumu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LinkState {
+    Connected,
+    Disconnected,
+    Retrying,
+}
+
+impl LinkState {
+    /// Duruma geçildiğinde yayınlanan event'in adı.
+    pub fn event_name(&self) -> &'static str {
+        match self {
+            LinkState::Connected => TRANSPORT_CONNECTED,
+            LinkState::Disconnected => TRANSPORT_DISCONNECTED,
+            LinkState::Retrying => TRANSPORT_RETRYING,
+        }
+    }
+}
+
+/// Bağlantı eventlerinin payload'ı ve `transport_status` sonucu.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct TransportStatus {
+    /// Manager'ın tag'i.
+    pub transport: String,
+    pub state: LinkState,
+    /// Kopmadan bu yana kaçıncı yeniden bağlanma denemesinde olunduğu; bağlıyken 0.
+    pub attempt: u32,
+    /// `Retrying` durumunda denemeden önce beklenecek süre.
+    pub backoff: Option<Duration>,
+    /// Son bağlantı hatası; bağlantı yeniden kurulduktan sonra da saklanır.
+    pub last_error: Option<String>,
+}
+
+/// Başlatılmış `TransportManager`'ların son bağlantı durumları, tag sırasıyla. Sağlık
+/// kontrollerinde broker kesintilerini göstermek için kullanılır; durum değişiklikleri
+/// ayrıca `rumt.transport.*` eventleriyle yayınlanır.
+///
+/// ```rust,ignore
+/// let down: Vec<_> = rumt::transport_status().await?
+///     .into_iter()
+///     .filter(|status| status.state != LinkState::Connected)
+///     .collect();
+/// ```
+pub async fn transport_status() -> Result<Vec<TransportStatus>> {
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.transports.values().cloned().collect()).await
+}
+
+/// Abonelikten gelen frame'ler. Bağlantı koptuğunda akış sona erer.
+pub type FrameStream = BoxStream<'static, Frame>;
+
+/// Süreçler arası event taşıyan dış sistem (NATS, Kafka, IPC soketi vb.).
+///
+/// Hatalar `Error::Transport` ile döner. Üçüncü parti crate'ler kendi broker'ları için
+/// bu trait'i uygulayabilir (ör. rdkafka üzerine kurulu bir Kafka transport'u); test için bkz.
+/// `rumt::loopback::LoopbackTransport`.
+///
+/// `TransportManager` abonelik akışından bir sonraki frame'i, öncekini bus'a emit edip
+/// `Sequential`/`Concurrent` modlarda handler'ları bitince ister (`Queued` modlarda kuyruğa
+/// alınınca). Tüketici gruplu broker'lar önceki kaydın offset'ini bu noktada commit ederek
+/// handler onayına bağlı, en az bir kez teslim sağlayabilir. Partition anahtarı gerekiyorsa
+/// `publish` frame başlıklarından (ör. `rumt-correlation-id`) üretebilir.
+///
+/// ```rust,ignore
+/// impl EventTransport for NatsTransport {
+///     fn name(&self) -> &str { "nats" }
+///     fn connect(&self) -> BoxFuture<'_, Result<()>> { Box::pin(self.client.connect()) }
+///     fn publish(&self, frame: Frame) -> BoxFuture<'_, Result<()>> {
+///         Box::pin(async move { self.client.publish(frame.event, frame.payload).await })
+///     }
+///     fn subscribe(&self, filter: FrameFilter) -> BoxFuture<'_, Result<FrameStream>> { .. }
+///     fn health(&self) -> TransportHealth { self.client.status().into() }
+/// }
+/// ```
+pub trait EventTransport: Send + Sync + 'static {
+    fn name(&self) -> &str;
+
+    /// Bağlantıyı kurar; bağlıyken tekrar çağrılması zararsız olmalıdır.
+    fn connect(&self) -> BoxFuture<'_, Result<()>>;
+
+    fn publish(&self, frame: Frame) -> BoxFuture<'_, Result<()>>;
+
+    /// Akış yalnızca önceki frame işlendikten sonra poll edilir; bkz. trait dökümanı.
+    fn subscribe(&self, filter: FrameFilter) -> BoxFuture<'_, Result<FrameStream>>;
+
+    fn health(&self) -> TransportHealth;
+}
+
+/// `TransportManager` ayarları.
+#[derive(Clone, Debug, Default)]
+pub struct TransportOptions {
+    pub retry: RetryPolicy,
+    /// Verilirse eşiği aşan giden payload'lar, algoritmayı açabildiğini duyuran eşler varsa
+    /// sıkıştırılır (bkz. `TransportManager`). Gelen sıkıştırılmış frame'ler bu ayardan bağımsız
+    /// olarak her zaman açılır.
+    pub compression: Option<CompressionConfig>,
+    /// Verilirse bağlantı yokken seçilen giden frame'ler diske biriktirilir ve bağlantı geri
+    /// gelince sırayla gönderilir (bkz. `TransportManager`).
+    #[cfg(not(target_arch = "wasm32"))]
+    pub spool: Option<SpoolConfig>,
+}
+
+/// Frame'i yayınlayan runtime'ı belirten başlık; kendi yayınlarının geri gelmesini önler.
+pub const ORIGIN_HEADER: &str = "rumt-origin";
+/// Emit zincirinin korelasyon kimliği (32 haneli onaltılık).
+pub const CORRELATION_HEADER: &str = "rumt-correlation-id";
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use manager::TransportManager;
+
+#[cfg(not(target_arch = "wasm32"))]
+mod manager {
+    use futures::StreamExt;
+    use futures::future::BoxFuture;
+    use std::{
+        collections::HashMap,
+        sync::{
+            Arc, Mutex as StdMutex,
+            atomic::{AtomicU64, Ordering},
+        },
+        time::Duration,
+    };
+
+    use tokio::sync::mpsc;
+
+    use super::{
+        ACCEPT_ENCODING_HEADER, CORRELATION_HEADER, EventTransport, Frame, FrameFilter, FrameStream, HELLO_EVENT, LinkState,
+        ORIGIN_HEADER, TransportHealth, TransportOptions, TransportStatus,
+    };
+    use crate::clock;
+    use crate::codec::{CodecError, PayloadCodec};
+    use crate::compression::Compression;
+    use crate::config::RetryPolicy;
+    use crate::context::{self, Context, EventId};
+    use crate::error::{Error, Result};
+    use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg};
+    use crate::spool::{SYNC_COMPLETED, SYNC_STARTED, Spool, SyncReport};
+    use crate::ticker::{self, TickerStop};
+    use crate::trace::TraceContext;
+
+    type InboundRoute = Box<dyn Fn(Frame) -> std::result::Result<BoxFuture<'static, Result<()>>, CodecError> + Send + Sync>;
+
+    tokio::task_local! {
+        // Transport'tan gelen bir event işlenirken aynı manager'a geri yayınlanmasını önlemek için
+        static INBOUND: String;
+    }
+
+    /// Bir `EventTransport`'u bus'a bağlar: yönlendirme kuralları, codec, yeniden bağlanma.
+    ///
+    /// `route_out` ile bus eventleri codec'le kodlanıp dış adlarıyla yayınlanır; `route_in` ile
+    /// dış adla gelen frame'ler çözülüp bus'a emit edilir. Giden frame'ler sırayla gönderilir;
+    /// yayın hata verirse `connect` tekrar denenir ve `RetryPolicy`'ye göre beklenir, denemeler
+    /// tükenirse frame bırakılır (`dropped`). Abonelik koptuğunda süresiz olarak yeniden bağlanılır.
+    /// Emit context'inin korelasyon kimliği ve trace bilgisi frame başlıklarıyla taşınır.
+    ///
+    /// `TransportOptions::spool` verilirse seçilen frame'ler bırakılmaz: yayın ilk denemede hata
+    /// verdiğinde veya bağlantı koptuğunda diske yazılır ve sonraki frame'ler de sıra bozulmasın
+    /// diye arkalarına eklenir. Bağlantı `RetryPolicy` aralıklarıyla yeniden denenir; kurulunca
+    /// `rumt.sync.started` yayınlanır, frame'ler sırayla gönderilir ve `rumt.sync.completed`
+    /// `SyncReport` ile sonucu bildirir. Önceki çalışmadan kalan frame'ler `start`'ta gönderilir.
+    /// Senkronizasyon sırasında süreç kapanırsa gönderilmiş frame'ler tekrar gönderilebilir.
+    ///
+    /// Sıkıştırma eşlerle anlaşılarak yapılır: gelen yönlendirmesi veya `compression` ayarı olan
+    /// manager'lar bağlantı kurulunca (ve her yeniden bağlanmada) açabildiği algoritmaları
+    /// `rumt.transport.hello` frame'iyle duyurur ve ilk kez duyduğu eşe kendi duyurusuyla cevap
+    /// verir. Giden frame'ler ancak en az bir eş duyulmuşsa ve duyulan tüm eşler algoritmayı
+    /// açabiliyorsa sıkıştırılır; duyuru yapmayan eski sürümler ve diğer abonelere giden
+    /// frame'ler bu yüzden ilk duyuru gelene kadar sıkıştırılmaz. `peer_encodings` anlaşılan
+    /// algoritmaları döner.
+    ///
+    /// Bağlantı durumu değiştikçe `rumt.transport.connected`, `.disconnected` ve `.retrying`
+    /// eventleri `TransportStatus` payload'ıyla yayınlanır; son durum `transport_status` ile okunur.
+    ///
+    /// Manager runtime'a aittir: `dispose` ile veya `shutdown_runtime` sırasında durdurulur.
+    ///
+    /// ```rust,ignore
+    /// let nats = TransportManager::new("nats", NatsTransport::new("nats://broker:4222"));
+    /// nats.route_out(order_created(), "orders.created", OrderJsonCodec).await?;
+    /// nats.route_in("payments.received", payment_received(), PaymentJsonCodec);
+    /// nats.start().await?;
+    /// ```
+    pub struct TransportManager {
+        tag: String,
+        origin: String,
+        options: TransportOptions,
+        transport: Arc<dyn EventTransport>,
+        // Dış ad -> hedef bus event'i ve çözümleyici
+        inbound: StdMutex<HashMap<String, (RuntimeEvent, Arc<InboundRoute>)>>,
+        outbound: mpsc::UnboundedSender<Frame>,
+        pending: StdMutex<Option<mpsc::UnboundedReceiver<Frame>>>,
+        dropped: AtomicU64,
+        status: StdMutex<TransportStatus>,
+        spool: Arc<StdMutex<Option<Spool>>>,
+        // Duyuru yapan eşlerin origin'i -> açabildiği algoritmalar
+        peers: Arc<StdMutex<HashMap<String, Vec<Compression>>>>,
+    }
+
+    impl TransportManager {
+        pub fn new(tag: impl Into<String>, transport: impl EventTransport) -> Arc<Self> {
+            Self::with_options(tag, transport, TransportOptions::default())
+        }
+
+        pub fn with_retry(tag: impl Into<String>, transport: impl EventTransport, retry: RetryPolicy) -> Arc<Self> {
+            Self::with_options(tag, transport, TransportOptions { retry, ..Default::default() })
+        }
+
+        pub fn with_options(tag: impl Into<String>, transport: impl EventTransport, options: TransportOptions) -> Arc<Self> {
+            let (outbound, pending) = mpsc::unbounded_channel();
+            let tag: String = tag.into();
+            let status = TransportStatus {
+                transport: tag.clone(),
+                state: LinkState::Disconnected,
+                attempt: 0,
+                backoff: None,
+                last_error: None,
+            };
+            Arc::new(Self {
+                tag,
+                origin: format!("{:032x}", EventId::generate().0),
+                options,
+                transport: Arc::new(transport),
+                inbound: StdMutex::new(HashMap::new()),
+                outbound,
+                pending: StdMutex::new(Some(pending)),
+                dropped: AtomicU64::new(0),
+                status: StdMutex::new(status),
+                spool: Arc::new(StdMutex::new(None)),
+                peers: Arc::new(StdMutex::new(HashMap::new())),
+            })
+        }
+
+        /// Bus'taki `event`i `external_name` adıyla transport'a yayınlar. `start`'tan önce
+        /// yayınlanan eventler bağlantı kurulunca gönderilir.
+        pub async fn route_out<T, C>(&self, event: RuntimeEvent, external_name: impl Into<String>, codec: C) -> Result<()>
+        where
+            T: Send + Sync + 'static,
+            C: PayloadCodec<T>,
+        {
+            let external_name: String = external_name.into();
+            let origin = self.origin.clone();
+            let tag = self.tag.clone();
+            let outbound = self.outbound.clone();
+            let compression = self.options.compression;
+            let peers = Arc::clone(&self.peers);
+
+            let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+                let echoed = INBOUND.try_with(|manager| *manager == tag).unwrap_or(false);
+                let encoded = args
+                    .downcast::<Arc<T>>()
+                    .filter(|_| !echoed)
+                    .and_then(|payload| codec.encode(payload).ok());
+                if let Some(bytes) = encoded {
+                    let mut frame = Frame::new(external_name.clone(), bytes).with_header(ORIGIN_HEADER, origin.clone());
+                    if let Some(context) = context::current() {
+                        frame = frame.with_header(CORRELATION_HEADER, format!("{:032x}", context.correlation_id.0));
+                        frame.headers.extend(context.trace.iter().flat_map(TraceContext::to_headers));
+                    }
+                    if let Some(compression) = &compression
+                        && peers_accept(&peers, compression.algorithm)
+                    {
+                        frame = frame.compress(compression);
+                    }
+                    let _ = outbound.send(frame);
+                }
+                Box::pin(async {}) as BoxFuture<'static, ()>
+            });
+
+            let listener = RuntimeEventListener::new(self.tag.clone(), handler);
+            RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await
+        }
+
+        /// `external_name` adıyla gelen frame'leri `event` olarak bus'a emit eder.
+        /// Abonelik bu adlarla yapıldığı için kurallar `start`'tan önce eklenmelidir;
+        /// sonradan eklenenler bir sonraki yeniden bağlanmada geçerli olur.
+        pub fn route_in<T, C>(&self, external_name: impl Into<String>, event: RuntimeEvent, codec: C)
+        where
+            T: Send + Sync + 'static,
+            C: PayloadCodec<T>,
+        {
+            let target = event.clone();
+            let route: InboundRoute = Box::new(move |frame: Frame| {
+                let payload = codec.decode(&frame.payload)?;
+                let event = event.clone();
+                let correlation_id = frame
+                    .header(CORRELATION_HEADER)
+                    .and_then(|id| u128::from_str_radix(id, 16).ok())
+                    .map(EventId)
+                    .unwrap_or_else(EventId::generate);
+                let mut context = Context::with_correlation_id(correlation_id);
+                let headers = frame.headers.iter().map(|(name, value)| (name.as_str(), value.as_str()));
+                if let Some(trace) = TraceContext::from_headers(headers) {
+                    context = context.with_trace(trace);
+                }
+                Ok(Box::pin(context::scope(context, crate::try_emit_event(event, payload))) as BoxFuture<'static, _>)
+            });
+            self.inbound
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(external_name.into(), (target, Arc::new(route)));
+        }
+
+        /// Bağlantıyı kurar, `route_in` adlarına abone olur ve bekleyen frame'leri göndermeye başlar.
+        /// İlk bağlantı veya abonelik kurulamazsa hata döner; ikinci çağrı `Error::Transport` döner.
+        pub async fn start(self: &Arc<Self>) -> Result<()> {
+            let already_started = || Error::Transport(format!("transport `{}` is already started", self.tag));
+            if self.pending.lock().unwrap_or_else(|e| e.into_inner()).is_none() {
+                return Err(already_started());
+            }
+            if let Some(config) = &self.options.spool {
+                let opened = config.clone();
+                let spool = tokio::task::spawn_blocking(move || Spool::open(opened))
+                    .await
+                    .map_err(std::io::Error::other)
+                    .and_then(|opened| opened)
+                    .map_err(|e| Error::Transport(format!("cannot open spool `{}`: {e}", config.path.display())))?;
+                *self.spool.lock().unwrap_or_else(|e| e.into_inner()) = Some(spool);
+            }
+            let connected = async {
+                self.transport.connect().await?;
+                self.subscribe().await
+            };
+            let frames = match connected.await {
+                Ok(frames) => frames,
+                Err(e) => {
+                    self.report(LinkState::Disconnected, 0, None, Some(e.to_string())).await;
+                    return Err(e);
+                }
+            };
+            let events: Vec<RuntimeEvent> = self
+                .inbound
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .values()
+                .map(|(event, _)| event.clone())
+                .collect();
+            let stop = ticker::register_source(source_name(&self.tag), &events).await?;
+            let pending = self
+                .pending
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .take()
+                .ok_or_else(already_started)?;
+            self.report(LinkState::Connected, 0, None, None).await;
+
+            crate::rt::spawn(Arc::clone(self).publish_loop(pending, Arc::clone(&stop)));
+            crate::rt::spawn(Arc::clone(self).subscribe_loop(frames, stop));
+            Ok(())
+        }
+
+        pub fn health(&self) -> TransportHealth {
+            self.transport.health()
+        }
+
+        /// Manager'ın son bağlantı durumu.
+        pub fn status(&self) -> TransportStatus {
+            self.status.lock().unwrap_or_else(|e| e.into_inner()).clone()
+        }
+
+        /// Duyuru yapan tüm eşlerin açabildiği algoritmalar; henüz eş duyulmadıysa boştur.
+        pub fn peer_encodings(&self) -> Vec<Compression> {
+            let peers = self.peers.lock().unwrap_or_else(|e| e.into_inner());
+            Compression::SUPPORTED
+                .iter()
+                .copied()
+                .filter(|algorithm| !peers.is_empty() && peers.values().all(|accepted| accepted.contains(algorithm)))
+                .collect()
+        }
+
+        /// Bağlantı beklerken spool'da duran frame sayısı.
+        pub fn spooled(&self) -> usize {
+            self.with_spool(|spool| spool.len()).unwrap_or(0)
+        }
+
+        /// Denemeler tükendiği veya spool dolduğu için gönderilemeyen frame sayısı.
+        pub fn dropped(&self) -> u64 {
+            self.dropped.load(Ordering::Relaxed)
+        }
+
+        /// Dinleyicileri kaldırır ve arka plan task'larını durdurur.
+        pub async fn dispose(&self) {
+            let tag = self.tag.clone();
+            let _ = RuntimeEventBus::try_with_instance_mut(|bus| {
+                bus.remove_all_listeners_by_tag(&tag);
+                bus.transports.remove(&tag);
+            })
+            .await;
+            ticker::stop_ticker(&source_name(&self.tag)).await;
+        }
+
+        async fn publish_loop(self: Arc<Self>, mut pending: mpsc::UnboundedReceiver<Frame>, stop: Arc<TickerStop>) {
+            // Spool'da frame varken bir sonraki senkronizasyon denemesinin zamanı
+            let mut sync_at = (self.spooled() > 0).then(clock::now);
+            let mut failures = 0;
+            'frames: loop {
+                let frame = tokio::select! {
+                    frame = pending.recv() => frame,
+                    _ = clock::sleep_until(sync_at.unwrap_or_else(clock::now)), if sync_at.is_some() => {
+                        match self.sync().await {
+                            Ok(()) => {
+                                failures = 0;
+                                sync_at = None;
+                                self.report(LinkState::Connected, 0, None, None).await;
+                            }
+                            Err(e) => {
+                                failures += 1;
+                                let backoff = self.options.retry.backoff(failures);
+                                sync_at = Some(clock::now() + backoff);
+                                self.report(LinkState::Retrying, failures, Some(backoff), Some(e.to_string())).await;
+                            }
+                        }
+                        continue;
+                    }
+                    _ = stop.stopped() => return,
+                };
+                let Some(frame) = frame else { return };
+                let connected = self.status.lock().unwrap_or_else(|e| e.into_inner()).state == LinkState::Connected;
+                if self.spool_accepts(&frame) && (!connected || self.spooled() > 0) {
+                    self.spool(frame).await;
+                    sync_at = sync_at.or_else(|| Some(clock::now() + self.options.retry.backoff(1)));
+                    continue;
+                }
+                let attempts = self.options.retry.max_attempts.max(1);
+                let mut attempt = 1;
+                while let Err(e) = self.transport.publish(frame.clone()).await {
+                    let error = Some(e.to_string());
+                    if attempt == 1 && self.spool_accepts(&frame) {
+                        self.report(LinkState::Disconnected, 0, None, error).await;
+                        self.spool(frame).await;
+                        sync_at = sync_at.or_else(|| Some(clock::now() + self.options.retry.backoff(1)));
+                        continue 'frames;
+                    }
+                    if attempt == attempts {
+                        self.dropped.fetch_add(1, Ordering::Relaxed);
+                        self.report(LinkState::Disconnected, attempt - 1, None, error).await;
+                        break;
+                    }
+                    if attempt == 1 {
+                        self.report(LinkState::Disconnected, 0, None, error.clone()).await;
+                    }
+                    let backoff = self.options.retry.backoff(attempt);
+                    self.report(LinkState::Retrying, attempt, Some(backoff), error).await;
+                    tokio::select! {
+                        _ = clock::sleep(backoff) => {}
+                        _ = stop.stopped() => return,
+                    }
+                    let _ = self.transport.connect().await;
+                    attempt += 1;
+                }
+                if attempt > 1 {
+                    self.report(LinkState::Connected, 0, None, None).await;
+                }
+            }
+        }
+
+        /// Bağlantıyı kurar ve spool'daki frame'leri sırayla gönderir; bir yayın hata verirse
+        /// kalanlar spool'da bırakılır ve hata döner.
+        async fn sync(&self) -> Result<()> {
+            self.transport.connect().await?;
+            let Some((pending, expired)) = self.with_spool(|spool| {
+                let expired = spool.expire();
+                (spool.len(), expired)
+            }) else {
+                return Ok(());
+            };
+            let mut report = SyncReport { transport: self.tag.clone(), pending, sent: 0, expired };
+            crate::global::emit_internal(RuntimeEvent::Static { event_name: SYNC_STARTED.into() }, report.clone()).await;
+            let mut result = Ok(());
+            while let Some(frame) = self.with_spool(|spool| spool.front()).flatten() {
+                if let Err(e) = self.transport.publish(frame).await {
+                    result = Err(e);
+                    break;
+                }
+                self.with_spool(Spool::pop_front);
+                report.sent += 1;
+            }
+            // Yazılamazsa dosyada kalan frame'ler bir sonraki açılışta tekrar gönderilir
+            let _ = self.with_spool_file(|spool| spool.persist()).await;
+            report.pending = self.spooled();
+            crate::global::emit_internal(RuntimeEvent::Static { event_name: SYNC_COMPLETED.into() }, report).await;
+            result
+        }
+
+        fn with_spool<R>(&self, f: impl FnOnce(&mut Spool) -> R) -> Option<R> {
+            self.spool.lock().unwrap_or_else(|e| e.into_inner()).as_mut().map(f)
+        }
+
+        // Dosya işlemleri bloklayan I/O olduğu için runtime thread'lerini meşgul etmez
+        async fn with_spool_file<R: Send + 'static>(&self, f: impl FnOnce(&mut Spool) -> R + Send + 'static) -> Option<R> {
+            let spool = Arc::clone(&self.spool);
+            tokio::task::spawn_blocking(move || spool.lock().unwrap_or_else(|e| e.into_inner()).as_mut().map(f))
+                .await
+                .ok()
+                .flatten()
+        }
+
+        fn spool_accepts(&self, frame: &Frame) -> bool {
+            self.with_spool(|spool| spool.accepts(frame)).unwrap_or(false)
+        }
+
+        // Dolu veya yazılamayan spool'a gelen frame bırakılır
+        async fn spool(&self, frame: Frame) {
+            if !matches!(self.with_spool_file(|spool| spool.push(frame)).await, Some(Ok(true))) {
+                self.dropped.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        async fn subscribe_loop(self: Arc<Self>, mut frames: FrameStream, stop: Arc<TickerStop>) {
+            loop {
+                loop {
+                    let frame = tokio::select! {
+                        frame = frames.next() => frame,
+                        _ = stop.stopped() => return,
+                    };
+                    let Some(frame) = frame else { break };
+                    if frame.header(ORIGIN_HEADER) == Some(self.origin.as_str()) {
+                        continue;
+                    }
+                    if self.receive(frame).await == Err(Error::NotInitialized) {
+                        return;
+                    }
+                }
+
+                // Kopan abonelik artan aralıklarla yeniden kurulur
+                let mut error = "subscription closed".to_string();
+                self.report(LinkState::Disconnected, 0, None, Some(error.clone())).await;
+                let mut failures = 0;
+                frames = loop {
+                    failures += 1;
+                    let backoff = self.options.retry.backoff(failures);
+                    self.report(LinkState::Retrying, failures, Some(backoff), Some(error)).await;
+                    tokio::select! {
+                        _ = clock::sleep(backoff) => {}
+                        _ = stop.stopped() => return,
+                    }
+                    let resubscribed = async {
+                        self.transport.connect().await?;
+                        self.subscribe().await
+                    };
+                    match resubscribed.await {
+                        Ok(frames) => break frames,
+                        Err(e) => error = e.to_string(),
+                    }
+                };
+                self.report(LinkState::Connected, 0, None, None).await;
+            }
+        }
+
+        /// Durumu günceller, bus'taki kaydını yeniler ve ilgili `rumt.transport.*` event'ini yayınlar.
+        /// Aynı duruma tekrar geçiş bildirilmez; her yeniden deneme ayrıca bildirilir.
+        async fn report(&self, state: LinkState, attempt: u32, backoff: Option<Duration>, error: Option<String>) {
+            let status = {
+                let mut status = self.status.lock().unwrap_or_else(|e| e.into_inner());
+                if status.state == state && state != LinkState::Retrying {
+                    return;
+                }
+                status.state = state;
+                status.attempt = attempt;
+                status.backoff = backoff;
+                if error.is_some() {
+                    status.last_error = error;
+                }
+                status.clone()
+            };
+            let tag = self.tag.clone();
+            let registered = status.clone();
+            let _ = RuntimeEventBus::try_with_instance_mut(|bus| bus.transports.insert(tag, registered)).await;
+            let event = RuntimeEvent::Static { event_name: state.event_name().into() };
+            crate::global::emit_internal(event, status).await;
+        }
+
+        async fn subscribe(&self) -> Result<FrameStream> {
+            let mut names: Vec<String> = self.inbound.lock().unwrap_or_else(|e| e.into_inner()).keys().cloned().collect();
+            // Gelen yönlendirmesi ve sıkıştırması olmayan manager anlaşmaya katılmaz
+            let negotiates = !names.is_empty() || self.options.compression.is_some();
+            if negotiates {
+                names.push(HELLO_EVENT.to_string());
+            }
+            let frames = self.transport.subscribe(FrameFilter::Events(names)).await?;
+            if negotiates {
+                self.hello().await;
+            }
+            Ok(frames)
+        }
+
+        // Duyuru yayınlanamazsa eşler bir sonraki bağlantıda veya kendi duyurularına cevapta öğrenir
+        async fn hello(&self) {
+            let accepted: Vec<&str> = Compression::SUPPORTED.iter().map(Compression::name).collect();
+            let frame = Frame::new(HELLO_EVENT, Vec::new())
+                .with_header(ORIGIN_HEADER, self.origin.clone())
+                .with_header(ACCEPT_ENCODING_HEADER, accepted.join(","));
+            let _ = self.transport.publish(frame).await;
+        }
+
+        async fn greet(&self, frame: &Frame) {
+            let Some(origin) = frame.header(ORIGIN_HEADER) else { return };
+            let accepted = frame
+                .header(ACCEPT_ENCODING_HEADER)
+                .unwrap_or_default()
+                .split(',')
+                .filter_map(Compression::from_name)
+                .collect();
+            let first = self.peers.lock().unwrap_or_else(|e| e.into_inner()).insert(origin.to_string(), accepted).is_none();
+            if first {
+                self.hello().await;
+            }
+        }
+
+        async fn receive(&self, frame: Frame) -> Result<()> {
+            if frame.event == HELLO_EVENT {
+                self.greet(&frame).await;
+                return Ok(());
+            }
+            let route = self.inbound.lock().unwrap_or_else(|e| e.into_inner()).get(&frame.event).map(|(_, route)| Arc::clone(route));
+            let Some(route) = route else { return Ok(()) };
+            // Açılamayan veya çözülemeyen frame'ler atlanır
+            let Ok(frame) = frame.decompress() else { return Ok(()) };
+            match route(frame) {
+                Ok(emit) => INBOUND.scope(self.tag.clone(), emit).await,
+                Err(_) => Ok(()),
+            }
+        }
+    }
+
+    fn source_name(tag: &str) -> String {
+        format!("transport:{tag}")
+    }
+
+    fn peers_accept(peers: &StdMutex<HashMap<String, Vec<Compression>>>, algorithm: Compression) -> bool {
+        let peers = peers.lock().unwrap_or_else(|e| e.into_inner());
+        !peers.is_empty() && peers.values().all(|accepted| accepted.contains(&algorithm))
+    }
+}
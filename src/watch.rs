@@ -0,0 +1,145 @@
+use std::{
+    collections::HashMap,
+    fs,
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use crate::error::{Error, Result};
+use crate::event_bus::RuntimeEvent;
+use crate::ticker;
+
+/// Dosya sistemi değişikliklerinin yayınlandığı event adı.
+pub const FS_CHANGED: &str = "fs.changed";
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum FsChangeKind {
+    Created,
+    Modified,
+    Removed,
+}
+
+/// `fs.changed` event'inin payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct FsChange {
+    /// Değişikliğin altında olduğu env yolunun adı (`insert_path` ile verilen anahtar).
+    pub name: String,
+    pub path: PathBuf,
+    pub kind: FsChangeKind,
+}
+
+pub fn fs_changed_event() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: FS_CHANGED.into() }
+}
+
+// Dosya başına son görülen değişiklik zamanı ve boyutu
+type Snapshot = HashMap<PathBuf, (Option<SystemTime>, u64)>;
+
+/// Env'e `insert_path` ile eklenmiş yolları (dosya veya dizin, alt dizinler dahil) her
+/// `interval` sürede bir tarar ve değişiklikleri `fs.changed` event'i olarak `FsChange`
+/// payload'ı ile yayınlar. İzleyici başladığında var olan dosyalar bildirilmez.
+///
+/// İzleyici runtime'a aittir; `stop_fs_watcher` veya `shutdown_runtime` ile durur.
+/// Tarama dosya sistemi bildirimleri yerine değişiklik zamanı ve boyut karşılaştırmasıyla yapılır.
+///
+/// ```rust,ignore
+/// let env = RuntimeModuleEnv::<Unlocked>::new()
+///     .add_app_info("Importer", "MyCompany", "com")
+///     .insert_path("inbox", "/var/spool/importer")
+///     .try_lock_env()?;
+/// rumt::try_init_runtime(env).await?;
+/// rumt::watch::start_fs_watcher(Duration::from_millis(500)).await?;
+/// ```
+pub async fn start_fs_watcher(interval: Duration) -> Result<()> {
+    let roots: Vec<(String, PathBuf)> = crate::try_runtime_env()?
+        .as_ref()
+        .map(|env| env.paths.iter().map(|(name, path)| (name.clone(), PathBuf::from(path))).collect())
+        .unwrap_or_default();
+    let event = fs_changed_event();
+    let stop = ticker::register_source(FS_CHANGED.into(), std::slice::from_ref(&event)).await?;
+
+    crate::rt::spawn(async move {
+        let mut previous = scan_roots(roots.clone()).await;
+        let mut interval = ticker::Interval::new(interval);
+        while stop.tick(&mut interval).await {
+            let current = scan_roots(roots.clone()).await;
+            for change in diff(&roots, &previous, &current) {
+                // Runtime kapatıldıysa izleyici kendiliğinden sonlanır
+                if crate::try_emit_event(event.clone(), change).await == Err(Error::NotInitialized) {
+                    return;
+                }
+            }
+            previous = current;
+        }
+    });
+    Ok(())
+}
+
+/// Çalışan izleyiciyi durdurur; izleyici yoksa `false` döner.
+pub async fn stop_fs_watcher() -> bool {
+    ticker::stop_ticker(FS_CHANGED).await
+}
+
+async fn scan_roots(roots: Vec<(String, PathBuf)>) -> Snapshot {
+    // Dizin taraması bloklayan I/O olduğu için runtime thread'lerini meşgul etmez
+    tokio::task::spawn_blocking(move || {
+        let mut snapshot = Snapshot::new();
+        for (_, root) in &roots {
+            scan(root, &mut snapshot);
+        }
+        snapshot
+    })
+    .await
+    .unwrap_or_default()
+}
+
+fn scan(path: &Path, snapshot: &mut Snapshot) {
+    // Sembolik bağlar takip edilmez; döngüsel dizinlerde tarama sonlanır
+    let Ok(metadata) = fs::symlink_metadata(path) else {
+        return;
+    };
+    if metadata.is_dir() {
+        let Ok(entries) = fs::read_dir(path) else {
+            return;
+        };
+        for entry in entries.flatten() {
+            scan(&entry.path(), snapshot);
+        }
+    } else {
+        snapshot.insert(path.to_path_buf(), (metadata.modified().ok(), metadata.len()));
+    }
+}
+
+fn diff(roots: &[(String, PathBuf)], previous: &Snapshot, current: &Snapshot) -> Vec<FsChange> {
+    let change = |path: &PathBuf, kind| FsChange {
+        name: root_name(roots, path),
+        path: path.clone(),
+        kind,
+    };
+    let mut changes: Vec<FsChange> = current
+        .iter()
+        .filter_map(|(path, state)| match previous.get(path) {
+            None => Some(change(path, FsChangeKind::Created)),
+            Some(old) if old != state => Some(change(path, FsChangeKind::Modified)),
+            Some(_) => None,
+        })
+        .chain(
+            previous
+                .keys()
+                .filter(|path| !current.contains_key(*path))
+                .map(|path| change(path, FsChangeKind::Removed)),
+        )
+        .collect();
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+// İç içe env yollarında en uzun (en özel) kök seçilir
+fn root_name(roots: &[(String, PathBuf)], path: &Path) -> String {
+    roots
+        .iter()
+        .filter(|(_, root)| path.starts_with(root))
+        .max_by_key(|(_, root)| root.as_os_str().len())
+        .map(|(name, _)| name.clone())
+        .unwrap_or_default()
+}
@@ -0,0 +1,251 @@
+use futures::future::BoxFuture;
+use std::{
+    sync::{Arc, PoisonError, RwLock},
+    time::Duration,
+};
+
+use crate::clock;
+use crate::codec::PayloadCodec;
+use crate::config::RetryPolicy;
+use crate::context;
+use crate::crypto::{hmac_sha256, to_hex};
+use crate::error::{Error, Result};
+use crate::event_bus::{RuntimeEvent, RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg};
+use crate::global::try_runtime_env;
+use crate::http::{self, HttpUrl};
+use crate::log::Level;
+use crate::reload::{CONFIG_CHANGED, ConfigChanged};
+use crate::telemetry::event_name;
+
+/// Tüm denemeleri başarısız olan gönderimlerin yayınlandığı event adı.
+pub const WEBHOOK_FAILED: &str = "webhook.failed";
+
+/// Webhook gönderim ayarları.
+#[derive(Clone, Debug)]
+pub struct WebhookConfig {
+    /// Verilirse gövde HMAC-SHA256 ile imzalanır ve `X-Rumt-Signature: sha256=<hex>` başlığı eklenir.
+    pub secret: Option<String>,
+    pub retry: RetryPolicy,
+    /// Tek bir denemenin bağlantı ve yanıt dahil süre sınırı.
+    pub timeout: Duration,
+    pub content_type: String,
+}
+
+impl Default for WebhookConfig {
+    fn default() -> Self {
+        Self {
+            secret: None,
+            retry: RetryPolicy::default(),
+            timeout: Duration::from_secs(10),
+            content_type: "application/octet-stream".into(),
+        }
+    }
+}
+
+impl WebhookConfig {
+    /// Başlıklara yazılan değerleri kontrol eder; `content_type` CR/LF veya kontrol karakteri
+    /// içeriyorsa `Error::Config` döner. `WebhookPublisher::route` bunu kendisi çağırır.
+    pub fn validate(&self) -> Result<()> {
+        match http::is_header_value(&self.content_type) {
+            true => Ok(()),
+            false => Err(Error::Config(format!("invalid webhook content type `{}`", self.content_type.escape_debug()))),
+        }
+    }
+}
+
+/// `webhook.failed` payload'ı.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct WebhookFailure {
+    pub event: String,
+    pub url: String,
+    pub attempts: u32,
+    pub error: String,
+}
+
+/// Seçilen eventleri HTTP `POST` ile dış adreslere gönderir.
+///
+/// Payload codec ile gövdeye çevrilir; gönderim emit'i bekletmeden arka planda yapılır.
+/// Bağlantı hataları, zaman aşımları, 5xx, 408 ve 429 yanıtları `RetryPolicy`'ye göre tekrar
+/// denenir; diğer 4xx yanıtları kalıcı hata sayılır. Her istekte `X-Rumt-Event` ve emit'in
+/// kimliğini taşıyan `X-Rumt-Delivery` başlıkları bulunur (alıcı tarafta tekrarları ayıklamak için).
+/// Süren gönderimler `shutdown_runtime` sırasında `BusConfig::shutdown_drain_timeout` kadar
+/// beklenir.
+///
+/// ```rust,ignore
+/// let webhooks = WebhookPublisher::new("webhooks", WebhookConfig {
+///     secret: Some("s3cr3t".into()),
+///     content_type: "application/json".into(),
+///     ..Default::default()
+/// });
+/// webhooks.route(order_created(), "http://crm.internal/hooks/orders", OrderJsonCodec).await?;
+/// ```
+pub struct WebhookPublisher {
+    tag: String,
+    config: Arc<WebhookConfig>,
+}
+
+impl WebhookPublisher {
+    pub fn new(tag: impl Into<String>, config: WebhookConfig) -> Self {
+        Self { tag: tag.into(), config: Arc::new(config) }
+    }
+
+    /// `event` her yayınlandığında payload'ı `url`'e gönderir. Adres geçersizse
+    /// (ör. `https`, TLS desteklenmez) `Error::Transport`; event adı `X-Rumt-Event` başlığına
+    /// yazılamıyorsa (HTTP token'ı değilse) veya config geçersizse `Error::Config` döner.
+    pub async fn route<T, C>(&self, event: RuntimeEvent, url: &str, codec: C) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        self.add_target(event, Endpoint::parse(url)?, codec).await.map(|_| ())
+    }
+
+    /// `route` gibi, ama adres env'in `key` yolundan okunur (`insert_path` veya
+    /// `config_loader`). `reload_config` bu yolu değiştirdiğinde sonraki gönderimler yeni adrese
+    /// yapılır; yeni adres geçersizse uyarı loglanır ve eskisi korunur. Yol yoksa `Error::Config`
+    /// döner.
+    ///
+    /// ```rust,ignore
+    /// let env = RuntimeModuleEnv::new()
+    ///     .insert_path("webhooks.orders", "http://crm.internal/hooks/orders")
+    ///     .config_loader(|| read_settings("/etc/shop.toml"))
+    ///     .try_lock_env()?;
+    /// webhooks.route_config(order_created(), "webhooks.orders", OrderJsonCodec).await?;
+    /// ```
+    pub async fn route_config<T, C>(&self, event: RuntimeEvent, key: &str, codec: C) -> Result<()>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        let url = {
+            let guard = try_runtime_env()?;
+            let env = guard.as_ref().ok_or(Error::NotInitialized)?;
+            env.paths.get(key).cloned().ok_or_else(|| Error::Config(format!("missing webhook target `{key}`")))?
+        };
+        let target = self.add_target(event, Endpoint::parse(&url)?, codec).await?;
+
+        let key = key.to_string();
+        let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+            let changed = args.downcast::<Arc<ConfigChanged>>().and_then(|changed| changed.paths.get(&key).cloned());
+            let target = Arc::clone(&target);
+            Box::pin(async move {
+                let Some(url) = changed else { return };
+                match Endpoint::parse(&url) {
+                    Ok(endpoint) => *target.endpoint.write().unwrap_or_else(PoisonError::into_inner) = endpoint,
+                    Err(e) => crate::log(Level::Warn, "rumt::webhook", format!("keeping previous target: {e}")).await,
+                }
+            }) as BoxFuture<'static, ()>
+        });
+        let listener = RuntimeEventListener::new(self.tag.clone(), handler);
+        let reloaded = RuntimeEvent::Static { event_name: CONFIG_CHANGED.into() };
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(reloaded, listener)).await
+    }
+
+    async fn add_target<T, C>(&self, event: RuntimeEvent, endpoint: Endpoint, codec: C) -> Result<Arc<Target>>
+    where
+        T: Send + Sync + 'static,
+        C: PayloadCodec<T>,
+    {
+        self.config.validate()?;
+        let name = event_name(&event);
+        if !http::is_token(name) {
+            return Err(Error::Config(format!("event `{}` cannot be sent as an `X-Rumt-Event` header", name.escape_debug())));
+        }
+        let target = Arc::new(Target {
+            event: name.to_string(),
+            endpoint: RwLock::new(endpoint),
+            config: Arc::clone(&self.config),
+        });
+
+        let delivering = Arc::clone(&target);
+        let handler = Box::new(move |args: &dyn RuntimeEventListenerHandlerArg| {
+            if let Some(payload) = args.downcast::<Arc<T>>() {
+                let target = Arc::clone(&delivering);
+                let delivery = context::current().map(|c| format!("{:032x}", c.event_id.0)).unwrap_or_default();
+                match codec.encode(payload) {
+                    Ok(body) => crate::rt::spawn_tracked(async move { target.deliver(delivery, body).await }),
+                    Err(e) => crate::rt::spawn_tracked(async move { target.fail(0, e.to_string()).await }),
+                }
+            }
+            Box::pin(async {}) as BoxFuture<'static, ()>
+        });
+
+        let listener = RuntimeEventListener::new(self.tag.clone(), handler);
+        RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(event, listener)).await?;
+        Ok(target)
+    }
+
+    /// Publisher'ın bus'a eklediği tüm yönlendirmeleri kaldırır. Süren gönderimler tamamlanır.
+    pub async fn dispose(&self) {
+        let tag = self.tag.clone();
+        let _ = RuntimeEventBus::try_with_instance_mut(|bus| bus.remove_all_listeners_by_tag(&tag)).await;
+    }
+}
+
+struct Target {
+    event: String,
+    endpoint: RwLock<Endpoint>,
+    config: Arc<WebhookConfig>,
+}
+
+#[derive(Clone)]
+struct Endpoint {
+    url: String,
+    address: HttpUrl,
+}
+
+impl Endpoint {
+    fn parse(url: &str) -> Result<Self> {
+        Ok(Self { url: url.to_string(), address: HttpUrl::parse(url)? })
+    }
+}
+
+impl Target {
+    fn endpoint(&self) -> Endpoint {
+        self.endpoint.read().unwrap_or_else(PoisonError::into_inner).clone()
+    }
+
+    async fn deliver(&self, delivery: String, body: Vec<u8>) {
+        // Denemeler arasında adres değişse de gönderim başladığı adrese tamamlanır
+        let endpoint = self.endpoint();
+        let mut headers = vec![
+            ("Content-Type".to_string(), self.config.content_type.clone()),
+            ("X-Rumt-Event".to_string(), self.event.clone()),
+            ("X-Rumt-Delivery".to_string(), delivery),
+        ];
+        if let Some(secret) = &self.config.secret {
+            let signature = to_hex(&hmac_sha256(secret.as_bytes(), &body));
+            headers.push(("X-Rumt-Signature".to_string(), format!("sha256={signature}")));
+        }
+
+        let retry = self.config.retry;
+        let attempts = retry.max_attempts.max(1);
+        let mut error = String::new();
+        for attempt in 1..=attempts {
+            match http::post(&endpoint.address, &headers, &body, self.config.timeout).await {
+                Ok(response) if (200..300).contains(&response.status) => return,
+                Ok(response) => {
+                    error = format!("http status {}", response.status);
+                    let retryable = response.status >= 500 || matches!(response.status, 408 | 429);
+                    if !retryable {
+                        return self.report(&endpoint.url, attempt, error).await;
+                    }
+                }
+                Err(e) => error = e.to_string(),
+            }
+            if attempt < attempts {
+                clock::sleep(retry.backoff(attempt)).await;
+            }
+        }
+        self.report(&endpoint.url, attempts, error).await;
+    }
+
+    async fn fail(&self, attempts: u32, error: String) {
+        self.report(&self.endpoint().url, attempts, error).await;
+    }
+
+    async fn report(&self, url: &str, attempts: u32, error: String) {
+        let failure = WebhookFailure { event: self.event.clone(), url: url.to_string(), attempts, error };
+        let _ = crate::try_emit_event(RuntimeEvent::Static { event_name: WEBHOOK_FAILED.into() }, failure).await;
+    }
+}
@@ -0,0 +1,106 @@
+#![cfg(feature = "actix")]
+
+use rumt::actix::{ActixRecipient, subscribe, subscribe_replies};
+use rumt::futures::future::BoxFuture;
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc, oneshot};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+#[derive(Debug)]
+pub struct Quote {
+    pub sku: String,
+}
+
+#[derive(Debug)]
+pub struct PriceQuoted {
+    pub sku: String,
+    pub price: u32,
+}
+
+pub struct QuoteCollector {
+    pub quotes: Arc<Mutex<Vec<(String, u32)>>>,
+}
+
+impl QuoteCollector {
+    pub async fn on_quoted(&self, arg: &PriceQuoted) {
+        self.quotes.lock().await.push((arg.sku.clone(), arg.price));
+    }
+}
+
+rumt::event_handlers! {
+    QuoteCollector;
+    RuntimeEvent::Static { event_name: "actix.pricing.quoted".into() } => async on_quoted : PriceQuoted
+}
+
+/// actix `Recipient<Quote>`'un yerine geçen sahte adres; aktör kanaldan okuyan bir task'tır
+struct FakeRecipient {
+    mailbox: mpsc::UnboundedSender<(Quote, Option<oneshot::Sender<PriceQuoted>>)>,
+}
+
+impl ActixRecipient<Quote> for FakeRecipient {
+    type Reply = PriceQuoted;
+
+    fn do_send(&self, message: Quote) {
+        let _ = self.mailbox.send((message, None));
+    }
+
+    fn send(&self, message: Quote) -> BoxFuture<'static, Result<PriceQuoted, String>> {
+        let (reply, replied) = oneshot::channel();
+        let sent = self.mailbox.send((message, Some(reply)));
+        Box::pin(async move {
+            sent.map_err(|_| "mailbox closed".to_string())?;
+            replied.await.map_err(|_| "mailbox closed".to_string())
+        })
+    }
+}
+
+fn pricing_actor(seen: Arc<Mutex<Vec<String>>>) -> FakeRecipient {
+    let (mailbox, mut inbox) = mpsc::unbounded_channel::<(Quote, Option<oneshot::Sender<PriceQuoted>>)>();
+    tokio::spawn(async move {
+        while let Some((quote, reply)) = inbox.recv().await {
+            seen.lock().await.push(quote.sku.clone());
+            // "free-" ile başlayan ürünlerde aktör cevap vermeden durur
+            if let Some(reply) = reply.filter(|_| !quote.sku.starts_with("free-")) {
+                let _ = reply.send(PriceQuoted { price: quote.sku.len() as u32 * 10, sku: quote.sku });
+            }
+        }
+    });
+    FakeRecipient { mailbox }
+}
+
+#[tokio::test]
+async fn test_actix_adapter_forwards_and_publishes_replies() {
+    setup_runtime().await;
+    let quotes = Arc::new(Mutex::new(Vec::new()));
+    let _collector = QuoteCollector { quotes: Arc::clone(&quotes) }.try_init().await.unwrap();
+    let requested = RuntimeEvent::Static { event_name: "actix.pricing.requested".into() };
+    let quoted = RuntimeEvent::Static { event_name: "actix.pricing.quoted".into() };
+    let to_quote = |payload: Arc<TestPayload>| Quote { sku: payload.data.clone() };
+
+    // `do_send` ile iletilen mesajların cevabı yayınlanmaz
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let audit = subscribe("audit", requested.clone(), pricing_actor(Arc::clone(&seen)), to_quote).await.unwrap();
+    let replies = subscribe_replies("pricing", requested.clone(), quoted, pricing_actor(Arc::default()), to_quote)
+        .await
+        .unwrap();
+
+    rumt::emit_event(requested.clone(), TestPayload { data: "SKU-1".into() }).await;
+    rumt::emit_event(requested.clone(), TestPayload { data: "free-sample".into() }).await;
+    assert_eq!(*quotes.lock().await, vec![("SKU-1".to_string(), 50)]);
+    for _ in 0..200 {
+        if seen.lock().await.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(std::time::Duration::from_millis(5)).await;
+    }
+    assert_eq!(*seen.lock().await, vec!["SKU-1".to_string(), "free-sample".into()]);
+
+    audit.unsubscribe().await;
+    replies.unsubscribe().await;
+    rumt::emit_event(requested, TestPayload { data: "SKU-2".into() }).await;
+    assert_eq!(quotes.lock().await.len(), 1);
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,72 @@
+use rumt::actor::{EventPublisher, subscribe_actor};
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::{Mutex, mpsc};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+/// Aktörün kabul ettiği mesaj tipi
+#[derive(Debug, PartialEq)]
+enum PricingMsg {
+    Quote { sku: String },
+}
+
+#[derive(Debug)]
+pub struct PriceQuoted {
+    pub sku: String,
+    pub price: u32,
+}
+
+pub struct QuoteCollector {
+    pub quotes: Arc<Mutex<Vec<(String, u32)>>>,
+}
+
+impl QuoteCollector {
+    pub async fn on_quoted(&self, arg: &PriceQuoted) {
+        self.quotes.lock().await.push((arg.sku.clone(), arg.price));
+    }
+}
+
+rumt::event_handlers! {
+    QuoteCollector;
+    RuntimeEvent::Static { event_name: "pricing.quoted".into() } => async on_quoted : PriceQuoted
+}
+
+#[tokio::test]
+async fn test_actor_receives_events_and_publishes_replies() {
+    setup_runtime().await;
+
+    let quotes = Arc::new(Mutex::new(Vec::new()));
+    let _controller = QuoteCollector { quotes: Arc::clone(&quotes) }.try_init().await.unwrap();
+
+    // Basit bir "aktör": mesajları kanaldan okur, cevapları yayınlar
+    let (mailbox, mut inbox) = mpsc::channel::<PricingMsg>(8);
+    let (reply_tx, reply_rx) = mpsc::channel::<PriceQuoted>(8);
+    let pump = EventPublisher::new(RuntimeEvent::Static { event_name: "pricing.quoted".into() }).pump(reply_rx);
+
+    let subscription = subscribe_actor(
+        "PricingActor",
+        RuntimeEvent::Static { event_name: "pricing.requested".into() },
+        mailbox,
+        |payload: Arc<TestPayload>| PricingMsg::Quote { sku: payload.data.clone() },
+    )
+    .await
+    .unwrap();
+
+    let event = RuntimeEvent::Static { event_name: "pricing.requested".into() };
+    rumt::emit_event(event, TestPayload { data: "SKU-1".into() }).await;
+
+    let PricingMsg::Quote { sku } = inbox.recv().await.unwrap();
+    assert_eq!(sku, "SKU-1");
+    reply_tx.send(PriceQuoted { sku, price: 120 }).await.unwrap();
+    drop(reply_tx);
+    pump.await.unwrap();
+
+    assert_eq!(quotes.lock().await.as_slice(), [("SKU-1".to_string(), 120)]);
+
+    subscription.unsubscribe().await;
+    let event = RuntimeEvent::Static { event_name: "pricing.requested".into() };
+    rumt::emit_event(event, TestPayload { data: "SKU-2".into() }).await;
+    assert!(inbox.try_recv().is_err());
+}
@@ -0,0 +1,79 @@
+use rumt::Error;
+use rumt::auth::{ClientPermissions, GatewayAuth};
+use rumt::transport::FrameFilter;
+
+mod common;
+
+fn gateway_auth() -> GatewayAuth {
+    GatewayAuth::new()
+        .client(
+            "dashboard",
+            "tok_dashboard",
+            ClientPermissions::none()
+                .subscribe(FrameFilter::Prefix("metrics.".into()))
+                .emit(FrameFilter::events(["dashboard.refresh"])),
+        )
+        .client("admin", "tok_admin", ClientPermissions::none().subscribe(FrameFilter::All).emit(FrameFilter::All))
+}
+
+#[test]
+fn test_gateway_auth_tokens_and_allowlists() {
+    let auth = gateway_auth();
+
+    let dashboard = auth.authenticate("tok_dashboard").unwrap();
+    assert_eq!(dashboard.id, "dashboard");
+    assert!(dashboard.can_subscribe("metrics.cpu"));
+    assert!(!dashboard.can_subscribe("orders.created"));
+    assert!(dashboard.check_emit("dashboard.refresh").is_ok());
+    assert!(matches!(dashboard.check_emit("orders.created"), Err(Error::Unauthorized(_))));
+    assert!(dashboard.check_subscribe("orders.created").is_err());
+
+    let admin = auth.authenticate_bearer(Some("Bearer tok_admin")).unwrap();
+    assert!(admin.can_emit("orders.created"));
+
+    assert!(matches!(auth.authenticate("tok_unknown"), Err(Error::Unauthorized(_))));
+    assert!(auth.authenticate_bearer(None).is_err());
+    assert!(auth.authenticate_bearer(Some("Basic dG9r")).is_err());
+
+    // Aynı token yeniden eklenirse yeni istemci geçerli olur
+    let auth = auth.client("rotated", "tok_admin", ClientPermissions::none());
+    let rotated = auth.authenticate("tok_admin").unwrap();
+    assert_eq!(rotated.id, "rotated");
+    assert!(!rotated.can_emit("orders.created"));
+}
+
+#[cfg(feature = "webhook")]
+#[tokio::test]
+async fn test_ingress_requires_authorized_client() {
+    use rumt::ingress::WebhookIngress;
+    use rumt::prelude::*;
+    use rumt::schema::PayloadSchema;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    common::setup_runtime().await;
+
+    let addr = WebhookIngress::new()
+        .auth(gateway_auth())
+        .route("/orders", RuntimeEvent::Static { event_name: "orders.created".into() }, PayloadSchema::new())
+        .start("auth.orders", "127.0.0.1:0")
+        .await
+        .unwrap();
+
+    let post = |authorization: Option<&'static str>| async move {
+        let mut stream = tokio::net::TcpStream::connect(addr).await.unwrap();
+        let mut request = String::from("POST /orders HTTP/1.1\r\nContent-Length: 2\r\n");
+        if let Some(authorization) = authorization {
+            request.push_str(&format!("Authorization: {authorization}\r\n"));
+        }
+        request.push_str("\r\n{}");
+        stream.write_all(request.as_bytes()).await.unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).await.unwrap();
+        response.split_whitespace().nth(1).unwrap().to_string()
+    };
+
+    assert_eq!(post(Some("Bearer tok_admin")).await, "202");
+    assert_eq!(post(Some("Bearer tok_dashboard")).await, "403");
+    assert_eq!(post(Some("Bearer tok_unknown")).await, "401");
+    assert_eq!(post(None).await, "401");
+}
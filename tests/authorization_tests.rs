@@ -0,0 +1,108 @@
+use rumt::policy::{Access, Decision, PayloadMeta, SandboxPolicy};
+use rumt::prelude::*;
+use rumt::{Error, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+type Outcomes = Arc<Mutex<Vec<(String, bool)>>>;
+
+// Plugin'in kaydettiği, kendi namespace'ine kapatılmış servis
+pub struct WeatherPlugin {
+    pub outcomes: Outcomes,
+}
+
+impl WeatherPlugin {
+    pub async fn on_refresh(&self, arg: &TestPayload) {
+        let event = RuntimeEvent::Static { event_name: arg.data.clone() };
+        let result = rumt::try_emit_event(event, TestPayload { data: "sunny".into() }).await;
+        if let Err(e) = &result {
+            assert!(matches!(e, Error::Unauthorized(_)), "{e}");
+        }
+        self.outcomes.lock().unwrap().push((arg.data.clone(), result.is_ok()));
+    }
+}
+
+rumt::event_handlers! {
+    WeatherPlugin;
+    RuntimeEvent::Static { event_name: "plugins.weather.refresh".into() } => async on_refresh : TestPayload
+}
+
+// Sandbox dışındaki bir event'i dinlemeye çalışan plugin
+pub struct SnoopingPlugin;
+
+impl SnoopingPlugin {
+    pub fn on_charge(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    SnoopingPlugin;
+    RuntimeEvent::Static { event_name: "billing.charge".into() } => on_charge : TestPayload
+}
+
+pub struct Renderer {
+    pub frames: Arc<Mutex<Vec<String>>>,
+}
+
+impl Renderer {
+    pub fn on_render(&self, arg: &TestPayload) {
+        self.frames.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Renderer;
+    RuntimeEvent::Static { event_name: "ui.render".into() } => on_render : TestPayload
+}
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+#[tokio::test]
+async fn test_authorization_policy_sandboxes_plugins() {
+    let sandbox = SandboxPolicy::new()
+        .sandbox("WeatherPlugin", ["plugins.weather.*"])
+        .sandbox("WeatherPlugin", ["ui.render"])
+        .sandbox("SnoopingPlugin", ["plugins.snoop.*"]);
+    // Uygulamanın kendi büyük payload'larını da sınırlayan bir kapanış
+    let policy = move |source: Option<&str>, event: &RuntimeEvent, meta: &PayloadMeta<'_>| {
+        if meta.access == Access::Emit && meta.type_name == Some(std::any::type_name::<[u8; 4096]>()) {
+            return Decision::Deny("payload too large".into());
+        }
+        rumt::AuthorizationPolicy::allow(&sandbox, source, event, meta)
+    };
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("AuthzApp", "MyCompany", "com")
+        .authorization(policy)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    // Kayıt anında kontrol
+    assert!(matches!(SnoopingPlugin.try_init().await, Err(Error::Unauthorized(reason)) if reason.contains("billing.charge")));
+    let snapshot = rumt::export_registrations().await.unwrap();
+    assert!(snapshot.registrations().iter().all(|r| r.tag != "SnoopingPlugin"));
+
+    let outcomes = Outcomes::default();
+    let frames = Arc::new(Mutex::new(Vec::new()));
+    let _plugin = WeatherPlugin { outcomes: Arc::clone(&outcomes) }.try_init().await.unwrap();
+    let _renderer = Renderer { frames: Arc::clone(&frames) }.try_init().await.unwrap();
+
+    // Emit anında kontrol: kaynak, emit'i yapan handler'ın tag'idir
+    for target in ["ui.render", "billing.charge", "plugins.weather.updated"] {
+        rumt::emit_event(event("plugins.weather.refresh"), TestPayload { data: target.into() }).await;
+    }
+    assert_eq!(
+        *outcomes.lock().unwrap(),
+        vec![("ui.render".into(), true), ("billing.charge".into(), false), ("plugins.weather.updated".into(), true)]
+    );
+    assert_eq!(*frames.lock().unwrap(), vec!["sunny"]);
+
+    // Handler dışından yapılan emit'lerin kaynağı yoktur; sandbox uygulanmaz
+    rumt::try_emit_event(event("billing.charge"), TestPayload { data: "app".into() }).await.unwrap();
+    assert!(matches!(
+        rumt::try_emit_event(event("billing.upload"), [0u8; 4096]).await,
+        Err(Error::Unauthorized(reason)) if reason == "payload too large"
+    ));
+}
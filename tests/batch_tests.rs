@@ -0,0 +1,79 @@
+use rumt::clock::ManualClock;
+use rumt::prelude::*;
+use rumt::{Coalesce, try_init_runtime};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+pub struct Quote {
+    pub price: u32,
+}
+
+fn quote_event() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "market.quote".into() }
+}
+
+// Fiyatları tek tek ve gruplar hâlinde kaydeden servis
+pub struct OrderBook {
+    pub batches: Arc<Mutex<Vec<Vec<u32>>>>,
+    pub singles: Arc<Mutex<Vec<u32>>>,
+}
+
+impl OrderBook {
+    pub async fn apply(&self, quotes: &[Arc<Quote>]) {
+        self.batches.lock().unwrap().push(quotes.iter().map(|quote| quote.price).collect());
+    }
+
+    pub fn audit(&self, quote: &Quote) {
+        self.singles.lock().unwrap().push(quote.price);
+    }
+}
+
+rumt::event_handlers! {
+    OrderBook;
+    quote_event() => async batch apply : Quote,
+    quote_event() => audit : Quote
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    for _ in 0..200 {
+        if condition() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("condition was never met");
+}
+
+#[tokio::test]
+async fn test_coalesced_emits_reach_batch_handlers() {
+    let clock = ManualClock::new();
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("BatchApp", "MyCompany", "com")
+        .clock(clock.clone())
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let batches = Arc::new(Mutex::new(Vec::new()));
+    let singles = Arc::new(Mutex::new(Vec::new()));
+    let _book = OrderBook { batches: Arc::clone(&batches), singles: Arc::clone(&singles) }.try_init().await.unwrap();
+    rumt::coalesce::<Quote>(quote_event(), Coalesce::new(Duration::from_millis(1)).max_items(3)).await.unwrap();
+
+    // Dolan gruplar pencere beklenmeden teslim edilir
+    for price in 1..=7 {
+        rumt::emit_event(quote_event(), Quote { price }).await;
+    }
+    assert_eq!(*batches.lock().unwrap(), vec![vec![1, 2, 3], vec![4, 5, 6]]);
+    assert_eq!(*singles.lock().unwrap(), (1..=7).collect::<Vec<_>>());
+
+    // Kalan payload pencere sonunda teslim edilir
+    wait_until(|| clock.sleepers() > 0).await;
+    clock.advance(Duration::from_millis(1));
+    wait_until(|| batches.lock().unwrap().len() == 3).await;
+    assert_eq!(batches.lock().unwrap()[2], vec![7]);
+
+    // Kapanışta bekleyen grup dinleyiciler kaldırılmadan önce teslim edilir
+    rumt::emit_event(quote_event(), Quote { price: 8 }).await;
+    rumt::emit_event(quote_event(), Quote { price: 9 }).await;
+    rumt::shutdown_runtime().await;
+    assert_eq!(batches.lock().unwrap()[3], vec![8, 9]);
+    assert_eq!(batches.lock().unwrap().len(), 4);
+}
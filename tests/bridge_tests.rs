@@ -0,0 +1,85 @@
+use futures::future::BoxFuture;
+use rumt::bridge::{BridgeSink, EventBridge, colon_separated};
+use rumt::codec::{CodecError, Utf8Codec, codec_fn};
+use rumt::prelude::*;
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+type SentMessages = Arc<StdMutex<Vec<(String, Vec<u8>)>>>;
+
+/// Webview'e giden mesajları kaydeden sahte sink
+#[derive(Clone, Default)]
+struct RecordingSink {
+    sent: SentMessages,
+}
+
+impl BridgeSink for RecordingSink {
+    fn send(&self, name: &str, payload: Vec<u8>) -> BoxFuture<'static, ()> {
+        self.sent.lock().unwrap().push((name.to_string(), payload));
+        Box::pin(async {})
+    }
+}
+
+pub struct CartService {
+    pub received: Arc<Mutex<Vec<String>>>,
+}
+
+impl CartService {
+    pub async fn on_item_added(&self, arg: &TestPayload) {
+        self.received.lock().await.push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    CartService;
+    RuntimeEvent::Static { event_name: "cart.item_added".into() } => async on_item_added : TestPayload
+}
+
+fn payload_codec() -> impl rumt::codec::PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_bridge_forwards_both_directions() {
+    setup_runtime().await;
+
+    let sink = RecordingSink::default();
+    let bridge = EventBridge::new("webview", sink.clone());
+
+    let status = RuntimeEvent::Static { event_name: "order.status".into() };
+    bridge.forward::<String, _>(status.clone(), colon_separated("order.status"), Utf8Codec).await.unwrap();
+
+    // Rust -> webview
+    rumt::emit_event(status, "shipped".to_string()).await;
+    assert_eq!(
+        sink.sent.lock().unwrap().as_slice(),
+        [("order:status".to_string(), b"shipped".to_vec())]
+    );
+
+    // webview -> Rust; aynı event dışarıya geri yansıtılmaz
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let _controller = CartService { received: Arc::clone(&received) }.try_init().await.unwrap();
+    let added = RuntimeEvent::Static { event_name: "cart.item_added".into() };
+    bridge.receive("cart:item_added", added.clone(), payload_codec());
+    bridge.forward(added, "cart:item_added", payload_codec()).await.unwrap();
+
+    bridge.handle_incoming("cart:item_added", b"kahve").await.unwrap();
+    assert_eq!(received.lock().await.as_slice(), ["kahve"]);
+    assert_eq!(sink.sent.lock().unwrap().len(), 1);
+
+    assert!(bridge.handle_incoming("cart:unknown", b"").await.is_err());
+
+    bridge.dispose().await;
+    rumt::emit_event(RuntimeEvent::Static { event_name: "order.status".into() }, "lost".to_string()).await;
+    assert_eq!(sink.sent.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,77 @@
+use rumt::cache::{Invalidation, InvalidationCodec};
+use rumt::codec::PayloadCodec;
+use rumt::prelude::*;
+use rumt::CacheInvalidated;
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::setup_runtime;
+
+#[derive(Debug, PartialEq)]
+pub struct Rates {
+    pub usd: u32,
+}
+
+// Paylaşılan önbellekten okuduğu değerin yerel kopyasını tutan servis
+pub struct PriceView {
+    pub local: Arc<Mutex<HashMap<String, u32>>>,
+    pub seen: Arc<Mutex<Vec<(String, Invalidation)>>>,
+}
+
+impl PriceView {
+    pub fn forget(&self, arg: &CacheInvalidated) {
+        self.local.lock().unwrap().remove(&arg.key);
+        // Dinleyici çalışırken önbellek zaten güncellenmiştir
+        let fresh = rumt::cache().get::<Rates>(&arg.key).map(|rates| rates.usd);
+        self.seen.lock().unwrap().push((format!("{}={fresh:?}", arg.key), arg.reason));
+    }
+}
+
+rumt::event_handlers! {
+    PriceView;
+    RuntimeEvent::Static { event_name: "rumt.cache.invalidated".into() } => forget : CacheInvalidated
+}
+
+#[tokio::test]
+async fn test_cache_writes_publish_invalidations() {
+    setup_runtime().await;
+    let local = Arc::new(Mutex::new(HashMap::from([("rates".to_string(), 30)])));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _view = PriceView { local: Arc::clone(&local), seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let cache = rumt::cache();
+    assert!(cache.put("rates", Rates { usd: 32 }).await.is_none());
+    assert!(local.lock().unwrap().is_empty());
+    let previous = cache.put("rates", Rates { usd: 33 }).await;
+    assert_eq!(previous.as_deref(), Some(&Rates { usd: 32 }));
+    assert_eq!(cache.get::<Rates>("rates").map(|rates| rates.usd), Some(33));
+    // Farklı tipte okuma değer döndürmez
+    assert!(cache.get::<String>("rates").is_none());
+
+    assert!(cache.remove("rates").await);
+    assert!(!cache.remove("rates").await);
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec![
+            ("rates=Some(32)".to_string(), Invalidation::Updated),
+            ("rates=Some(33)".to_string(), Invalidation::Updated),
+            ("rates=None".to_string(), Invalidation::Removed),
+        ]
+    );
+
+    // Önbellek runtime'a aittir
+    cache.put("session", 7_u64).await;
+    rumt::shutdown_runtime().await;
+    assert!(rumt::cache().is_empty());
+}
+
+#[test]
+fn test_invalidation_codec_round_trip() {
+    let codec = InvalidationCodec;
+    let invalidated = CacheInvalidated { key: "pricing.rates".into(), reason: Invalidation::Removed };
+    let bytes = codec.encode(&invalidated).unwrap();
+    assert_eq!(bytes, b"rpricing.rates");
+    assert_eq!(codec.decode(&bytes).unwrap(), invalidated);
+    assert!(codec.decode(b"").is_err());
+}
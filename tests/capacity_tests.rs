@@ -0,0 +1,49 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Unlocked};
+use std::time::Duration;
+
+mod common;
+use common::TestPayload;
+
+pub struct SlowStartService;
+
+impl SlowStartService {
+    pub async fn work(&self, arg: &TestPayload) {
+        if arg.data == "slow" {
+            tokio::time::sleep(Duration::from_millis(50)).await;
+        }
+    }
+}
+
+rumt::event_handlers! {
+    SlowStartService;
+    RuntimeEvent::Static { event_name: "capacity.work".into() } => async work : TestPayload
+}
+
+#[tokio::test]
+async fn test_presized_bus() {
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .bus_config(BusConfig {
+            mode: DispatchMode::Sequential,
+            event_capacity: 4096,
+            listener_capacity: 16,
+            queue_capacity: 256,
+            latency_window: 2,
+            ..Default::default()
+        })
+        .try_lock_env().unwrap();
+    rumt::try_init_runtime(env).await.unwrap();
+    SlowStartService.try_init().await.unwrap();
+
+    let work = || RuntimeEvent::Static { event_name: "capacity.work".into() };
+    rumt::emit_event(work(), TestPayload { data: "slow".into() }).await;
+    for _ in 0..2 {
+        rumt::emit_event(work(), TestPayload { data: "fast".into() }).await;
+    }
+
+    // Yüzdelikler yalnızca son 2 çalışmadan hesaplanır; yavaş ilk çalışma pencereden çıkmıştır
+    let latency = rumt::bus_stats().await.unwrap().latency;
+    assert_eq!(latency.handled, 3);
+    assert!(latency.max < Duration::from_millis(50));
+}
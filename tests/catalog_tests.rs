@@ -0,0 +1,70 @@
+use rumt::prelude::*;
+use rumt::schema::generate_module;
+
+mod common;
+use common::setup_runtime;
+
+mod events {
+    rumt::define_events! {
+        /// Sipariş oluşturulduğunda yayınlanır.
+        /// Tutar kuruş cinsindendir.
+        #[allow(dead_code)]
+        CATALOG_ORDER_CREATED = "catalog.order.created" => CatalogOrder { order_id: u64, lines: Vec<String> },
+        /// Uygulama hazır.
+        CATALOG_READY = once "catalog.ready",
+    }
+}
+
+use events::{CATALOG_ORDER_CREATED, CatalogOrder};
+
+pub struct Billing;
+
+impl Billing {
+    pub fn on_order(&self, _arg: &CatalogOrder) {}
+    pub fn on_refund(&self, _arg: &CatalogOrder) {}
+}
+
+rumt::event_handlers! {
+    Billing;
+    CATALOG_ORDER_CREATED.event() => on_order : CatalogOrder,
+    RuntimeEvent::Static { event_name: "catalog.refund".into() } => on_refund : CatalogOrder
+}
+
+#[tokio::test]
+async fn test_defined_events_are_described_at_runtime() {
+    assert_eq!(CATALOG_ORDER_CREATED.doc(), " Sipariş oluşturulduğunda yayınlanır.\n Tutar kuruş cinsindendir.\n");
+    assert_eq!(CATALOG_ORDER_CREATED.fields(), &[("order_id", "u64"), ("lines", "Vec<String>")]);
+    assert_eq!(events::EVENTS.len(), 2);
+
+    // Kayıtlı tanımlar runtime başlatılmadan da sorgulanabilir
+    rumt::register_events(events::EVENTS);
+    let ready = rumt::describe_event("catalog.ready").await.unwrap();
+    assert!(ready.once && ready.payload.is_none() && ready.listeners.is_empty());
+    assert_eq!(ready.doc, "Uygulama hazır.");
+
+    setup_runtime().await;
+    let _billing = Billing.try_init().await.unwrap();
+    let order = rumt::describe_event("catalog.order.created").await.unwrap();
+    assert_eq!(order.doc, "Sipariş oluşturulduğunda yayınlanır.\nTutar kuruş cinsindendir.");
+    assert_eq!(order.payload.as_deref(), Some("CatalogOrder"));
+    assert_eq!(order.listeners, vec!["Billing"]);
+    // Tanımı olmayan ama dinlenen eventler de katalogda yer alır
+    let refund = rumt::describe_event("catalog.refund").await.unwrap();
+    assert_eq!((refund.doc.as_str(), refund.payload), ("", None));
+    assert!(rumt::describe_event("catalog.unknown").await.is_none());
+
+    let catalog = rumt::event_catalog().await;
+    let json = catalog.to_json();
+    assert!(json.starts_with(
+        "{\"events\":[{\"name\":\"catalog.order.created\",\"once\":false,\
+         \"doc\":\"Sipariş oluşturulduğunda yayınlanır.\\nTutar kuruş cinsindendir.\",\
+         \"payload\":{\"type\":\"CatalogOrder\",\"fields\":{\"order_id\":\"u64\",\"lines\":\"Vec<String>\"}},\
+         \"listeners\":[\"Billing\"]}"
+    ));
+    // Katalog şema olarak geri okunabilir
+    let module = generate_module(&json).unwrap();
+    assert!(module.contains("CATALOG_READY = once \"catalog.ready\","));
+    assert!(module.contains("=> CatalogOrder { order_id: u64, lines: Vec<String> },"));
+
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,95 @@
+use rumt::clock::{Clock, ManualClock};
+use rumt::prelude::*;
+use rumt::ticker::Tick;
+use rumt::{BusConfig, EmitOptions, try_init_runtime};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+use common::TestPayload;
+
+pub struct ClockService {
+    pub ticks: Arc<Mutex<Vec<u64>>>,
+    pub orders: Arc<Mutex<Vec<String>>>,
+}
+
+impl ClockService {
+    pub fn on_tick(&self, tick: &Tick) {
+        self.ticks.lock().unwrap().push(tick.sequence);
+    }
+
+    pub fn on_order(&self, arg: &TestPayload) {
+        self.orders.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    ClockService;
+    RuntimeEvent::Static { event_name: "clock.hourly".into() } => on_tick : Tick,
+    RuntimeEvent::Static { event_name: "clock.order".into() } => on_order : TestPayload
+}
+
+// Ticker task'ı saatte beklemeye girene kadar gerçek zamanda kısa aralıklarla bekler
+async fn wait_for_sleeper(clock: &ManualClock) {
+    for _ in 0..200 {
+        if clock.sleepers() > 0 {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("ticker did not start waiting on the clock");
+}
+
+#[tokio::test]
+async fn test_manual_clock_drives_ticker_and_dedup_ttl() {
+    let clock = ManualClock::new();
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("ClockApp", "MyCompany", "com")
+        .bus_config(BusConfig { dedup_ttl: Duration::from_secs(60), ..Default::default() })
+        .clock(clock.clone())
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let (ticks, orders) = (Arc::default(), Arc::default());
+    let _service = ClockService { ticks: Arc::clone(&ticks), orders: Arc::clone(&orders) }.try_init().await.unwrap();
+
+    // Saatlik ticker, saat ilerletilmedikçe tick üretmez
+    let start = clock.now();
+    rumt::start_ticker("clock.hourly", Duration::from_secs(3600)).await.unwrap();
+    wait_for_sleeper(&clock).await;
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(ticks.lock().unwrap().is_empty());
+
+    clock.advance(Duration::from_secs(3600));
+    wait_for_sleeper(&clock).await;
+    assert_eq!(*ticks.lock().unwrap(), vec![1]);
+
+    // Kaçırılan tick'ler biriktirilmez
+    clock.advance(Duration::from_secs(3 * 3600));
+    wait_for_sleeper(&clock).await;
+    assert_eq!(*ticks.lock().unwrap(), vec![1, 2]);
+    assert!(rumt::stop_ticker("clock.hourly").await);
+
+    // Idempotency anahtarı, TTL saate göre dolana kadar tekrar sayılır
+    let order = RuntimeEvent::Static { event_name: "clock.order".into() };
+    let keyed = || EmitOptions { idempotency_key: Some("order-1".into()), ..Default::default() };
+    rumt::emit_with(order.clone(), TestPayload { data: "first".into() }, keyed()).await;
+    clock.advance(Duration::from_secs(59));
+    rumt::emit_with(order.clone(), TestPayload { data: "again".into() }, keyed()).await;
+    clock.advance(Duration::from_secs(2));
+    rumt::emit_with(order.clone(), TestPayload { data: "expired".into() }, keyed()).await;
+    assert_eq!(*orders.lock().unwrap(), vec!["first".to_string(), "expired".to_string()]);
+    assert_eq!(clock.now() - start, Duration::from_secs(4 * 3600 + 61));
+
+    // Süre sınırları da runtime saatiyle dolar
+    let waiting = tokio::spawn(rumt::clock::timeout(Duration::from_secs(30), std::future::pending::<()>()));
+    wait_for_sleeper(&clock).await;
+    clock.advance(Duration::from_secs(29));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(!waiting.is_finished());
+    clock.advance(Duration::from_secs(1));
+    assert_eq!(waiting.await.unwrap(), None);
+    assert_eq!(rumt::clock::timeout(Duration::from_secs(30), async { 7 }).await, Some(7));
+
+    rumt::shutdown_runtime().await;
+}
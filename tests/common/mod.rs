@@ -1,4 +1,7 @@
-use rumt::{Unlocked, init_runtime, prelude::*};
+// Her test dosyası yardımcıların yalnızca bir kısmını kullanır
+#![allow(dead_code)]
+
+use rumt::{Unlocked, prelude::*, try_init_runtime};
 use std::sync::Arc;
 use tokio::sync::Mutex;
 
@@ -13,9 +16,13 @@ pub async fn setup_runtime() {
     let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
     .add_app_info("MyApp", "MyCompany", "com")
     .insert_path("db", "/tmp/test.db")
-    .lock_env();
+    .try_lock_env().unwrap();
     
-    init_runtime(env).await;
+    // Aynı dosyadaki testler runtime'ı paylaşır; ilk başlatan kazanır
+    match try_init_runtime(env).await {
+        Ok(()) | Err(rumt::Error::AlreadyInitialized) => {}
+        Err(e) => panic!("{e}"),
+    }
 }
 // 2. Servis Yapısı
 pub struct InventoryService {
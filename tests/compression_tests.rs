@@ -0,0 +1,187 @@
+use futures::StreamExt;
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::compression::{Compression, CompressionConfig};
+use rumt::loopback::LoopbackTransport;
+use rumt::prelude::*;
+use rumt::transport::{
+    ACCEPT_ENCODING_HEADER, DECODED_LENGTH_HEADER, ENCODING_HEADER, EventTransport, Frame, FrameFilter, HELLO_EVENT,
+    ORIGIN_HEADER, TransportManager, TransportOptions,
+};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+fn analytics_payload(rows: usize) -> Vec<u8> {
+    let mut out = String::from("[");
+    for row in 0..rows {
+        out.push_str(&format!(r#"{{"session":"s-{}","page":"/products/{}","duration_ms":{}}},"#, row % 7, row % 13, row * 31 % 997));
+    }
+    out.push(']');
+    out.into_bytes()
+}
+
+#[test]
+fn test_lz4_round_trip() {
+    let mut noise = Vec::new();
+    let mut state = 0x2545f491u32;
+    for _ in 0..5000 {
+        state ^= state << 13;
+        state ^= state >> 17;
+        state ^= state << 5;
+        noise.push(state as u8);
+    }
+    let samples = [
+        Vec::new(),
+        b"a".to_vec(),
+        b"abcabcabcabca".to_vec(),
+        vec![7u8; 70_000],
+        noise,
+        analytics_payload(2000),
+    ];
+    for sample in samples {
+        let compressed = Compression::Lz4.compress(&sample);
+        assert_eq!(Compression::Lz4.decompress(&compressed, sample.len()).unwrap(), sample);
+    }
+
+    let payload = analytics_payload(2000);
+    let compressed = Compression::Lz4.compress(&payload);
+    assert!(compressed.len() * 4 < payload.len(), "{} -> {}", payload.len(), compressed.len());
+
+    // Yanlış uzunluk ve bozuk veri reddedilir
+    assert!(Compression::Lz4.decompress(&compressed, payload.len() - 1).is_err());
+    assert!(Compression::Lz4.decompress(&compressed[..compressed.len() / 2], payload.len()).is_err());
+    assert!(Compression::Lz4.decompress(&[0x0f, 0x01, 0x00], 19).is_err());
+}
+
+#[test]
+fn test_frame_compression_headers() {
+    let config = CompressionConfig::lz4(1024);
+    let small = Frame::new("analytics.batch", analytics_payload(5)).compress(&config);
+    assert_eq!(small.header(ENCODING_HEADER), None);
+
+    let payload = analytics_payload(2000);
+    let frame = Frame::new("analytics.batch", payload.clone()).compress(&config);
+    assert_eq!(frame.header(ENCODING_HEADER), Some("lz4"));
+    assert_eq!(frame.header(DECODED_LENGTH_HEADER), Some(payload.len().to_string().as_str()));
+    assert!(frame.payload.len() < payload.len());
+
+    let restored = frame.decompress().unwrap();
+    assert_eq!(restored, Frame::new("analytics.batch", payload));
+
+    let unknown = Frame::new("analytics.batch", vec![1, 2, 3]).with_header(ENCODING_HEADER, "zstd");
+    assert!(matches!(unknown.decompress(), Err(rumt::Error::Transport(_))));
+}
+
+pub struct AnalyticsSink {
+    pub received: Arc<Mutex<Vec<usize>>>,
+}
+
+impl AnalyticsSink {
+    pub async fn on_batch(&self, batch: &TestPayload) {
+        self.received.lock().await.push(batch.data.len());
+    }
+}
+
+rumt::event_handlers! {
+    AnalyticsSink;
+    RuntimeEvent::Static { event_name: "compression.remote.batch".into() } => async on_batch : TestPayload
+}
+
+async fn wait_for_encodings(manager: &TransportManager, expected: &[Compression]) {
+    for _ in 0..200 {
+        if manager.peer_encodings() == expected {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("peer encodings stayed {:?}", manager.peer_encodings());
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_transport_manager_compresses_large_payloads() {
+    setup_runtime().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    AnalyticsSink { received: Arc::clone(&received) }.try_init().await.unwrap();
+
+    let network = LoopbackTransport::new();
+    let mut wire = network.subscribe(FrameFilter::events(["analytics"])).await.unwrap();
+    let local = RuntimeEvent::Static { event_name: "compression.local.batch".into() };
+
+    let options = TransportOptions { compression: Some(CompressionConfig::lz4(1024)), ..Default::default() };
+    let sender = TransportManager::with_options("compression.sender", network.clone(), options);
+    sender.route_out(local.clone(), "analytics", payload_codec()).await.unwrap();
+    let receiver = TransportManager::new("compression.receiver", network.clone());
+    receiver.route_in("analytics", RuntimeEvent::Static { event_name: "compression.remote.batch".into() }, payload_codec());
+    receiver.start().await.unwrap();
+    sender.start().await.unwrap();
+    wait_for_encodings(&sender, &[Compression::Lz4]).await;
+
+    let large = String::from_utf8(analytics_payload(2000)).unwrap();
+    rumt::emit_event(local.clone(), TestPayload { data: "small".into() }).await;
+    rumt::emit_event(local, TestPayload { data: large.clone() }).await;
+
+    let small_frame = wire.next().await.unwrap();
+    assert_eq!(small_frame.header(ENCODING_HEADER), None);
+    let large_frame = wire.next().await.unwrap();
+    assert_eq!(large_frame.header(ENCODING_HEADER), Some("lz4"));
+    assert!(large_frame.payload.len() < large.len() / 4);
+
+    for _ in 0..200 {
+        if received.lock().await.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(*received.lock().await, vec![5, large.len()]);
+}
+
+#[tokio::test]
+async fn test_transport_manager_compresses_only_for_accepting_peers() {
+    setup_runtime().await;
+    let network = LoopbackTransport::new();
+    let mut wire = network.subscribe(FrameFilter::events(["metrics"])).await.unwrap();
+    let local = RuntimeEvent::Static { event_name: "compression.local.metrics".into() };
+    let large = String::from_utf8(analytics_payload(2000)).unwrap();
+
+    let options = TransportOptions { compression: Some(CompressionConfig::lz4(1024)), ..Default::default() };
+    let sender = TransportManager::with_options("compression.metrics", network.clone(), options);
+    sender.route_out(local.clone(), "metrics", payload_codec()).await.unwrap();
+    sender.start().await.unwrap();
+
+    // Duyuru yapan eş yokken sıkıştırılmaz
+    rumt::emit_event(local.clone(), TestPayload { data: large.clone() }).await;
+    assert_eq!(wire.next().await.unwrap().header(ENCODING_HEADER), None);
+
+    // Manager duyuruya kendi duyurusuyla cevap verir
+    let mut hellos = network.subscribe(FrameFilter::events([HELLO_EVENT])).await.unwrap();
+    let hello = Frame::new(HELLO_EVENT, Vec::new()).with_header(ORIGIN_HEADER, "new-peer").with_header(ACCEPT_ENCODING_HEADER, "lz4");
+    network.publish(hello).await.unwrap();
+    let reply = hellos.next().await.unwrap();
+    assert_eq!(reply.header(ACCEPT_ENCODING_HEADER), Some("lz4"));
+    wait_for_encodings(&sender, &[Compression::Lz4]).await;
+    rumt::emit_event(local.clone(), TestPayload { data: large.clone() }).await;
+    assert_eq!(wire.next().await.unwrap().header(ENCODING_HEADER), Some("lz4"));
+
+    // LZ4 açamayan bir eş katılınca sıkıştırma durur
+    let legacy = Frame::new(HELLO_EVENT, Vec::new()).with_header(ORIGIN_HEADER, "old-peer").with_header(ACCEPT_ENCODING_HEADER, "");
+    network.publish(legacy).await.unwrap();
+    wait_for_encodings(&sender, &[]).await;
+    rumt::emit_event(local, TestPayload { data: large.clone() }).await;
+    let frame = wire.next().await.unwrap();
+    assert_eq!(frame.header(ENCODING_HEADER), None);
+    assert_eq!(frame.payload.len(), large.len());
+}
@@ -0,0 +1,113 @@
+use rumt::prelude::*;
+use rumt::{Context, EventId, TraceContext, context};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+/// Her handler'da görülen context'i kaydeden servis
+pub struct TraceService {
+    pub seen: Arc<Mutex<Vec<Context>>>,
+}
+
+impl TraceService {
+    pub async fn on_checkout(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(context::current().unwrap());
+        let event = RuntimeEvent::Static { event_name: "ctx.payment".into() };
+        rumt::emit_event(event, TestPayload { data: arg.data.clone() }).await;
+    }
+
+    pub async fn on_payment(&self, _arg: &TestPayload) {
+        self.seen.lock().await.push(context::current().unwrap());
+    }
+}
+
+rumt::event_handlers! {
+    TraceService;
+    RuntimeEvent::Static { event_name: "ctx.checkout".into() } => async on_checkout : TestPayload,
+    RuntimeEvent::Static { event_name: "ctx.payment".into() } => async on_payment : TestPayload
+}
+
+#[tokio::test]
+async fn test_context_propagates_to_follow_up_emits() {
+    setup_runtime().await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = TraceService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    assert!(context::current().is_none());
+
+    let root = Context::with_correlation_id(EventId(42));
+    let event = RuntimeEvent::Static { event_name: "ctx.checkout".into() };
+    context::scope(root, rumt::emit_event(event, TestPayload { data: "x".into() })).await;
+
+    let seen = seen.lock().await;
+    let (checkout, payment) = (&seen[0], &seen[1]);
+    assert_eq!(checkout.correlation_id, EventId(42));
+    assert_eq!(checkout.causation_id, Some(EventId(42)));
+    assert_eq!(payment.correlation_id, EventId(42));
+    assert_eq!(payment.causation_id, Some(checkout.event_id));
+    assert_eq!(
+        payment.causation_chain(),
+        [EventId(42), checkout.event_id, payment.event_id]
+    );
+}
+
+/// Trace testine ait ayrı servis; testler aynı global bus'ı paylaşır
+pub struct SpanService {
+    pub seen: Arc<Mutex<Vec<Context>>>,
+}
+
+impl SpanService {
+    pub async fn on_request(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(context::current().unwrap());
+        let event = RuntimeEvent::Static { event_name: "trace.response".into() };
+        rumt::emit_event(event, TestPayload { data: arg.data.clone() }).await;
+    }
+
+    pub async fn on_response(&self, _arg: &TestPayload) {
+        self.seen.lock().await.push(context::current().unwrap());
+    }
+}
+
+rumt::event_handlers! {
+    SpanService;
+    RuntimeEvent::Static { event_name: "trace.request".into() } => async on_request : TestPayload,
+    RuntimeEvent::Static { event_name: "trace.response".into() } => async on_response : TestPayload
+}
+
+#[test]
+fn test_traceparent_roundtrip() {
+    let header = "00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01";
+    let trace = TraceContext::from_headers([("TraceParent", header), ("tracestate", "rumt=1")]).unwrap();
+
+    assert!(trace.is_sampled());
+    assert_eq!(trace.traceparent(), header);
+    assert_eq!(trace.state.as_deref(), Some("rumt=1"));
+
+    assert!(TraceContext::parse("00-00000000000000000000000000000000-00f067aa0ba902b7-01", None).is_none());
+    assert!(TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7", None).is_none());
+}
+
+#[tokio::test]
+async fn test_trace_continues_across_emits() {
+    setup_runtime().await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = SpanService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let incoming = TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01", None).unwrap();
+    let root = Context::with_correlation_id(EventId(7)).with_trace(incoming.clone());
+    let event = RuntimeEvent::Static { event_name: "trace.request".into() };
+    context::scope(root, rumt::emit_event(event, TestPayload { data: "x".into() })).await;
+
+    let seen = seen.lock().await;
+    let traces: Vec<_> = seen.iter().map(|c| c.trace.clone().unwrap()).collect();
+    assert_eq!(traces.len(), 2);
+    for trace in &traces {
+        assert_eq!(trace.trace_id, incoming.trace_id);
+        assert_ne!(trace.parent_id, incoming.parent_id);
+    }
+    assert_ne!(traces[0].parent_id, traces[1].parent_id);
+}
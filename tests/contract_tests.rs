@@ -0,0 +1,66 @@
+use rumt::Contract;
+use rumt::contracts::ContractViolation;
+
+// Üretici crate'in tanımları
+mod orders {
+    rumt::define_events! {
+        /// Sipariş oluşturuldu
+        ORDER_CREATED = "contract.order.created" => OrderCreated { order_id: u64, total: f64, note: Option<String> },
+        ORDER_SHIPPED = "contract.order.shipped" => OrderShipped { order_id: u64 },
+        ORDERS_READY = once "contract.orders.ready",
+    }
+}
+
+// Tüketici crate'in aynı eventler için beklediği (eski) tanımlar
+mod billing {
+    rumt::define_events! {
+        ORDER_CREATED = "contract.order.created" => OrderCreated { order_id: u64, total: f64 },
+        ORDERS_READY = once "contract.orders.ready",
+    }
+}
+
+mod stale_billing {
+    rumt::define_events! {
+        ORDER_CREATED = "contract.order.created" => OrderCreated { order_id: String, currency: String },
+        ORDER_CANCELLED = "contract.order.cancelled",
+        ORDERS_READY = "contract.orders.ready",
+    }
+}
+
+#[test]
+fn test_consumer_contract_is_checked_against_exported_producer_contract() {
+    let exported = Contract::from_events("orders", orders::EVENTS).version("contract.order.shipped", 2).to_json();
+    assert!(exported.starts_with(
+        "{\"producer\":\"orders\",\"events\":[{\"name\":\"contract.order.created\",\"once\":false,\"version\":1,\
+         \"payload\":{\"type\":\"OrderCreated\",\"fields\":{\"order_id\":\"u64\",\"total\":\"f64\",\"note\":\"Option<String>\"}}}"
+    ));
+    let producer = Contract::from_json(&exported).unwrap();
+    assert_eq!(producer, Contract::from_events("orders", orders::EVENTS).version("contract.order.shipped", 2));
+
+    // Üreticinin fazladan alanı ve eventi uyumu bozmaz
+    producer.assert_compatible(&Contract::from_events("billing", billing::EVENTS));
+
+    let violations = producer.check(&Contract::from_events("billing", stale_billing::EVENTS)).unwrap_err();
+    let reasons: Vec<String> = violations.iter().map(ContractViolation::to_string).collect();
+    assert_eq!(
+        reasons,
+        vec![
+            "`contract.order.created`: payload field `order_id` is `u64`, expected `String`",
+            "`contract.order.created`: payload field `currency` is missing",
+            "`contract.order.cancelled`: not emitted by `orders`",
+            "`contract.orders.ready`: expected a static event, `orders` emits a once-triggered event",
+        ]
+    );
+
+    // Sürüm sabitleyen tüketici, üretici sürümü artırdığında uyarılır
+    let pinned = Contract::new("shipping").event(producer.get("contract.order.shipped").unwrap().clone()).version("contract.order.shipped", 1);
+    let violations = producer.check(&pinned).unwrap_err();
+    assert_eq!(violations[0].reason, "expected version 1, `orders` emits version 2");
+}
+
+#[test]
+#[should_panic(expected = "`billing` is not compatible with `orders`")]
+fn test_assert_compatible_panics_with_report() {
+    let producer = Contract::from_events("orders", orders::EVENTS);
+    producer.assert_compatible(&Contract::from_events("billing", stale_billing::EVENTS));
+}
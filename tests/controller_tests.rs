@@ -0,0 +1,60 @@
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+// Aynı tipten birden fazla instance'ı ayırt edebilmek için isim taşıyan servis
+pub struct CounterService {
+    pub name: &'static str,
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl CounterService {
+    pub async fn count(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(format!("{}:{}", self.name, arg.data));
+    }
+}
+
+rumt::event_handlers! {
+    CounterService;
+    RuntimeEvent::Static { event_name: "controller.tick".into() } => async count : TestPayload
+}
+
+fn tick() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "controller.tick".into() }
+}
+
+#[tokio::test]
+async fn test_controller_is_per_instance() {
+    setup_runtime().await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let first = CounterService { name: "a", seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    let second = CounterService { name: "b", seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    let _third = CounterService { name: "c", seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    assert_ne!(first.instance_id(), second.instance_id());
+    assert_eq!(first.handles().len(), 1);
+    assert_eq!(first.handles()[0].event, tick());
+    assert_eq!(first.handles()[0].instance, first.instance_id());
+
+    // Pause edilen instance emit'leri kaçırır, diğeri etkilenmez
+    first.pause();
+    rumt::emit_event(tick(), TestPayload { data: "1".into() }).await;
+    first.resume();
+    rumt::emit_event(tick(), TestPayload { data: "2".into() }).await;
+
+    // Bir instance'ı dispose etmek aynı tipteki diğerinin handler'larını silmez
+    first.dispose().await;
+    rumt::emit_event(tick(), TestPayload { data: "3".into() }).await;
+
+    // Servisin kendi `dispose_self`'i de yalnızca o instance'ı kaldırır
+    second.service().dispose_self().await;
+    rumt::emit_event(tick(), TestPayload { data: "4".into() }).await;
+
+    let mut seen = seen.lock().await.clone();
+    seen.sort();
+    assert_eq!(seen, vec!["a:2", "b:1", "b:2", "b:3", "c:1", "c:2", "c:3", "c:4"]);
+}
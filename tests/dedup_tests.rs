@@ -0,0 +1,70 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, EmitOptions, try_init_runtime};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+use common::TestPayload;
+
+pub struct WebhookService {
+    pub processed: Arc<Mutex<Vec<String>>>,
+}
+
+impl WebhookService {
+    pub fn on_order(&self, arg: &TestPayload) {
+        self.processed.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    WebhookService;
+    RuntimeEvent::Static { event_name: "webhook.order".into() } => on_order : TestPayload,
+    RuntimeEvent::Static { event_name: "webhook.refund".into() } => on_order : TestPayload
+}
+
+async fn deliver(event: &str, key: &str, data: &str) {
+    rumt::emit_with(
+        RuntimeEvent::Static { event_name: event.into() },
+        TestPayload { data: data.into() },
+        EmitOptions { idempotency_key: Some(key.into()), ..Default::default() },
+    )
+    .await;
+}
+
+#[tokio::test]
+async fn test_duplicate_emits_are_suppressed() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("DedupApp", "MyCompany", "com")
+        .bus_config(BusConfig {
+            dedup_capacity: 2,
+            dedup_ttl: Duration::from_millis(100),
+            ..Default::default()
+        })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let processed = Arc::new(Mutex::new(Vec::new()));
+    let _controller = WebhookService { processed: Arc::clone(&processed) }.try_init().await.unwrap();
+
+    deliver("webhook.order", "order-1", "ilk").await;
+    // Aynı webhook tekrar gelir: bastırılır
+    deliver("webhook.order", "order-1", "tekrar").await;
+    // Anahtarlar event başınadır
+    deliver("webhook.refund", "order-1", "iade").await;
+    assert_eq!(*processed.lock().unwrap(), vec!["ilk", "iade"]);
+
+    // Kapasite 2: en eski anahtar düşer ve tekrar kabul edilir
+    deliver("webhook.order", "order-2", "ikinci").await;
+    deliver("webhook.order", "order-3", "üçüncü").await;
+    deliver("webhook.order", "order-1", "yeniden").await;
+
+    // TTL dolunca anahtar unutulur
+    tokio::time::sleep(Duration::from_millis(120)).await;
+    deliver("webhook.order", "order-3", "süre doldu").await;
+
+    assert_eq!(
+        *processed.lock().unwrap(),
+        vec!["ilk", "iade", "ikinci", "üçüncü", "yeniden", "süre doldu"]
+    );
+    assert_eq!(rumt::bus_stats().await.unwrap().suppressed, 1);
+}
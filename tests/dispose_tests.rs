@@ -0,0 +1,63 @@
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+// Dispose sırasında tamponunu "kalıcı" depoya boşaltan servis
+pub struct BufferedService {
+    pub name: &'static str,
+    pub buffer: Mutex<Vec<String>>,
+    pub flushed: Arc<Mutex<Vec<String>>>,
+}
+
+impl BufferedService {
+    fn new(name: &'static str, flushed: &Arc<Mutex<Vec<String>>>) -> Self {
+        Self { name, buffer: Mutex::new(Vec::new()), flushed: Arc::clone(flushed) }
+    }
+
+    pub async fn collect(&self, arg: &TestPayload) {
+        self.buffer.lock().await.push(arg.data.clone());
+    }
+
+    pub async fn flush(&self) {
+        // Kanca çalışırken handler'lar henüz kaldırılmamıştır
+        rumt::emit_event(write(), TestPayload { data: format!("{}:last", self.name) }).await;
+        let buffered = std::mem::take(&mut *self.buffer.lock().await);
+        self.flushed.lock().await.push(format!("{}:{}", self.name, buffered.join(",")));
+    }
+}
+
+rumt::event_handlers! {
+    BufferedService [on_dispose = flush];
+    RuntimeEvent::Static { event_name: "dispose.write".into() } => async collect : TestPayload
+}
+
+fn write() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "dispose.write".into() }
+}
+
+// Shutdown global bus'ı kapattığı için tüm senaryo tek testte sırayla çalışır
+#[tokio::test]
+async fn test_on_dispose_runs_before_listeners_are_removed() {
+    setup_runtime().await;
+    let flushed = Arc::new(Mutex::new(Vec::new()));
+
+    let first = BufferedService::new("a", &flushed).try_init().await.unwrap();
+    BufferedService::new("b", &flushed).try_init().await.unwrap();
+    BufferedService::new("c", &flushed).try_init().await.unwrap();
+    rumt::emit_event(write(), TestPayload { data: "1".into() }).await;
+
+    // Controller dispose: kanca yalnızca bir kez çalışır
+    first.dispose().await;
+    first.dispose().await;
+    assert_eq!(flushed.lock().await.clone(), vec!["a:1,a:last"]);
+
+    rumt::emit_event(write(), TestPayload { data: "2".into() }).await;
+
+    // Shutdown: kalan servisler kayıt sırasının tersiyle boşaltılır
+    rumt::shutdown_runtime().await;
+    let flushed = flushed.lock().await.clone();
+    assert_eq!(flushed, vec!["a:1,a:last", "c:1,a:last,2,c:last", "b:1,a:last,2,c:last,b:last"]);
+}
@@ -0,0 +1,66 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Error, Unlocked, try_init_runtime};
+use std::sync::{Arc, Mutex as StdMutex};
+
+#[derive(Debug)]
+pub struct FrameInput {
+    pub key: char,
+}
+
+pub struct InputSystem {
+    pub pressed: Arc<StdMutex<Vec<char>>>,
+}
+
+impl InputSystem {
+    pub async fn on_key(&self, arg: &FrameInput) {
+        self.pressed.lock().unwrap().push(arg.key);
+        // Handler içinden yapılan emit bir sonraki frame'e kalır
+        if arg.key == 'a' {
+            let event = RuntimeEvent::Static { event_name: "input.key".into() };
+            rumt::emit_event(event, FrameInput { key: 'b' }).await;
+        }
+    }
+}
+
+rumt::event_handlers! {
+    InputSystem;
+    RuntimeEvent::Static { event_name: "input.key".into() } => async on_key : FrameInput
+}
+
+#[test]
+fn test_handlers_run_only_on_drain() {
+    let runtime = tokio::runtime::Builder::new_current_thread().build().unwrap();
+    let pressed = Arc::new(StdMutex::new(Vec::new()));
+
+    runtime.block_on(async {
+        let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+            .add_app_info("MyGame", "MyStudio", "com")
+            .bus_config(BusConfig {
+                mode: DispatchMode::Deferred,
+                workers: 0,
+                ..Default::default()
+            })
+            .try_lock_env().unwrap();
+        try_init_runtime(env).await.unwrap();
+
+        let _controller = InputSystem { pressed: Arc::clone(&pressed) }.try_init().await.unwrap();
+
+        let event = RuntimeEvent::Static { event_name: "input.key".into() };
+        rumt::emit_event(event, FrameInput { key: 'a' }).await;
+        // Runtime thread'inden bloklayan drain reddedilir
+        assert_eq!(rumt::drain_pending_blocking(), Err(Error::BlockingInRuntime));
+    });
+
+    // Emit handler'ı çalıştırmaz
+    assert!(pressed.lock().unwrap().is_empty());
+
+    // Frame 1: yalnızca 'a' işlenir, handler'ın emit ettiği 'b' kuyrukta kalır
+    assert_eq!(rumt::drain_pending_blocking(), Ok(1));
+    assert_eq!(pressed.lock().unwrap().as_slice(), ['a']);
+
+    // Frame 2: async drain
+    assert_eq!(runtime.block_on(rumt::drain_pending()), 1);
+    assert_eq!(pressed.lock().unwrap().as_slice(), ['a', 'b']);
+
+    assert_eq!(rumt::drain_pending_blocking(), Ok(0));
+}
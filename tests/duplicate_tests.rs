@@ -0,0 +1,98 @@
+use rumt::log::LogRecord;
+use rumt::prelude::*;
+use rumt::{BusConfig, DuplicatePolicy, Error, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+pub struct Mailer {
+    pub name: &'static str,
+    pub journal: Journal,
+}
+
+impl Mailer {
+    pub fn on_signup(&self, _arg: &TestPayload) {
+        self.journal.lock().unwrap().push(format!("{}:mail", self.name));
+    }
+
+    pub async fn close(&self) {
+        self.journal.lock().unwrap().push(format!("{}:dispose", self.name));
+    }
+}
+
+rumt::event_handlers! {
+    Mailer [on_dispose = close];
+    RuntimeEvent::Static { event_name: "duplicate.signup".into() } => on_signup : TestPayload
+}
+
+pub struct Diagnostics {
+    pub journal: Journal,
+}
+
+impl Diagnostics {
+    pub fn on_log(&self, record: &LogRecord) {
+        self.journal.lock().unwrap().push(record.to_string());
+    }
+}
+
+rumt::event_handlers! {
+    Diagnostics;
+    RuntimeEvent::Static { event_name: "rumt.log".into() } => on_log : LogRecord
+}
+
+async fn register_twice(policy: DuplicatePolicy) -> (Vec<String>, bool) {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("DuplicateApp", "MyCompany", "com")
+        .bus_config(BusConfig { duplicate_listeners: policy, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let journal = Journal::default();
+    let _diagnostics = Diagnostics { journal: Arc::clone(&journal) }.try_init().await.unwrap();
+
+    Mailer { name: "first", journal: Arc::clone(&journal) }.try_init().await.unwrap();
+    let second = Mailer { name: "second", journal: Arc::clone(&journal) }.try_init().await;
+    let rejected = matches!(second, Err(Error::DuplicateListener { .. }));
+    rumt::emit_event(RuntimeEvent::Static { event_name: "duplicate.signup".into() }, TestPayload { data: "ada".into() }).await;
+
+    rumt::shutdown_runtime().await;
+    let journal = journal.lock().unwrap().clone();
+    (journal, rejected)
+}
+
+#[tokio::test]
+async fn test_duplicate_listener_policies() {
+    // Varsayılan: iki kayıt da çalışır, uyarı yazılır
+    let (journal, rejected) = register_twice(DuplicatePolicy::Allow).await;
+    assert!(!rejected);
+    assert_eq!(
+        journal[..3],
+        [
+            "WARN rumt::listeners: `Mailer` is already listening to `duplicate.signup`; both registrations will be invoked",
+            "first:mail",
+            "second:mail",
+        ]
+    );
+
+    let (journal, rejected) = register_twice(DuplicatePolicy::Reject).await;
+    assert!(rejected);
+    assert_eq!(
+        journal[..2],
+        ["WARN rumt::listeners: rejected duplicate listener: `Mailer` is already listening to `duplicate.signup`", "first:mail"]
+    );
+
+    // Eski instance'ın tek handler'ı değiştirildiği için kapatılır
+    let (journal, rejected) = register_twice(DuplicatePolicy::Replace).await;
+    assert!(!rejected);
+    assert_eq!(
+        journal,
+        [
+            "WARN rumt::listeners: `Mailer` is already listening to `duplicate.signup`; replacing the previous registration",
+            "first:dispose",
+            "second:mail",
+            "second:dispose",
+        ]
+    );
+}
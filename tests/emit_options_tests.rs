@@ -0,0 +1,88 @@
+use rumt::prelude::*;
+use rumt::telemetry::{EmitSpan, TelemetryObserver};
+use rumt::{DispatchMode, EmitOptions};
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::{Duration, Instant};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct SlowService {
+    pub finished: Arc<Mutex<Vec<&'static str>>>,
+}
+
+impl SlowService {
+    pub async fn first(&self, _arg: &TestPayload) {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        self.finished.lock().unwrap().push("first");
+    }
+
+    pub async fn second(&self, _arg: &TestPayload) {
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        self.finished.lock().unwrap().push("second");
+    }
+}
+
+rumt::event_handlers! {
+    SlowService;
+    RuntimeEvent::Static { event_name: "options.slow".into() } => async first : TestPayload,
+    RuntimeEvent::Static { event_name: "options.slow".into() } => async second : TestPayload
+}
+
+#[derive(Default)]
+struct CountingObserver {
+    emits: AtomicUsize,
+}
+
+impl TelemetryObserver for CountingObserver {
+    fn on_emit(&self, _span: &EmitSpan<'_>) {
+        self.emits.fetch_add(1, Ordering::SeqCst);
+    }
+}
+
+fn slow() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "options.slow".into() }
+}
+
+fn payload() -> TestPayload {
+    TestPayload { data: "x".into() }
+}
+
+#[tokio::test]
+async fn test_emit_with_overrides() {
+    setup_runtime().await;
+    let observer = Arc::new(CountingObserver::default());
+    rumt::add_telemetry_observer(observer.clone()).await;
+
+    let finished = Arc::new(Mutex::new(Vec::new()));
+    let _controller = SlowService { finished: Arc::clone(&finished) }.try_init().await.unwrap();
+
+    // Concurrent: iki handler aynı anda bekler
+    let started = Instant::now();
+    let concurrent = EmitOptions { mode: Some(DispatchMode::Concurrent), ..Default::default() };
+    rumt::emit_with(slow(), payload(), concurrent).await;
+    assert!(started.elapsed() < Duration::from_millis(190));
+    assert_eq!(finished.lock().unwrap().len(), 2);
+
+    // Süre sınırı: bitmeyen handler'lar bırakılır ve emit expired sayılır
+    let expired_before = rumt::bus_stats().await.unwrap().expired;
+    let started = Instant::now();
+    let timed = EmitOptions { timeout: Some(Duration::from_millis(20)), trace: false, ..Default::default() };
+    rumt::emit_with(slow(), payload(), timed).await;
+    assert!(started.elapsed() < Duration::from_millis(90));
+    assert_eq!(finished.lock().unwrap().len(), 2);
+    assert_eq!(rumt::bus_stats().await.unwrap().expired, expired_before + 1);
+
+    // trace: false olan emit telemetriye bildirilmedi
+    assert_eq!(observer.emits.load(Ordering::SeqCst), 1);
+
+    // Queued: emit beklemeden döner, handler'lar worker üzerinde çalışır
+    let queued = EmitOptions { mode: Some(DispatchMode::Queued), ..Default::default() };
+    rumt::emit_with(slow(), payload(), queued).await;
+    assert_eq!(finished.lock().unwrap().len(), 2);
+    tokio::time::sleep(Duration::from_millis(400)).await;
+    assert_eq!(*finished.lock().unwrap(), vec!["first", "second", "first", "second"]);
+}
@@ -0,0 +1,64 @@
+use rumt::prelude::*;
+use std::sync::{
+    Arc, Mutex,
+    atomic::{AtomicUsize, Ordering},
+};
+
+mod common;
+use common::setup_runtime;
+
+static CLONES: AtomicUsize = AtomicUsize::new(0);
+
+// Kopyalanma sayısını ölçebilmek için Clone elle yazılmıştır
+#[derive(Debug)]
+pub struct FramePayload {
+    pub frame: u64,
+}
+
+impl Clone for FramePayload {
+    fn clone(&self) -> Self {
+        CLONES.fetch_add(1, Ordering::SeqCst);
+        Self { frame: self.frame }
+    }
+}
+
+pub struct FrameService {
+    pub frames: Arc<Mutex<Vec<u64>>>,
+}
+
+impl FrameService {
+    pub fn on_frame(&self, arg: &FramePayload) {
+        self.frames.lock().unwrap().push(arg.frame);
+    }
+
+    pub async fn on_frame_async(&self, arg: &FramePayload) {
+        self.frames.lock().unwrap().push(arg.frame * 100);
+    }
+}
+
+rumt::event_handlers! {
+    FrameService;
+    RuntimeEvent::Static { event_name: "frame.sync".into() } => on_frame : FramePayload,
+    RuntimeEvent::Static { event_name: "frame.mixed".into() } => on_frame : FramePayload,
+    RuntimeEvent::Static { event_name: "frame.mixed".into() } => async on_frame_async : FramePayload
+}
+
+#[tokio::test]
+async fn test_emit_ref_borrows_payload() {
+    setup_runtime().await;
+
+    let frames = Arc::new(Mutex::new(Vec::new()));
+    let _controller = FrameService { frames: Arc::clone(&frames) }.try_init().await.unwrap();
+
+    // Yalnızca senkron dinleyiciler: veri hiç kopyalanmaz
+    let payload = FramePayload { frame: 1 };
+    rumt::emit_ref(RuntimeEvent::Static { event_name: "frame.sync".into() }, &payload).await;
+    assert_eq!(CLONES.load(Ordering::SeqCst), 0);
+
+    // Async dinleyici varsa emit başına tek kopya
+    let payload = FramePayload { frame: 2 };
+    rumt::emit_ref(RuntimeEvent::Static { event_name: "frame.mixed".into() }, &payload).await;
+    assert_eq!(CLONES.load(Ordering::SeqCst), 1);
+
+    assert_eq!(*frames.lock().unwrap(), vec![1, 2, 200]);
+}
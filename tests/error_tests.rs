@@ -0,0 +1,35 @@
+use rumt::prelude::*;
+use rumt::{Error, Unlocked};
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{InventoryService, TestPayload};
+
+// Bu dosyadaki testler runtime'ı hiç başlatmaz
+#[tokio::test]
+async fn test_fallible_api_without_runtime() {
+    let event = RuntimeEvent::Static { event_name: "order.created".into() };
+    let result = rumt::try_emit_event(event.clone(), TestPayload { data: "x".into() }).await;
+    assert_eq!(result, Err(Error::NotInitialized));
+
+    // Panic etmeyen emit sessizce yok sayar
+    rumt::emit_event(event, TestPayload { data: "x".into() }).await;
+
+    assert_eq!(rumt::try_runtime_env().err(), Some(Error::NotInitialized));
+
+    let service = InventoryService::new(Arc::new(Mutex::new(Vec::new())));
+    assert_eq!(service.try_init().await.err(), Some(Error::NotInitialized));
+}
+
+#[test]
+fn test_lock_env_without_app_info() {
+    let result = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .insert_path("db", "/tmp/test.db")
+        .try_lock_env();
+    assert_eq!(result.err(), Some(Error::MissingAppInfo));
+    assert_eq!(
+        Error::MissingAppInfo.to_string(),
+        "AppInfo must be set before locking the env"
+    );
+}
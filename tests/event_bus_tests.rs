@@ -1,5 +1,6 @@
 use rumt::prelude::*;
 use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
 use tokio::sync::Mutex;
 
 mod common;
@@ -18,9 +19,9 @@ async fn test_event_bus_flow() {
     // II. Servisi Başlat
     let service = InventoryService::new(Arc::clone(&storage));
     
-    // Makro tarafından oluşturulan .init() metodunu çağırıyoruz
+    // Makro tarafından oluşturulan .try_init() metodunu çağırıyoruz
     // Bu metod servisi Event Bus'a kaydeder.
-    let _controller = service.init().await;
+    let _controller = service.try_init().await.unwrap();
 
     // III. Olayı Tetikle
     let event = rumt::event_bus::RuntimeEvent::Static { 
@@ -40,4 +41,147 @@ async fn test_event_bus_flow() {
     assert_eq!(final_data[0], "Merhaba Rust!");
     
     println!("Test başarıyla tamamlandı!");
-}
\ No newline at end of file
+}
+
+// Handler içinden yeni bir event yayınlayan servis
+pub struct RelayService {
+    pub received_data: Arc<Mutex<Vec<String>>>,
+}
+
+impl RelayService {
+    pub async fn relay(&self, arg: &TestPayload) {
+        let event = RuntimeEvent::Static { event_name: "relay.forwarded".into() };
+        let payload = TestPayload { data: format!("{} (iletildi)", arg.data) };
+        rumt::emit_event(event, payload).await;
+    }
+
+    pub async fn collect(&self, arg: &TestPayload) {
+        self.received_data.lock().await.push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    RelayService;
+    RuntimeEvent::Static { event_name: "relay.incoming".into() } => async relay : TestPayload,
+    RuntimeEvent::Static { event_name: "relay.forwarded".into() } => async collect : TestPayload
+}
+
+#[tokio::test]
+async fn test_nested_emit_from_handler() {
+    setup_runtime().await;
+
+    let storage = Arc::new(Mutex::new(Vec::new()));
+    let _controller = RelayService { received_data: Arc::clone(&storage) }.try_init().await.unwrap();
+
+    // Dispatch sırasında bus kilidi tutulmadığı için iç içe emit kilitlenmez
+    let event = RuntimeEvent::Static { event_name: "relay.incoming".into() };
+    let payload = TestPayload { data: "Sipariş".into() };
+    tokio::time::timeout(
+        std::time::Duration::from_secs(1),
+        rumt::emit_event(event, payload),
+    )
+    .await
+    .expect("İç içe emit kilitlendi");
+
+    let final_data = storage.lock().await;
+    assert_eq!(final_data.as_slice(), ["Sipariş (iletildi)"]);
+}
+
+// Yeniden yüklenebilen servis; hangi instance'ın çalıştığını kaydeder
+pub struct PricingService {
+    pub version: &'static str,
+    pub calls: Arc<Mutex<Vec<String>>>,
+}
+
+impl PricingService {
+    pub async fn on_price_request(&self, arg: &TestPayload) {
+        self.calls.lock().await.push(format!("{}:{}", self.version, arg.data));
+    }
+}
+
+rumt::event_handlers! {
+    PricingService;
+    RuntimeEvent::Static { event_name: "pricing.request".into() } => async on_price_request : TestPayload
+}
+
+#[tokio::test]
+async fn test_reload_swaps_service_instance() {
+    setup_runtime().await;
+
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let _v1 = PricingService { version: "v1", calls: Arc::clone(&calls) }.try_init().await.unwrap();
+
+    let emit = |data: &str| {
+        let event = RuntimeEvent::Static { event_name: "pricing.request".into() };
+        rumt::emit_event(event, TestPayload { data: data.into() })
+    };
+
+    emit("a").await;
+    let _v2 = PricingService { version: "v2", calls: Arc::clone(&calls) }
+        .reload()
+        .await
+        .unwrap();
+    emit("b").await;
+
+    assert_eq!(calls.lock().await.as_slice(), ["v1:a", "v2:b"]);
+}
+
+// Elle kurulan yeni handler kümesiyle değiştirilen servis
+pub struct MeterService {
+    pub calls: Arc<Mutex<Vec<String>>>,
+    pub closed: Arc<AtomicBool>,
+}
+
+impl MeterService {
+    pub async fn on_reading(&self, arg: &TestPayload) {
+        self.calls.lock().await.push(format!("old:{}", arg.data));
+    }
+
+    pub async fn close(&self) {
+        self.closed.store(true, Ordering::SeqCst);
+    }
+}
+
+rumt::event_handlers! {
+    MeterService [on_dispose = close];
+    RuntimeEvent::Static { event_name: "meter.reading".into() } => async on_reading : TestPayload
+}
+
+#[tokio::test]
+async fn test_replace_by_tag_validates_before_swapping() {
+    use rumt::Guarantee;
+    use rumt::event_bus::{RuntimeEventBus, RuntimeEventListener};
+
+    setup_runtime().await;
+    let calls = Arc::new(Mutex::new(Vec::new()));
+    let closed = Arc::new(AtomicBool::new(false));
+    let _meter = MeterService { calls: Arc::clone(&calls), closed: Arc::clone(&closed) }.try_init().await.unwrap();
+    let reading = || RuntimeEvent::Static { event_name: "meter.reading".into() };
+    let bundle = |calls: &Arc<Mutex<Vec<String>>>| {
+        let calls = Arc::clone(calls);
+        let handler = Box::new(move |_: &dyn RuntimeEventListenerHandlerArg| {
+            let calls = Arc::clone(&calls);
+            Box::pin(async move { calls.lock().await.push("new".to_string()) }) as rumt::futures::future::BoxFuture<'static, ()>
+        });
+        RuntimeEventListener::new("MeterService", handler)
+    };
+
+    // Reddedilen bundle eski handler'lara dokunmaz
+    let rejected = vec![(reading(), bundle(&calls).requires(Guarantee::Durable))];
+    let result = RuntimeEventBus::try_with_instance_mut(|bus| bus.replace_by_tag("MeterService", rejected)).await.unwrap();
+    assert!(result.is_err());
+    rumt::emit_event(reading(), TestPayload { data: "1".into() }).await;
+    assert!(!closed.load(Ordering::SeqCst));
+
+    let accepted = vec![(reading(), bundle(&calls))];
+    let outcome = RuntimeEventBus::try_with_instance_mut(|bus| bus.replace_by_tag("MeterService", accepted))
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(outcome.replaced(), 1);
+    outcome.finish().await;
+    assert!(closed.load(Ordering::SeqCst));
+    rumt::emit_event(reading(), TestPayload { data: "2".into() }).await;
+
+    assert_eq!(calls.lock().await.as_slice(), ["old:1", "new"]);
+}
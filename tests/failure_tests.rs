@@ -0,0 +1,101 @@
+use rumt::prelude::*;
+use rumt::telemetry::{HandlerSpan, TelemetryObserver};
+use rumt::{EventId, Guarantee, HandlerFailure, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+pub struct Charge {
+    pub amount: u64,
+}
+
+pub struct Billing {
+    pub attempts: Arc<Mutex<u32>>,
+}
+
+impl Billing {
+    pub async fn charge(&self, arg: &Charge) {
+        let attempt = {
+            let mut attempts = self.attempts.lock().unwrap();
+            *attempts += 1;
+            *attempts
+        };
+        // İlk iki deneme ödeme sağlayıcısına ulaşamaz
+        if attempt < 3 {
+            panic!("gateway timeout after charging {}", arg.amount);
+        }
+    }
+}
+
+rumt::event_handlers! {
+    Billing;
+    RuntimeEvent::Static { event_name: "billing.charge".into() } => async charge : Charge [requires = Guarantee::Durable]
+}
+
+pub struct Alerts {
+    pub failures: Arc<Mutex<Vec<HandlerFailure>>>,
+}
+
+impl Alerts {
+    pub fn page(&self, failure: &HandlerFailure) {
+        self.failures.lock().unwrap().push(failure.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Alerts;
+    RuntimeEvent::Static { event_name: "rumt.handler.failed".into() } => page : HandlerFailure
+}
+
+// Metrik tarafı: başarısız span'lerin bağlamını kaydeder
+#[derive(Default)]
+struct FailureMetrics {
+    seen: Mutex<Vec<(EventId, u32)>>,
+}
+
+impl TelemetryObserver for FailureMetrics {
+    fn on_handler(&self, span: &HandlerSpan<'_>) {
+        if let Some(failure) = span.failure {
+            assert_eq!(span.attributes().iter().find(|(key, _)| *key == "error.type").map(|(_, v)| v.as_str()), Some("panic"));
+            self.seen.lock().unwrap().push((span.context.correlation_id, failure.attempt));
+        }
+    }
+}
+
+#[tokio::test]
+async fn test_handler_failures_share_context() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("FailureApp", "MyCompany", "com")
+        .guarantee(RuntimeEvent::Static { event_name: "billing.charge".into() }, Guarantee::Durable)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let metrics = Arc::new(FailureMetrics::default());
+    rumt::add_telemetry_observer(metrics.clone()).await;
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    let _alerts = Alerts { failures: Arc::clone(&failures) }.try_init().await.unwrap();
+    let attempts = Arc::new(Mutex::new(0));
+    let billing = Billing { attempts: Arc::clone(&attempts) }.try_init().await.unwrap();
+
+    rumt::emit_event(RuntimeEvent::Static { event_name: "billing.charge".into() }, Charge { amount: 40 }).await;
+    // Tekrar teslim deneme sayısını artırır, ikincisi başarılı olur
+    assert_eq!(billing.redeliver().await, 1);
+    assert_eq!(billing.redeliver().await, 1);
+    assert_eq!(billing.redeliver().await, 0);
+    assert_eq!(*attempts.lock().unwrap(), 3);
+
+    let failures = failures.lock().unwrap().clone();
+    assert_eq!(failures.len(), 2);
+    assert_eq!(failures[0].event, "billing.charge");
+    assert_eq!(failures[0].tag, "Billing");
+    assert_eq!(failures[0].payload, "failure_tests::Charge");
+    assert_eq!(failures[0].message, "gateway timeout after charging 40");
+    assert_eq!([failures[0].attempt, failures[1].attempt], [1, 2]);
+    // Tekrar teslimler aynı emit'e aittir
+    assert_eq!(failures[0].correlation_id, failures[1].correlation_id);
+    assert_eq!(failures[0].event_id, failures[1].event_id);
+    assert_eq!(
+        *metrics.seen.lock().unwrap(),
+        vec![(failures[0].correlation_id, 1), (failures[0].correlation_id, 2)]
+    );
+    assert!(failures[1].to_string().starts_with("`Billing` failed on `billing.charge` (failure_tests::Charge, attempt 2"));
+
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,93 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Fairness, try_init_runtime};
+use std::collections::BTreeMap;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+// Her tick'te bir yığın örnek üreten yoğun telemetri servisi
+pub struct Telemetry;
+
+impl Telemetry {
+    pub async fn on_tick(&self, _arg: &TestPayload) {
+        for i in 0..6 {
+            rumt::emit_event(sample(), TestPayload { data: format!("s{i}") }).await;
+        }
+    }
+}
+
+rumt::event_handlers! {
+    Telemetry;
+    RuntimeEvent::Static { event_name: "fair.tick".into() } => async on_tick : TestPayload
+}
+
+pub struct OrderService;
+
+impl OrderService {
+    pub async fn on_request(&self, _arg: &TestPayload) {
+        for i in 0..4 {
+            rumt::emit_event(order(), TestPayload { data: format!("o{i}") }).await;
+        }
+    }
+}
+
+rumt::event_handlers! {
+    OrderService;
+    RuntimeEvent::Static { event_name: "fair.request".into() } => async on_request : TestPayload
+}
+
+pub struct Worker {
+    pub processed: Arc<Mutex<Vec<String>>>,
+}
+
+impl Worker {
+    pub fn on_job(&self, arg: &TestPayload) {
+        self.processed.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Worker;
+    RuntimeEvent::Static { event_name: "fair.sample".into() } => on_job : TestPayload,
+    RuntimeEvent::Static { event_name: "fair.order".into() } => on_job : TestPayload
+}
+
+fn sample() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "fair.sample".into() }
+}
+
+fn order() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "fair.order".into() }
+}
+
+async fn processing_order(fairness: Fairness) -> String {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("FairApp", "MyCompany", "com")
+        .bus_config(BusConfig { mode: DispatchMode::Deferred, fairness, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let processed = Arc::new(Mutex::new(Vec::new()));
+    let _telemetry = Telemetry.try_init().await.unwrap();
+    let _orders = OrderService.try_init().await.unwrap();
+    let _worker = Worker { processed: Arc::clone(&processed) }.try_init().await.unwrap();
+
+    // İlk drain üreticileri çalıştırır, ikincisi ürettikleri emit'leri işler
+    rumt::emit_event(RuntimeEvent::Static { event_name: "fair.tick".into() }, TestPayload { data: "tick".into() }).await;
+    rumt::emit_event(RuntimeEvent::Static { event_name: "fair.request".into() }, TestPayload { data: "req".into() }).await;
+    assert_eq!(rumt::drain_pending().await, 2);
+    assert_eq!(rumt::drain_pending().await, 10);
+
+    rumt::shutdown_runtime().await;
+    processed.lock().unwrap().join(",")
+}
+
+#[tokio::test]
+async fn test_fair_scheduling_between_producer_tags() {
+    // Varsayılan: önce gelen yığın tamamen işlenir
+    assert_eq!(processing_order(Fairness::Off).await, "s0,s1,s2,s3,s4,s5,o0,o1,o2,o3");
+    assert_eq!(processing_order(Fairness::RoundRobin).await, "s0,o0,s1,o1,s2,o2,s3,o3,s4,s5");
+
+    let weights = BTreeMap::from([("OrderService".to_string(), 2)]);
+    assert_eq!(processing_order(Fairness::Weighted(weights)).await, "s0,o0,o1,s1,o2,o3,s2,s3,s4,s5");
+}
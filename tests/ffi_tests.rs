@@ -0,0 +1,111 @@
+#![cfg(feature = "ffi")]
+
+use rumt::ffi::*;
+use rumt::{NamePolicy, Unlocked};
+use std::ffi::{CStr, c_char, c_void};
+use std::sync::Mutex as StdMutex;
+use std::sync::atomic::{AtomicI32, Ordering};
+
+type Received = StdMutex<Vec<(String, Vec<u8>)>>;
+
+extern "C" fn record(user_data: *mut c_void, event_name: *const c_char, data: *const u8, len: usize) {
+    let received = unsafe { &*(user_data as *const Received) };
+    let name = unsafe { CStr::from_ptr(event_name) }.to_string_lossy().into_owned();
+    let bytes = unsafe { std::slice::from_raw_parts(data, len) }.to_vec();
+    received.lock().unwrap().push((name, bytes));
+}
+
+// Callback içinden yapılan emit'in dönüş kodu
+static REENTRANT: AtomicI32 = AtomicI32::new(0);
+
+extern "C" fn emit_from_callback(_: *mut c_void, _: *const c_char, _: *const u8, _: usize) {
+    let code = unsafe { rumt_emit(c"host.echo".as_ptr(), std::ptr::null(), 0, c"utf8".as_ptr()) };
+    REENTRANT.store(code, Ordering::SeqCst);
+}
+
+#[test]
+fn test_c_host_roundtrip() {
+    let received: &'static Received = Box::leak(Box::new(StdMutex::new(Vec::new())));
+    let user_data = received as *const Received as *mut c_void;
+
+    unsafe {
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), std::ptr::null(), 0, c"utf8".as_ptr()), RUMT_ERR_NOT_INITIALIZED);
+        assert_eq!(rumt_init(c"HostApp".as_ptr(), c"MyCompany".as_ptr(), c"com".as_ptr()), RUMT_OK);
+        assert_eq!(rumt_init(c"HostApp".as_ptr(), c"MyCompany".as_ptr(), c"com".as_ptr()), RUMT_ERR_ALREADY_INITIALIZED);
+
+        let id = rumt_register_listener(c"host.ping".as_ptr(), c"utf8".as_ptr(), record, user_data);
+        assert!(id > 0);
+        assert_eq!(
+            rumt_register_listener(c"host.ping".as_ptr(), c"msgpack".as_ptr(), record, user_data),
+            RUMT_ERR_UNKNOWN_CODEC as i64
+        );
+
+        let payload = b"merhaba";
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), payload.as_ptr(), payload.len(), c"utf8".as_ptr()), RUMT_OK);
+        // Geçersiz UTF-8 codec hatası döner
+        let invalid = [0xffu8, 0xfe];
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), invalid.as_ptr(), invalid.len(), c"utf8".as_ptr()), RUMT_ERR_CODEC);
+
+        assert_eq!(
+            received.lock().unwrap().as_slice(),
+            [("host.ping".to_string(), b"merhaba".to_vec())]
+        );
+
+        // Callback'ten tekrar girilen çağrı panic yerine hata kodu döner
+        let echo = rumt_register_listener(c"host.ping".as_ptr(), c"utf8".as_ptr(), emit_from_callback, std::ptr::null_mut());
+        assert!(echo > 0);
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), payload.as_ptr(), payload.len(), c"utf8".as_ptr()), RUMT_OK);
+        assert_eq!(REENTRANT.load(Ordering::SeqCst), RUMT_ERR_REENTRANT);
+        assert_eq!(rumt_unregister_listener(echo), RUMT_OK);
+        assert_eq!(received.lock().unwrap().len(), 2);
+        received.lock().unwrap().pop();
+
+        assert_eq!(rumt_unregister_listener(id), RUMT_OK);
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), payload.as_ptr(), payload.len(), c"utf8".as_ptr()), RUMT_OK);
+        assert_eq!(received.lock().unwrap().len(), 1);
+
+        assert_eq!(rumt_shutdown(), RUMT_OK);
+        assert_eq!(rumt_emit(c"host.ping".as_ptr(), payload.as_ptr(), payload.len(), c"utf8".as_ptr()), RUMT_ERR_NOT_INITIALIZED);
+    }
+
+    // C listener'ları da Rust tarafında kurulan runtime'ın ad politikasına tabidir
+    let host = tokio::runtime::Runtime::new().unwrap();
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("HostApp", "MyCompany", "com")
+        .name_policy(NamePolicy::new().allow("host.*"))
+        .try_lock_env().unwrap();
+    host.block_on(rumt::try_init_runtime(env)).unwrap();
+    unsafe {
+        assert!(rumt_register_listener(c"host.ping".as_ptr(), c"utf8".as_ptr(), record, user_data) > 0);
+        assert_eq!(
+            rumt_register_listener(c"billing.ping".as_ptr(), c"utf8".as_ptr(), record, user_data),
+            RUMT_ERR_INVALID_EVENT_NAME as i64
+        );
+        assert_eq!(rumt_shutdown(), RUMT_OK);
+    }
+}
+
+// Başlık dosyası Rust tarafındaki hata kodlarının hepsini aynı değerlerle tanımlar
+#[test]
+fn test_header_matches_error_codes() {
+    let header = include_str!("../include/rumt.h");
+    let codes = [
+        ("RUMT_OK", RUMT_OK),
+        ("RUMT_ERR_INVALID_ARGUMENT", RUMT_ERR_INVALID_ARGUMENT),
+        ("RUMT_ERR_NOT_INITIALIZED", RUMT_ERR_NOT_INITIALIZED),
+        ("RUMT_ERR_UNKNOWN_CODEC", RUMT_ERR_UNKNOWN_CODEC),
+        ("RUMT_ERR_CODEC", RUMT_ERR_CODEC),
+        ("RUMT_ERR_INIT_FAILED", RUMT_ERR_INIT_FAILED),
+        ("RUMT_ERR_REENTRANT", RUMT_ERR_REENTRANT),
+        ("RUMT_ERR_INVALID_EVENT_NAME", RUMT_ERR_INVALID_EVENT_NAME),
+        ("RUMT_ERR_UNAUTHORIZED", RUMT_ERR_UNAUTHORIZED),
+        ("RUMT_ERR_REJECTED", RUMT_ERR_REJECTED),
+        ("RUMT_ERR_ALREADY_INITIALIZED", RUMT_ERR_ALREADY_INITIALIZED),
+    ];
+    for (name, code) in codes {
+        assert!(header.contains(&format!("#define {name} {code}\n")), "{name}");
+    }
+    // Include guard değer taşımaz
+    let defined = header.lines().filter(|line| line.starts_with("#define RUMT_") && line.split(' ').count() == 3);
+    assert_eq!(defined.count(), codes.len());
+}
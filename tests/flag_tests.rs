@@ -0,0 +1,56 @@
+use rumt::prelude::*;
+use rumt::{Unlocked, try_init_runtime};
+use std::sync::{Arc, Mutex as StdMutex};
+
+mod common;
+use common::TestPayload;
+
+pub struct MailerService {
+    pub calls: Arc<StdMutex<Vec<String>>>,
+}
+
+impl MailerService {
+    // Senkron handler
+    pub fn log(&self, arg: &TestPayload) {
+        self.calls.lock().unwrap().push(format!("log:{}", arg.data));
+    }
+
+    pub async fn send_email(&self, arg: &TestPayload) {
+        self.calls.lock().unwrap().push(format!("email:{}", arg.data));
+    }
+}
+
+rumt::event_handlers! {
+    MailerService;
+    RuntimeEvent::Static { event_name: "order.completed".into() } => log : TestPayload,
+    RuntimeEvent::Static { event_name: "order.completed".into() } => async send_email : TestPayload [enabled_if = "features.email"],
+}
+
+async fn emit(data: &str) {
+    let event = RuntimeEvent::Static { event_name: "order.completed".into() };
+    rumt::emit_event(event, TestPayload { data: data.into() }).await;
+}
+
+#[tokio::test]
+async fn test_listeners_follow_flags() {
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .set_flag("features.email", false)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let calls = Arc::new(StdMutex::new(Vec::new()));
+    let _controller = MailerService { calls: Arc::clone(&calls) }.try_init().await.unwrap();
+
+    emit("1").await;
+    rumt::set_flag("features.email", true).await.unwrap();
+    emit("2").await;
+    rumt::set_flag("features.email", false).await.unwrap();
+    emit("3").await;
+
+    assert_eq!(
+        calls.lock().unwrap().as_slice(),
+        ["log:1", "log:2", "email:2", "log:3"]
+    );
+    assert!(!rumt::runtime_env().as_ref().unwrap().flag("features.email"));
+}
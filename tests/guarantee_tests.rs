@@ -0,0 +1,104 @@
+use rumt::prelude::*;
+use rumt::{Error, Guarantee, Unlocked};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+mod common;
+use common::TestPayload;
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+pub struct LedgerService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl LedgerService {
+    pub async fn record(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(arg.data.clone());
+    }
+
+    pub async fn settle(&self, arg: &TestPayload) {
+        let mut seen = self.seen.lock().await;
+        // İlk deneme onaylanmaz (panic), tekrar teslimde başarılı olur
+        let attempts = seen.iter().filter(|s| s.starts_with("attempt:")).count();
+        seen.push(format!("attempt:{}", arg.data));
+        if attempts == 0 {
+            drop(seen);
+            panic!("settlement backend unavailable");
+        }
+    }
+}
+
+rumt::event_handlers! {
+    LedgerService;
+    RuntimeEvent::Static { event_name: "guarantee.queued".into() } => async record : TestPayload [requires = Guarantee::Queued],
+    RuntimeEvent::Static { event_name: "guarantee.best".into() } => async record : TestPayload,
+    RuntimeEvent::Static { event_name: "guarantee.durable".into() } => async settle : TestPayload [requires = Guarantee::Durable]
+}
+
+pub struct StrictConsumer;
+
+impl StrictConsumer {
+    pub async fn consume(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    StrictConsumer;
+    RuntimeEvent::Static { event_name: "guarantee.best".into() } => async consume : TestPayload [requires = Guarantee::Durable]
+}
+
+async fn wait_for(seen: &Arc<Mutex<Vec<String>>>, len: usize) {
+    for _ in 0..100 {
+        if seen.lock().await.len() >= len {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_delivery_guarantees() {
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .guarantee(event("guarantee.queued"), Guarantee::Queued)
+        .guarantee(event("guarantee.durable"), Guarantee::Durable)
+        .try_lock_env().unwrap();
+    rumt::try_init_runtime(env).await.unwrap();
+
+    // Dayanıklı teslim isteyen tüketici best-effort bir event'e bağlanamaz
+    let error = StrictConsumer.try_init().await.err().unwrap();
+    assert_eq!(
+        error,
+        Error::GuaranteeMismatch {
+            event: "guarantee.best".into(),
+            required: Guarantee::Durable,
+            declared: Guarantee::BestEffort,
+        }
+    );
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let controller = LedgerService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    // Pause sırasında `Queued` event bekletilir, best-effort event kaçırılır
+    controller.pause();
+    rumt::emit_event(event("guarantee.queued"), TestPayload { data: "q1".into() }).await;
+    rumt::emit_event(event("guarantee.best"), TestPayload { data: "lost".into() }).await;
+    rumt::emit_event(event("guarantee.queued"), TestPayload { data: "q2".into() }).await;
+    assert_eq!(controller.pending(), 2);
+    assert!(seen.lock().await.is_empty());
+
+    controller.resume();
+    wait_for(&seen, 2).await;
+    assert_eq!(*seen.lock().await, vec!["q1", "q2"]);
+    assert_eq!(controller.pending(), 0);
+
+    // Onaylanmayan dayanıklı teslim saklanır ve tekrar verilir
+    rumt::emit_event(event("guarantee.durable"), TestPayload { data: "d1".into() }).await;
+    assert_eq!(controller.pending(), 1);
+    assert_eq!(controller.redeliver().await, 1);
+    assert_eq!(controller.pending(), 0);
+    assert_eq!(*seen.lock().await, vec!["q1", "q2", "attempt:d1", "attempt:d1"]);
+}
@@ -0,0 +1,70 @@
+use rumt::ids::{IdGenerator, UuidV7};
+use rumt::prelude::*;
+use rumt::{EventId, context, try_init_runtime};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+// Kurumsal Snowflake biçimi: milisaniye | worker | sıra
+pub struct Snowflake {
+    pub worker: u128,
+    pub sequence: AtomicU64,
+}
+
+impl IdGenerator for Snowflake {
+    fn generate(&self) -> EventId {
+        let sequence = self.sequence.fetch_add(1, Ordering::Relaxed) as u128;
+        EventId(1_700_000_000_000 << 22 | self.worker << 12 | sequence)
+    }
+}
+
+pub struct Recorder {
+    pub seen: Arc<Mutex<Vec<(EventId, EventId)>>>,
+}
+
+impl Recorder {
+    pub async fn on_order(&self, _arg: &TestPayload) {
+        let current = context::current().unwrap();
+        self.seen.lock().unwrap().push((current.correlation_id, current.event_id));
+    }
+}
+
+rumt::event_handlers! {
+    Recorder;
+    RuntimeEvent::Static { event_name: "ids.order".into() } => async on_order : TestPayload
+}
+
+#[test]
+fn test_uuid_v7_layout_and_order() {
+    let generator = UuidV7::new();
+    let ids: Vec<EventId> = (0..100).map(|_| generator.generate()).collect();
+    assert!(ids.windows(2).all(|pair| pair[0] < pair[1]));
+    let id = ids[0].0;
+    assert_eq!(id >> 76 & 0xf, 7);
+    assert_eq!(id >> 62 & 0b11, 0b10);
+    let millis = std::time::SystemTime::now().duration_since(std::time::UNIX_EPOCH).unwrap().as_millis();
+    assert!(millis - (id >> 80) < 60_000);
+    assert_eq!(ids[0].to_string().len(), 32);
+}
+
+#[tokio::test]
+async fn test_runtime_uses_configured_generator() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("IdsApp", "MyCompany", "com")
+        .id_generator(Snowflake { worker: 5, sequence: AtomicU64::new(0) })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _recorder = Recorder { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let key = rumt::ids::generate();
+    rumt::emit_event(RuntimeEvent::Static { event_name: "ids.order".into() }, TestPayload { data: "a".into() }).await;
+    let (correlation, event) = seen.lock().unwrap()[0];
+    assert_eq!(key.0 >> 12 & 0x3ff, 5);
+    assert_eq!(correlation, event);
+    assert_eq!(event.0, key.0 + 1);
+
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,155 @@
+#![cfg(feature = "webhook")]
+
+use rumt::codec::Utf8Codec;
+use rumt::crypto::{hmac_sha256, to_hex};
+use rumt::ingress::{IncomingWebhook, WebhookIngress, stop_webhook_ingress};
+use rumt::json::JsonValue;
+use rumt::prelude::*;
+use rumt::schema::PayloadSchema;
+use rumt::webhook::{WebhookConfig, WebhookPublisher};
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpStream;
+use tokio::sync::Mutex;
+
+mod common;
+use common::setup_runtime;
+
+pub struct PaymentService {
+    pub received: Arc<Mutex<Vec<IncomingWebhook>>>,
+}
+
+impl PaymentService {
+    pub async fn on_payment(&self, webhook: &IncomingWebhook) {
+        self.received.lock().await.push(webhook.clone());
+    }
+}
+
+rumt::event_handlers! {
+    PaymentService;
+    RuntimeEvent::Static { event_name: "ingress.payment".into() } => async on_payment : IncomingWebhook
+}
+
+async fn post(addr: SocketAddr, path: &str, signature: Option<String>, body: &str) -> u16 {
+    let mut stream = TcpStream::connect(addr).await.unwrap();
+    let mut request = format!("POST {path} HTTP/1.1\r\nHost: test\r\nContent-Length: {}\r\n", body.len());
+    if let Some(signature) = signature {
+        request.push_str(&format!("X-Rumt-Signature: {signature}\r\n"));
+    }
+    request.push_str("\r\n");
+    request.push_str(body);
+    stream.write_all(request.as_bytes()).await.unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).await.unwrap();
+    response.split_whitespace().nth(1).unwrap().parse().unwrap()
+}
+
+fn sign(body: &str) -> Option<String> {
+    Some(format!("sha256={}", to_hex(&hmac_sha256(b"s3cr3t", body.as_bytes()))))
+}
+
+#[test]
+fn test_payload_schema_validation() {
+    let schema = PayloadSchema::new()
+        .field("amount", "u32")
+        .field("currency", "String")
+        .field("note", "Option<String>")
+        .field("tags", "Vec<String>");
+    let parse = |text: &str| rumt::json::parse(text).unwrap();
+
+    assert!(schema.validate(&parse(r#"{"amount": 10, "currency": "TRY", "tags": []}"#)).is_ok());
+    assert!(schema.validate(&parse(r#"{"amount": -1, "currency": "TRY", "tags": []}"#)).is_err());
+    assert!(schema.validate(&parse(r#"{"amount": 1.5, "currency": "TRY", "tags": []}"#)).is_err());
+    assert!(schema.validate(&parse(r#"{"amount": 1, "currency": "TRY", "tags": [1]}"#)).is_err());
+    assert!(schema.validate(&parse(r#"{"currency": "TRY", "tags": []}"#)).is_err());
+    assert!(schema.validate(&parse("[]")).is_err());
+
+    let from_file = PayloadSchema::from_schema(
+        r#"{ "events": [ { "name": "ingress.payment",
+            "payload": { "type": "Payment", "fields": { "amount": "u64" } } } ] }"#,
+        "ingress.payment",
+    )
+    .unwrap();
+    assert_eq!(from_file, PayloadSchema::new().field("amount", "u64"));
+}
+
+// Sunucu runtime ile birlikte kapatıldığı için senaryo tek testte çalışır
+#[tokio::test]
+async fn test_ingress_validates_and_emits() {
+    setup_runtime().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    PaymentService { received: Arc::clone(&received) }.try_init().await.unwrap();
+
+    let schema = PayloadSchema::new().field("amount", "u64");
+    let addr = WebhookIngress::new()
+        .secret("s3cr3t")
+        .route("/hooks/payments", RuntimeEvent::Static { event_name: "ingress.payment".into() }, schema)
+        .start("payments", "127.0.0.1:0")
+        .await
+        .unwrap();
+
+    let body = r#"{"amount": 250, "id": "p_1"}"#;
+    assert_eq!(post(addr, "/hooks/payments?source=test", sign(body), body).await, 202);
+    assert_eq!(post(addr, "/hooks/payments", None, body).await, 401);
+    assert_eq!(post(addr, "/hooks/payments", Some("sha256=00".into()), body).await, 401);
+    assert_eq!(post(addr, "/hooks/unknown", sign(body), body).await, 404);
+    let invalid = r#"{"amount": "many"}"#;
+    assert_eq!(post(addr, "/hooks/payments", sign(invalid), invalid).await, 400);
+    assert_eq!(post(addr, "/hooks/payments", sign("{"), "{").await, 400);
+
+    {
+        let received = received.lock().await;
+        assert_eq!(received.len(), 1);
+        assert_eq!(received[0].path, "/hooks/payments");
+        assert_eq!(received[0].body.get("amount"), Some(&JsonValue::Number(250.0)));
+        assert_eq!(received[0].body.get("id").and_then(JsonValue::as_str), Some("p_1"));
+    }
+
+    // Webhook yayıncısıyla imzalanan istekler doğrudan kabul edilir
+    let webhooks = WebhookPublisher::new(
+        "ingress.egress",
+        WebhookConfig { secret: Some("s3cr3t".into()), ..Default::default() },
+    );
+    let outgoing = RuntimeEvent::Static { event_name: "ingress.outgoing".into() };
+    let url = format!("http://{addr}/hooks/payments");
+    webhooks.route::<String, _>(outgoing.clone(), &url, Utf8Codec).await.unwrap();
+    rumt::emit_event(outgoing, r#"{"amount": 7}"#.to_string()).await;
+    for _ in 0..200 {
+        if received.lock().await.len() == 2 {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    {
+        let received = received.lock().await;
+        assert_eq!(received.len(), 2);
+        assert!(received[1].delivery.as_ref().is_some_and(|id| id.len() == 32));
+    }
+
+    // Yavaş istemci süre dolunca `408` alır; o sürerken sıradaki bağlantı bekletilir
+    let slow = WebhookIngress::new()
+        .read_timeout(Duration::from_millis(100))
+        .max_connections(1)
+        .route("/hooks/payments", RuntimeEvent::Static { event_name: "ingress.payment".into() }, PayloadSchema::new())
+        .start("slow", "127.0.0.1:0")
+        .await
+        .unwrap();
+    let mut stalled = TcpStream::connect(slow).await.unwrap();
+    stalled.write_all(b"POST /hooks/payments HTTP/1.1\r\nHost: test\r\n").await.unwrap();
+    let queued = tokio::spawn(async move { post(slow, "/hooks/unknown", None, "{}").await });
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!queued.is_finished(), "sınır aşıldı");
+    let mut response = String::new();
+    stalled.read_to_string(&mut response).await.unwrap();
+    assert!(response.starts_with("HTTP/1.1 408 "), "{response}");
+    assert_eq!(queued.await.unwrap(), 404);
+    assert!(stop_webhook_ingress("slow").await);
+
+    assert!(stop_webhook_ingress("payments").await);
+    assert!(!stop_webhook_ingress("payments").await);
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert!(TcpStream::connect(addr).await.is_err(), "sunucu kapanmadı");
+}
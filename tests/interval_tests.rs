@@ -0,0 +1,51 @@
+use futures::StreamExt;
+use rumt::clock::{Clock, ManualClock};
+use rumt::try_init_runtime;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    for _ in 0..200 {
+        if condition() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("condition was never met");
+}
+
+#[tokio::test]
+async fn test_interval_stream_ends_with_runtime() {
+    // Runtime yokken oluşturulan akış hemen biter
+    assert_eq!(rumt::interval(Duration::from_secs(1)).next().await, None);
+
+    let clock = ManualClock::new();
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("IntervalApp", "MyCompany", "com")
+        .clock(clock.clone())
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    // Servis döngüsü: kendi iptal mekanizması yok
+    let seen: Arc<Mutex<Vec<Instant>>> = Arc::default();
+    let start = clock.now();
+    let mut ticks = rumt::interval(Duration::from_secs(60));
+    let recorded = Arc::clone(&seen);
+    let service_loop = tokio::spawn(async move {
+        while let Some(at) = ticks.next().await {
+            recorded.lock().unwrap().push(at);
+        }
+    });
+
+    wait_until(|| clock.sleepers() > 0).await;
+    clock.advance(Duration::from_secs(60));
+    wait_until(|| seen.lock().unwrap().len() == 1).await;
+    wait_until(|| clock.sleepers() > 0).await;
+    clock.advance(Duration::from_secs(60));
+    wait_until(|| seen.lock().unwrap().len() == 2).await;
+    assert_eq!(*seen.lock().unwrap(), vec![start + Duration::from_secs(60), start + Duration::from_secs(120)]);
+
+    rumt::shutdown_runtime().await;
+    tokio::time::timeout(Duration::from_secs(1), service_loop).await.expect("loop outlived the runtime").unwrap();
+    assert_eq!(seen.lock().unwrap().len(), 2);
+}
@@ -0,0 +1,183 @@
+#![cfg(all(feature = "ipc", unix))]
+
+use rumt::auth::{ClientPermissions, GatewayAuth};
+use rumt::codec::Utf8Codec;
+use futures::future::BoxFuture;
+use rumt::ipc::{IpcEncryption, IpcGateway, IpcStream};
+use rumt::prelude::*;
+use rumt::transport::{FrameFilter, TransportManager};
+use std::os::unix::fs::PermissionsExt;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncReadExt, AsyncWriteExt, BufReader};
+use tokio::net::UnixStream;
+use tokio::net::unix::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::sync::Mutex;
+
+mod common;
+use common::setup_runtime;
+
+pub struct Printers {
+    pub statuses: Arc<Mutex<Vec<String>>>,
+}
+
+impl Printers {
+    // Handler'lar payload tipinin referansını alır
+    #[allow(clippy::ptr_arg)]
+    pub async fn on_status(&self, status: &String) {
+        self.statuses.lock().await.push(status.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Printers;
+    RuntimeEvent::Static { event_name: "ipc.printer.status".into() } => async on_status : String
+}
+
+struct Peer {
+    lines: BufReader<OwnedReadHalf>,
+    writer: OwnedWriteHalf,
+}
+
+impl Peer {
+    async fn connect(path: &std::path::Path) -> Self {
+        let (reader, writer) = UnixStream::connect(path).await.unwrap().into_split();
+        Self { lines: BufReader::new(reader), writer }
+    }
+
+    async fn send(&mut self, line: &str) {
+        self.writer.write_all(format!("{line}\n").as_bytes()).await.unwrap();
+    }
+
+    // Bağlantı kapanmışsa boş dizgi döner
+    async fn recv(&mut self) -> String {
+        let mut line = String::new();
+        tokio::time::timeout(Duration::from_secs(2), self.lines.read_line(&mut line)).await.unwrap().unwrap();
+        line.trim_end().to_string()
+    }
+
+    async fn call(&mut self, line: &str) -> String {
+        self.send(line).await;
+        self.recv().await
+    }
+}
+
+fn hex(text: &str) -> String {
+    text.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+// Handshake yerine geçen önsöz; doğru önsözü göndermeyen bağlantı kapatılır
+struct Preface;
+
+impl IpcEncryption for Preface {
+    fn accept(&self, mut stream: UnixStream) -> BoxFuture<'static, std::io::Result<Box<dyn IpcStream>>> {
+        Box::pin(async move {
+            let mut preface = [0u8; 6];
+            stream.read_exact(&mut preface).await?;
+            if &preface != b"NOISE\n" {
+                return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, "bad preface"));
+            }
+            Ok(Box::new(stream) as Box<dyn IpcStream>)
+        })
+    }
+}
+
+#[tokio::test]
+async fn test_ipc_gateway_enforces_tokens_and_allowlists() {
+    setup_runtime().await;
+    let statuses = Arc::new(Mutex::new(Vec::new()));
+    let _printers = Printers { statuses: Arc::clone(&statuses) }.try_init().await.unwrap();
+
+    let path = std::env::temp_dir().join(format!("rumt-ipc-{}.sock", std::process::id()));
+    let auth = GatewayAuth::new().client(
+        "printer",
+        "tok_printer",
+        ClientPermissions::none()
+            .subscribe(FrameFilter::Prefix("receipt.".into()))
+            .emit(FrameFilter::events(["printer.status"])),
+    );
+    let ipc = TransportManager::new("ipc.printers", IpcGateway::new(&path).auth(auth));
+    let receipt = RuntimeEvent::Static { event_name: "ipc.receipt.ready".into() };
+    let order = RuntimeEvent::Static { event_name: "ipc.order.created".into() };
+    ipc.route_out(receipt.clone(), "receipt.ready", Utf8Codec).await.unwrap();
+    ipc.route_out(order.clone(), "order.created", Utf8Codec).await.unwrap();
+    ipc.route_in("printer.status", RuntimeEvent::Static { event_name: "ipc.printer.status".into() }, Utf8Codec);
+    ipc.route_in("order.created", order.clone(), Utf8Codec);
+    ipc.start().await.unwrap();
+    assert_eq!(std::fs::metadata(&path).unwrap().permissions().mode() & 0o777, 0o600);
+
+    // Geçersiz token ve doğrulanmadan gönderilen komut bağlantıyı kapatır
+    let mut stranger = Peer::connect(&path).await;
+    assert_eq!(stranger.call("AUTH\ttok_unknown").await, "ERR\tunauthorized: unknown token");
+    assert_eq!(stranger.recv().await, "");
+    let mut stranger = Peer::connect(&path).await;
+    assert_eq!(stranger.call("SUB\t*").await, "ERR\tauthenticate with AUTH first");
+    assert_eq!(stranger.recv().await, "");
+
+    let mut printer = Peer::connect(&path).await;
+    assert_eq!(printer.call("AUTH\ttok_printer").await, "OK");
+    assert!(printer.call("SUB\torder.created").await.starts_with("ERR\tunauthorized"));
+    assert_eq!(printer.call("SUB\t*").await, "OK");
+
+    // Önekli abonelikte yalnızca izinli eventler gelir
+    rumt::emit_event(order.clone(), "order-1".to_string()).await;
+    rumt::emit_event(receipt.clone(), "receipt-1".to_string()).await;
+    let event = printer.recv().await;
+    assert!(event.starts_with(&format!("EVENT\treceipt.ready\t{}\t", hex("receipt-1"))), "{event}");
+
+    // İzinsiz emit bus'a ulaşmaz
+    assert!(printer.call(&format!("EMIT\torder.created\t{}", hex("forged"))).await.starts_with("ERR\tunauthorized"));
+    assert_eq!(printer.call(&format!("EMIT\tprinter.status\t{}", hex("paper-low"))).await, "OK");
+    for _ in 0..200 {
+        if !statuses.lock().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(*statuses.lock().await, vec!["paper-low".to_string()]);
+
+    // Bağlantılar şifreleme katmanından geçer; okumayan istemci atılır
+    let lag_path = std::env::temp_dir().join(format!("rumt-ipc-lag-{}.sock", std::process::id()));
+    let ipc = TransportManager::new("ipc.lagging", IpcGateway::new(&lag_path).encryption(Preface).queue(4));
+    let report = RuntimeEvent::Static { event_name: "ipc.report.ready".into() };
+    ipc.route_out(report.clone(), "report.ready", Utf8Codec).await.unwrap();
+    ipc.start().await.unwrap();
+    assert_eq!(std::fs::metadata(&lag_path).unwrap().permissions().mode() & 0o777, 0o600);
+    let staging = std::fs::read_dir(std::env::temp_dir())
+        .unwrap()
+        .filter_map(|entry| entry.ok())
+        .any(|entry| entry.file_name().to_string_lossy().starts_with(&format!(".rumt-ipc-lag-{}.sock.", std::process::id())));
+    assert!(!staging);
+
+    let mut intruder = Peer::connect(&lag_path).await;
+    intruder.send("HELLO").await;
+    assert_eq!(intruder.recv().await, "");
+
+    let mut reader = Peer::connect(&lag_path).await;
+    reader.send("NOISE").await;
+    assert_eq!(reader.call("SUB\t*").await, "OK");
+
+    // Hiç okumayan istemcinin kuyruğu dolunca bağlantısı kesilir
+    let events = 64;
+    let body = "x".repeat(64 * 1024);
+    for _ in 0..events {
+        rumt::emit_event(report.clone(), body.clone()).await;
+    }
+    let mut received = 0;
+    while reader.recv().await.starts_with("EVENT\treport.ready") {
+        received += 1;
+    }
+    assert!(received < events, "{received}");
+
+    rumt::shutdown_runtime().await;
+    assert_eq!(printer.recv().await, "");
+    for _ in 0..200 {
+        if !path.exists() && !lag_path.exists() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert!(!path.exists());
+    assert!(!lag_path.exists());
+}
+
@@ -0,0 +1,72 @@
+use rumt::prelude::*;
+use std::rc::Rc;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::setup_runtime;
+
+#[derive(Clone, Debug)]
+pub struct ForeignCall {
+    pub data: String,
+}
+
+// `!Send` bir FFI tutamacını temsil eder
+struct ForeignHandle(Rc<String>);
+
+pub struct ForeignService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl ForeignService {
+    pub async fn handle(&self, arg: &ForeignCall) {
+        let handle = ForeignHandle(Rc::new(arg.data.clone()));
+        // `Rc` await noktası boyunca tutulduğu için future `Send` değildir
+        tokio::task::yield_now().await;
+        let thread = std::thread::current().name().unwrap_or_default().to_string();
+        self.seen.lock().unwrap().push(format!("{}@{thread}", handle.0));
+    }
+
+    pub fn handle_sync(&self, arg: &ForeignCall) {
+        let thread = std::thread::current().name().unwrap_or_default().to_string();
+        self.seen.lock().unwrap().push(format!("sync:{}@{thread}", arg.data));
+    }
+
+    pub async fn explode(&self, _arg: &ForeignCall) {
+        let _handle = ForeignHandle(Rc::new(String::new()));
+        tokio::task::yield_now().await;
+        panic!("ffi failure");
+    }
+}
+
+rumt::event_handlers! {
+    ForeignService;
+    RuntimeEvent::Static { event_name: "isolated.call".into() } => async handle : ForeignCall [isolated],
+    RuntimeEvent::Static { event_name: "isolated.call".into() } => handle_sync : ForeignCall [replay = 0, isolated],
+    RuntimeEvent::Static { event_name: "isolated.fail".into() } => async explode : ForeignCall [isolated]
+}
+
+#[tokio::test]
+async fn test_isolated_handlers_run_on_dedicated_thread() {
+    setup_runtime().await;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    ForeignService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let call = RuntimeEvent::Static { event_name: "isolated.call".into() };
+    rumt::emit_event(call.clone(), ForeignCall { data: "1".into() }).await;
+    // `emit_ref` de senkron handler'ı çağıran thread'de çalıştırmaz
+    rumt::emit_ref(call, &ForeignCall { data: "2".into() }).await;
+
+    // Emit, izole handler'lar bitene kadar bekler
+    assert_eq!(
+        seen.lock().unwrap().clone(),
+        vec!["1@rumt-isolated", "sync:1@rumt-isolated", "2@rumt-isolated", "sync:2@rumt-isolated"]
+    );
+
+    // Panic eden izole handler hata olarak sayılır, izole runtime çalışmaya devam eder
+    let failures = rumt::bus_stats().await.unwrap().failures;
+    rumt::emit_event(RuntimeEvent::Static { event_name: "isolated.fail".into() }, ForeignCall { data: "x".into() }).await;
+    assert_eq!(rumt::bus_stats().await.unwrap().failures, failures + 1);
+
+    rumt::emit_event(RuntimeEvent::Static { event_name: "isolated.call".into() }, ForeignCall { data: "3".into() }).await;
+    assert_eq!(seen.lock().unwrap().len(), 6);
+}
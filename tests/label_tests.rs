@@ -0,0 +1,103 @@
+use rumt::event_bus::{RuntimeEventBus, RuntimeEventListener, RuntimeEventListenerHandlerArg};
+use rumt::futures::future::BoxFuture;
+use rumt::log::{Level, LogRecord};
+use rumt::prelude::*;
+use rumt::telemetry::{HandlerSpan, TelemetryObserver};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::setup_runtime;
+
+#[derive(Clone)]
+pub struct Scan {
+    pub code: String,
+}
+
+pub struct Checkout {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl Checkout {
+    pub async fn on_scan(&self, _scan: &Scan) {
+        self.seen.lock().unwrap().push(rumt::rt::current_label().unwrap().to_string());
+        rumt::log(Level::Info, "checkout", "scanned").await;
+    }
+
+    pub fn on_price(&self, _scan: &Scan) {
+        self.seen.lock().unwrap().push(rumt::rt::current_label().unwrap().to_string());
+    }
+}
+
+rumt::event_handlers! {
+    Checkout;
+    RuntimeEvent::Static { event_name: "label.scan".into() } => async on_scan : Scan,
+    RuntimeEvent::Static { event_name: "label.price".into() } => on_price : Scan
+}
+
+pub struct LogSink {
+    pub records: Arc<Mutex<Vec<LogRecord>>>,
+}
+
+impl LogSink {
+    pub fn on_log(&self, record: &LogRecord) {
+        self.records.lock().unwrap().push(record.clone());
+    }
+}
+
+rumt::event_handlers! {
+    LogSink;
+    RuntimeEvent::Static { event_name: "rumt.log".into() } => on_log : LogRecord
+}
+
+#[derive(Default)]
+struct Labels {
+    spans: Mutex<Vec<String>>,
+}
+
+impl TelemetryObserver for Labels {
+    fn on_handler(&self, span: &HandlerSpan<'_>) {
+        let attribute = span.attributes().into_iter().find(|(key, _)| *key == "rumt.handler.label").map(|(_, v)| v);
+        assert_eq!(attribute.as_deref(), Some(span.label));
+        self.spans.lock().unwrap().push(span.label.to_string());
+    }
+}
+
+#[tokio::test]
+async fn test_handlers_run_with_context_labels() {
+    setup_runtime().await;
+    let labels = Arc::new(Labels::default());
+    rumt::add_telemetry_observer(labels.clone()).await;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _checkout = Checkout { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    let records = Arc::new(Mutex::new(Vec::new()));
+    let _sink = LogSink { records: Arc::clone(&records) }.try_init().await.unwrap();
+
+    // Elle kurulan listener'da handler adı yoksa etiket `tag@event` olur
+    let manual = Arc::clone(&seen);
+    let handler = Box::new(move |_: &dyn RuntimeEventListenerHandlerArg| {
+        manual.lock().unwrap().push(rumt::rt::current_label().unwrap().to_string());
+        Box::pin(async {}) as BoxFuture<'static, ()>
+    });
+    let listener = RuntimeEventListener::new("scanner", handler);
+    RuntimeEventBus::try_with_instance_mut(|bus| bus.add_listener(RuntimeEvent::Static { event_name: "label.scan".into() }, listener))
+        .await
+        .unwrap();
+
+    assert!(rumt::rt::current_label().is_none());
+    rumt::emit_event(RuntimeEvent::Static { event_name: "label.scan".into() }, Scan { code: "4006381".into() }).await;
+    // Senkron handler `emit_ref` ile ödünç alınan veriyle çalışırken de etiketlidir
+    let price = Scan { code: "4006381".into() };
+    rumt::emit_ref(RuntimeEvent::Static { event_name: "label.price".into() }, &price).await;
+
+    assert_eq!(
+        *seen.lock().unwrap(),
+        vec!["Checkout::on_scan@label.scan", "scanner@label.scan", "Checkout::on_price@label.price"]
+    );
+    let records = records.lock().unwrap().clone();
+    assert_eq!(records.len(), 1);
+    assert_eq!(records[0].handler.as_deref(), Some("Checkout::on_scan@label.scan"));
+    assert!(labels.spans.lock().unwrap().contains(&"Checkout::on_price@label.price".to_string()));
+    assert!(labels.spans.lock().unwrap().contains(&"LogSink::on_log@rumt.log".to_string()));
+
+    rumt::shutdown_runtime().await;
+}
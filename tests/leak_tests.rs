@@ -0,0 +1,81 @@
+use rumt::prelude::*;
+use rumt::{RuntimeSnapshot, leak_report};
+use std::collections::BTreeMap;
+use std::time::Instant;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct RequestScope;
+
+impl RequestScope {
+    pub fn on_cancel(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    RequestScope;
+    RuntimeEvent::Static { event_name: "leak.request.cancel".into() } => on_cancel : TestPayload
+}
+
+pub struct Auditor;
+
+impl Auditor {
+    pub fn on_cancel(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    Auditor;
+    RuntimeEvent::Static { event_name: "leak.request.cancel".into() } => on_cancel : TestPayload
+}
+
+fn snapshot(metrics: &[(&str, usize)]) -> RuntimeSnapshot {
+    let metrics: BTreeMap<String, usize> = metrics.iter().map(|(name, value)| (name.to_string(), *value)).collect();
+    RuntimeSnapshot { taken_at: Instant::now(), metrics }
+}
+
+#[tokio::test]
+async fn test_leak_report_flags_forgotten_dispose() {
+    setup_runtime().await;
+    let _auditor = Auditor.try_init().await.unwrap();
+
+    let mut snapshots = vec![rumt::runtime_snapshot().await.unwrap()];
+    // Her istekte kapsam servisi başlatılır ama dispose edilmez
+    for _ in 0..3 {
+        let _forgotten = RequestScope.try_init().await.unwrap();
+        snapshots.push(rumt::runtime_snapshot().await.unwrap());
+    }
+    let report = leak_report(&snapshots);
+    let metrics: Vec<_> = report.growing.iter().map(|growth| (growth.metric.as_str(), growth.first, growth.last)).collect();
+    assert_eq!(
+        metrics,
+        vec![
+            ("listeners.tag:RequestScope", 0, 3),
+            ("listeners:leak.request.cancel", 1, 4),
+            ("services", 1, 4),
+        ]
+    );
+    assert!(report.to_string().starts_with("listeners.tag:RequestScope: 0 -> 3 (+3)"));
+
+    // Dispose edilen kapsamlar büyüme üretmez
+    let before = rumt::runtime_snapshot().await.unwrap();
+    for _ in 0..3 {
+        RequestScope.try_init().await.unwrap().dispose().await;
+    }
+    let after = rumt::runtime_snapshot().await.unwrap();
+    assert!(leak_report(&[before, after]).is_clean());
+}
+
+#[test]
+fn test_temporary_growth_is_not_a_leak() {
+    let series = [
+        snapshot(&[("queued", 0), ("sources", 1)]),
+        snapshot(&[("queued", 40), ("sources", 2)]),
+        snapshot(&[("queued", 3), ("sources", 2)]),
+        snapshot(&[("queued", 9), ("sources", 3)]),
+    ];
+    let report = leak_report(&series);
+    assert_eq!(report.growing.len(), 1);
+    assert_eq!(report.growing[0].metric, "sources");
+    assert!(leak_report(&series[..1]).is_clean());
+    assert_eq!(leak_report(&[]).to_string(), "no monotonic growth");
+}
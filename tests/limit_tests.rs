@@ -0,0 +1,68 @@
+use rumt::prelude::*;
+use rumt::{DispatchMode, EmitOptions};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use std::time::Duration;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+#[derive(Default)]
+pub struct InFlight {
+    pub current: AtomicUsize,
+    pub peak: AtomicUsize,
+    pub total: AtomicUsize,
+}
+
+// Kısıtlı bir bağlantı havuzunu kullanan yavaş tüketici
+pub struct ReportService {
+    pub stats: Arc<InFlight>,
+}
+
+impl ReportService {
+    async fn work(&self) {
+        let now = self.stats.current.fetch_add(1, Ordering::SeqCst) + 1;
+        self.stats.peak.fetch_max(now, Ordering::SeqCst);
+        tokio::time::sleep(Duration::from_millis(20)).await;
+        self.stats.current.fetch_sub(1, Ordering::SeqCst);
+        self.stats.total.fetch_add(1, Ordering::SeqCst);
+    }
+
+    pub async fn build(&self, _arg: &TestPayload) {
+        self.work().await;
+    }
+
+    pub async fn archive(&self, _arg: &TestPayload) {
+        self.work().await;
+    }
+}
+
+rumt::event_handlers! {
+    ReportService [max_in_flight = 2];
+    RuntimeEvent::Static { event_name: "limit.report".into() } => async build : TestPayload,
+    RuntimeEvent::Static { event_name: "limit.report".into() } => async archive : TestPayload
+}
+
+#[tokio::test]
+async fn test_max_in_flight_limits_tag() {
+    setup_runtime().await;
+
+    let stats = Arc::new(InFlight::default());
+    let _first = ReportService { stats: Arc::clone(&stats) }.try_init().await.unwrap();
+    let _second = ReportService { stats: Arc::clone(&stats) }.try_init().await.unwrap();
+
+    let emits = (0..3).map(|_| {
+        rumt::emit_with(
+            RuntimeEvent::Static { event_name: "limit.report".into() },
+            TestPayload { data: "rapor".into() },
+            EmitOptions { mode: Some(DispatchMode::Concurrent), ..Default::default() },
+        )
+    });
+    futures::future::join_all(emits).await;
+
+    // 3 emit x 4 handler; limit tag başına olduğundan iki instance da aynı semaforu paylaşır
+    assert_eq!(stats.total.load(Ordering::SeqCst), 12);
+    assert_eq!(stats.peak.load(Ordering::SeqCst), 2);
+}
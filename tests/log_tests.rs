@@ -0,0 +1,69 @@
+use rumt::log::{Level, LogRecord};
+use rumt::prelude::*;
+use rumt::{BusConfig, Context, EventId, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+pub struct LogSink {
+    pub lines: Arc<Mutex<Vec<String>>>,
+    pub records: Arc<Mutex<Vec<LogRecord>>>,
+}
+
+impl LogSink {
+    pub async fn on_log(&self, record: &LogRecord) {
+        self.lines.lock().unwrap().push(record.to_string());
+        self.records.lock().unwrap().push(record.clone());
+        // Sink'in kendi yazdığı kayıt yeniden yayınlanmaz
+        rumt::log(Level::Error, "sink", "forwarded").await;
+    }
+}
+
+rumt::event_handlers! {
+    LogSink;
+    RuntimeEvent::Static { event_name: "rumt.log".into() } => async on_log : LogRecord
+}
+
+pub struct Billing;
+
+impl Billing {
+    pub async fn on_invoice(&self, arg: &TestPayload) {
+        rumt::log(Level::Warn, "billing", format!("invoice {} retried", arg.data)).await;
+    }
+}
+
+rumt::event_handlers! {
+    Billing;
+    RuntimeEvent::Static { event_name: "log.invoice".into() } => async on_invoice : TestPayload
+}
+
+#[tokio::test]
+async fn test_log_records_are_bus_events() {
+    // Runtime yokken yazılan kayıtlar sessizce bırakılır
+    rumt::log(Level::Error, "boot", "too early").await;
+
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("LogApp", "MyCompany", "com")
+        .bus_config(BusConfig { log_level: Level::Info, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let (lines, records) = (Arc::default(), Arc::default());
+    let _sink = LogSink { lines: Arc::clone(&lines), records: Arc::clone(&records) }.try_init().await.unwrap();
+    let _billing = Billing.try_init().await.unwrap();
+
+    rumt::log(Level::Debug, "db", "connection pool warmed").await;
+    rumt::log(Level::Info, "app", "started").await;
+    let invoice = RuntimeEvent::Static { event_name: "log.invoice".into() };
+    let context = Context::with_correlation_id(EventId(7));
+    rumt::context::scope(context, rumt::emit_scoped("acme", invoice, TestPayload { data: "42".into() })).await;
+
+    assert_eq!(*lines.lock().unwrap(), vec!["INFO app: started", "WARN billing: invoice 42 retried"]);
+    let records = records.lock().unwrap().clone();
+    assert_eq!(records[0].correlation_id, None);
+    assert_eq!(records[1].correlation_id, Some(EventId(7)));
+    assert_eq!(records[1].tenant.as_deref(), Some("acme"));
+    assert!(Level::Trace < Level::Warn && Level::Warn < Level::Error);
+
+    rumt::shutdown_runtime().await;
+}
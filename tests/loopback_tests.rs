@@ -0,0 +1,95 @@
+use futures::StreamExt;
+use rumt::Error;
+use rumt::loopback::{LoopbackConfig, LoopbackTransport};
+use rumt::transport::{EventTransport, Frame, FrameFilter, FrameStream, TransportHealth};
+use std::time::{Duration, Instant};
+
+fn frame(event: &str, index: u32) -> Frame {
+    Frame::new(event, index.to_string().into_bytes()).with_header("X-Index", index.to_string())
+}
+
+async fn collect(frames: &mut FrameStream, count: usize) -> Vec<u32> {
+    let mut indices = Vec::new();
+    while indices.len() < count {
+        match tokio::time::timeout(Duration::from_millis(200), frames.next()).await {
+            Ok(Some(frame)) => indices.push(frame.header("x-index").unwrap().parse().unwrap()),
+            _ => break,
+        }
+    }
+    indices
+}
+
+#[tokio::test]
+async fn test_loopback_filters_and_preserves_order() {
+    let transport = LoopbackTransport::new();
+    let mut orders = transport.subscribe(FrameFilter::Prefix("order.".into())).await.unwrap();
+    let mut created = transport.subscribe(FrameFilter::events(["order.created"])).await.unwrap();
+
+    for index in 0..5 {
+        transport.publish(frame("order.created", index)).await.unwrap();
+        transport.publish(frame("user.created", 100 + index)).await.unwrap();
+    }
+    transport.publish(frame("order.shipped", 9)).await.unwrap();
+
+    assert_eq!(collect(&mut orders, 6).await, vec![0, 1, 2, 3, 4, 9]);
+    assert_eq!(collect(&mut created, 10).await, vec![0, 1, 2, 3, 4]);
+    assert_eq!(transport.published(), 11);
+}
+
+#[tokio::test]
+async fn test_loopback_latency_drops_and_reordering() {
+    let transport = LoopbackTransport::with_config(LoopbackConfig {
+        latency: Duration::from_millis(30),
+        ..Default::default()
+    });
+    let mut frames = transport.subscribe(FrameFilter::All).await.unwrap();
+    let started = Instant::now();
+    transport.publish(frame("metrics", 1)).await.unwrap();
+    assert_eq!(collect(&mut frames, 1).await, vec![1]);
+    assert!(started.elapsed() >= Duration::from_millis(30));
+
+    // Tüm frame'ler kaybolur
+    transport.set_config(LoopbackConfig { drop_rate: 1.0, ..Default::default() });
+    for index in 0..10 {
+        transport.publish(frame("metrics", index)).await.unwrap();
+    }
+    assert_eq!(transport.dropped(), 10);
+    assert!(collect(&mut frames, 1).await.is_empty());
+
+    // Geciktirilen frame'ler sonrakilerin arkasına düşer, hiçbiri kaybolmaz
+    transport.set_config(LoopbackConfig {
+        reorder_rate: 0.5,
+        reorder_delay: Duration::from_millis(20),
+        ..Default::default()
+    });
+    for index in 0..20 {
+        transport.publish(frame("metrics", index)).await.unwrap();
+    }
+    let received = collect(&mut frames, 20).await;
+    let mut sorted = received.clone();
+    sorted.sort();
+    assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+    assert_ne!(received, sorted, "sıra bozulmadı");
+}
+
+#[tokio::test]
+async fn test_loopback_disconnect_ends_subscriptions() {
+    let transport = LoopbackTransport::new();
+    let peer = transport.clone();
+    let mut frames = transport.subscribe(FrameFilter::All).await.unwrap();
+
+    peer.publish(frame("ping", 1)).await.unwrap();
+    assert_eq!(collect(&mut frames, 1).await, vec![1]);
+
+    transport.disconnect();
+    assert!(matches!(peer.health(), TransportHealth::Disconnected(_)));
+    assert!(frames.next().await.is_none());
+    assert!(matches!(peer.publish(frame("ping", 2)).await, Err(Error::Transport(_))));
+    assert!(transport.subscribe(FrameFilter::All).await.is_err());
+
+    peer.connect().await.unwrap();
+    assert_eq!(transport.health(), TransportHealth::Connected);
+    let mut frames = transport.subscribe(FrameFilter::All).await.unwrap();
+    peer.publish(frame("ping", 3)).await.unwrap();
+    assert_eq!(collect(&mut frames, 1).await, vec![3]);
+}
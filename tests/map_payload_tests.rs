@@ -0,0 +1,62 @@
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::setup_runtime;
+
+#[derive(Clone, Debug)]
+pub struct RawWebhook {
+    pub customer_id: u64,
+}
+
+#[derive(Debug)]
+pub struct EnrichedWebhook {
+    pub customer_id: u64,
+    pub customer_name: String,
+}
+
+pub struct CrmService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl CrmService {
+    pub async fn on_webhook(&self, arg: &EnrichedWebhook) {
+        self.seen.lock().await.push(format!("{}:{}", arg.customer_id, arg.customer_name));
+    }
+
+    pub async fn on_raw(&self, arg: &RawWebhook) {
+        self.seen.lock().await.push(format!("raw:{}", arg.customer_id));
+    }
+}
+
+rumt::event_handlers! {
+    CrmService;
+    RuntimeEvent::Static { event_name: "map.webhook".into() } => async on_webhook : EnrichedWebhook,
+    RuntimeEvent::Static { event_name: "map.webhook".into() } => async on_raw : RawWebhook
+}
+
+fn webhook() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "map.webhook".into() }
+}
+
+#[tokio::test]
+async fn test_payload_is_mapped_before_listeners() {
+    setup_runtime().await;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    CrmService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    rumt::map_payload(webhook(), |raw: RawWebhook| EnrichedWebhook {
+        customer_id: raw.customer_id,
+        customer_name: format!("customer-{}", raw.customer_id),
+    })
+    .await
+    .unwrap();
+
+    rumt::emit_event(webhook(), RawWebhook { customer_id: 7 }).await;
+    rumt::emit_ref(webhook(), &RawWebhook { customer_id: 8 }).await;
+    // Dönüşümün tipine uymayan payload olduğu gibi iletilir
+    rumt::emit_event(webhook(), EnrichedWebhook { customer_id: 9, customer_name: "direct".into() }).await;
+
+    assert_eq!(*seen.lock().await, vec!["7:customer-7", "8:customer-8", "9:direct"]);
+}
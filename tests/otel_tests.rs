@@ -0,0 +1,107 @@
+#![cfg(feature = "otel")]
+
+use rumt::json::{self, JsonValue};
+use rumt::otel::{OtlpConfig, OtlpExporter};
+use rumt::prelude::*;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{InventoryService, TestPayload, setup_runtime};
+
+/// Gelen OTLP isteklerinin başlığını ve gövdesini kaydeden sahte collector
+async fn collector() -> (String, Arc<Mutex<Vec<(String, String)>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/v1/traces", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&received);
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = Vec::new();
+            let mut chunk = [0u8; 1024];
+            let (head, body) = loop {
+                let read = stream.read(&mut chunk).await.unwrap();
+                buffer.extend_from_slice(&chunk[..read]);
+                let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else { continue };
+                let head = String::from_utf8_lossy(&buffer[..end]).to_string();
+                let length: usize = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map(|value| value.parse().unwrap())
+                    .unwrap_or(0);
+                if buffer.len() >= end + 4 + length {
+                    break (head, String::from_utf8_lossy(&buffer[end + 4..end + 4 + length]).to_string());
+                }
+            };
+            log.lock().await.push((head, body));
+            stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").await.unwrap();
+        }
+    });
+    (url, received)
+}
+
+fn attribute<'a>(span: &'a JsonValue, key: &str) -> Option<&'a str> {
+    let attributes = span.get("attributes")?.as_array()?;
+    let attribute = attributes.iter().find(|a| a.get("key").and_then(JsonValue::as_str) == Some(key))?;
+    attribute.get("value")?.get("stringValue")?.as_str()
+}
+
+#[tokio::test]
+async fn test_spans_are_exported_over_otlp() {
+    setup_runtime().await;
+    let (url, received) = collector().await;
+    let config = OtlpConfig {
+        endpoint: url,
+        service_name: "inventory".into(),
+        headers: vec![("X-Collector-Token".into(), "t0k3n".into())],
+        flush_interval: Duration::from_secs(3600),
+        ..Default::default()
+    };
+    let exporter = OtlpExporter::start("otel-test", config).await.unwrap();
+    let storage = Arc::new(Mutex::new(Vec::new()));
+    let _inventory = InventoryService::new(storage).try_init().await.unwrap();
+
+    rumt::emit_event(RuntimeEvent::Static { event_name: "order.created".into() }, TestPayload { data: "x".into() }).await;
+    assert_eq!(exporter.pending(), 2);
+    exporter.flush().await.unwrap();
+    assert_eq!(exporter.pending(), 0);
+
+    let received = received.lock().await;
+    assert_eq!(received.len(), 1);
+    let (head, body) = &received[0];
+    assert!(head.starts_with("POST /v1/traces HTTP/1.1"));
+    assert!(head.contains("Content-Type: application/json"));
+    assert!(head.contains("X-Collector-Token: t0k3n"));
+
+    let request = json::parse(body).unwrap();
+    let resource = &request.get("resourceSpans").unwrap().as_array().unwrap()[0];
+    let service = resource.get("resource").unwrap();
+    assert_eq!(attribute(service, "service.name"), Some("inventory"));
+    let scope = &resource.get("scopeSpans").unwrap().as_array().unwrap()[0];
+    let spans = scope.get("spans").unwrap().as_array().unwrap();
+    assert_eq!(spans.len(), 2);
+
+    let (publish, process) = (&spans[0], &spans[1]);
+    assert_eq!(publish.get("name").and_then(JsonValue::as_str), Some("order.created publish"));
+    assert_eq!(publish.get("kind").and_then(JsonValue::as_f64), Some(4.0));
+    assert_eq!(process.get("name").and_then(JsonValue::as_str), Some("order.created process"));
+    assert_eq!(process.get("kind").and_then(JsonValue::as_f64), Some(5.0));
+    assert_eq!(attribute(process, "messaging.system"), Some("rumt"));
+    assert_eq!(attribute(process, "messaging.destination.name"), Some("order.created"));
+
+    // Handler span'i emit span'inin çocuğudur; kimlikler OTLP'nin beklediği hex uzunluklarındadır
+    let trace_id = publish.get("traceId").and_then(JsonValue::as_str).unwrap();
+    let span_id = publish.get("spanId").and_then(JsonValue::as_str).unwrap();
+    assert_eq!((trace_id.len(), span_id.len()), (32, 16));
+    assert_eq!(process.get("traceId").and_then(JsonValue::as_str), Some(trace_id));
+    assert_eq!(process.get("parentSpanId").and_then(JsonValue::as_str), Some(span_id));
+    let message_id = attribute(publish, "messaging.message.id").unwrap();
+    assert!(message_id.len() == 32 && message_id.bytes().all(|b| b.is_ascii_hexdigit()));
+    let start: u128 = process.get("startTimeUnixNano").and_then(JsonValue::as_str).unwrap().parse().unwrap();
+    let end: u128 = process.get("endTimeUnixNano").and_then(JsonValue::as_str).unwrap().parse().unwrap();
+    assert!(start <= end && start > 0);
+}
@@ -0,0 +1,88 @@
+use rumt::guard::{GuardAction, PayloadViolation};
+use rumt::prelude::*;
+use rumt::{Error, PayloadGuard};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::setup_runtime;
+
+#[derive(Clone, Debug)]
+pub struct Blob {
+    pub kind: String,
+    pub bytes: Vec<u8>,
+}
+
+pub struct StorageService {
+    pub stored: Arc<Mutex<Vec<usize>>>,
+    pub violations: Arc<Mutex<Vec<PayloadViolation>>>,
+}
+
+impl StorageService {
+    pub fn on_blob(&self, blob: &Blob) {
+        self.stored.lock().unwrap().push(blob.bytes.len());
+    }
+
+    pub fn on_violation(&self, violation: &PayloadViolation) {
+        self.violations.lock().unwrap().push(violation.clone());
+    }
+}
+
+rumt::event_handlers! {
+    StorageService;
+    RuntimeEvent::Static { event_name: "guard.upload".into() } => on_blob : Blob,
+    RuntimeEvent::Static { event_name: "guard.preview".into() } => on_blob : Blob,
+    RuntimeEvent::Static { event_name: "payload.violation".into() } => on_violation : PayloadViolation
+}
+
+fn upload() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "guard.upload".into() }
+}
+
+fn preview() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "guard.preview".into() }
+}
+
+fn blob(kind: &str, size: usize) -> Blob {
+    Blob { kind: kind.into(), bytes: vec![0; size] }
+}
+
+#[tokio::test]
+async fn test_oversized_payloads_are_rejected_or_truncated() {
+    setup_runtime().await;
+    let (stored, violations) = (Arc::default(), Arc::default());
+    let _service = StorageService { stored: Arc::clone(&stored), violations: Arc::clone(&violations) }.try_init().await.unwrap();
+
+    let size = |blob: &Blob| blob.bytes.len();
+    let has_kind = |blob: &Blob| if blob.kind.is_empty() { Err("kind is empty".to_string()) } else { Ok(()) };
+    rumt::guard_payload(upload(), PayloadGuard::new(size).max_size(1024).shape(has_kind)).await.unwrap();
+    rumt::guard_payload(preview(), PayloadGuard::new(size).max_size(16).truncate_with(|blob, max| blob.bytes.truncate(max)))
+        .await
+        .unwrap();
+
+    assert_eq!(rumt::try_emit_event(upload(), blob("png", 512)).await, Ok(()));
+    let rejected = rumt::try_emit_event(upload(), blob("png", 4 << 20)).await;
+    assert!(matches!(rejected, Err(Error::PayloadRejected { ref event, .. }) if event == "guard.upload"));
+    assert!(matches!(rumt::try_emit_event(upload(), blob("", 10)).await, Err(Error::PayloadRejected { .. })));
+    // Başka tipteki payload'lar kontrol edilmez
+    assert_eq!(rumt::try_emit_event(upload(), vec![0u8; 4096]).await, Ok(()));
+
+    rumt::emit_event(preview(), blob("jpg", 100)).await;
+    // `emit_ref` kırpamadığı için sınırı aşan payload'ı reddeder
+    rumt::emit_ref(preview(), &blob("jpg", 100)).await;
+    rumt::emit_ref(preview(), &blob("jpg", 8)).await;
+
+    assert_eq!(*stored.lock().unwrap(), vec![512, 16, 8]);
+    let violations = violations.lock().unwrap();
+    let summary: Vec<_> = violations.iter().map(|v| (v.event.as_str(), v.size, v.action)).collect();
+    assert_eq!(
+        summary,
+        vec![
+            ("guard.upload", 4 << 20, GuardAction::Rejected),
+            ("guard.upload", 10, GuardAction::Rejected),
+            ("guard.preview", 100, GuardAction::Truncated),
+            ("guard.preview", 100, GuardAction::Rejected),
+        ]
+    );
+    assert_eq!(violations[1].reason, "kind is empty");
+    assert_eq!(violations[2].limit, Some(16));
+}
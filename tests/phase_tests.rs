@@ -0,0 +1,112 @@
+use rumt::prelude::*;
+use rumt::{Error, NamePolicy, Phase, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+pub struct PhaseLog {
+    pub phases: Arc<Mutex<Vec<Phase>>>,
+}
+
+impl PhaseLog {
+    pub fn on_phase(&self, phase: &Phase) {
+        self.phases.lock().unwrap().push(*phase);
+    }
+}
+
+rumt::event_handlers! {
+    PhaseLog;
+    Phase::Infrastructure.ready_event() => on_phase : Phase,
+    Phase::Domain.ready_event() => on_phase : Phase,
+    Phase::Api.ready_event() => on_phase : Phase
+}
+
+pub struct OrderDomain;
+
+impl OrderDomain {
+    pub fn on_order(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    OrderDomain;
+    RuntimeEvent::Static { event_name: "phase.order".into() } => on_order : TestPayload
+}
+
+// Ad politikasının dışında kalan bir event'i dinler
+pub struct Misnamed;
+
+impl Misnamed {
+    pub fn on_other(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    Misnamed;
+    RuntimeEvent::Static { event_name: "other.event".into() } => on_other : TestPayload
+}
+
+// Runtime başladıktan sonra bağlanır; altyapı fazının event'ini geçmişten alır
+pub struct LatePhaseLog {
+    pub phases: Arc<Mutex<Vec<Phase>>>,
+}
+
+impl LatePhaseLog {
+    pub fn on_phase(&self, phase: &Phase) {
+        self.phases.lock().unwrap().push(*phase);
+    }
+}
+
+rumt::event_handlers! {
+    LatePhaseLog;
+    Phase::Infrastructure.ready_event() => on_phase : Phase [replay = 1]
+}
+
+#[tokio::test]
+async fn test_deferred_init_runs_phase_by_phase() {
+    let phases = Arc::new(Mutex::new(Vec::new()));
+
+    // Domain servisi önce sıraya alınsa da altyapıdan sonra kaydedilir
+    let domain = OrderDomain.defer_init(Phase::Domain);
+    let log = PhaseLog { phases: Arc::clone(&phases) }.defer_init(Phase::Infrastructure);
+    assert!(!domain.is_registered());
+
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("PhaseApp", "MyCompany", "com")
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    assert!(log.is_registered());
+    assert_eq!(domain.phase(), Phase::Domain);
+    assert_eq!(domain.controller().unwrap().tag(), "OrderDomain");
+    // Altyapı dinleyicisi kendi fazı dahil tüm faz eventlerini gördü
+    assert_eq!(*phases.lock().unwrap(), Phase::ALL.to_vec());
+    rumt::shutdown_runtime().await;
+
+    // Başarısız kayıt sonraki kayıtları ve fazları durdurmaz; ilk hata döner
+    phases.lock().unwrap().clear();
+    let broken = Misnamed.defer_init(Phase::Infrastructure);
+    let log = PhaseLog { phases: Arc::clone(&phases) }.defer_init(Phase::Infrastructure);
+    let api = OrderDomain.defer_init(Phase::Api);
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("PhaseApp", "MyCompany", "com")
+        .name_policy(NamePolicy::new().allow("phase.*"))
+        .try_lock_env().unwrap();
+    let result = try_init_runtime(env).await;
+    assert!(matches!(result, Err(Error::InvalidEventName { ref name, .. }) if name == "other.event"), "{result:?}");
+    assert!(!broken.is_registered());
+    assert!(log.is_registered());
+    assert!(api.is_registered());
+    assert_eq!(*phases.lock().unwrap(), Phase::ALL.to_vec());
+    rumt::shutdown_runtime().await;
+
+    // Sıraya alınmış kayıt olmasa da faz eventleri yayınlanır
+    let late = Arc::new(Mutex::new(Vec::new()));
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("PhaseApp", "MyCompany", "com")
+        .retain_last(Phase::Infrastructure.ready_event(), 1)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let _late = LatePhaseLog { phases: Arc::clone(&late) }.try_init().await.unwrap();
+    assert_eq!(*late.lock().unwrap(), vec![Phase::Infrastructure]);
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,76 @@
+use rumt::prelude::*;
+use rumt::{Error, NamePolicy, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+pub struct ShippingService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl ShippingService {
+    pub fn on_shipped(&self, arg: &TestPayload) {
+        self.seen.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    ShippingService;
+    RuntimeEvent::Static { event_name: "shipping.sent".into() } => on_shipped : TestPayload
+}
+
+// Allow listesindeki namespace'lerin dışında bir event dinleyen servis
+pub struct RogueService;
+
+impl RogueService {
+    pub fn on_anything(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    RogueService;
+    RuntimeEvent::Static { event_name: "misc.thing".into() } => on_anything : TestPayload
+}
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+#[tokio::test]
+async fn test_name_policy_enforced() {
+    let policy = NamePolicy::new()
+        .allow("shipping.*")
+        .validate(|name| match name.split('.').count() {
+            1 => Err("name needs a namespace".into()),
+            _ => Ok(()),
+        });
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("PolicyApp", "MyCompany", "com")
+        .name_policy(policy)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = ShippingService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    // Kayıt anında kontrol
+    assert!(matches!(
+        RogueService.try_init().await,
+        Err(Error::InvalidEventName { name, .. }) if name == "misc.thing"
+    ));
+
+    // Emit anında kontrol
+    rumt::try_emit_event(event("shipping.sent"), TestPayload { data: "ok".into() }).await.unwrap();
+    assert!(matches!(
+        rumt::try_emit_event(event("shipping"), TestPayload { data: "-".into() }).await,
+        Err(Error::InvalidEventName { reason, .. }) if reason == "name does not match any allowed namespace"
+    ));
+    assert!(matches!(
+        rumt::try_emit_event(event("rumt.phase.api"), TestPayload { data: "-".into() }).await,
+        Err(Error::InvalidEventName { reason, .. }) if reason == "name is reserved"
+    ));
+
+    // Hata dönmeyen emit kuralı ihlal eden eventi yayınlamaz
+    rumt::emit_event(event("shipping sent"), TestPayload { data: "boşluk".into() }).await;
+    assert_eq!(*seen.lock().unwrap(), vec!["ok"]);
+}
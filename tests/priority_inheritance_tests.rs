@@ -0,0 +1,101 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Priority, Unlocked, try_init_runtime};
+use std::sync::Arc;
+use tokio::sync::{Mutex, Notify};
+
+#[derive(Debug)]
+pub struct Signal {
+    pub name: String,
+}
+
+/// Alarmın devamındaki bildirimi yayınlayan ve işlenme sırasını kaydeden servis.
+pub struct AlarmPipeline {
+    pub order: Arc<Mutex<Vec<(String, Priority)>>>,
+    pub gate_started: Arc<Notify>,
+    pub gate_release: Arc<Notify>,
+}
+
+impl AlarmPipeline {
+    pub async fn gate(&self, _arg: &Signal) {
+        self.gate_started.notify_one();
+        self.gate_release.notified().await;
+    }
+
+    pub async fn on_alarm(&self, arg: &Signal) {
+        self.record(arg).await;
+        // Öncelik verilmeden yapılan emit alarmın önceliğini devralır
+        rumt::emit_event(notify(), Signal { name: format!("notify-{}", arg.name) }).await;
+    }
+
+    pub async fn record(&self, arg: &Signal) {
+        let priority = rumt::context::current().map(|c| c.priority).unwrap_or_default();
+        self.order.lock().await.push((arg.name.clone(), priority));
+    }
+}
+
+rumt::event_handlers! {
+    AlarmPipeline;
+    RuntimeEvent::Static { event_name: "inherit.gate".into() } => async gate : Signal,
+    RuntimeEvent::Static { event_name: "inherit.bulk".into() } => async record : Signal,
+    RuntimeEvent::Static { event_name: "inherit.alarm".into() } => async on_alarm : Signal,
+    RuntimeEvent::Static { event_name: "inherit.notify".into() } => async record : Signal
+}
+
+fn notify() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "inherit.notify".into() }
+}
+
+fn signal(name: &str) -> Signal {
+    Signal { name: name.into() }
+}
+
+#[tokio::test]
+async fn test_follow_up_emits_inherit_priority() {
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .bus_config(BusConfig {
+            mode: DispatchMode::Queued,
+            workers: 1,
+            inherit_priority: true,
+            ..Default::default()
+        })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let gate_started = Arc::new(Notify::new());
+    let gate_release = Arc::new(Notify::new());
+    let _controller = AlarmPipeline {
+        order: Arc::clone(&order),
+        gate_started: Arc::clone(&gate_started),
+        gate_release: Arc::clone(&gate_release),
+    }
+    .try_init()
+    .await
+    .unwrap();
+
+    // Tek worker meşgulken kuyrukta normal öncelikli yoğun trafik birikir
+    rumt::emit_event(RuntimeEvent::Static { event_name: "inherit.gate".into() }, signal("gate")).await;
+    gate_started.notified().await;
+    for i in 0..3 {
+        rumt::emit_event(RuntimeEvent::Static { event_name: "inherit.bulk".into() }, signal(&format!("bulk-{i}"))).await;
+    }
+    let alarm = RuntimeEvent::Static { event_name: "inherit.alarm".into() };
+    rumt::emit_event_with_priority(alarm, signal("fire"), Priority::High).await;
+
+    gate_release.notify_one();
+
+    tokio::time::timeout(std::time::Duration::from_secs(1), async {
+        while order.lock().await.len() < 5 {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("Kuyruk işlenmedi");
+
+    let order = order.lock().await;
+    let names: Vec<&str> = order.iter().map(|(name, _)| name.as_str()).collect();
+    assert_eq!(names, ["fire", "notify-fire", "bulk-0", "bulk-1", "bulk-2"]);
+    assert_eq!(order[1].1, Priority::High);
+    assert_eq!(order[2].1, Priority::Normal);
+}
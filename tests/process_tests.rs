@@ -0,0 +1,90 @@
+#![cfg(unix)]
+
+use rumt::prelude::*;
+use rumt::Unlocked;
+use rumt::process::{ProcessExited, ProcessLine, ProcessSpec, ProcessStarted, RestartPolicy};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+pub struct SupervisorLog {
+    pub events: Arc<Mutex<Vec<String>>>,
+    pub pids: Arc<Mutex<Vec<u32>>>,
+}
+
+impl SupervisorLog {
+    pub async fn started(&self, arg: &ProcessStarted) {
+        self.events.lock().await.push(format!("started:{}:{}", arg.name, arg.restarts));
+        self.pids.lock().await.extend(arg.pid);
+    }
+
+    pub async fn exited(&self, arg: &ProcessExited) {
+        self.events.lock().await.push(format!("exited:{}:{:?}:{}", arg.name, arg.code, arg.will_restart));
+    }
+
+    pub async fn line(&self, arg: &ProcessLine) {
+        self.events.lock().await.push(format!("line:{}:{}", arg.name, arg.line));
+    }
+}
+
+rumt::event_handlers! {
+    SupervisorLog;
+    RuntimeEvent::Static { event_name: "process.started".into() } => async started : ProcessStarted,
+    RuntimeEvent::Static { event_name: "process.exited".into() } => async exited : ProcessExited,
+    RuntimeEvent::Static { event_name: "process.stdout.line".into() } => async line : ProcessLine
+}
+
+async fn wait_until(events: &Arc<Mutex<Vec<String>>>, done: impl Fn(&[String]) -> bool) {
+    for _ in 0..400 {
+        if done(&events.lock().await) {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+// Dinleyicilerin süreçler başlamadan kaydı için `defer_init` kullanılır
+#[tokio::test]
+async fn test_process_supervisor() {
+    let events = Arc::new(Mutex::new(Vec::new()));
+    let pids = Arc::new(Mutex::new(Vec::new()));
+    SupervisorLog { events: Arc::clone(&events), pids: Arc::clone(&pids) }.defer_init(rumt::Phase::Infrastructure);
+
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .process(
+            ProcessSpec::new("job", "sh")
+                .args(["-c", "echo hello; echo world; exit 3"])
+                .restart(RestartPolicy::OnFailure)
+                .max_restarts(1)
+                .backoff(Duration::from_millis(5)),
+        )
+        .process(ProcessSpec::new("sidecar", "sleep").arg("30").restart(RestartPolicy::Always))
+        .try_lock_env().unwrap();
+    rumt::try_init_runtime(env).await.unwrap();
+
+    wait_until(&events, |e| e.iter().filter(|l| l.starts_with("exited:job")).count() == 2).await;
+    let job: Vec<String> = events.lock().await.iter().filter(|l| l.contains(":job")).cloned().collect();
+    assert_eq!(
+        job,
+        vec![
+            "started:job:0",
+            "line:job:hello",
+            "line:job:world",
+            "exited:job:Some(3):true",
+            "started:job:1",
+            "line:job:hello",
+            "line:job:world",
+            "exited:job:Some(3):false",
+        ]
+    );
+
+    // Shutdown izlenen süreçleri sonlandırır ve yeniden başlatmaz
+    assert!(events.lock().await.contains(&"started:sidecar:0".to_string()));
+    rumt::shutdown_runtime().await;
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(!events.lock().await.iter().any(|l| l.starts_with("exited:sidecar")));
+    for pid in pids.lock().await.iter() {
+        assert!(!std::path::Path::new(&format!("/proc/{pid}")).exists(), "süreç {pid} hâlâ çalışıyor");
+    }
+}
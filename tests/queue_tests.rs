@@ -0,0 +1,90 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Priority, Unlocked, try_init_runtime};
+use std::sync::Arc;
+use tokio::sync::{Mutex, Notify};
+
+#[derive(Debug)]
+pub struct Signal {
+    pub name: String,
+}
+
+/// Kuyruk sırasını kaydeden servis. `gate` handler'ı serbest bırakılana kadar worker'ı meşgul eder.
+pub struct OrderRecorder {
+    pub order: Arc<Mutex<Vec<String>>>,
+    pub gate_started: Arc<Notify>,
+    pub gate_release: Arc<Notify>,
+}
+
+impl OrderRecorder {
+    pub async fn gate(&self, _arg: &Signal) {
+        self.gate_started.notify_one();
+        self.gate_release.notified().await;
+    }
+
+    pub async fn record(&self, arg: &Signal) {
+        self.order.lock().await.push(arg.name.clone());
+    }
+}
+
+rumt::event_handlers! {
+    OrderRecorder;
+    RuntimeEvent::Static { event_name: "queue.gate".into() } => async gate : Signal,
+    RuntimeEvent::Static { event_name: "telemetry.sample".into() } => async record : Signal,
+    RuntimeEvent::Static { event_name: "alarm.triggered".into() } => async record : Signal
+}
+
+fn signal(name: &str) -> Signal {
+    Signal { name: name.into() }
+}
+
+#[tokio::test]
+async fn test_high_priority_preempts_backlog() {
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .bus_config(BusConfig {
+            mode: DispatchMode::Queued,
+            workers: 1,
+            ..Default::default()
+        })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let order = Arc::new(Mutex::new(Vec::new()));
+    let gate_started = Arc::new(Notify::new());
+    let gate_release = Arc::new(Notify::new());
+    let _controller = OrderRecorder {
+        order: Arc::clone(&order),
+        gate_started: Arc::clone(&gate_started),
+        gate_release: Arc::clone(&gate_release),
+    }
+    .try_init()
+    .await
+    .unwrap();
+
+    // Tek worker'ı meşgul et, ardından kuyrukta birikme oluştur
+    rumt::emit_event(RuntimeEvent::Static { event_name: "queue.gate".into() }, signal("gate")).await;
+    gate_started.notified().await;
+
+    for i in 0..3 {
+        let event = RuntimeEvent::Static { event_name: "telemetry.sample".into() };
+        rumt::emit_event_with_priority(event, signal(&format!("telemetry-{i}")), Priority::Low).await;
+    }
+    let alarm = RuntimeEvent::Static { event_name: "alarm.triggered".into() };
+    rumt::emit_event_with_priority(alarm, signal("alarm"), Priority::High).await;
+
+    gate_release.notify_one();
+
+    tokio::time::timeout(std::time::Duration::from_secs(1), async {
+        while order.lock().await.len() < 4 {
+            tokio::task::yield_now().await;
+        }
+    })
+    .await
+    .expect("Kuyruk işlenmedi");
+
+    let order = order.lock().await;
+    assert_eq!(
+        order.as_slice(),
+        ["alarm", "telemetry-0", "telemetry-1", "telemetry-2"]
+    );
+}
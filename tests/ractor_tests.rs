@@ -0,0 +1,103 @@
+#![cfg(feature = "ractor")]
+
+use rumt::prelude::*;
+use rumt::ractor::{RactorRef, subscribe, subscribe_calls};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{Mutex, mpsc, oneshot};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+#[derive(Debug)]
+pub struct PriceQuoted {
+    pub sku: String,
+    pub price: u32,
+}
+
+pub struct QuoteCollector {
+    pub quotes: Arc<Mutex<Vec<(String, u32)>>>,
+}
+
+impl QuoteCollector {
+    pub async fn on_quoted(&self, arg: &PriceQuoted) {
+        self.quotes.lock().await.push((arg.sku.clone(), arg.price));
+    }
+}
+
+rumt::event_handlers! {
+    QuoteCollector;
+    RuntimeEvent::Static { event_name: "ractor.pricing.quoted".into() } => async on_quoted : PriceQuoted
+}
+
+// ractor'da cevap kanalı `RpcReplyPort<PriceQuoted>` olurdu
+enum PricingMsg {
+    Invalidate(String),
+    Quote { sku: String, reply: oneshot::Sender<PriceQuoted> },
+}
+
+/// ractor `ActorRef<PricingMsg>`'in yerine geçen sahte referans
+#[derive(Clone)]
+struct FakeRef(mpsc::UnboundedSender<PricingMsg>);
+
+impl RactorRef<PricingMsg> for FakeRef {
+    fn send_message(&self, message: PricingMsg) -> Result<(), String> {
+        self.0.send(message).map_err(|_| "actor stopped".to_string())
+    }
+}
+
+#[tokio::test]
+async fn test_ractor_adapter_casts_and_calls() {
+    setup_runtime().await;
+    let quotes = Arc::new(Mutex::new(Vec::new()));
+    let _collector = QuoteCollector { quotes: Arc::clone(&quotes) }.try_init().await.unwrap();
+
+    let invalidated = Arc::new(Mutex::new(Vec::new()));
+    let (actor, mut inbox) = mpsc::unbounded_channel();
+    let seen = Arc::clone(&invalidated);
+    // "slow-" ile başlayan ürünlerde aktör cevap kanalını tutar ama cevap vermez
+    tokio::spawn(async move {
+        let mut held = Vec::new();
+        while let Some(message) = inbox.recv().await {
+            match message {
+                PricingMsg::Invalidate(sku) => seen.lock().await.push(sku),
+                PricingMsg::Quote { sku, reply } if sku.starts_with("slow-") => held.push(reply),
+                PricingMsg::Quote { sku, reply } => {
+                    let _ = reply.send(PriceQuoted { price: 99, sku });
+                }
+            }
+        }
+    });
+    let actor = FakeRef(actor);
+
+    let changed = RuntimeEvent::Static { event_name: "ractor.catalog.changed".into() };
+    let requested = RuntimeEvent::Static { event_name: "ractor.pricing.requested".into() };
+    let quoted = RuntimeEvent::Static { event_name: "ractor.pricing.quoted".into() };
+    let _cast = subscribe("catalog", changed.clone(), actor.clone(), |p: Arc<TestPayload>| {
+        PricingMsg::Invalidate(p.data.clone())
+    })
+    .await
+    .unwrap();
+    let calls = subscribe_calls(
+        "pricing",
+        requested.clone(),
+        quoted,
+        actor,
+        |p: Arc<TestPayload>, reply| PricingMsg::Quote { sku: p.data.clone(), reply },
+        Duration::from_millis(50),
+    )
+    .await
+    .unwrap();
+
+    rumt::emit_event(changed, TestPayload { data: "SKU-0".into() }).await;
+    rumt::emit_event(requested.clone(), TestPayload { data: "SKU-1".into() }).await;
+    assert_eq!(*quotes.lock().await, vec![("SKU-1".to_string(), 99)]);
+
+    // Süre sınırı içinde cevap gelmezse yayın yapılmaz
+    rumt::emit_event(requested.clone(), TestPayload { data: "slow-sku".into() }).await;
+    assert_eq!(quotes.lock().await.len(), 1);
+    assert_eq!(*invalidated.lock().await, vec!["SKU-0".to_string()]);
+
+    calls.unsubscribe().await;
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,78 @@
+use rumt::event_bus::{RuntimeEventBus, RuntimeEventListener};
+use rumt::futures::future::BoxFuture;
+use rumt::prelude::*;
+use rumt::{Error, NamePolicy, try_init_runtime};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+
+mod common;
+use common::TestPayload;
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+// Plugin'in keşfettiği bir handler: her çağrıda sayacı artırır
+fn counting_listener(tag: &str, calls: &Arc<AtomicUsize>) -> RuntimeEventListener {
+    let calls = Arc::clone(calls);
+    RuntimeEventListener::new(
+        tag,
+        Box::new(move |arg: &dyn RuntimeEventListenerHandlerArg| -> BoxFuture<'static, ()> {
+            if arg.downcast::<Arc<TestPayload>>().is_some() {
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+            Box::pin(async {})
+        }),
+    )
+}
+
+#[tokio::test]
+async fn test_register_many_validates_all_entries_atomically() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("PluginHost", "MyCompany", "com")
+        .name_policy(NamePolicy::new().allow("plugins.*"))
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let calls = Arc::new(AtomicUsize::new(0));
+    let entries = (0..200).map(|i| (event(&format!("plugins.geo.{i}")), counting_listener("GeoPlugin", &calls)));
+    rumt::register_many(entries).await.unwrap();
+
+    rumt::emit_event(event("plugins.geo.7"), TestPayload { data: "x".into() }).await;
+    rumt::emit_event(event("plugins.geo.199"), TestPayload { data: "y".into() }).await;
+    assert_eq!(calls.load(Ordering::SeqCst), 2);
+
+    // Politikaya uymayan tek bir kayıt tüm grubu reddeder
+    let rejected = Arc::new(AtomicUsize::new(0));
+    let entries = vec![
+        (event("plugins.maps.tile"), counting_listener("MapsPlugin", &rejected)),
+        (event("core.shutdown"), counting_listener("MapsPlugin", &rejected)),
+    ];
+    let result = RuntimeEventBus::try_with_instance_mut(|bus| bus.register_many(entries)).await.unwrap();
+    assert!(matches!(result, Err(Error::InvalidEventName { name, .. }) if name == "core.shutdown"));
+    rumt::emit_event(event("plugins.maps.tile"), TestPayload { data: "z".into() }).await;
+    assert_eq!(rejected.load(Ordering::SeqCst), 0);
+
+    // Aynı tag'in aynı event'e ikinci kaydı, grup içinde de bus'ta da tekrar sayılır
+    let entries = vec![
+        (event("plugins.maps.tile"), counting_listener("MapsPlugin", &rejected)),
+        (event("plugins.maps.tile"), counting_listener("MapsPlugin", &rejected)),
+    ];
+    assert_eq!(
+        rumt::register_many(entries).await,
+        Err(Error::DuplicateListener { event: "plugins.maps.tile".into(), tag: "MapsPlugin".into() })
+    );
+    let entries = [(event("plugins.geo.7"), counting_listener("GeoPlugin", &calls))];
+    assert!(matches!(rumt::register_many(entries).await, Err(Error::DuplicateListener { .. })));
+
+    // Farklı tenant'lara bağlı kayıtlar tekrar sayılmaz
+    let entries = vec![
+        (event("plugins.maps.tile"), counting_listener("MapsPlugin", &rejected).tenant("acme")),
+        (event("plugins.maps.tile"), counting_listener("MapsPlugin", &rejected).tenant("globex")),
+    ];
+    rumt::register_many(entries).await.unwrap();
+    rumt::emit_scoped("acme", event("plugins.maps.tile"), TestPayload { data: "a".into() }).await;
+    assert_eq!(rejected.load(Ordering::SeqCst), 1);
+}
@@ -0,0 +1,107 @@
+use rumt::prelude::*;
+use rumt::reload::{CONFIG_CHANGED, CONTROL_RELOAD};
+use rumt::{ConfigChanged, ConfigValues, Error, try_init_runtime};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+// Operatörün değiştirdiği ayar dosyasının yerine geçer
+type Settings = Arc<Mutex<ConfigValues>>;
+
+pub struct Mailer {
+    pub sent: Arc<Mutex<Vec<String>>>,
+}
+
+impl Mailer {
+    pub fn on_signup(&self, arg: &TestPayload) {
+        self.sent.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Mailer;
+    RuntimeEvent::Static { event_name: "reload.signup".into() } => on_signup : TestPayload [enabled_if = "features.email"]
+}
+
+pub struct ConfigWatcher {
+    pub changes: Arc<Mutex<Vec<ConfigChanged>>>,
+}
+
+impl ConfigWatcher {
+    pub fn on_changed(&self, arg: &ConfigChanged) {
+        self.changes.lock().unwrap().push(arg.clone());
+    }
+}
+
+rumt::event_handlers! {
+    ConfigWatcher;
+    RuntimeEvent::Static { event_name: "rumt.config.changed".into() } => on_changed : ConfigChanged
+}
+
+fn env(settings: &Settings) -> rumt::RuntimeModuleEnv<rumt::Locked> {
+    let source = Arc::clone(settings);
+    rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("ReloadApp", "MyCompany", "com")
+        .insert_path("feed", "/srv/feed-a")
+        .config_loader(move || Ok(source.lock().unwrap().clone()))
+        .validate_config(|values| match values.paths.get("feed") {
+            Some(path) if path.starts_with("/srv/") => Ok(()),
+            _ => Err("`feed` must live under /srv".into()),
+        })
+        .try_lock_env().unwrap()
+}
+
+#[tokio::test]
+async fn test_reload_event_applies_loaded_config() {
+    assert_eq!(CONFIG_CHANGED, "rumt.config.changed");
+    let settings = Settings::default();
+    try_init_runtime(env(&settings)).await.unwrap();
+    // Kapatılmadan yapılan ikinci başlatma reddedilir; reload kontrolü bir kez bağlı kalır
+    assert_eq!(try_init_runtime(env(&settings)).await, Err(Error::AlreadyInitialized));
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    let _mailer = Mailer { sent: Arc::clone(&sent) }.try_init().await.unwrap();
+    let _watcher = ConfigWatcher { changes: Arc::clone(&changes) }.try_init().await.unwrap();
+    let signup = || RuntimeEvent::Static { event_name: "reload.signup".into() };
+    let reload = || RuntimeEvent::Static { event_name: CONTROL_RELOAD.into() };
+
+    rumt::emit_event(signup(), TestPayload { data: "ada".into() }).await;
+    assert!(sent.lock().unwrap().is_empty());
+
+    // Operatör ayarları değiştirip reload event'i yayınlar
+    *settings.lock().unwrap() = ConfigValues {
+        flags: HashMap::from([("features.email".to_string(), true)]),
+        paths: HashMap::from([("feed".to_string(), "/srv/feed-b".to_string())]),
+    };
+    rumt::emit_event(reload(), String::from("enable email")).await;
+    rumt::emit_event(signup(), TestPayload { data: "grace".into() }).await;
+    assert_eq!(*sent.lock().unwrap(), vec!["grace"]);
+    assert_eq!(rumt::runtime_env().as_ref().unwrap().paths["feed"], "/srv/feed-b");
+    {
+        let changes = changes.lock().unwrap();
+        assert_eq!(changes.len(), 1);
+        assert_eq!(changes[0].flags.get("features.email"), Some(&true));
+        assert_eq!(changes[0].paths.get("feed").map(String::as_str), Some("/srv/feed-b"));
+    }
+
+    // Değişiklik yoksa bildirim yapılmaz
+    assert!(rumt::reload_config().await.unwrap().is_empty());
+
+    // Doğrulayıcının reddettiği değerler uygulanmaz
+    settings.lock().unwrap().paths.insert("feed".into(), "/tmp/feed".into());
+    assert_eq!(rumt::reload_config().await, Err(Error::Config("`feed` must live under /srv".into())));
+    assert_eq!(rumt::runtime_env().as_ref().unwrap().paths["feed"], "/srv/feed-b");
+    assert_eq!(changes.lock().unwrap().len(), 1);
+
+    rumt::shutdown_runtime().await;
+}
+
+#[tokio::test]
+async fn test_init_rejects_invalid_config() {
+    let settings = Settings::default();
+    settings.lock().unwrap().paths.insert("feed".into(), "relative/feed".into());
+    let result = rumt::try_init_runtime(env(&settings)).await;
+    assert_eq!(result, Err(Error::Config("`feed` must live under /srv".into())));
+}
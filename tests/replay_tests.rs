@@ -0,0 +1,48 @@
+use rumt::try_init_runtime;
+use rumt::prelude::*;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+// Sonradan açılan ve geçmiş ilerlemeyi yakalaması gereken dashboard
+pub struct Dashboard {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl Dashboard {
+    pub fn on_progress(&self, arg: &TestPayload) {
+        self.seen.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Dashboard;
+    RuntimeEvent::Static { event_name: "job.progress".into() } => on_progress : TestPayload [replay = 2]
+}
+
+fn progress() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "job.progress".into() }
+}
+
+#[tokio::test]
+async fn test_late_listener_receives_last_payloads() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("ReplayApp", "MyCompany", "com")
+        .retain_last(progress(), 3)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    // Henüz dinleyici yokken yayınlanan ilerleme tampona yazılır; en eskisi taşar
+    for percent in ["10", "20", "30", "40"] {
+        rumt::emit_event(progress(), TestPayload { data: percent.into() }).await;
+    }
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = Dashboard { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    // replay = 2: tampondaki üç payload'ın son ikisi, eskiden yeniye
+    assert_eq!(*seen.lock().unwrap(), vec!["30", "40"]);
+
+    rumt::emit_event(progress(), TestPayload { data: "50".into() }).await;
+    assert_eq!(*seen.lock().unwrap(), vec!["30", "40", "50"]);
+}
@@ -0,0 +1,47 @@
+use rumt::prelude::*;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+// Handler'ın constructor'da almadığı, çalışma anında çektiği paylaşılan kaynak
+pub struct AuditLog {
+    pub lines: Mutex<Vec<String>>,
+}
+
+pub struct AuditService;
+
+impl AuditService {
+    pub fn audit(&self, arg: &TestPayload) {
+        let log = rumt::resources().get::<AuditLog>().expect("AuditLog kayıtlı olmalı");
+        log.lines.lock().unwrap().push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    AuditService;
+    RuntimeEvent::Static { event_name: "resources.audit".into() } => audit : TestPayload
+}
+
+#[tokio::test]
+async fn test_handlers_fetch_resources() {
+    setup_runtime().await;
+
+    let log = Arc::new(AuditLog { lines: Mutex::new(Vec::new()) });
+    assert!(rumt::resources().insert_arc(Arc::clone(&log)).is_none());
+    assert!(rumt::resources().contains::<AuditLog>());
+    assert_eq!(
+        rumt::resources().try_get::<String>().err(),
+        Some(rumt::Error::MissingResource("alloc::string::String"))
+    );
+
+    let _controller = AuditService.try_init().await.unwrap();
+    let event = RuntimeEvent::Static { event_name: "resources.audit".into() };
+    rumt::emit_event(event, TestPayload { data: "giriş".into() }).await;
+
+    assert_eq!(*log.lines.lock().unwrap(), vec!["giriş"]);
+
+    // Aynı tipte yeni kayıt eskisini döner
+    let previous = rumt::resources().insert(AuditLog { lines: Mutex::new(Vec::new()) });
+    assert!(previous.is_some_and(|p| Arc::ptr_eq(&p, &log)));
+}
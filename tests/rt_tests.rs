@@ -0,0 +1,51 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Unlocked, try_init_runtime};
+use std::sync::{
+    Arc,
+    atomic::{AtomicUsize, Ordering},
+};
+use tokio::sync::Notify;
+
+static SPAWNED: AtomicUsize = AtomicUsize::new(0);
+
+pub struct Pinger {
+    pub done: Arc<Notify>,
+}
+
+impl Pinger {
+    pub async fn on_ping(&self, _arg: &u32) {
+        self.done.notify_one();
+    }
+}
+
+rumt::event_handlers! {
+    Pinger;
+    RuntimeEvent::Static { event_name: "rt.ping".into() } => async on_ping : u32
+}
+
+#[tokio::test]
+async fn test_workers_use_installed_spawner() {
+    assert!(rumt::rt::set_spawner(|task| {
+        SPAWNED.fetch_add(1, Ordering::SeqCst);
+        tokio::spawn(task);
+    }));
+    assert!(!rumt::rt::set_spawner(|_| {}));
+
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .bus_config(BusConfig {
+            mode: DispatchMode::Queued,
+            workers: 2,
+            ..Default::default()
+        })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    assert_eq!(SPAWNED.load(Ordering::SeqCst), 2);
+
+    let done = Arc::new(Notify::new());
+    let _controller = Pinger { done: Arc::clone(&done) }.try_init().await.unwrap();
+    rumt::emit_event(RuntimeEvent::Static { event_name: "rt.ping".into() }, 1u32).await;
+    tokio::time::timeout(std::time::Duration::from_secs(1), done.notified())
+        .await
+        .expect("Worker çalışmadı");
+}
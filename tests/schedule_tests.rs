@@ -0,0 +1,87 @@
+use rumt::clock::ManualClock;
+use rumt::prelude::*;
+use rumt::{RuntimeModuleEnv, try_init_runtime};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+#[derive(Clone)]
+pub struct Mail {
+    pub body: String,
+}
+
+pub struct Outbox {
+    pub sent: Arc<Mutex<Vec<String>>>,
+}
+
+impl Outbox {
+    pub fn on_send(&self, arg: &Mail) {
+        self.sent.lock().unwrap().push(arg.body.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Outbox;
+    RuntimeEvent::Static { event_name: "mail.send".into() } => on_send : Mail,
+    RuntimeEvent::Static { event_name: "jobs.cleanup".into() } => on_send : Mail
+}
+
+fn event(name: &str) -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: name.into() }
+}
+
+fn payload(body: &str) -> Mail {
+    Mail { body: body.into() }
+}
+
+async fn wait_until(condition: impl Fn() -> bool) {
+    for _ in 0..200 {
+        if condition() {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("condition was never met");
+}
+
+#[tokio::test]
+async fn test_cancel_delayed_and_scheduled_emits() {
+    let clock = ManualClock::new();
+    let env = RuntimeModuleEnv::new().add_app_info("ScheduleApp", "MyCompany", "com").clock(clock.clone()).try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let sent = Arc::new(Mutex::new(Vec::new()));
+    let _outbox = Outbox { sent: Arc::clone(&sent) }.try_init().await.unwrap();
+
+    // "Gönderimi geri al": iptal edilen emit hiç yapılmaz
+    let undone = rumt::emit_event_after(event("mail.send"), payload("draft"), Duration::from_secs(10)).await.unwrap();
+    let kept = rumt::emit_event_after(event("mail.send"), payload("final"), Duration::from_secs(10)).await.unwrap();
+    assert!(undone.cancel());
+    assert!(!undone.cancel());
+    assert!(!undone.is_pending());
+
+    let scheduler = rumt::scheduler();
+    scheduler.every("nightly-cleanup", Duration::from_secs(20), event("jobs.cleanup"), payload("cleanup")).await.unwrap();
+    scheduler.after("reminder", Duration::from_secs(30), event("mail.send"), payload("reminder")).await.unwrap();
+    assert!(scheduler.is_scheduled("nightly-cleanup").await);
+
+    wait_until(|| clock.sleepers() >= 3).await;
+    clock.advance(Duration::from_secs(10));
+    wait_until(|| !kept.is_pending()).await;
+    wait_until(|| sent.lock().unwrap().len() == 1).await;
+    assert!(!kept.cancel());
+
+    wait_until(|| clock.sleepers() >= 2).await;
+    clock.advance(Duration::from_secs(10));
+    wait_until(|| sent.lock().unwrap().len() == 2).await;
+    assert!(scheduler.cancel("nightly-cleanup").await);
+    assert!(!scheduler.cancel("nightly-cleanup").await);
+    assert!(scheduler.cancel("reminder").await);
+
+    clock.advance(Duration::from_secs(60));
+    tokio::time::sleep(Duration::from_millis(20)).await;
+    assert_eq!(*sent.lock().unwrap(), vec!["final", "cleanup"]);
+    let stats = rumt::bus_stats().await.unwrap();
+    assert_eq!(stats.cancelled, 3);
+    assert!(stats.to_json().contains("\"cancelled\":3"));
+
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,79 @@
+use rumt::prelude::*;
+use rumt::schema::{SchemaError, generate_module};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::setup_runtime;
+
+// generate_module çıktısıyla birebir aynı tanım
+mod events {
+    rumt::define_events! {
+        /// Sipariş oluşturuldu
+        SCHEMA_ORDER_CREATED = "schema.order.created" => SchemaOrder { order_id: u64, note: String },
+        APP_READY = once "schema.app.ready",
+    }
+}
+
+use events::{APP_READY, SCHEMA_ORDER_CREATED, SchemaOrder};
+
+const SCHEMA: &str = r#"{
+  "events": [
+    { "name": "schema.order.created", "const": "SCHEMA_ORDER_CREATED", "doc": "Sipariş oluşturuldu",
+      "payload": { "type": "SchemaOrder", "fields": { "order_id": "u64", "note": "String" } } },
+    { "name": "schema.app.ready", "const": "APP_READY", "once": true }
+  ]
+}"#;
+
+#[test]
+fn test_generate_module_from_json() {
+    let module = generate_module(SCHEMA).unwrap();
+    assert_eq!(
+        module,
+        "// rumt::schema tarafından üretilmiştir, elle düzenlemeyin.\n\n\
+         rumt::define_events! {\n    \
+         /// Sipariş oluşturuldu\n    \
+         SCHEMA_ORDER_CREATED = \"schema.order.created\" => SchemaOrder { order_id: u64, note: String },\n    \
+         APP_READY = once \"schema.app.ready\",\n\
+         }\n"
+    );
+
+    // const verilmezse event adından türetilir
+    let module = generate_module(r#"{ "events": [ { "name": "user.signed-in" } ] }"#).unwrap();
+    assert!(module.contains("USER_SIGNED_IN = \"user.signed-in\","));
+}
+
+#[test]
+fn test_generate_module_rejects_invalid_schema() {
+    assert!(generate_module("{").is_err());
+    assert_eq!(
+        generate_module(r#"{ "events": [ { "name": "a" }, { "name": "b", "const": "A" } ] }"#),
+        Err(SchemaError::new("`b`: duplicate constant `A`"))
+    );
+    assert!(generate_module(r#"{ "events": [ { "name": "x", "payload": { "type": "not a type" } } ] }"#).is_err());
+}
+
+pub struct SchemaService {
+    pub seen: Arc<Mutex<Vec<u64>>>,
+}
+
+impl SchemaService {
+    pub fn on_order(&self, arg: &SchemaOrder) {
+        self.seen.lock().unwrap().push(arg.order_id);
+    }
+}
+
+rumt::event_handlers! {
+    SchemaService;
+    SCHEMA_ORDER_CREATED.event() => on_order : SchemaOrder
+}
+
+#[tokio::test]
+async fn test_event_constants_dispatch() {
+    setup_runtime().await;
+    assert_eq!(APP_READY.event(), RuntimeEvent::OnceTriggered { event_name: "schema.app.ready".into() });
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = SchemaService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    rumt::emit_event(SCHEMA_ORDER_CREATED.into(), SchemaOrder { order_id: 42, note: String::new() }).await;
+    assert_eq!(*seen.lock().unwrap(), vec![42]);
+}
@@ -0,0 +1,98 @@
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::prelude::*;
+use rumt::session::{Session, SessionRecorder, SessionReplayer};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+type Seen = Arc<Mutex<Vec<(Option<String>, String)>>>;
+
+pub struct PaymentService {
+    pub seen: Seen,
+}
+
+impl PaymentService {
+    pub async fn on_checkout(&self, arg: &TestPayload) {
+        let tenant = rumt::context::current().and_then(|c| c.tenant);
+        self.seen.lock().unwrap().push((tenant, arg.data.clone()));
+        // Handler'ın ürettiği emit kaydedilmez, oynatmada yeniden üretilir
+        rumt::emit_event(charged(), TestPayload { data: format!("charged {}", arg.data) }).await;
+    }
+
+    pub fn on_charged(&self, arg: &TestPayload) {
+        self.seen.lock().unwrap().push((None, arg.data.clone()));
+    }
+}
+
+rumt::event_handlers! {
+    PaymentService;
+    RuntimeEvent::Static { event_name: "session.checkout".into() } => async on_checkout : TestPayload,
+    RuntimeEvent::Static { event_name: "session.charged".into() } => on_charged : TestPayload
+}
+
+fn checkout() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "session.checkout".into() }
+}
+
+fn charged() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "session.charged".into() }
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+#[tokio::test]
+async fn test_record_and_replay_session() {
+    setup_runtime().await;
+    let recorded = Seen::default();
+    let _service = PaymentService { seen: Arc::clone(&recorded) }.try_init().await.unwrap();
+
+    let recorder = SessionRecorder::new("incident");
+    recorder.record(checkout(), payload_codec()).await.unwrap();
+    recorder.record(charged(), payload_codec()).await.unwrap();
+    rumt::emit_event(checkout(), TestPayload { data: "cart-1".into() }).await;
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    rumt::emit_scoped("acme", checkout(), TestPayload { data: "cart\t2".into() }).await;
+    let session = recorder.finish().await.unwrap();
+
+    assert_eq!(session.entries.len(), 2);
+    assert_eq!(session.entries[1].tenant.as_deref(), Some("acme"));
+    assert!(session.entries[1].offset - session.entries[0].offset >= Duration::from_millis(40));
+
+    // Dosyaya yazılıp okunan oturum aynı kalır
+    let path = std::env::temp_dir().join(format!("rumt-session-{}.session", std::process::id()));
+    session.save(&path).unwrap();
+    let loaded = Session::load(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, session);
+    assert!(Session::from_text("rumt-session 1\n12\tS\tbroken\n").is_err());
+    assert!(Session::from_text("not a session\n").is_err());
+
+    // Yeni bir runtime'a hızlandırılmış olarak oynatılır
+    rumt::shutdown_runtime().await;
+    setup_runtime().await;
+    let replayed = Seen::default();
+    let _service = PaymentService { seen: Arc::clone(&replayed) }.try_init().await.unwrap();
+
+    let started = Instant::now();
+    let emitted = SessionReplayer::new(loaded)
+        .route(checkout(), payload_codec())
+        .speed(8.0)
+        .run()
+        .await
+        .unwrap();
+    assert_eq!(emitted, 2);
+    assert!(started.elapsed() >= Duration::from_millis(5));
+    assert_eq!(*replayed.lock().unwrap(), *recorded.lock().unwrap());
+    assert_eq!(replayed.lock().unwrap()[2], (Some("acme".into()), "cart\t2".into()));
+}
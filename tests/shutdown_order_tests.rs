@@ -0,0 +1,102 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, DispatchMode, Phase, PhaseStopped, try_init_runtime};
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+type Journal = Arc<Mutex<Vec<String>>>;
+
+// Kapanışta son isteği domain katmanına kuyruk üzerinden bırakan API servisi
+pub struct HttpApi {
+    pub journal: Journal,
+}
+
+impl HttpApi {
+    pub fn on_request(&self, _arg: &TestPayload) {}
+
+    pub async fn close(&self) {
+        self.journal.lock().unwrap().push("api:dispose".into());
+        rumt::emit_event(settle(), TestPayload { data: "last-order".into() }).await;
+    }
+}
+
+rumt::event_handlers! {
+    HttpApi [on_dispose = close];
+    RuntimeEvent::Static { event_name: "shutdown.request".into() } => on_request : TestPayload
+}
+
+pub struct OrderDomain {
+    pub journal: Journal,
+}
+
+impl OrderDomain {
+    pub fn on_settle(&self, arg: &TestPayload) {
+        self.journal.lock().unwrap().push(format!("domain:settle:{}", arg.data));
+    }
+
+    pub async fn close(&self) {
+        self.journal.lock().unwrap().push("domain:dispose".into());
+    }
+}
+
+rumt::event_handlers! {
+    OrderDomain [on_dispose = close];
+    RuntimeEvent::Static { event_name: "shutdown.settle".into() } => on_settle : TestPayload
+}
+
+// Fazların kapanış onaylarını en son kapanan altyapı katmanı dinler
+pub struct Database {
+    pub journal: Journal,
+}
+
+impl Database {
+    pub fn on_stopped(&self, arg: &PhaseStopped) {
+        self.journal.lock().unwrap().push(format!("stopped:{}:{}:{}", arg.phase.name(), arg.services, arg.drained));
+    }
+
+    pub async fn close(&self) {
+        self.journal.lock().unwrap().push("infrastructure:dispose".into());
+    }
+}
+
+rumt::event_handlers! {
+    Database [on_dispose = close];
+    Phase::Api.stopped_event() => on_stopped : PhaseStopped,
+    Phase::Domain.stopped_event() => on_stopped : PhaseStopped
+}
+
+fn settle() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "shutdown.settle".into() }
+}
+
+async fn run_scenario(mode: DispatchMode) -> Vec<String> {
+    let journal = Journal::default();
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("ShutdownApp", "MyCompany", "com")
+        .bus_config(BusConfig { mode, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    // Kayıt sırası kapanış sırasını belirlemez; fazlar tersten kapatılır
+    HttpApi { journal: Arc::clone(&journal) }.try_init_in(Phase::Api).await.unwrap();
+    Database { journal: Arc::clone(&journal) }.try_init_in(Phase::Infrastructure).await.unwrap();
+    OrderDomain { journal: Arc::clone(&journal) }.try_init().await.unwrap();
+
+    rumt::shutdown_runtime().await;
+    journal.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_shutdown_disposes_phases_in_reverse_and_drains_between() {
+    let expected = vec![
+        "api:dispose",
+        "domain:settle:last-order",
+        "stopped:api:1:true",
+        "domain:dispose",
+        "stopped:domain:1:true",
+        "infrastructure:dispose",
+    ];
+    assert_eq!(run_scenario(DispatchMode::Queued).await, expected);
+    assert_eq!(run_scenario(DispatchMode::Deferred).await, expected);
+}
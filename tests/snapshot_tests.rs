@@ -0,0 +1,66 @@
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct LedgerService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl LedgerService {
+    pub async fn record(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    LedgerService;
+    RuntimeEvent::Static { event_name: "snapshot.entry".into() } => async record : TestPayload,
+    RuntimeEvent::Static { event_name: "snapshot.audit".into() } => async record : TestPayload [enabled_if = "snapshot.audit"]
+}
+
+fn entry() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "snapshot.entry".into() }
+}
+
+// Runtime yeniden başlatıldığı için senaryo tek testte çalışır
+#[tokio::test]
+async fn test_registrations_survive_restart() {
+    setup_runtime().await;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let controller = LedgerService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let snapshot = rumt::export_registrations().await.unwrap();
+    let mut registrations = snapshot.registrations();
+    registrations.sort_by(|a, b| a.tag.cmp(&b.tag).then(a.enabled_if.cmp(&b.enabled_if)));
+    assert_eq!(registrations.len(), 2);
+    assert!(registrations.iter().all(|r| r.tag == "LedgerService" && r.instance == Some(controller.instance_id())));
+    assert_eq!(registrations[1].enabled_if.as_deref(), Some("snapshot.audit"));
+
+    rumt::shutdown_runtime().await;
+    rumt::emit_event(entry(), TestPayload { data: "kayıp".into() }).await;
+
+    setup_runtime().await;
+    rumt::import_registrations(snapshot).await.unwrap();
+    rumt::emit_event(entry(), TestPayload { data: "1".into() }).await;
+
+    // Eski controller yeni bus üzerinde çalışmaya devam eder
+    controller.pause();
+    rumt::emit_event(entry(), TestPayload { data: "2".into() }).await;
+    controller.resume();
+
+    // Bekletilen dinleyici de taşınır ve flag açılınca bağlanır
+    rumt::set_flag("snapshot.audit", true).await.unwrap();
+    rumt::emit_event(RuntimeEvent::Static { event_name: "snapshot.audit".into() }, TestPayload { data: "audit".into() }).await;
+
+    // Aynı snapshot ikinci kez bağlanmaz
+    let again = rumt::export_registrations().await.unwrap();
+    rumt::import_registrations(again).await.unwrap();
+    rumt::emit_event(entry(), TestPayload { data: "3".into() }).await;
+
+    controller.dispose().await;
+    rumt::emit_event(entry(), TestPayload { data: "4".into() }).await;
+    assert_eq!(*seen.lock().await, vec!["1", "audit", "3"]);
+}
@@ -0,0 +1,192 @@
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::futures::future::BoxFuture;
+use rumt::loopback::LoopbackTransport;
+use rumt::prelude::*;
+use rumt::spool::{SpoolConfig, SyncReport};
+use rumt::transport::{EventTransport, Frame, FrameFilter, FrameStream, TransportHealth, TransportManager, TransportOptions};
+use rumt::{Error, Result, RetryPolicy};
+use std::sync::Arc;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct Backoffice {
+    pub sales: Arc<Mutex<Vec<String>>>,
+}
+
+impl Backoffice {
+    pub async fn on_sale(&self, sale: &TestPayload) {
+        self.sales.lock().await.push(sale.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Backoffice;
+    RuntimeEvent::Static { event_name: "spool.backoffice.sale".into() } => async on_sale : TestPayload
+}
+
+pub struct SyncLog {
+    pub reports: Arc<Mutex<Vec<(&'static str, SyncReport)>>>,
+}
+
+impl SyncLog {
+    pub async fn on_started(&self, report: &SyncReport) {
+        self.reports.lock().await.push(("started", report.clone()));
+    }
+
+    pub async fn on_completed(&self, report: &SyncReport) {
+        self.reports.lock().await.push(("completed", report.clone()));
+    }
+}
+
+rumt::event_handlers! {
+    SyncLog;
+    RuntimeEvent::Static { event_name: "rumt.sync.started".into() } => async on_started : SyncReport,
+    RuntimeEvent::Static { event_name: "rumt.sync.completed".into() } => async on_completed : SyncReport
+}
+
+// Yalnızca kasanın bağlantısını koparır; arka ofisin aboneliği açık kalır
+struct Uplink {
+    network: LoopbackTransport,
+    online: Arc<AtomicBool>,
+}
+
+impl Uplink {
+    fn check(&self) -> Result<()> {
+        match self.online.load(Ordering::SeqCst) {
+            true => Ok(()),
+            false => Err(Error::Transport("till is offline".into())),
+        }
+    }
+}
+
+impl EventTransport for Uplink {
+    fn name(&self) -> &str {
+        "uplink"
+    }
+
+    fn connect(&self) -> BoxFuture<'_, Result<()>> {
+        match self.check() {
+            Ok(()) => self.network.connect(),
+            Err(e) => Box::pin(async move { Err(e) }),
+        }
+    }
+
+    fn publish(&self, frame: Frame) -> BoxFuture<'_, Result<()>> {
+        match self.check() {
+            Ok(()) => self.network.publish(frame),
+            Err(e) => Box::pin(async move { Err(e) }),
+        }
+    }
+
+    fn subscribe(&self, filter: FrameFilter) -> BoxFuture<'_, Result<FrameStream>> {
+        self.network.subscribe(filter)
+    }
+
+    fn health(&self) -> TransportHealth {
+        self.network.health()
+    }
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+fn retry(backoff: Duration) -> RetryPolicy {
+    RetryPolicy { max_attempts: 3, initial_backoff: backoff, max_backoff: backoff }
+}
+
+async fn wait_until<T>(items: &Arc<Mutex<Vec<T>>>, count: usize) {
+    for _ in 0..400 {
+        if items.lock().await.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+fn hex(text: &str) -> String {
+    text.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[tokio::test]
+async fn test_offline_sales_spool_and_sync_in_order() {
+    setup_runtime().await;
+    let sales = Arc::new(Mutex::new(Vec::new()));
+    let _backoffice = Backoffice { sales: Arc::clone(&sales) }.try_init().await.unwrap();
+    let reports = Arc::new(Mutex::new(Vec::new()));
+    let _log = SyncLog { reports: Arc::clone(&reports) }.try_init().await.unwrap();
+
+    // Önceki çalışmadan kalan biri süresi dolmuş iki satış
+    let path = std::env::temp_dir().join(format!("rumt-spool-{}.spool", std::process::id()));
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    std::fs::write(
+        &path,
+        format!("rumt-spool 1\n1\tpos.sales\t{}\n{now}\tpos.sales\t{}\n", hex("sale-stale"), hex("sale-0")),
+    )
+    .unwrap();
+
+    let network = LoopbackTransport::new();
+    let sale = RuntimeEvent::Static { event_name: "spool.till.sale".into() };
+    let backoffice = TransportManager::with_retry("spool.backoffice", network.clone(), retry(Duration::from_millis(5)));
+    backoffice.route_in("pos.sales", RuntimeEvent::Static { event_name: "spool.backoffice.sale".into() }, payload_codec());
+    backoffice.start().await.unwrap();
+
+    let online = Arc::new(AtomicBool::new(true));
+    let options = TransportOptions {
+        retry: retry(Duration::from_millis(5)),
+        spool: Some(
+            SpoolConfig::new(&path)
+                .events(FrameFilter::events(["pos.sales"]))
+                .max_frames(2)
+                .ttl(Duration::from_secs(3600)),
+        ),
+        ..Default::default()
+    };
+    let uplink = Uplink { network: network.clone(), online: Arc::clone(&online) };
+    let till = TransportManager::with_options("spool.till", uplink, options);
+    till.route_out(sale.clone(), "pos.sales", payload_codec()).await.unwrap();
+    till.start().await.unwrap();
+
+    wait_until(&reports, 2).await;
+    assert_eq!(*sales.lock().await, vec!["sale-0".to_string()]);
+    let startup = SyncReport { transport: "spool.till".into(), pending: 1, sent: 0, expired: 1 };
+    assert_eq!(reports.lock().await[0], ("started", startup));
+    let completed = SyncReport { transport: "spool.till".into(), pending: 0, sent: 1, expired: 1 };
+    assert_eq!(reports.lock().await[1], ("completed", completed));
+
+    // Bağlantı yokken satışlar diske yazılır; dolu spool'a gelen bırakılır
+    online.store(false, Ordering::SeqCst);
+    for data in ["sale-1", "sale-2", "sale-3"] {
+        rumt::emit_event(sale.clone(), TestPayload { data: data.into() }).await;
+    }
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(till.spooled(), 2);
+    assert_eq!(till.dropped(), 1);
+    assert_eq!(std::fs::read_to_string(&path).unwrap().lines().count(), 3);
+    assert_eq!(sales.lock().await.len(), 1);
+
+    // Bağlantı gelince sırayla gönderilir
+    online.store(true, Ordering::SeqCst);
+    wait_until(&sales, 3).await;
+    assert_eq!(*sales.lock().await, vec!["sale-0".to_string(), "sale-1".into(), "sale-2".into()]);
+    wait_until(&reports, 4).await;
+    let reports = reports.lock().await;
+    assert_eq!(reports[2], ("started", SyncReport { transport: "spool.till".into(), pending: 2, sent: 0, expired: 0 }));
+    assert_eq!(reports[3], ("completed", SyncReport { transport: "spool.till".into(), pending: 0, sent: 2, expired: 0 }));
+    assert_eq!(till.spooled(), 0);
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "rumt-spool 1\n");
+
+    let _ = std::fs::remove_file(&path);
+    rumt::shutdown_runtime().await;
+}
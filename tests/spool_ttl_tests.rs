@@ -0,0 +1,91 @@
+use rumt::clock::ManualClock;
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::loopback::LoopbackTransport;
+use rumt::prelude::*;
+use rumt::spool::SpoolConfig;
+use rumt::transport::{TransportManager, TransportOptions};
+use rumt::try_init_runtime;
+use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+use tokio::sync::Mutex;
+
+mod common;
+use common::TestPayload;
+
+pub struct Ledger {
+    pub sales: Arc<Mutex<Vec<String>>>,
+}
+
+impl Ledger {
+    pub async fn on_sale(&self, sale: &TestPayload) {
+        self.sales.lock().await.push(sale.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Ledger;
+    RuntimeEvent::Static { event_name: "spool.ttl.sale".into() } => async on_sale : TestPayload
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+fn hex(text: &str) -> String {
+    text.bytes().map(|byte| format!("{byte:02x}")).collect()
+}
+
+#[tokio::test]
+async fn test_spool_ttl_follows_runtime_clock() {
+    let clock = ManualClock::new();
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("SpoolTtlApp", "MyCompany", "com")
+        .clock(clock.clone())
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let sales = Arc::new(Mutex::new(Vec::new()));
+    let _ledger = Ledger { sales: Arc::clone(&sales) }.try_init().await.unwrap();
+
+    // İkinci satış iki saat sonrasına damgalı; saat ilerletilince yalnızca ilki bir saati aşar
+    let path = std::env::temp_dir().join(format!("rumt-spool-ttl-{}.spool", std::process::id()));
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap().as_millis();
+    let later = now + 2 * 3600 * 1000;
+    std::fs::write(
+        &path,
+        format!("rumt-spool 1\n{now}\tpos.sales\t{}\n{later}\tpos.sales\t{}\n", hex("sale-old"), hex("sale-new")),
+    )
+    .unwrap();
+    clock.advance(Duration::from_secs(90 * 60));
+
+    let network = LoopbackTransport::new();
+    let ledger = TransportManager::new("spool.ttl.ledger", network.clone());
+    ledger.route_in("pos.sales", RuntimeEvent::Static { event_name: "spool.ttl.sale".into() }, payload_codec());
+    ledger.start().await.unwrap();
+    let options = TransportOptions {
+        spool: Some(SpoolConfig::new(&path).ttl(Duration::from_secs(3600))),
+        ..Default::default()
+    };
+    let till = TransportManager::with_options("spool.ttl.till", network, options);
+    till.start().await.unwrap();
+
+    for _ in 0..400 {
+        if till.spooled() == 0 && !sales.lock().await.is_empty() {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    assert_eq!(*sales.lock().await, vec!["sale-new".to_string()]);
+    // Dosya geçici dosya üzerinden yeniden yazılır; geride `.tmp` kalmaz
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "rumt-spool 1\n");
+    assert!(!path.with_file_name(format!("rumt-spool-ttl-{}.spool.tmp", std::process::id())).exists());
+
+    let _ = std::fs::remove_file(&path);
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,66 @@
+use rumt::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct FlakyService {
+    pub seen: Arc<Mutex<Vec<String>>>,
+}
+
+impl FlakyService {
+    pub async fn explode(&self, arg: &TestPayload) {
+        if arg.data == "boom" {
+            panic!("handler hatası");
+        }
+    }
+
+    pub async fn record(&self, arg: &TestPayload) {
+        self.seen.lock().await.push(arg.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    FlakyService;
+    RuntimeEvent::Static { event_name: "stats.work".into() } => async explode : TestPayload,
+    RuntimeEvent::Static { event_name: "stats.work".into() } => async record : TestPayload,
+    RuntimeEvent::OnceTriggered { event_name: "stats.once".into() } => async record : TestPayload
+}
+
+#[tokio::test]
+async fn test_bus_stats() {
+    setup_runtime().await;
+
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _controller = FlakyService { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+    // `init_runtime`'ın yayınladığı faz eventleri de sayılır
+    let before = rumt::bus_stats().await.unwrap();
+
+    let work = || RuntimeEvent::Static { event_name: "stats.work".into() };
+    let once = || RuntimeEvent::OnceTriggered { event_name: "stats.once".into() };
+    rumt::emit_event(work(), TestPayload { data: "ok".into() }).await;
+    // Panic eden handler diğer handler'ların çalışmasını engellemez
+    rumt::emit_event(work(), TestPayload { data: "boom".into() }).await;
+    rumt::emit_event(once(), TestPayload { data: "ilk".into() }).await;
+    rumt::emit_event(once(), TestPayload { data: "geç".into() }).await;
+    rumt::emit_event(RuntimeEvent::Static { event_name: "stats.nobody".into() }, TestPayload { data: "-".into() }).await;
+
+    assert_eq!(*seen.lock().await, vec!["ok", "boom", "ilk"]);
+
+    let stats = rumt::bus_stats().await.unwrap();
+    assert_eq!(stats.emits - before.emits, 5);
+    assert_eq!(stats.failures, 1);
+    assert_eq!(stats.expired, 1);
+    assert_eq!(stats.unhandled - before.unhandled, 2);
+    assert_eq!(stats.latency.handled, 5);
+    assert!(stats.latency.p50 <= stats.latency.p99);
+    assert!(stats.latency.p99 <= stats.latency.max);
+    assert!(stats.listeners.contains(&("stats.work".to_string(), 2)));
+
+    let json = stats.to_json();
+    assert!(json.starts_with("{\"uptime_ms\":"));
+    assert!(json.contains(&format!("\"emits\":{}", stats.emits)));
+    assert!(json.contains("\"stats.work\":2"));
+    assert!(json.ends_with("}}"));
+}
@@ -0,0 +1,121 @@
+#![cfg(feature = "tauri")]
+
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::prelude::*;
+use rumt::tauri::{TauriBridge, TauriEvents};
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+type WebviewHandler = Arc<dyn Fn(String) + Send + Sync>;
+
+/// Tauri `AppHandle`'ının yerine geçen sahte uygulama
+#[derive(Clone, Default)]
+struct FakeApp {
+    emitted: Arc<StdMutex<Vec<(String, String)>>>,
+    listeners: Arc<StdMutex<HashMap<String, Vec<WebviewHandler>>>>,
+}
+
+impl FakeApp {
+    // Tauri dinleyicileri kendi thread'inde çağırır
+    fn webview_emit(&self, event: &str, json: &str) {
+        let handlers = self.listeners.lock().unwrap().get(event).cloned().unwrap_or_default();
+        let json = json.to_string();
+        std::thread::spawn(move || {
+            for handler in handlers {
+                handler(json.clone());
+            }
+        })
+        .join()
+        .unwrap();
+    }
+}
+
+impl TauriEvents for FakeApp {
+    fn emit_json(&self, event: &str, json: String) -> Result<(), String> {
+        self.emitted.lock().unwrap().push((event.to_string(), json));
+        Ok(())
+    }
+
+    fn listen_json(&self, event: &str, handler: Box<dyn Fn(String) + Send + Sync>) {
+        self.listeners.lock().unwrap().entry(event.to_string()).or_default().push(Arc::from(handler));
+    }
+}
+
+pub struct Cart {
+    pub items: Arc<Mutex<Vec<String>>>,
+}
+
+impl Cart {
+    pub async fn on_item_added(&self, item: &TestPayload) {
+        self.items.lock().await.push(item.data.clone());
+    }
+}
+
+rumt::event_handlers! {
+    Cart;
+    RuntimeEvent::Static { event_name: "tauri.cart.item_added".into() } => async on_item_added : TestPayload
+}
+
+// `{"data": "..."}`
+fn json_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(format!("{{\"data\":\"{}\"}}", p.data).into_bytes()),
+        |bytes: &[u8]| {
+            let text = std::str::from_utf8(bytes).map_err(|e| CodecError::new(e.to_string()))?;
+            let value = rumt::json::parse(text).map_err(|e| CodecError::new(e.to_string()))?;
+            let data = value.get("data").and_then(|data| data.as_str()).ok_or_else(|| CodecError::new("missing `data`"))?;
+            Ok(TestPayload { data: data.to_string() })
+        },
+    )
+}
+
+async fn wait_until(items: &Arc<Mutex<Vec<String>>>, count: usize) {
+    for _ in 0..200 {
+        if items.lock().await.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[tokio::test]
+async fn test_tauri_bridge_maps_names_both_directions() {
+    setup_runtime().await;
+    let items = Arc::new(Mutex::new(Vec::new()));
+    let _cart = Cart { items: Arc::clone(&items) }.try_init().await.unwrap();
+
+    let app = FakeApp::default();
+    let bridge = TauriBridge::new("webview", app.clone());
+    let status = RuntimeEvent::Static { event_name: "tauri.order.status".into() };
+    let added = RuntimeEvent::Static { event_name: "tauri.cart.item_added".into() };
+    bridge.emit_to_webview(status.clone(), json_codec()).await.unwrap();
+    bridge.emit_to_webview(added.clone(), json_codec()).await.unwrap();
+    bridge.listen_from_webview(added.clone(), json_codec());
+
+    // Bus -> webview, adlar `:` ile eşlenir
+    rumt::emit_event(status.clone(), TestPayload { data: "shipped".into() }).await;
+    assert_eq!(
+        *app.emitted.lock().unwrap(),
+        vec![("tauri:order:status".to_string(), "{\"data\":\"shipped\"}".to_string())]
+    );
+
+    // Webview -> bus; gelen event webview'e geri yansıtılmaz
+    app.webview_emit("tauri:cart:item_added", "{\"data\":\"sku-42\"}");
+    wait_until(&items, 1).await;
+    assert_eq!(*items.lock().await, vec!["sku-42".to_string()]);
+    assert_eq!(app.emitted.lock().unwrap().len(), 1);
+
+    // Çözülemeyen payload bırakılır; dispose'dan sonra gelenler yok sayılır
+    app.webview_emit("tauri:cart:item_added", "not json");
+    bridge.dispose().await;
+    app.webview_emit("tauri:cart:item_added", "{\"data\":\"sku-43\"}");
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(items.lock().await.len(), 1);
+
+    rumt::shutdown_runtime().await;
+}
@@ -0,0 +1,61 @@
+use rumt::prelude::*;
+use rumt::telemetry::{EmitSpan, HandlerSpan, SpanAttributes, TelemetryObserver};
+use std::sync::{Arc, Mutex as StdMutex};
+use tokio::sync::Mutex;
+
+mod common;
+use common::{InventoryService, TestPayload, setup_runtime};
+
+/// Span isimlerini ve attribute'larını kaydeden gözlemci
+#[derive(Default)]
+struct RecordingObserver {
+    spans: StdMutex<Vec<(String, SpanAttributes)>>,
+}
+
+impl TelemetryObserver for RecordingObserver {
+    fn on_emit(&self, span: &EmitSpan<'_>) {
+        self.spans.lock().unwrap().push((span.span_name(), span.attributes()));
+    }
+
+    fn on_handler(&self, span: &HandlerSpan<'_>) {
+        self.spans.lock().unwrap().push((span.span_name(), span.attributes()));
+    }
+}
+
+fn attribute<'a>(attributes: &'a SpanAttributes, key: &str) -> Option<&'a str> {
+    attributes.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str())
+}
+
+#[tokio::test]
+async fn test_emit_and_handler_spans() {
+    setup_runtime().await;
+
+    let observer = Arc::new(RecordingObserver::default());
+    rumt::add_telemetry_observer(observer.clone()).await;
+
+    let storage = Arc::new(Mutex::new(Vec::new()));
+    let _controller = InventoryService::new(storage).try_init().await.unwrap();
+
+    let event = RuntimeEvent::Static { event_name: "order.created".into() };
+    rumt::emit_event(event, TestPayload { data: "x".into() }).await;
+
+    let spans = observer.spans.lock().unwrap();
+    assert_eq!(spans.len(), 2);
+
+    let (publish_name, publish) = &spans[0];
+    assert_eq!(publish_name, "order.created publish");
+    assert_eq!(attribute(publish, "messaging.system"), Some("rumt"));
+    assert_eq!(attribute(publish, "messaging.destination.name"), Some("order.created"));
+    assert_eq!(attribute(publish, "rumt.listener.count"), Some("1"));
+
+    let (process_name, process) = &spans[1];
+    assert_eq!(process_name, "order.created process");
+    assert_eq!(attribute(process, "messaging.consumer.group.name"), Some("InventoryService"));
+    assert_eq!(
+        attribute(process, "messaging.message.id"),
+        attribute(publish, "messaging.message.id")
+    );
+    // Kimlikler transport başlıklarıyla aynı biçimde, 32 haneli hex olarak yazılır
+    let id = attribute(publish, "messaging.message.id").unwrap();
+    assert!(id.len() == 32 && id.bytes().all(|b| b.is_ascii_hexdigit()), "{id}");
+}
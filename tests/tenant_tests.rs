@@ -0,0 +1,126 @@
+use rumt::{EmitOptions, try_init_runtime};
+use rumt::prelude::*;
+use std::sync::{Arc, Mutex};
+
+mod common;
+use common::TestPayload;
+
+type Seen = Arc<Mutex<Vec<(Option<String>, String)>>>;
+
+// Her tenant için ayrı kaydedilen servis
+pub struct TenantInbox {
+    pub seen: Seen,
+}
+
+impl TenantInbox {
+    pub async fn on_order(&self, arg: &TestPayload) {
+        let tenant = rumt::context::current().and_then(|c| c.tenant);
+        self.seen.lock().unwrap().push((tenant, arg.data.clone()));
+        // Handler içinden yapılan emit tenant'ı devralır
+        rumt::emit_event(invoiced(), TestPayload { data: arg.data.clone() }).await;
+    }
+}
+
+rumt::event_handlers! {
+    TenantInbox;
+    RuntimeEvent::Static { event_name: "tenant.order.created".into() } => async on_order : TestPayload
+}
+
+// Tenant'a bağlı olmayan, tüm tenant'ları gören servis
+pub struct Auditor {
+    pub seen: Seen,
+}
+
+impl Auditor {
+    pub fn on_invoice(&self, arg: &TestPayload) {
+        let tenant = rumt::context::current().and_then(|c| c.tenant);
+        self.seen.lock().unwrap().push((tenant, arg.data.clone()));
+    }
+}
+
+rumt::event_handlers! {
+    Auditor;
+    RuntimeEvent::Static { event_name: "tenant.invoice.created".into() } => on_invoice : TestPayload
+}
+
+// Geçmişi yeniden alan, tenant'a bağlı dashboard
+pub struct TenantDashboard {
+    pub seen: Seen,
+}
+
+impl TenantDashboard {
+    pub fn on_usage(&self, arg: &TestPayload) {
+        let tenant = rumt::context::current().and_then(|c| c.tenant);
+        self.seen.lock().unwrap().push((tenant, arg.data.clone()));
+    }
+}
+
+rumt::event_handlers! {
+    TenantDashboard;
+    RuntimeEvent::Static { event_name: "tenant.usage".into() } => on_usage : TestPayload [replay = 2]
+}
+
+fn order_created() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "tenant.order.created".into() }
+}
+
+fn invoiced() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "tenant.invoice.created".into() }
+}
+
+fn usage() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "tenant.usage".into() }
+}
+
+fn entry(tenant: Option<&str>, data: &str) -> (Option<String>, String) {
+    (tenant.map(str::to_string), data.to_string())
+}
+
+#[tokio::test]
+async fn test_scoped_emits_reach_tenant_and_global_listeners() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("TenantApp", "MyCompany", "com")
+        .retain_last(usage(), 4)
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let acme = Seen::default();
+    let globex = Seen::default();
+    let audit = Seen::default();
+    let _acme = TenantInbox { seen: Arc::clone(&acme) }.try_init_scoped("acme").await.unwrap();
+    let _globex = TenantInbox { seen: Arc::clone(&globex) }.try_init_scoped("globex").await.unwrap();
+    let _auditor = Auditor { seen: Arc::clone(&audit) }.try_init().await.unwrap();
+
+    rumt::emit_scoped("acme", order_created(), TestPayload { data: "a-1".into() }).await;
+    rumt::emit_scoped("globex", order_created(), TestPayload { data: "g-1".into() }).await;
+    rumt::try_emit_scoped("initech", order_created(), TestPayload { data: "i-1".into() }).await.unwrap();
+    // Tenant'sız emit tenant'a bağlı dinleyicilere ulaşmaz
+    rumt::emit_event(order_created(), TestPayload { data: "none".into() }).await;
+
+    assert_eq!(*acme.lock().unwrap(), vec![entry(Some("acme"), "a-1")]);
+    assert_eq!(*globex.lock().unwrap(), vec![entry(Some("globex"), "g-1")]);
+    assert_eq!(*audit.lock().unwrap(), vec![entry(Some("acme"), "a-1"), entry(Some("globex"), "g-1")]);
+
+    // Tenant'a bağlı dinleyici yalnızca kendi tenant'ının geçmişini yeniden alır
+    rumt::emit_scoped("acme", usage(), TestPayload { data: "u-1".into() }).await;
+    rumt::emit_scoped("globex", usage(), TestPayload { data: "u-2".into() }).await;
+    rumt::emit_scoped("acme", usage(), TestPayload { data: "u-3".into() }).await;
+    rumt::emit_event(usage(), TestPayload { data: "u-4".into() }).await;
+
+    let dashboard = Seen::default();
+    let _dashboard = TenantDashboard { seen: Arc::clone(&dashboard) }.try_init_scoped("acme").await.unwrap();
+    assert_eq!(*dashboard.lock().unwrap(), vec![entry(Some("acme"), "u-1"), entry(Some("acme"), "u-3")]);
+
+    let options = EmitOptions { tenant: Some("acme".into()), ..Default::default() };
+    rumt::emit_with(usage(), TestPayload { data: "u-5".into() }, options).await;
+    assert_eq!(dashboard.lock().unwrap().last(), Some(&entry(Some("acme"), "u-5")));
+
+    let registrations = rumt::export_registrations().await.unwrap().registrations();
+    let tenants: Vec<_> = registrations
+        .iter()
+        .filter(|r| r.tag == "TenantInbox")
+        .map(|r| r.tenant.clone())
+        .collect();
+    assert_eq!(tenants.len(), 2);
+    assert!(tenants.contains(&Some("acme".into())) && tenants.contains(&Some("globex".into())));
+}
@@ -0,0 +1,55 @@
+use rumt::prelude::*;
+use rumt::Tick;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+mod common;
+use common::setup_runtime;
+
+pub struct TimerService {
+    pub ticks: Arc<Mutex<Vec<u64>>>,
+}
+
+impl TimerService {
+    pub async fn on_tick(&self, tick: &Tick) {
+        self.ticks.lock().await.push(tick.sequence);
+    }
+}
+
+rumt::event_handlers! {
+    TimerService;
+    RuntimeEvent::Static { event_name: "ticker.10ms".into() } => async on_tick : Tick
+}
+
+async fn settle(ticks: &Arc<Mutex<Vec<u64>>>) -> usize {
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let count = ticks.lock().await.len();
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    assert_eq!(ticks.lock().await.len(), count, "ticker durmadı");
+    count
+}
+
+// Ticker'lar runtime ile birlikte kapatıldığı için senaryo tek testte çalışır
+#[tokio::test]
+async fn test_ticker_lifecycle() {
+    setup_runtime().await;
+    let ticks = Arc::new(Mutex::new(Vec::new()));
+    TimerService { ticks: Arc::clone(&ticks) }.try_init().await.unwrap();
+
+    rumt::start_ticker("ticker.10ms", Duration::from_millis(10)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(75)).await;
+    assert!(rumt::stop_ticker("ticker.10ms").await);
+    assert!(!rumt::stop_ticker("ticker.10ms").await);
+
+    let count = settle(&ticks).await;
+    assert!(count >= 3, "yalnızca {count} tick");
+    assert_eq!(*ticks.lock().await, (1..=count as u64).collect::<Vec<_>>());
+
+    // Shutdown çalışan ticker'ları da durdurur
+    rumt::start_ticker("ticker.10ms", Duration::from_millis(10)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(35)).await;
+    rumt::shutdown_runtime().await;
+    assert!(settle(&ticks).await > count);
+    assert_eq!(rumt::start_ticker("ticker.late", Duration::from_millis(10)).await, Err(rumt::Error::NotInitialized));
+}
@@ -0,0 +1,91 @@
+use rumt::prelude::*;
+use rumt::topology::{EmitEdge, EventNode, SourceNode};
+use rumt::{BusConfig, try_init_runtime};
+use std::time::Duration;
+
+mod common;
+use common::TestPayload;
+
+pub struct CheckoutService;
+
+impl CheckoutService {
+    pub async fn on_checkout(&self, arg: &TestPayload) {
+        rumt::emit_event(order_created(), TestPayload { data: arg.data.clone() }).await;
+    }
+}
+
+rumt::event_handlers! {
+    CheckoutService;
+    RuntimeEvent::Static { event_name: "cart.checkout".into() } => async on_checkout : TestPayload
+}
+
+pub struct StockService;
+
+impl StockService {
+    pub fn on_order(&self, _arg: &TestPayload) {}
+}
+
+rumt::event_handlers! {
+    StockService;
+    RuntimeEvent::Static { event_name: "order.created".into() } => on_order : TestPayload
+}
+
+fn order_created() -> RuntimeEvent {
+    RuntimeEvent::Static { event_name: "order.created".into() }
+}
+
+#[tokio::test]
+async fn test_export_topology_with_emit_counts() {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("TopologyApp", "MyCompany", "com")
+        .bus_config(BusConfig { record_topology: true, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let _checkout = CheckoutService.try_init().await.unwrap();
+    let _stock = StockService.try_init().await.unwrap();
+    rumt::start_ticker("topology.tick", Duration::from_secs(3600)).await.unwrap();
+
+    let checkout = RuntimeEvent::Static { event_name: "cart.checkout".into() };
+    for id in ["1", "2"] {
+        rumt::emit_event(checkout.clone(), TestPayload { data: id.into() }).await;
+    }
+
+    let topology = rumt::export_topology().await.unwrap();
+    assert_eq!(
+        topology.events,
+        vec![
+            EventNode { name: "cart.checkout".into(), listeners: vec!["CheckoutService".into()], emits: Some(2) },
+            EventNode { name: "order.created".into(), listeners: vec!["StockService".into()], emits: Some(2) },
+            // `init_runtime` her fazın sonunda fazın event'ini yayınlar
+            EventNode { name: "rumt.phase.api".into(), listeners: vec![], emits: Some(1) },
+            EventNode { name: "rumt.phase.domain".into(), listeners: vec![], emits: Some(1) },
+            EventNode { name: "rumt.phase.infrastructure".into(), listeners: vec![], emits: Some(1) },
+            EventNode { name: "topology.tick".into(), listeners: vec![], emits: Some(0) },
+        ]
+    );
+    assert_eq!(
+        topology.sources,
+        vec![SourceNode { name: "topology.tick".into(), events: vec!["topology.tick".into()] }]
+    );
+    // Uygulama kodundan yapılan emit'ler sayılır ama kenar üretmez
+    assert_eq!(
+        topology.emits,
+        vec![EmitEdge { source: "CheckoutService".into(), event: "order.created".into(), count: 2 }]
+    );
+
+    let dot = topology.to_dot();
+    assert!(dot.starts_with("digraph rumt {"));
+    assert!(dot.contains(r#""event:order.created" [label="order.created\n2 emits", shape=ellipse];"#));
+    assert!(dot.contains(r#""event:cart.checkout" -> "tag:CheckoutService";"#));
+    assert!(dot.contains(r#""tag:CheckoutService" -> "event:order.created" [label="2"];"#));
+    assert!(dot.contains(r#""source:topology.tick" -> "event:topology.tick" [style=dashed];"#));
+
+    let json = rumt::json::parse(&topology.to_json()).unwrap();
+    let events = json.get("events").and_then(|events| events.as_array()).unwrap();
+    assert_eq!(events[1].get("emits").and_then(|emits| emits.as_f64()), Some(2.0));
+    assert!(topology.to_json().contains(r#"{"source":"CheckoutService","event":"order.created","count":2}"#));
+
+    assert!(rumt::stop_ticker("topology.tick").await);
+    assert!(rumt::export_topology().await.unwrap().sources.is_empty());
+}
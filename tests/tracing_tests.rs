@@ -0,0 +1,117 @@
+#![cfg(feature = "tracing")]
+
+use rumt::prelude::*;
+use rumt::{Context, EmitOptions, EventId, TraceContext, context};
+use rumt::tracing::{Span, SpanKind, SpanSubscriber};
+use std::sync::{Arc, Mutex as StdMutex};
+use std::time::Duration;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+/// Açılan ve kapanan span'leri sırasıyla kaydeden izleyici
+#[derive(Default)]
+struct Recorder {
+    entered: StdMutex<Vec<Span>>,
+    exited: StdMutex<Vec<(String, Option<String>)>>,
+}
+
+impl SpanSubscriber for Recorder {
+    fn enter(&self, span: &Span) {
+        self.entered.lock().unwrap().push(span.clone());
+    }
+
+    fn exit(&self, span: &Span, _elapsed: Duration, error: Option<&str>) {
+        self.exited.lock().unwrap().push((span.name.clone(), error.map(Into::into)));
+    }
+}
+
+pub struct Checkout {
+    pub seen: Arc<StdMutex<Vec<Arc<Span>>>>,
+}
+
+impl Checkout {
+    pub async fn on_order(&self, _order: &TestPayload) {
+        self.seen.lock().unwrap().push(rumt::tracing::current_span().unwrap());
+        rumt::emit_event(RuntimeEvent::Static { event_name: "tracing.order.paid".into() }, TestPayload { data: "paid".into() }).await;
+    }
+
+    pub async fn on_paid(&self, payment: &TestPayload) {
+        if payment.data == "refused" {
+            panic!("card refused");
+        }
+    }
+}
+
+rumt::event_handlers! {
+    Checkout;
+    RuntimeEvent::Static { event_name: "tracing.order.created".into() } => async on_order : TestPayload,
+    RuntimeEvent::Static { event_name: "tracing.order.paid".into() } => async on_paid : TestPayload
+}
+
+#[tokio::test]
+async fn test_spans_wrap_emits_and_handlers() {
+    setup_runtime().await;
+    let recorder = Arc::new(Recorder::default());
+    rumt::tracing::add_subscriber(recorder.clone()).await.unwrap();
+    let seen = Arc::new(StdMutex::new(Vec::new()));
+    let _checkout = Checkout { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let incoming = TraceContext::parse("00-4bf92f3577b34da6a3ce929d0e0e4736-00f067aa0ba902b7-01", None).unwrap();
+    let root = Context::with_correlation_id(EventId(7)).with_trace(incoming);
+    let created = RuntimeEvent::Static { event_name: "tracing.order.created".into() };
+    context::scope(root, rumt::emit_event(created, TestPayload { data: "order-1".into() })).await;
+
+    let entered = recorder.entered.lock().unwrap().clone();
+    let names: Vec<_> = entered.iter().map(|span| (span.kind, span.name.as_str())).collect();
+    assert_eq!(
+        names,
+        vec![
+            (SpanKind::Publish, "tracing.order.created publish"),
+            (SpanKind::Process, "tracing.order.created process"),
+            (SpanKind::Publish, "tracing.order.paid publish"),
+            (SpanKind::Process, "tracing.order.paid process"),
+        ]
+    );
+    // Tüm span'ler gelen trace'e bağlanır; her span bir öncekinin çocuğudur
+    assert!(entered.iter().all(|span| span.trace_id == "4bf92f3577b34da6a3ce929d0e0e4736"));
+    assert_eq!(entered[0].parent_id.as_deref(), Some("00f067aa0ba902b7"));
+    for pair in entered.windows(2) {
+        assert_eq!(pair[1].parent_id.as_ref(), Some(&pair[0].span_id));
+    }
+    // Handler içinden açık span okunabilir; span'ler içten dışa kapanır
+    assert_eq!(seen.lock().unwrap()[0].span_id, entered[1].span_id);
+    let exited: Vec<_> = recorder.exited.lock().unwrap().iter().map(|(name, _)| name.clone()).collect();
+    assert_eq!(
+        exited,
+        vec![
+            "tracing.order.paid process",
+            "tracing.order.paid publish",
+            "tracing.order.created process",
+            "tracing.order.created publish",
+        ]
+    );
+
+    // Trace'i olmayan emit'te kimlikler korelasyon ve event kimliğinden gelir; panic hata olarak kapanır
+    recorder.entered.lock().unwrap().clear();
+    recorder.exited.lock().unwrap().clear();
+    let root = Context::with_correlation_id(EventId(42));
+    let paid = RuntimeEvent::Static { event_name: "tracing.order.paid".into() };
+    context::scope(root, rumt::emit_event(paid, TestPayload { data: "refused".into() })).await;
+    let entered = recorder.entered.lock().unwrap().clone();
+    assert_eq!(entered[0].trace_id, format!("{:032x}", 42));
+    assert_eq!(entered[1].parent_id.as_ref(), Some(&entered[0].span_id));
+    let exited = recorder.exited.lock().unwrap().clone();
+    assert_eq!(exited[0].0, "tracing.order.paid process");
+    assert!(exited[0].1.as_deref().is_some_and(|error| error.contains("card refused")));
+    assert_eq!(exited[1].1, None);
+
+    // Trace kapatılan emit span üretmez
+    recorder.entered.lock().unwrap().clear();
+    let options = EmitOptions { trace: false, ..Default::default() };
+    rumt::emit_with(RuntimeEvent::Static { event_name: "tracing.order.paid".into() }, TestPayload { data: "ok".into() }, options).await;
+    assert!(recorder.entered.lock().unwrap().is_empty());
+    assert!(rumt::tracing::current_span().is_none());
+
+    rumt::shutdown_runtime().await;
+}
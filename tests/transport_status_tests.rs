@@ -0,0 +1,93 @@
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::loopback::LoopbackTransport;
+use rumt::prelude::*;
+use rumt::transport::{LinkState, TransportManager, TransportStatus};
+use rumt::RetryPolicy;
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct HealthCheck {
+    pub seen: Arc<Mutex<Vec<(LinkState, u32)>>>,
+}
+
+impl HealthCheck {
+    pub fn on_status(&self, status: &TransportStatus) {
+        self.seen.lock().unwrap().push((status.state, status.attempt));
+    }
+}
+
+rumt::event_handlers! {
+    HealthCheck;
+    RuntimeEvent::Static { event_name: "rumt.transport.connected".into() } => on_status : TransportStatus,
+    RuntimeEvent::Static { event_name: "rumt.transport.disconnected".into() } => on_status : TransportStatus,
+    RuntimeEvent::Static { event_name: "rumt.transport.retrying".into() } => on_status : TransportStatus
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+async fn wait_for_state(state: LinkState) -> TransportStatus {
+    for _ in 0..200 {
+        let status = rumt::transport_status().await.unwrap().remove(0);
+        if status.state == state {
+            return status;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    panic!("transport never reached {state:?}");
+}
+
+#[tokio::test]
+async fn test_transport_reports_outage_and_recovery() {
+    setup_runtime().await;
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let _health = HealthCheck { seen: Arc::clone(&seen) }.try_init().await.unwrap();
+
+    let network = LoopbackTransport::new();
+    let retry = RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(5),
+        max_backoff: Duration::from_millis(10),
+    };
+    let manager = TransportManager::with_retry("status.west", network.clone(), retry);
+    manager.route_in("orders", RuntimeEvent::Static { event_name: "status.order".into() }, payload_codec());
+    assert!(rumt::transport_status().await.unwrap().is_empty());
+    manager.start().await.unwrap();
+    assert_eq!(rumt::transport_status().await.unwrap(), vec![manager.status()]);
+    assert_eq!(manager.status().state, LinkState::Connected);
+
+    // Broker'a ulaşılamazken abonelik artan aralıklarla yeniden denenir
+    network.set_reachable(false);
+    tokio::time::sleep(Duration::from_millis(40)).await;
+    let retrying = wait_for_state(LinkState::Retrying).await;
+    assert!(retrying.attempt >= 2);
+    assert_eq!(retrying.backoff, Some(Duration::from_millis(10)));
+    assert_eq!(retrying.last_error.as_deref(), Some("transport error: loopback broker is unreachable"));
+
+    network.set_reachable(true);
+    let recovered = wait_for_state(LinkState::Connected).await;
+    assert_eq!(recovered.attempt, 0);
+    assert!(recovered.last_error.is_some());
+
+    let seen = seen.lock().unwrap().clone();
+    assert_eq!(
+        seen[..4],
+        [(LinkState::Connected, 0), (LinkState::Disconnected, 0), (LinkState::Retrying, 1), (LinkState::Retrying, 2)]
+    );
+    assert_eq!(seen.last(), Some(&(LinkState::Connected, 0)));
+    assert_eq!(seen.iter().filter(|(state, _)| *state == LinkState::Disconnected).count(), 1);
+
+    manager.dispose().await;
+    assert!(rumt::transport_status().await.unwrap().is_empty());
+}
@@ -0,0 +1,105 @@
+use rumt::codec::{CodecError, PayloadCodec, codec_fn};
+use rumt::loopback::LoopbackTransport;
+use rumt::prelude::*;
+use rumt::transport::{TransportHealth, TransportManager};
+use rumt::{Context, EventId, RetryPolicy};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+mod common;
+use common::{TestPayload, setup_runtime};
+
+pub struct RemoteOrders {
+    pub received: Arc<Mutex<Vec<(String, EventId)>>>,
+}
+
+impl RemoteOrders {
+    pub async fn on_order(&self, order: &TestPayload) {
+        let correlation_id = rumt::context::current().unwrap().correlation_id;
+        self.received.lock().await.push((order.data.clone(), correlation_id));
+    }
+}
+
+rumt::event_handlers! {
+    RemoteOrders;
+    RuntimeEvent::Static { event_name: "transport.remote.order".into() } => async on_order : TestPayload
+}
+
+fn payload_codec() -> impl PayloadCodec<TestPayload> {
+    codec_fn(
+        |p: &TestPayload| Ok(p.data.as_bytes().to_vec()),
+        |bytes: &[u8]| {
+            String::from_utf8(bytes.to_vec())
+                .map(|data| TestPayload { data })
+                .map_err(|e| CodecError::new(e.to_string()))
+        },
+    )
+}
+
+fn retry() -> RetryPolicy {
+    RetryPolicy {
+        max_attempts: 3,
+        initial_backoff: Duration::from_millis(5),
+        max_backoff: Duration::from_millis(20),
+    }
+}
+
+async fn wait_for(received: &Arc<Mutex<Vec<(String, EventId)>>>, count: usize) {
+    for _ in 0..200 {
+        if received.lock().await.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+// Manager'lar runtime ile birlikte kapatıldığı için senaryo tek testte çalışır
+#[tokio::test]
+async fn test_transport_manager_routes_between_runtimes() {
+    setup_runtime().await;
+    let received = Arc::new(Mutex::new(Vec::new()));
+    RemoteOrders { received: Arc::clone(&received) }.try_init().await.unwrap();
+
+    // İki manager aynı loopback ağını paylaşarak iki ayrı süreci taklit eder
+    let network = LoopbackTransport::new();
+    let local = RuntimeEvent::Static { event_name: "transport.local.order".into() };
+    let remote = RuntimeEvent::Static { event_name: "transport.remote.order".into() };
+
+    let east = TransportManager::with_retry("transport.east", network.clone(), retry());
+    east.route_out(local.clone(), "orders", payload_codec()).await.unwrap();
+    let west = TransportManager::with_retry("transport.west", network.clone(), retry());
+    west.route_in("orders", remote.clone(), payload_codec());
+    // Gelen event aynı manager'a geri yayınlanmaz
+    west.route_out(remote.clone(), "orders", payload_codec()).await.unwrap();
+
+    // Başlatılmadan önce yayınlanan event bağlantı kurulunca gönderilir
+    let context = Context::with_correlation_id(EventId(42));
+    rumt::context::scope(context, rumt::emit_event(local.clone(), TestPayload { data: "order-1".into() })).await;
+    west.start().await.unwrap();
+    east.start().await.unwrap();
+    assert!(east.start().await.is_err());
+
+    wait_for(&received, 1).await;
+    assert_eq!(*received.lock().await, vec![("order-1".to_string(), EventId(42))]);
+    // Gelen yönlendirmesi olan west bağlanınca `rumt.transport.hello` duyurur
+    assert_eq!(network.published(), 2);
+
+    // Bağlantı koptuğunda abonelik yeniden kurulur
+    network.disconnect();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert_eq!(west.health(), TransportHealth::Connected);
+    rumt::emit_event(local.clone(), TestPayload { data: "order-2".into() }).await;
+    wait_for(&received, 2).await;
+    assert_eq!(received.lock().await.len(), 2);
+    assert_eq!(received.lock().await[1].0, "order-2");
+    assert_eq!(east.dropped(), 0);
+
+    // Dispose edilen manager artık yayınlamaz
+    east.dispose().await;
+    rumt::emit_event(local, TestPayload { data: "order-3".into() }).await;
+    tokio::time::sleep(Duration::from_millis(30)).await;
+    assert_eq!(received.lock().await.len(), 2);
+    // order-2 ve west'in yeniden bağlanınca yaptığı duyuru
+    assert_eq!(network.published(), 4);
+}
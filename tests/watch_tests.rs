@@ -0,0 +1,69 @@
+#![cfg(feature = "fs-watch")]
+
+use rumt::prelude::*;
+use rumt::Unlocked;
+use rumt::watch::{FsChange, FsChangeKind};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::Mutex;
+
+pub struct ImportService {
+    pub changes: Arc<Mutex<Vec<FsChange>>>,
+}
+
+impl ImportService {
+    pub async fn on_change(&self, change: &FsChange) {
+        self.changes.lock().await.push(change.clone());
+    }
+}
+
+rumt::event_handlers! {
+    ImportService;
+    RuntimeEvent::Static { event_name: rumt::watch::FS_CHANGED.into() } => async on_change : FsChange
+}
+
+async fn wait_for(changes: &Arc<Mutex<Vec<FsChange>>>, len: usize) -> Vec<FsChange> {
+    for _ in 0..200 {
+        if changes.lock().await.len() >= len {
+            break;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+    std::mem::take(&mut *changes.lock().await)
+}
+
+#[tokio::test]
+async fn test_fs_watcher_reports_changes() {
+    let inbox = std::env::temp_dir().join(format!("rumt-watch-{}", std::process::id()));
+    std::fs::create_dir_all(&inbox).unwrap();
+    std::fs::write(inbox.join("existing.csv"), "a").unwrap();
+
+    let env = rumt::env::RuntimeModuleEnv::<Unlocked>::new()
+        .add_app_info("MyApp", "MyCompany", "com")
+        .insert_path("inbox", inbox.to_str().unwrap())
+        .try_lock_env().unwrap();
+    rumt::try_init_runtime(env).await.unwrap();
+    let changes = Arc::new(Mutex::new(Vec::new()));
+    ImportService { changes: Arc::clone(&changes) }.try_init().await.unwrap();
+    rumt::watch::start_fs_watcher(Duration::from_millis(10)).await.unwrap();
+    tokio::time::sleep(Duration::from_millis(30)).await;
+
+    // Başlangıçta var olan dosya bildirilmez
+    let file = inbox.join("orders.csv");
+    std::fs::write(&file, "1").unwrap();
+    let created = wait_for(&changes, 1).await;
+    assert_eq!(created, vec![FsChange { name: "inbox".into(), path: file.clone(), kind: FsChangeKind::Created }]);
+
+    std::fs::write(&file, "1,2").unwrap();
+    assert_eq!(wait_for(&changes, 1).await[0].kind, FsChangeKind::Modified);
+
+    std::fs::remove_file(&file).unwrap();
+    assert_eq!(wait_for(&changes, 1).await[0].kind, FsChangeKind::Removed);
+
+    assert!(rumt::watch::stop_fs_watcher().await);
+    std::fs::write(inbox.join("late.csv"), "x").unwrap();
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    assert!(changes.lock().await.is_empty());
+
+    std::fs::remove_dir_all(&inbox).unwrap();
+}
@@ -0,0 +1,109 @@
+#![cfg(feature = "webhook")]
+
+use rumt::codec::Utf8Codec;
+use rumt::prelude::*;
+use rumt::reload::CONTROL_RELOAD;
+use rumt::webhook::{WebhookConfig, WebhookPublisher};
+use rumt::{ConfigValues, Error, RetryPolicy, try_init_runtime};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+
+type Settings = Arc<Mutex<ConfigValues>>;
+
+/// İstek gövdelerini kaydeden, `delay` sonra `200` dönen sahte alıcı
+async fn receiver(delay: Duration) -> (String, Arc<Mutex<Vec<String>>>, Arc<AtomicBool>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+    let bodies = Arc::new(Mutex::new(Vec::new()));
+    let answered = Arc::new(AtomicBool::new(false));
+    let (log, done) = (Arc::clone(&bodies), Arc::clone(&answered));
+    tokio::spawn(async move {
+        loop {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = Vec::new();
+            let mut chunk = [0u8; 512];
+            let body = loop {
+                let read = stream.read(&mut chunk).await.unwrap();
+                buffer.extend_from_slice(&chunk[..read]);
+                let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else { continue };
+                let head = String::from_utf8_lossy(&buffer[..end]).to_string();
+                let length: usize = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map(|value| value.parse().unwrap())
+                    .unwrap_or(0);
+                if buffer.len() >= end + 4 + length {
+                    break String::from_utf8_lossy(&buffer[end + 4..end + 4 + length]).to_string();
+                }
+            };
+            log.lock().unwrap().push(body);
+            tokio::time::sleep(delay).await;
+            stream.write_all(b"HTTP/1.1 200 OK\r\nContent-Length: 0\r\n\r\n").await.unwrap();
+            done.store(true, Ordering::SeqCst);
+        }
+    });
+    (url, bodies, answered)
+}
+
+async fn wait_for(items: &Arc<Mutex<Vec<String>>>, count: usize) {
+    for _ in 0..200 {
+        if items.lock().unwrap().len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+// Hedefler env'den okunduğu ve sonda runtime kapatıldığı için senaryo tek testte çalışır
+#[tokio::test]
+async fn test_config_targets_follow_reload_and_drain_on_shutdown() {
+    let (first, first_bodies, _) = receiver(Duration::ZERO).await;
+    let (second, second_bodies, answered) = receiver(Duration::from_millis(100)).await;
+    let settings = Settings::default();
+    let source = Arc::clone(&settings);
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("WebhookApp", "MyCompany", "com")
+        .insert_path("webhooks.orders", first.as_str())
+        .config_loader(move || Ok(source.lock().unwrap().clone()))
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+
+    let config = WebhookConfig {
+        retry: RetryPolicy { max_attempts: 1, ..Default::default() },
+        ..Default::default()
+    };
+    let webhooks = WebhookPublisher::new("webhooks.config", config);
+    let order = RuntimeEvent::Static { event_name: "webhook.config.order".into() };
+    let missing = webhooks.route_config::<String, _>(order.clone(), "webhooks.invoices", Utf8Codec).await;
+    assert!(matches!(missing, Err(Error::Config(_))));
+    webhooks.route_config::<String, _>(order.clone(), "webhooks.orders", Utf8Codec).await.unwrap();
+
+    rumt::emit_event(order.clone(), "order-1".to_string()).await;
+    wait_for(&first_bodies, 1).await;
+    assert_eq!(*first_bodies.lock().unwrap(), vec!["order-1"]);
+
+    // Reload adresi değiştirir; geçersiz adres yüklenirse önceki korunur
+    let reload = || RuntimeEvent::Static { event_name: CONTROL_RELOAD.into() };
+    let set_target = |url: &str| {
+        *settings.lock().unwrap() = ConfigValues {
+            paths: HashMap::from([("webhooks.orders".to_string(), url.to_string())]),
+            ..Default::default()
+        };
+    };
+    set_target(&second);
+    rumt::emit_event(reload(), String::from("move orders")).await;
+    set_target("https://crm.example.com/hooks");
+    rumt::emit_event(reload(), String::from("tls")).await;
+
+    // Yanıtı gecikse de gönderim kapanışta beklenir
+    rumt::emit_event(order, "order-2".to_string()).await;
+    wait_for(&second_bodies, 1).await;
+    rumt::shutdown_runtime().await;
+    assert!(answered.load(Ordering::SeqCst), "kapanış gönderimi beklemedi");
+    assert_eq!(*second_bodies.lock().unwrap(), vec!["order-2"]);
+    assert_eq!(first_bodies.lock().unwrap().len(), 1);
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "webhook")]
+
+use rumt::codec::Utf8Codec;
+use rumt::crypto::{hmac_sha256, sha256, to_hex};
+use rumt::prelude::*;
+use rumt::webhook::{WEBHOOK_FAILED, WebhookConfig, WebhookFailure, WebhookPublisher};
+use rumt::{Error, RetryPolicy};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::io::{AsyncReadExt, AsyncWriteExt};
+use tokio::net::TcpListener;
+use tokio::sync::Mutex;
+
+mod common;
+use common::setup_runtime;
+
+/// Gelen istekleri kaydeden, sıradaki durum kodlarıyla yanıt veren sahte sunucu
+#[derive(Clone, Debug)]
+struct Received {
+    head: String,
+    body: Vec<u8>,
+}
+
+async fn serve(statuses: Vec<u16>) -> (String, Arc<Mutex<Vec<Received>>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+    let received = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&received);
+    tokio::spawn(async move {
+        for status in statuses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buffer = Vec::new();
+            let mut chunk = [0u8; 512];
+            let (head, body) = loop {
+                let read = stream.read(&mut chunk).await.unwrap();
+                buffer.extend_from_slice(&chunk[..read]);
+                let Some(end) = buffer.windows(4).position(|w| w == b"\r\n\r\n") else { continue };
+                let head = String::from_utf8_lossy(&buffer[..end]).to_string();
+                let length: usize = head
+                    .lines()
+                    .find_map(|line| line.strip_prefix("Content-Length: "))
+                    .map(|value| value.parse().unwrap())
+                    .unwrap_or(0);
+                if buffer.len() >= end + 4 + length {
+                    break (head, buffer[end + 4..end + 4 + length].to_vec());
+                }
+            };
+            log.lock().await.push(Received { head, body });
+            let response = format!("HTTP/1.1 {status} X\r\nContent-Length: 0\r\n\r\n");
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+    });
+    (url, received)
+}
+
+pub struct WebhookMonitor {
+    pub failures: Arc<Mutex<Vec<WebhookFailure>>>,
+}
+
+impl WebhookMonitor {
+    pub async fn on_failed(&self, failure: &WebhookFailure) {
+        self.failures.lock().await.push(failure.clone());
+    }
+}
+
+rumt::event_handlers! {
+    WebhookMonitor;
+    RuntimeEvent::Static { event_name: WEBHOOK_FAILED.into() } => async on_failed : WebhookFailure
+}
+
+fn config() -> WebhookConfig {
+    WebhookConfig {
+        secret: Some("s3cr3t".into()),
+        retry: RetryPolicy {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(5),
+            max_backoff: Duration::from_millis(20),
+        },
+        timeout: Duration::from_secs(2),
+        content_type: "text/plain".into(),
+    }
+}
+
+async fn wait_for<T>(items: &Arc<Mutex<Vec<T>>>, count: usize) {
+    for _ in 0..200 {
+        if items.lock().await.len() >= count {
+            return;
+        }
+        tokio::time::sleep(Duration::from_millis(5)).await;
+    }
+}
+
+#[test]
+fn test_known_vectors() {
+    assert_eq!(
+        to_hex(&sha256(b"abc")),
+        "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+    );
+    assert_eq!(
+        to_hex(&sha256(b"")),
+        "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+    );
+    // RFC 4231, test case 2
+    assert_eq!(
+        to_hex(&hmac_sha256(b"Jefe", b"what do ya want for nothing?")),
+        "5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_retries_and_signs() {
+    setup_runtime().await;
+    let (url, received) = serve(vec![503, 200]).await;
+
+    let webhooks = WebhookPublisher::new("webhooks.retry", config());
+    let event = RuntimeEvent::Static { event_name: "webhook.order".into() };
+    webhooks.route::<String, _>(event.clone(), &url, Utf8Codec).await.unwrap();
+
+    rumt::emit_event(event, "order-42".to_string()).await;
+    wait_for(&received, 2).await;
+
+    let received = received.lock().await.clone();
+    assert_eq!(received.len(), 2, "503 yanıtından sonra tekrar denenmedi");
+    let signature = format!("X-Rumt-Signature: sha256={}", to_hex(&hmac_sha256(b"s3cr3t", b"order-42")));
+    for request in &received {
+        assert!(request.head.starts_with("POST /hooks HTTP/1.1"));
+        assert!(request.head.contains("X-Rumt-Event: webhook.order"));
+        assert!(request.head.contains("Content-Type: text/plain"));
+        assert!(request.head.contains(&signature));
+        assert_eq!(request.body, b"order-42");
+    }
+    webhooks.dispose().await;
+}
+
+#[tokio::test]
+async fn test_webhook_permanent_failure_is_reported() {
+    setup_runtime().await;
+    let failures = Arc::new(Mutex::new(Vec::new()));
+    WebhookMonitor { failures: Arc::clone(&failures) }.try_init().await.unwrap();
+    let (url, received) = serve(vec![400]).await;
+
+    let webhooks = WebhookPublisher::new("webhooks.failure", config());
+    let event = RuntimeEvent::Static { event_name: "webhook.invoice".into() };
+    webhooks.route::<String, _>(event.clone(), &url, Utf8Codec).await.unwrap();
+
+    rumt::emit_event(event, "invoice-7".to_string()).await;
+    wait_for(&failures, 1).await;
+
+    // 4xx kalıcı hatadır, tekrar denenmez
+    assert_eq!(received.lock().await.len(), 1);
+    let failures = failures.lock().await.clone();
+    assert_eq!(
+        failures,
+        vec![WebhookFailure {
+            event: "webhook.invoice".into(),
+            url,
+            attempts: 1,
+            error: "http status 400".into(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn test_webhook_rejects_https() {
+    setup_runtime().await;
+    let webhooks = WebhookPublisher::new("webhooks.https", WebhookConfig::default());
+    let event = RuntimeEvent::Static { event_name: "webhook.secure".into() };
+    let result = webhooks.route::<String, _>(event, "https://example.com/hooks", Utf8Codec).await;
+    assert!(matches!(result, Err(Error::Transport(_))));
+}
+
+#[tokio::test]
+async fn test_webhook_rejects_header_injection() {
+    setup_runtime().await;
+    let webhooks = WebhookPublisher::new("webhooks.headers", WebhookConfig::default());
+    let injected = RuntimeEvent::Static { event_name: "webhook.order\r\nX-Admin: 1".into() };
+    let result = webhooks.route::<String, _>(injected, "http://127.0.0.1:9/hooks", Utf8Codec).await;
+    assert!(matches!(result, Err(Error::Config(_))));
+
+    let event = RuntimeEvent::Static { event_name: "webhook.order".into() };
+    let result = webhooks.route::<String, _>(event.clone(), "http://127.0.0.1:9/hooks\r\nX-Admin: 1", Utf8Codec).await;
+    assert!(matches!(result, Err(Error::Transport(_))));
+
+    let config = WebhookConfig { content_type: "text/plain\r\nX-Admin: 1".into(), ..Default::default() };
+    assert!(matches!(config.validate(), Err(Error::Config(_))));
+    let result = WebhookPublisher::new("webhooks.headers", config).route::<String, _>(event, "http://127.0.0.1:9/hooks", Utf8Codec).await;
+    assert!(matches!(result, Err(Error::Config(_))));
+}
@@ -0,0 +1,64 @@
+use rumt::prelude::*;
+use rumt::{BusConfig, YieldPolicy, try_init_runtime};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+mod common;
+use common::TestPayload;
+
+// Handler çalışırken aynı thread'deki diğer task'ın çalışıp çalışmadığını kaydeder
+pub struct FanOut {
+    pub heartbeat: Arc<AtomicBool>,
+    pub seen: Arc<Mutex<Vec<bool>>>,
+    // await etmeden yapılan iş
+    pub work: Duration,
+}
+
+impl FanOut {
+    pub fn on_fan_out(&self, _arg: &TestPayload) {
+        self.seen.lock().unwrap().push(self.heartbeat.load(Ordering::SeqCst));
+        std::thread::sleep(self.work);
+    }
+}
+
+rumt::event_handlers! {
+    FanOut;
+    RuntimeEvent::Static { event_name: "yield.fan_out".into() } => on_fan_out : TestPayload
+}
+
+async fn heartbeat_seen_by(yield_policy: YieldPolicy, work: Duration) -> Vec<bool> {
+    let env = rumt::env::RuntimeModuleEnv::new()
+        .add_app_info("YieldApp", "MyCompany", "com")
+        .bus_config(BusConfig { yield_policy, ..Default::default() })
+        .try_lock_env().unwrap();
+    try_init_runtime(env).await.unwrap();
+    let heartbeat = Arc::new(AtomicBool::new(false));
+    let seen = Arc::new(Mutex::new(Vec::new()));
+    let mut controllers = Vec::new();
+    for _ in 0..4 {
+        let fan_out = FanOut { heartbeat: Arc::clone(&heartbeat), seen: Arc::clone(&seen), work };
+        controllers.push(fan_out.try_init().await.unwrap());
+    }
+
+    // current_thread runtime'da bu task yalnızca emit executor'ı bırakırsa çalışır
+    let beat = Arc::clone(&heartbeat);
+    tokio::spawn(async move { beat.store(true, Ordering::SeqCst) });
+    rumt::emit_event(RuntimeEvent::Static { event_name: "yield.fan_out".into() }, TestPayload { data: "x".into() }).await;
+
+    rumt::shutdown_runtime().await;
+    seen.lock().unwrap().clone()
+}
+
+#[tokio::test]
+async fn test_sequential_dispatch_yields_between_handlers() {
+    // Varsayılan: zincir boyunca executor bırakılmaz
+    assert_eq!(heartbeat_seen_by(YieldPolicy::default(), Duration::ZERO).await, [false; 4]);
+
+    let every_two = YieldPolicy { handlers: Some(2), elapsed: None };
+    assert_eq!(heartbeat_seen_by(every_two, Duration::ZERO).await, [false, false, true, true]);
+
+    // Süre sınırı emit'in başından itibaren sayılır; ilk handler sınırı tek başına aşar
+    let time_slice = YieldPolicy { handlers: None, elapsed: Some(Duration::from_millis(1)) };
+    assert_eq!(heartbeat_seen_by(time_slice, Duration::from_millis(2)).await, [false, true, true, true]);
+}